@@ -0,0 +1,262 @@
+/*!
+ * Streaming NDJSON Batch Verification
+ *
+ * `POST /api/v1/verify/stream` accepts `application/x-ndjson`, one request per
+ * line:
+ *
+ * ```text
+ * {"id":"<correlation id>","proof":{...ComplianceProof...}}
+ * ```
+ *
+ * Each line is verified as soon as it arrives, with at most
+ * `MAX_CONCURRENT_VERIFICATIONS` in flight, and one NDJSON result line is
+ * streamed back per input line in input order. The correlation `id` is copied to
+ * the result so clients can match them up. Malformed lines produce an error
+ * result rather than aborting the batch. A batch stops after
+ * `MAX_BATCH_LINES` lines with one final error result, and the whole batch is
+ * still subject to the global request body limit.
+ */
+
+use axum::{
+    body::{Body, Bytes},
+    extract::State,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Json, Response},
+};
+use futures::stream::{self, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use velocity_crypto_core::blockchain_compliance::{ComplianceProof, VerificationResult};
+
+use crate::AppState;
+
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+/// Proofs verified concurrently per request
+pub const MAX_CONCURRENT_VERIFICATIONS: usize = 8;
+/// Longest accepted input line; bounds buffering of a line without a newline
+const MAX_LINE_BYTES: usize = 256 * 1024;
+/// Most proofs verified per request; bounds the verification work of one batch
+pub const MAX_BATCH_LINES: usize = 10_000;
+
+#[derive(Debug, Deserialize)]
+struct StreamVerifyRequest {
+    id: Option<String>,
+    proof: ComplianceProof,
+}
+
+/// One output line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamVerifyResult {
+    /// Correlation id from the input line, if it could be read
+    pub id: Option<String>,
+    /// 1-based input line number
+    pub line: usize,
+    pub is_valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<VerificationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl StreamVerifyResult {
+    fn error(id: Option<String>, line: usize, error: impl Into<String>) -> Self {
+        Self {
+            id,
+            line,
+            is_valid: false,
+            result: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// POST /api/v1/verify/stream
+pub async fn verify_stream(State(state): State<AppState>, headers: HeaderMap, body: Body) -> Response {
+    let is_ndjson = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with(NDJSON_CONTENT_TYPE));
+    if !is_ndjson {
+        return (
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Json(serde_json::json!({
+                "error": "unsupported_media_type",
+                "message": format!("Expected Content-Type: {}", NDJSON_CONTENT_TYPE),
+            })),
+        )
+            .into_response();
+    }
+
+    let results = limit_lines(ndjson_lines(body.into_data_stream()), MAX_BATCH_LINES)
+        .enumerate()
+        .map(move |(index, line)| verify_line(state.clone(), index + 1, line))
+        .buffered(MAX_CONCURRENT_VERIFICATIONS)
+        .map(|result| {
+            let mut line = serde_json::to_vec(&result).unwrap_or_else(|e| {
+                serde_json::to_vec(&StreamVerifyResult::error(result.id.clone(), result.line, e.to_string()))
+                    .unwrap_or_default()
+            });
+            line.push(b'\n');
+            Ok::<_, std::convert::Infallible>(Bytes::from(line))
+        });
+
+    let mut response = Response::new(Body::from_stream(results));
+    response
+        .headers_mut()
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static(NDJSON_CONTENT_TYPE));
+    response
+}
+
+async fn verify_line(state: AppState, line: usize, input: Result<String, String>) -> StreamVerifyResult {
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => return StreamVerifyResult::error(None, line, e),
+    };
+    let request: StreamVerifyRequest = match serde_json::from_str(&input) {
+        Ok(request) => request,
+        Err(e) => {
+            // Still echo the id if the line is JSON with an id but a bad proof
+            let id = serde_json::from_str::<serde_json::Value>(&input)
+                .ok()
+                .and_then(|value| value.get("id")?.as_str().map(str::to_string));
+            return StreamVerifyResult::error(id, line, format!("Invalid request: {}", e));
+        }
+    };
+
+    // Verification is CPU-bound; keep it off the async workers
+    let engine = state.compliance.clone();
+    let proof = request.proof;
+    let verified = tokio::task::spawn_blocking(move || engine.verify_proof(&proof)).await;
+
+    match verified {
+        Ok(Ok(result)) => StreamVerifyResult {
+            id: request.id,
+            line,
+            is_valid: result.is_valid,
+            result: Some(result),
+            error: None,
+        },
+        Ok(Err(e)) => StreamVerifyResult::error(request.id, line, e.to_string()),
+        Err(e) => {
+            warn!(error = %e, line, "Verification task failed");
+            StreamVerifyResult::error(request.id, line, "Verification task failed")
+        }
+    }
+}
+
+/// Split a byte stream into non-empty lines as chunks arrive
+fn ndjson_lines<S, E>(chunks: S) -> impl Stream<Item = Result<String, String>>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    E: std::fmt::Display,
+{
+    struct LineState<S> {
+        chunks: S,
+        buffer: Vec<u8>,
+        done: bool,
+    }
+
+    let state = LineState {
+        chunks,
+        buffer: Vec::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(newline) = state.buffer.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = state.buffer.drain(..=newline).collect();
+                match decode_line(&line[..newline]) {
+                    Some(line) => return Some((line, state)),
+                    None => continue,
+                }
+            }
+            if state.done {
+                let rest = std::mem::take(&mut state.buffer);
+                return decode_line(&rest).map(|line| (line, state));
+            }
+            if state.buffer.len() > MAX_LINE_BYTES {
+                // Drop the oversized line's buffered prefix and stop reading
+                state.buffer.clear();
+                state.done = true;
+                return Some((Err(format!("Line exceeds {} bytes", MAX_LINE_BYTES)), state));
+            }
+
+            match state.chunks.next().await {
+                Some(Ok(chunk)) => state.buffer.extend_from_slice(&chunk),
+                Some(Err(e)) => {
+                    state.buffer.clear();
+                    state.done = true;
+                    return Some((Err(format!("Failed to read request body: {}", e)), state));
+                }
+                None => state.done = true,
+            }
+        }
+    })
+}
+
+/// Pass through at most `max_lines` lines, then one error in place of the rest
+fn limit_lines<S>(lines: S, max_lines: usize) -> impl Stream<Item = Result<String, String>>
+where
+    S: Stream<Item = Result<String, String>>,
+{
+    lines
+        .take(max_lines + 1)
+        .enumerate()
+        .map(move |(index, line)| {
+            if index < max_lines {
+                line
+            } else {
+                Err(format!("Batch exceeds {} lines", max_lines))
+            }
+        })
+}
+
+/// Decode one line, skipping blank lines
+fn decode_line(line: &[u8]) -> Option<Result<String, String>> {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    if line.iter().all(u8::is_ascii_whitespace) {
+        return None;
+    }
+    Some(String::from_utf8(line.to_vec()).map_err(|_| "Line is not valid UTF-8".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lines_split_across_chunks() {
+        let chunks = stream::iter(vec![
+            Ok::<_, std::convert::Infallible>(Bytes::from_static(b"{\"id\":\"a\"}\n{\"id\"")),
+            Ok(Bytes::from_static(b":\"b\"}\r\n\n")),
+            Ok(Bytes::from_static(b"{\"id\":\"c\"}")),
+        ]);
+
+        let lines: Vec<_> = ndjson_lines(chunks).collect().await;
+        assert_eq!(
+            lines,
+            vec![
+                Ok("{\"id\":\"a\"}".to_string()),
+                Ok("{\"id\":\"b\"}".to_string()),
+                Ok("{\"id\":\"c\"}".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lines_past_the_batch_limit_end_the_stream() {
+        let lines = stream::iter((0..100).map(|i| Ok(i.to_string())));
+
+        let limited: Vec<_> = limit_lines(lines, 3).collect().await;
+        assert_eq!(
+            limited,
+            vec![
+                Ok("0".to_string()),
+                Ok("1".to_string()),
+                Ok("2".to_string()),
+                Err("Batch exceeds 3 lines".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,94 @@
+/*!
+ * Compliance Proof Verification Endpoints
+ *
+ * Exposes the blockchain compliance engine so auditors and partners can verify
+ * a submitted compliance proof without access to the issuing account
+ */
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+};
+use tracing::warn;
+use velocity_crypto_core::blockchain_compliance::{BlockchainComplianceEngine, ComplianceProof};
+
+use crate::errors::{AppError, Result};
+use crate::AppState;
+
+/// Environment variable holding the hex-encoded ed25519 key of the proof issuer
+pub const ISSUER_PUBLIC_KEY_ENV: &str = "VELOCITY_COMPLIANCE_ISSUER_PUBLIC_KEY";
+
+/// Settings for verifying submitted compliance proofs
+#[derive(Debug, Clone)]
+pub struct ComplianceConfig {
+    /// Hex-encoded ed25519 public key of the engine that issues the proofs
+    pub issuer_public_key: String,
+}
+
+impl ComplianceConfig {
+    /// Load from the environment; startup fails if the issuer key is missing or malformed
+    pub fn from_env() -> Result<Self> {
+        let issuer_public_key = std::env::var(ISSUER_PUBLIC_KEY_ENV)
+            .map_err(|_| AppError::Internal(format!("{} is not set", ISSUER_PUBLIC_KEY_ENV)))?;
+        let config = Self { issuer_public_key: issuer_public_key.trim().to_string() };
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        self.verifier().map(|_| ())
+    }
+
+    /// Verify-only engine that checks proofs against the issuer key
+    pub fn verifier(&self) -> Result<BlockchainComplianceEngine> {
+        BlockchainComplianceEngine::verifier(&self.issuer_public_key)
+            .map_err(|e| AppError::Internal(format!("Invalid {}: {}", ISSUER_PUBLIC_KEY_ENV, e)))
+    }
+}
+
+/// POST /api/v1/compliance/verify
+///
+/// Returns the `VerificationResult` with 200 when the proof is valid and 422 when
+/// any verification check fails or the proof cannot be verified at all.
+pub async fn verify_compliance_proof(
+    State(state): State<AppState>,
+    Json(proof): Json<ComplianceProof>,
+) -> Response {
+    // Verification is CPU-bound; keep it off the async workers
+    let engine = state.compliance.clone();
+    let proof_id = proof.id.clone();
+    let verified = tokio::task::spawn_blocking(move || engine.verify_proof(&proof)).await;
+
+    match verified {
+        Ok(Ok(result)) if result.is_valid => (StatusCode::OK, Json(result)).into_response(),
+        Ok(Ok(result)) => {
+            warn!(proof_id = %proof_id, "Compliance proof failed verification");
+            (StatusCode::UNPROCESSABLE_ENTITY, Json(result)).into_response()
+        }
+        Ok(Err(e)) => {
+            warn!(proof_id = %proof_id, error = %e, "Compliance proof could not be verified");
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(serde_json::json!({
+                    "error": "verification_failed",
+                    "message": e.to_string(),
+                    "proof_id": proof_id,
+                })),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            warn!(proof_id = %proof_id, error = %e, "Verification task failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "internal_error",
+                    "message": "Verification task failed",
+                    "proof_id": proof_id,
+                })),
+            )
+                .into_response()
+        }
+    }
+}
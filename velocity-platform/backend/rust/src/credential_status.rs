@@ -0,0 +1,281 @@
+/*!
+ * Credential Status Responder
+ *
+ * OCSP-style online status checks for issued credentials. Relying parties call
+ * `GET /api/v1/credentials/{id}/status` and receive a signed assertion of
+ * `valid`, `revoked`, `expired` or `unknown` with a `producedAt` timestamp.
+ *
+ * - Revocations are kept in a sparse Merkle tree; its root is included in every
+ *   assertion so responses can be tied to a published registry state
+ * - The assertion is signed with the service ed25519 key (the same key published
+ *   at `GET /webhooks/verification`), so a MITM cannot forge a `valid` answer.
+ *   The signed bytes carry their own domain tag, so a webhook signature never
+ *   verifies as a status response or the reverse
+ * - An optional `nonce` query parameter is echoed back to prevent replay of an
+ *   older response
+ */
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Json, Response},
+};
+use chrono::{DateTime, Duration, Utc};
+use data_encoding::HEXLOWER;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use velocity_crypto_core::hash_engine::HashAlgorithm;
+use velocity_crypto_core::sparse_merkle_tree::SparseMerkleTree;
+
+use crate::errors::{AppError, Result};
+use crate::AppState;
+
+/// How long a relying party may cache a status assertion
+pub const STATUS_VALIDITY_SECS: i64 = 300;
+const MAX_NONCE_LENGTH: usize = 128;
+/// Domain tag prefixed to the assertion before signing
+pub const STATUS_SIGNATURE_DOMAIN: &str = "velocity:credential-status:v1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CredentialStatus {
+    Valid,
+    Revoked,
+    Expired,
+    /// The credential was not issued by this registry
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct CredentialRecord {
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revocation_reason: Option<String>,
+}
+
+/// Issued credentials and their revocations
+pub struct CredentialStatusRegistry {
+    records: HashMap<String, CredentialRecord>,
+    revocations: SparseMerkleTree,
+}
+
+impl CredentialStatusRegistry {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            records: HashMap::new(),
+            revocations: SparseMerkleTree::new(HashAlgorithm::Sha256)?,
+        })
+    }
+
+    /// Record a newly issued credential
+    pub fn register(&mut self, credential_id: &str, expires_at: Option<DateTime<Utc>>) {
+        self.records.insert(
+            credential_id.to_string(),
+            CredentialRecord {
+                expires_at,
+                revoked_at: None,
+                revocation_reason: None,
+            },
+        );
+    }
+
+    /// Revoke an issued credential; revocation is permanent
+    pub fn revoke(&mut self, credential_id: &str, reason: &str, revoked_at: DateTime<Utc>) -> Result<()> {
+        let record = self
+            .records
+            .get_mut(credential_id)
+            .ok_or_else(|| AppError::NotFound(format!("Credential {} not found", credential_id)))?;
+        if record.revoked_at.is_some() {
+            return Err(AppError::Conflict(format!("Credential {} is already revoked", credential_id)));
+        }
+
+        let key = self.revocations.derive_key(credential_id.as_bytes())?;
+        self.revocations
+            .insert(key, revoked_at.timestamp().to_be_bytes().to_vec())?;
+        record.revoked_at = Some(revoked_at);
+        record.revocation_reason = Some(reason.to_string());
+        Ok(())
+    }
+
+    /// Status of a credential at `now`; revocation takes precedence over expiry
+    pub fn status_at(&self, credential_id: &str, now: DateTime<Utc>) -> CredentialStatus {
+        match self.records.get(credential_id) {
+            None => CredentialStatus::Unknown,
+            Some(record) if record.revoked_at.is_some() => CredentialStatus::Revoked,
+            Some(record) if record.expires_at.is_some_and(|expires_at| expires_at <= now) => CredentialStatus::Expired,
+            Some(_) => CredentialStatus::Valid,
+        }
+    }
+
+    /// Hex root of the revocation tree
+    pub fn revocation_root(&self) -> String {
+        HEXLOWER.encode(&self.revocations.root())
+    }
+
+    /// Build the unsigned assertion for a credential
+    pub fn assertion(&self, credential_id: &str, nonce: Option<String>, produced_at: DateTime<Utc>) -> StatusAssertion {
+        let status = self.status_at(credential_id, produced_at);
+        let record = self.records.get(credential_id);
+
+        StatusAssertion {
+            credential_id: credential_id.to_string(),
+            status,
+            produced_at,
+            next_update: produced_at + Duration::seconds(STATUS_VALIDITY_SECS),
+            expires_at: record.and_then(|record| record.expires_at),
+            revoked_at: record.and_then(|record| record.revoked_at),
+            revocation_reason: record.and_then(|record| record.revocation_reason.clone()),
+            revocation_root: self.revocation_root(),
+            nonce,
+        }
+    }
+}
+
+/// The signed portion of a status response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusAssertion {
+    pub credential_id: String,
+    pub status: CredentialStatus,
+    pub produced_at: DateTime<Utc>,
+    /// Relying parties should re-check after this time
+    pub next_update: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revocation_reason: Option<String>,
+    pub revocation_root: String,
+    pub nonce: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedStatusResponse {
+    /// Exact JSON bytes covered by the signature
+    pub assertion: String,
+    pub algorithm: String,
+    pub signature: String,
+    pub public_key: String,
+}
+
+impl SignedStatusResponse {
+    pub fn sign(signing_key: &SigningKey, assertion: &StatusAssertion) -> Result<Self> {
+        let assertion = serde_json::to_string(assertion)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize status assertion: {}", e)))?;
+        let signature = signing_key.sign(signed_message(&assertion).as_bytes());
+
+        Ok(Self {
+            assertion,
+            algorithm: "ed25519".to_string(),
+            signature: HEXLOWER.encode(&signature.to_bytes()),
+            public_key: HEXLOWER.encode(signing_key.verifying_key().as_bytes()),
+        })
+    }
+
+    /// Verify against a key obtained out of band and return the parsed assertion.
+    /// The embedded `public_key` is informational only and must not be trusted.
+    pub fn verify(&self, verifying_key: &VerifyingKey) -> Option<StatusAssertion> {
+        let signature_bytes: [u8; 64] = HEXLOWER
+            .decode(self.signature.as_bytes())
+            .ok()?
+            .try_into()
+            .ok()?;
+        verifying_key
+            .verify(signed_message(&self.assertion).as_bytes(), &Signature::from_bytes(&signature_bytes))
+            .ok()?;
+        serde_json::from_str(&self.assertion).ok()
+    }
+}
+
+/// The exact bytes covered by a status response signature
+fn signed_message(assertion: &str) -> String {
+    format!("{}|{}", STATUS_SIGNATURE_DOMAIN, assertion)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StatusQuery {
+    pub nonce: Option<String>,
+}
+
+/// GET /api/v1/credentials/:id/status
+pub async fn credential_status(
+    State(state): State<AppState>,
+    Path(credential_id): Path<String>,
+    Query(query): Query<StatusQuery>,
+) -> Result<Response> {
+    if query.nonce.as_ref().is_some_and(|nonce| nonce.len() > MAX_NONCE_LENGTH) {
+        return Ok((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "invalid_nonce",
+                "message": format!("Nonce must be at most {} characters", MAX_NONCE_LENGTH),
+            })),
+        )
+            .into_response());
+    }
+
+    let assertion = state
+        .credentials
+        .read()
+        .await
+        .assertion(&credential_id, query.nonce, Utc::now());
+    let signed = SignedStatusResponse::sign(state.crypto.signing_key(), &assertion)?;
+
+    // Nonced responses are unique to the caller and must not be served from a cache
+    let cache_control = if assertion.nonce.is_some() {
+        HeaderValue::from_static("no-store")
+    } else {
+        HeaderValue::from_str(&format!("max-age={}", STATUS_VALIDITY_SECS))
+            .map_err(|e| AppError::Internal(e.to_string()))?
+    };
+    let mut response = Json(signed).into_response();
+    response.headers_mut().insert(header::CACHE_CONTROL, cache_control);
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revocation_precedes_expiry_and_changes_root() {
+        let mut registry = CredentialStatusRegistry::new().unwrap();
+        let issued = Utc::now() - Duration::days(30);
+        registry.register("cred-1", Some(issued + Duration::days(1)));
+        let root_before = registry.revocation_root();
+
+        assert_eq!(registry.status_at("cred-1", Utc::now()), CredentialStatus::Expired);
+        registry.revoke("cred-1", "key_compromise", Utc::now()).unwrap();
+        assert_eq!(registry.status_at("cred-1", Utc::now()), CredentialStatus::Revoked);
+        assert_ne!(registry.revocation_root(), root_before);
+
+        assert!(registry.revoke("cred-1", "superseded", Utc::now()).is_err());
+        assert!(registry.revoke("cred-missing", "superseded", Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_modified_assertion_fails_verification() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let mut registry = CredentialStatusRegistry::new().unwrap();
+        registry.register("cred-2", None);
+        registry.revoke("cred-2", "superseded", Utc::now()).unwrap();
+
+        let signed = SignedStatusResponse::sign(&key, &registry.assertion("cred-2", None, Utc::now())).unwrap();
+        assert_eq!(signed.verify(&key.verifying_key()).unwrap().status, CredentialStatus::Revoked);
+
+        let mut forged = signed.clone();
+        forged.assertion = forged.assertion.replace("\"revoked\"", "\"valid\"");
+        assert!(forged.verify(&key.verifying_key()).is_none());
+
+        // A response re-signed by an attacker's key is rejected by the pinned key
+        let attacker = SigningKey::from_bytes(&[9u8; 32]);
+        let assertion: StatusAssertion = serde_json::from_str(&forged.assertion).unwrap();
+        let resigned = SignedStatusResponse::sign(&attacker, &assertion).unwrap();
+        assert!(resigned.verify(&key.verifying_key()).is_none());
+
+        // The service key's signature over the bare assertion, outside the status domain
+        let mut untagged = signed.clone();
+        untagged.signature = HEXLOWER.encode(&key.sign(signed.assertion.as_bytes()).to_bytes());
+        assert!(untagged.verify(&key.verifying_key()).is_none());
+    }
+}
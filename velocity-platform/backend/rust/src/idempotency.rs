@@ -0,0 +1,324 @@
+/*!
+ * Idempotency Keys for Non-Idempotent Endpoints
+ *
+ * Clients may send an `Idempotency-Key` header on retried POSTs. The first
+ * successful response for a key is stored in Redis and replayed for repeats
+ * within the TTL, so a retry never creates a second payment method or
+ * deletion request.
+ *
+ * - Keys are scoped per user and route, and bound to a hash of the request body
+ * - Only 2xx responses are cached; failures may be retried with the same key
+ * - Concurrent duplicates are serialized with a Redis lock: the loser waits for
+ *   the winner's response instead of running the handler again. The winner
+ *   renews the lock while its handler runs, so slow handlers keep it.
+ */
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{MatchedPath, Request, State},
+    http::{header, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use data_encoding::{BASE64, HEXLOWER};
+use redis::AsyncCommands;
+use ring::digest;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::middleware_auth::AuthenticatedUser;
+use crate::AppState;
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+pub const IDEMPOTENT_REPLAY_HEADER: &str = "Idempotent-Replayed";
+/// How long a successful response is replayed for
+pub const RESPONSE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// Lock lifetime between renewals; bounds how long a crashed request can block its key
+const LOCK_TTL: Duration = Duration::from_secs(30);
+/// How often the lock holder extends its lock while the handler runs
+const LOCK_RENEW_INTERVAL: Duration = Duration::from_secs(10);
+/// How long a concurrent duplicate waits for the in-flight request
+const LOCK_WAIT: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+const MAX_KEY_LENGTH: usize = 255;
+/// Matches the router's request body limit
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Successful response stored for replay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredResponse {
+    status: u16,
+    content_type: Option<String>,
+    /// Base64 encoded response body
+    body: String,
+    /// SHA-256 of the request body the key was first used with
+    request_fingerprint: String,
+}
+
+/// Axum middleware; attach with `post(handler).layer(from_fn_with_state(state, idempotency_middleware))`
+pub async fn idempotency_middleware(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let key = match request.headers().get(IDEMPOTENCY_KEY_HEADER) {
+        None => return next.run(request).await,
+        Some(value) => match value.to_str() {
+            Ok(key) if !key.is_empty() && key.len() <= MAX_KEY_LENGTH => key.to_string(),
+            _ => return error_response(StatusCode::BAD_REQUEST, "Invalid Idempotency-Key header"),
+        },
+    };
+
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+    let user = request
+        .extensions()
+        .get::<AuthenticatedUser>()
+        .map(|user| user.user_id.to_string())
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    // Buffer the body so it can be fingerprinted and still passed to the handler
+    let (parts, body) = request.into_parts();
+    let body = match to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(_) => return error_response(StatusCode::PAYLOAD_TOO_LARGE, "Request body too large"),
+    };
+    let fingerprint = request_fingerprint(parts.method.as_str(), &body);
+    let request = Request::from_parts(parts, Body::from(body));
+
+    let base_key = format!("velocity:idempotency:{}:{}:{}", user, route, key);
+    match run_idempotent(&state.redis, &base_key, &fingerprint, request, next).await {
+        Ok(response) => response,
+        Err(e) => {
+            error!(error = %e, route = %route, "Idempotency store unavailable");
+            error_response(StatusCode::SERVICE_UNAVAILABLE, "Idempotency store unavailable, please retry")
+        }
+    }
+}
+
+async fn run_idempotent(
+    redis: &redis::Client,
+    base_key: &str,
+    fingerprint: &str,
+    request: Request,
+    next: Next,
+) -> redis::RedisResult<Response> {
+    let mut conn = redis.get_multiplexed_async_connection().await?;
+    let response_key = format!("{}:response", base_key);
+    let lock_key = format!("{}:lock", base_key);
+    let lock_token = Uuid::new_v4().to_string();
+    let started = Instant::now();
+
+    loop {
+        if let Some(stored) = load_response(&mut conn, &response_key).await? {
+            return Ok(replay(stored, fingerprint));
+        }
+
+        let acquired: bool = redis::cmd("SET")
+            .arg(&lock_key)
+            .arg(&lock_token)
+            .arg("NX")
+            .arg("PX")
+            .arg(LOCK_TTL.as_millis() as u64)
+            .query_async::<_, Option<String>>(&mut conn)
+            .await?
+            .is_some();
+
+        if acquired {
+            break;
+        }
+        if started.elapsed() >= LOCK_WAIT {
+            return Ok(error_response(
+                StatusCode::CONFLICT,
+                "A request with this Idempotency-Key is still in progress",
+            ));
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+
+    let renewal = spawn_lock_renewal(conn.clone(), lock_key.clone(), lock_token.clone());
+
+    // The winner may have finished between our cache check and lock acquisition
+    let outcome = match load_response(&mut conn, &response_key).await {
+        Ok(Some(stored)) => Ok(replay(stored, fingerprint)),
+        Ok(None) => execute_and_store(&mut conn, &response_key, fingerprint, request, next).await,
+        Err(e) => Err(e),
+    };
+
+    // The outcome stands either way; an unreleased lock just expires after LOCK_TTL
+    renewal.abort();
+    if let Err(e) = release_lock(&mut conn, &lock_key, &lock_token).await {
+        warn!(error = %e, lock_key = %lock_key, "Failed to release idempotency lock");
+    }
+    outcome
+}
+
+/// Extend the lock every `LOCK_RENEW_INTERVAL` until aborted or the lock is lost
+fn spawn_lock_renewal(
+    mut conn: redis::aio::MultiplexedConnection,
+    lock_key: String,
+    token: String,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(LOCK_RENEW_INTERVAL);
+        interval.tick().await; // The first tick completes immediately
+        loop {
+            interval.tick().await;
+            match renew_lock(&mut conn, &lock_key, &token).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    warn!(lock_key = %lock_key, "Idempotency lock lost while the request was running");
+                    return;
+                }
+                Err(e) => warn!(error = %e, lock_key = %lock_key, "Failed to renew idempotency lock"),
+            }
+        }
+    })
+}
+
+async fn execute_and_store(
+    conn: &mut redis::aio::MultiplexedConnection,
+    response_key: &str,
+    fingerprint: &str,
+    request: Request,
+    next: Next,
+) -> redis::RedisResult<Response> {
+    let response = next.run(request).await;
+    if !response.status().is_success() {
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warn!(error = %e, "Failed to buffer response for idempotent replay");
+            return Ok(error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to read response"));
+        }
+    };
+
+    let stored = StoredResponse {
+        status: parts.status.as_u16(),
+        content_type: parts
+            .headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string),
+        body: BASE64.encode(&body),
+        request_fingerprint: fingerprint.to_string(),
+    };
+    let json = serde_json::to_string(&stored).map_err(|e| {
+        redis::RedisError::from((redis::ErrorKind::TypeError, "serialize", e.to_string()))
+    })?;
+    conn.set_ex::<_, _, ()>(response_key, json, RESPONSE_TTL.as_secs()).await?;
+
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+async fn load_response(
+    conn: &mut redis::aio::MultiplexedConnection,
+    response_key: &str,
+) -> redis::RedisResult<Option<StoredResponse>> {
+    let stored: Option<String> = conn.get(response_key).await?;
+    Ok(stored.and_then(|json| serde_json::from_str(&json).ok()))
+}
+
+/// Delete the lock only if we still own it
+async fn release_lock(
+    conn: &mut redis::aio::MultiplexedConnection,
+    lock_key: &str,
+    token: &str,
+) -> redis::RedisResult<()> {
+    redis::Script::new(
+        r#"if redis.call("GET", KEYS[1]) == ARGV[1] then return redis.call("DEL", KEYS[1]) else return 0 end"#,
+    )
+    .key(lock_key)
+    .arg(token)
+    .invoke_async::<_, ()>(conn)
+    .await
+}
+
+/// Reset the lock's TTL if we still own it, returning whether we do
+async fn renew_lock(
+    conn: &mut redis::aio::MultiplexedConnection,
+    lock_key: &str,
+    token: &str,
+) -> redis::RedisResult<bool> {
+    redis::Script::new(
+        r#"if redis.call("GET", KEYS[1]) == ARGV[1] then return redis.call("PEXPIRE", KEYS[1], ARGV[2]) else return 0 end"#,
+    )
+    .key(lock_key)
+    .arg(token)
+    .arg(LOCK_TTL.as_millis() as u64)
+    .invoke_async::<_, bool>(conn)
+    .await
+}
+
+fn replay(stored: StoredResponse, fingerprint: &str) -> Response {
+    if stored.request_fingerprint != fingerprint {
+        return error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Idempotency-Key was already used with a different request",
+        );
+    }
+
+    let body = match BASE64.decode(stored.body.as_bytes()) {
+        Ok(body) => body,
+        Err(_) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, "Corrupt idempotent response"),
+    };
+    info!("Replaying stored idempotent response");
+
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    if let Some(content_type) = stored.content_type.and_then(|ct| HeaderValue::from_str(&ct).ok()) {
+        response.headers_mut().insert(header::CONTENT_TYPE, content_type);
+    }
+    response
+        .headers_mut()
+        .insert(IDEMPOTENT_REPLAY_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+fn request_fingerprint(method: &str, body: &[u8]) -> String {
+    let mut context = digest::Context::new(&digest::SHA256);
+    context.update(method.as_bytes());
+    context.update(b"\n");
+    context.update(body);
+    HEXLOWER.encode(context.finish().as_ref())
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response {
+    (
+        status,
+        Json(serde_json::json!({
+            "error": status.canonical_reason().unwrap_or("error"),
+            "message": message,
+        })),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_restores_status_and_body() {
+        let stored = StoredResponse {
+            status: 201,
+            content_type: Some("application/json".to_string()),
+            body: BASE64.encode(br#"{"id":"pm_123"}"#),
+            request_fingerprint: request_fingerprint("POST", br#"{"token":"tok_visa"}"#),
+        };
+
+        let response = replay(stored.clone(), &request_fingerprint("POST", br#"{"token":"tok_visa"}"#));
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()[IDEMPOTENT_REPLAY_HEADER], "true");
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+
+        // Reusing the key for a different body is rejected rather than replayed
+        let response = replay(stored, &request_fingerprint("POST", br#"{"token":"tok_amex"}"#));
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
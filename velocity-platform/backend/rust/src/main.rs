@@ -25,6 +25,7 @@ use axum::{
     routing::{get, post, put, delete},
     Router,
 };
+use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tower::ServiceBuilder;
@@ -33,8 +34,10 @@ use tower_http::{
     limit::RequestBodyLimitLayer,
     trace::TraceLayer,
 };
+use tokio::sync::RwLock;
 use tracing::{info, error, warn};
 use uuid::Uuid;
+use velocity_crypto_core::blockchain_compliance::BlockchainComplianceEngine;
 
 mod config;
 mod crypto;
@@ -43,13 +46,24 @@ mod models;
 mod handlers;
 mod middleware_auth;
 mod audit;
+mod batch_verify;
+mod compliance;
+mod credential_status;
 mod errors;
+mod idempotency;
+mod rate_limit;
+mod two_factor;
+mod webhooks;
 
 use config::Config;
+use credential_status::CredentialStatusRegistry;
 use crypto::CryptoService;
 use database::DatabasePool;
 use audit::AuditLogger;
+use compliance::ComplianceConfig;
 use errors::{AppError, Result};
+use rate_limit::RateLimitConfig;
+use webhooks::{WebhookEventType, WebhookSender};
 
 /// Application state shared across all handlers
 #[derive(Clone)]
@@ -59,6 +73,12 @@ pub struct AppState {
     pub crypto: CryptoService,
     pub audit: AuditLogger,
     pub redis: redis::Client,
+    /// Verify-only engine holding the proof issuer's public key
+    pub compliance: Arc<BlockchainComplianceEngine>,
+    pub credentials: Arc<RwLock<CredentialStatusRegistry>>,
+    pub rate_limits: Arc<RateLimitConfig>,
+    /// Endpoints notified of password changes and session revocations
+    pub webhooks: Arc<WebhookSender>,
 }
 
 #[tokio::main]
@@ -88,6 +108,14 @@ async fn main() -> Result<()> {
     let audit = AuditLogger::new(&config.audit).await?;
     info!("📝 Audit logging system initialized for PCI DSS compliance");
     
+    // Initialize blockchain compliance engine for proof verification; it checks
+    // proofs against the configured issuer key rather than a key of its own
+    let compliance = Arc::new(ComplianceConfig::from_env()?.verifier()?);
+    info!("⛓️  Blockchain compliance engine initialized");
+    
+    // Initialize credential status registry for the OCSP-style responder
+    let credentials = Arc::new(RwLock::new(CredentialStatusRegistry::new()?));
+    
     // Create application state
     let app_state = AppState {
         config: config.clone(),
@@ -95,6 +123,10 @@ async fn main() -> Result<()> {
         crypto,
         audit,
         redis,
+        compliance,
+        credentials,
+        rate_limits: Arc::new(RateLimitConfig::default()),
+        webhooks: Arc::new(WebhookSender::from_env()),
     };
     
     // Build production-ready router with middleware stack
@@ -109,7 +141,7 @@ async fn main() -> Result<()> {
     info!("🏥 Health check endpoint at https://{}/health", addr);
     
     // Start the server
-    axum::serve(listener, app)
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
         .with_graceful_shutdown(shutdown_signal())
         .await?;
     
@@ -125,12 +157,15 @@ async fn create_router(state: AppState) -> Router {
         .route("/profile", get(handlers::get_profile))
         .route("/profile", put(handlers::update_profile))
         
-        // Security settings
+        // Security settings; password changes emit a signed webhook
         .route("/security", get(handlers::get_security_settings))
         .route("/security", put(handlers::update_security_settings))
-        .route("/security/password", put(handlers::change_password))
-        .route("/security/2fa", post(handlers::enable_2fa))
-        .route("/security/2fa", delete(handlers::disable_2fa))
+        .route("/security/password", put(handlers::change_password).layer(
+            middleware::from_fn_with_state((state.clone(), WebhookEventType::PasswordChanged), webhooks::security_event_middleware),
+        ))
+        .route("/security/2fa", post(two_factor::enable_2fa))
+        .route("/security/2fa", delete(two_factor::disable_2fa))
+        .route("/security/2fa/verify", post(two_factor::verify_2fa))
         
         // Notification preferences
         .route("/notifications", get(handlers::get_notifications))
@@ -140,20 +175,34 @@ async fn create_router(state: AppState) -> Router {
         .route("/system", get(handlers::get_system_settings))
         .route("/system", put(handlers::update_system_settings))
         
-        // Payment methods (PCI DSS compliant)
+        // Payment methods (PCI DSS compliant); POSTs honour Idempotency-Key
         .route("/payment-methods", get(handlers::get_payment_methods))
-        .route("/payment-methods", post(handlers::add_payment_method))
+        .route("/payment-methods", post(handlers::add_payment_method).layer(
+            middleware::from_fn_with_state(state.clone(), idempotency::idempotency_middleware),
+        ))
         .route("/payment-methods/:id", delete(handlers::remove_payment_method))
         
-        // Audit and compliance
+        // Audit and compliance; deletion requests honour Idempotency-Key
         .route("/audit-log", get(handlers::get_audit_log))
         .route("/data-export", post(handlers::export_user_data))
-        .route("/data-deletion", post(handlers::request_data_deletion))
+        .route("/data-deletion", post(handlers::request_data_deletion).layer(
+            middleware::from_fn_with_state(state.clone(), idempotency::idempotency_middleware),
+        ))
         
-        // Session management
+        // Session management; revocations emit signed webhooks
         .route("/sessions", get(handlers::get_active_sessions))
-        .route("/sessions/:id", delete(handlers::revoke_session))
-        .route("/sessions/revoke-all", post(handlers::revoke_all_sessions));
+        .route("/sessions/:id", delete(handlers::revoke_session).layer(
+            middleware::from_fn_with_state((state.clone(), WebhookEventType::SessionRevoked), webhooks::security_event_middleware),
+        ))
+        .route("/sessions/revoke-all", post(handlers::revoke_all_sessions).layer(
+            middleware::from_fn_with_state((state.clone(), WebhookEventType::AllSessionsRevoked), webhooks::security_event_middleware),
+        ))
+        
+        // Rate limiting per (user, route, IP); runs after authentication
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit::rate_limit_middleware,
+        ));
     
     // Main application router
     Router::new()
@@ -169,6 +218,21 @@ async fn create_router(state: AppState) -> Router {
         .route("/metrics", get(handlers::metrics))
         .route("/version", get(handlers::version_info))
         
+        // Compliance proof verification (proofs are independently verifiable);
+        // unauthenticated, so rate limited per client IP
+        .route("/api/v1/compliance/verify", post(compliance::verify_compliance_proof).layer(
+            middleware::from_fn_with_state(state.clone(), rate_limit::rate_limit_middleware),
+        ))
+        .route("/api/v1/verify/stream", post(batch_verify::verify_stream).layer(
+            middleware::from_fn_with_state(state.clone(), rate_limit::rate_limit_middleware),
+        ))
+        
+        // Signed credential status for relying parties
+        .route("/api/v1/credentials/:id/status", get(credential_status::credential_status))
+        
+        // Public key and recipe for verifying signed webhooks
+        .route("/api/v1/webhooks/verification", get(webhooks::verification_recipe))
+        
         // Global middleware stack
         .layer(
             ServiceBuilder::new()
@@ -193,8 +257,6 @@ async fn create_router(state: AppState) -> Router {
                 // Request body size limits (PCI DSS Requirement 6.5.1)
                 .layer(RequestBodyLimitLayer::new(1024 * 1024)) // 1MB limit
                 
-                // Rate limiting middleware would go here
-                // .layer(RateLimitLayer::new(...))
         )
         .with_state(state)
 }
@@ -273,17 +335,441 @@ mod tests {
         assert_eq!(response.status_code(), 200);
     }
     
-    async fn create_test_app() -> Router {
-        // Create minimal app state for testing
+    #[tokio::test]
+    async fn test_verify_compliance_proof_endpoint() {
+        let mut issuer = BlockchainComplianceEngine::new().unwrap();
+        let proof = issuer
+            .create_compliance_proof(
+                "entity-verify-001".to_string(),
+                "SOC2".to_string(),
+                test_compliance_data(),
+                false,
+            )
+            .unwrap();
+        let server = TestServer::new(create_router(create_test_state_for(&issuer).await).await).unwrap();
+        
+        let response = server.post("/api/v1/compliance/verify").json(&proof).await;
+        assert_eq!(response.status_code(), 200);
+        let result: serde_json::Value = response.json();
+        assert_eq!(result["is_valid"], true);
+        assert_eq!(result["proof_id"], proof.id.as_str());
+        
+        // Inflating the evidence count invalidates the blockchain hash and Merkle root
+        let mut tampered = proof.clone();
+        tampered.compliance_data.evidence_count += 1;
+        let response = server.post("/api/v1/compliance/verify").json(&tampered).await;
+        assert_eq!(response.status_code(), 422);
+        let result: serde_json::Value = response.json();
+        assert_eq!(result["is_valid"], false);
+        assert_eq!(result["verification_details"]["cryptographic_integrity"], false);
+        
+        // A proof signed by any key but the configured issuer's is rejected
+        let foreign = BlockchainComplianceEngine::new().unwrap()
+            .create_compliance_proof(
+                "entity-verify-001".to_string(),
+                "SOC2".to_string(),
+                test_compliance_data(),
+                false,
+            )
+            .unwrap();
+        let response = server.post("/api/v1/compliance/verify").json(&foreign).await;
+        assert_eq!(response.status_code(), 422);
+    }
+    
+    #[tokio::test]
+    async fn test_public_verification_is_rate_limited_per_ip() {
+        let issuer = BlockchainComplianceEngine::new().unwrap();
+        let mut state = create_test_state_for(&issuer).await;
+        state.rate_limits = Arc::new(
+            RateLimitConfig::default()
+                .with_route("/api/v1/compliance/verify", rate_limit::RateLimit::new(2, std::time::Duration::from_secs(60)))
+                .with_route("/api/v1/verify/stream", rate_limit::RateLimit::new(2, std::time::Duration::from_secs(60))),
+        );
+        
+        // Unique client address so reruns within the window start from an empty log
+        let client = SocketAddr::from((std::net::Ipv6Addr::from(Uuid::new_v4().as_u128()), 443));
+        let app = create_router(state).await.layer(axum::extract::connect_info::MockConnectInfo(client));
+        let server = TestServer::new(app).unwrap();
+        
+        for _ in 0..2 {
+            let response = server.post("/api/v1/compliance/verify").json(&serde_json::json!({})).await;
+            assert_ne!(response.status_code(), 429);
+        }
+        let response = server.post("/api/v1/compliance/verify").json(&serde_json::json!({})).await;
+        assert_eq!(response.status_code(), 429);
+        let retry_after: u64 = response.header("Retry-After").to_str().unwrap().parse().unwrap();
+        assert!(retry_after >= 1 && retry_after <= 60);
+        
+        // Each route keeps its own window
+        let stream = || {
+            server.post("/api/v1/verify/stream")
+                .content_type(batch_verify::NDJSON_CONTENT_TYPE)
+                .bytes(Vec::new().into())
+        };
+        for _ in 0..2 {
+            assert_ne!(stream().await.status_code(), 429);
+        }
+        assert_eq!(stream().await.status_code(), 429);
+    }
+    
+    #[tokio::test]
+    async fn test_enable_and_verify_2fa() {
+        let user = middleware_auth::AuthenticatedUser {
+            user_id: Uuid::new_v4(),
+            email: "security@example.com".to_string(),
+        };
+        let app = Router::new()
+            .route("/2fa", post(two_factor::enable_2fa).delete(two_factor::disable_2fa))
+            .route("/2fa/verify", post(two_factor::verify_2fa))
+            .layer(axum::Extension(user))
+            .with_state(create_test_state().await);
+        let server = TestServer::new(app).unwrap();
+        
+        let response = server.post("/2fa").await;
+        assert_eq!(response.status_code(), 201);
+        let enrollment: two_factor::EnableTwoFactorResponse = response.json();
+        assert!(enrollment.provisioning_uri.starts_with("otpauth://totp/Velocity:security@example.com"));
+        assert_eq!(enrollment.recovery_codes.len(), two_factor::RECOVERY_CODE_COUNT);
+        
+        // A recovery code does not prove the authenticator app holds the secret
+        let response = server.post("/2fa/verify").json(&serde_json::json!({ "code": enrollment.recovery_codes[0] })).await;
+        assert_eq!(response.status_code(), 401);
+        
+        // Compute the current code the way an authenticator app would
+        let secret = data_encoding::BASE32_NOPAD.decode(enrollment.secret.as_bytes()).unwrap();
+        let step = chrono::Utc::now().timestamp() as u64 / two_factor::TOTP_STEP_SECS;
+        let code = two_factor::totp_code(&secret, step);
+        
+        let response = server.post("/2fa/verify").json(&serde_json::json!({ "code": code })).await;
+        assert_eq!(response.status_code(), 200);
+        let verified: two_factor::VerifyTwoFactorResponse = response.json();
+        assert!(verified.verified);
+        assert!(!verified.recovery_code_used);
+        
+        // The same code cannot be replayed
+        let response = server.post("/2fa/verify").json(&serde_json::json!({ "code": code })).await;
+        assert_eq!(response.status_code(), 401);
+        
+        // Recovery codes work once
+        let recovery = &enrollment.recovery_codes[0];
+        let response = server.post("/2fa/verify").json(&serde_json::json!({ "code": recovery })).await;
+        assert_eq!(response.status_code(), 200);
+        let verified: two_factor::VerifyTwoFactorResponse = response.json();
+        assert!(verified.recovery_code_used);
+        assert_eq!(verified.recovery_codes_remaining, two_factor::RECOVERY_CODE_COUNT - 1);
+        let response = server.post("/2fa/verify").json(&serde_json::json!({ "code": recovery })).await;
+        assert_eq!(response.status_code(), 401);
+        
+        let response = server.delete("/2fa").json(&serde_json::json!({ "code": enrollment.recovery_codes[1] })).await;
+        assert_eq!(response.status_code(), 204);
+    }
+    
+    #[tokio::test]
+    async fn test_concurrent_2fa_verifications_spend_code_once() {
+        use std::future::IntoFuture;
+        
+        let user = middleware_auth::AuthenticatedUser {
+            user_id: Uuid::new_v4(),
+            email: "security@example.com".to_string(),
+        };
+        let app = Router::new()
+            .route("/2fa", post(two_factor::enable_2fa))
+            .route("/2fa/verify", post(two_factor::verify_2fa))
+            .layer(axum::Extension(user))
+            .with_state(create_test_state().await);
+        let server = TestServer::new(app).unwrap();
+        
+        let enrollment: two_factor::EnableTwoFactorResponse = server.post("/2fa").await.json();
+        let secret = data_encoding::BASE32_NOPAD.decode(enrollment.secret.as_bytes()).unwrap();
+        let step = chrono::Utc::now().timestamp() as u64 / two_factor::TOTP_STEP_SECS;
+        let code = two_factor::totp_code(&secret, step);
+        let response = server.post("/2fa/verify").json(&serde_json::json!({ "code": code })).await;
+        assert_eq!(response.status_code(), 200);
+        
+        // Racing requests with one recovery code: exactly one of them spends it
+        let recovery = serde_json::json!({ "code": enrollment.recovery_codes[0] });
+        let (first, second) = tokio::join!(
+            server.post("/2fa/verify").json(&recovery).into_future(),
+            server.post("/2fa/verify").json(&recovery).into_future(),
+        );
+        let mut statuses = vec![first.status_code().as_u16(), second.status_code().as_u16()];
+        statuses.sort_unstable();
+        assert_eq!(statuses, vec![200, 401]);
+        
+        let next_code = two_factor::totp_code(&secret, step + 1);
+        let response = server.post("/2fa/verify").json(&serde_json::json!({ "code": next_code })).await;
+        let verified: two_factor::VerifyTwoFactorResponse = response.json();
+        assert_eq!(verified.recovery_codes_remaining, two_factor::RECOVERY_CODE_COUNT - 1);
+    }
+    
+    #[tokio::test]
+    async fn test_rate_limit_rejects_request_over_limit() {
+        let mut state = create_test_state().await;
+        let balancer = SocketAddr::from(([10, 0, 0, 1], 443));
+        state.rate_limits = Arc::new(
+            RateLimitConfig::default()
+                .with_route("/limited", rate_limit::RateLimit::new(3, std::time::Duration::from_secs(60)))
+                .with_trusted_proxy(balancer.ip()),
+        );
+        let app = Router::new()
+            .route("/limited", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(state.clone(), rate_limit::rate_limit_middleware))
+            .layer(axum::extract::connect_info::MockConnectInfo(balancer))
+            .with_state(state);
+        let server = TestServer::new(app).unwrap();
+        
+        // Unique client address so reruns within the window start from an empty log
+        let client_ip = std::net::Ipv6Addr::from(Uuid::new_v4().as_u128()).to_string();
+        for remaining in (0..3).rev() {
+            let response = server.get("/limited").add_header(
+                "x-forwarded-for".parse().unwrap(),
+                client_ip.parse().unwrap(),
+            ).await;
+            assert_eq!(response.status_code(), 200);
+            assert_eq!(response.header("X-RateLimit-Remaining"), remaining.to_string().as_str());
+        }
+        
+        let response = server.get("/limited").add_header(
+            "x-forwarded-for".parse().unwrap(),
+            client_ip.parse().unwrap(),
+        ).await;
+        assert_eq!(response.status_code(), 429);
+        let retry_after: u64 = response.header("Retry-After").to_str().unwrap().parse().unwrap();
+        assert!(retry_after >= 1 && retry_after <= 60);
+    }
+    
+    #[tokio::test]
+    async fn test_idempotency_key_replays_original_response() {
+        let state = create_test_state().await;
+        let effects = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = effects.clone();
+        let app = Router::new()
+            .route("/data-deletion", post(move || {
+                let counter = counter.clone();
+                async move {
+                    // Slow enough that concurrent duplicates overlap
+                    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                    let n = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                    (axum::http::StatusCode::ACCEPTED, axum::Json(serde_json::json!({
+                        "request_id": Uuid::new_v4(),
+                        "effect": n,
+                    })))
+                }
+            }).layer(middleware::from_fn_with_state(state.clone(), idempotency::idempotency_middleware)))
+            .with_state(state);
+        let server = TestServer::new(app).unwrap();
+        let body = serde_json::json!({ "reason": "account closure" });
+        
+        let key = Uuid::new_v4().to_string();
+        let send = || server.post("/data-deletion")
+            .add_header(idempotency::IDEMPOTENCY_KEY_HEADER.parse().unwrap(), key.parse().unwrap())
+            .json(&body);
+        let (first, second) = tokio::join!(send(), send());
+        assert_eq!(first.status_code(), 202);
+        assert_eq!(second.status_code(), 202);
+        assert_eq!(first.text(), second.text());
+        assert_eq!(effects.load(std::sync::atomic::Ordering::SeqCst), 1);
+        
+        // A later retry still replays, while a different body under the same key is refused
+        let retry = send().await;
+        assert_eq!(retry.text(), first.text());
+        assert_eq!(retry.header(idempotency::IDEMPOTENT_REPLAY_HEADER), "true");
+        let response = server.post("/data-deletion")
+            .add_header(idempotency::IDEMPOTENCY_KEY_HEADER.parse().unwrap(), key.parse().unwrap())
+            .json(&serde_json::json!({ "reason": "changed my mind" }))
+            .await;
+        assert_eq!(response.status_code(), 422);
+        assert_eq!(effects.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_credential_status_endpoint() {
+        use credential_status::{CredentialStatus, SignedStatusResponse};
+        
+        let state = create_test_state().await;
+        let now = chrono::Utc::now();
+        {
+            let mut registry = state.credentials.write().await;
+            registry.register("cred-valid", Some(now + chrono::Duration::days(365)));
+            registry.register("cred-expired", Some(now - chrono::Duration::days(1)));
+            registry.register("cred-revoked", None);
+            registry.revoke("cred-revoked", "key_compromise", now).unwrap();
+        }
+        let verifying_key = state.crypto.signing_key().verifying_key();
+        let server = TestServer::new(create_router(state).await).unwrap();
+        
+        for (id, expected) in [
+            ("cred-valid", CredentialStatus::Valid),
+            ("cred-expired", CredentialStatus::Expired),
+            ("cred-revoked", CredentialStatus::Revoked),
+            ("cred-never-issued", CredentialStatus::Unknown),
+        ] {
+            let response = server.get(&format!("/api/v1/credentials/{}/status", id)).await;
+            assert_eq!(response.status_code(), 200);
+            let signed: SignedStatusResponse = response.json();
+            let assertion = signed.verify(&verifying_key).expect("status assertion must verify");
+            assert_eq!(assertion.credential_id, id);
+            assert_eq!(assertion.status, expected);
+            assert!(assertion.produced_at <= chrono::Utc::now());
+            assert!(assertion.next_update > assertion.produced_at);
+        }
+        
+        // Nonces are echoed inside the signed assertion, so old responses can't be replayed
+        let response = server.get("/api/v1/credentials/cred-revoked/status?nonce=rp-4711").await;
+        assert_eq!(response.header("Cache-Control"), "no-store");
+        let signed: SignedStatusResponse = response.json();
+        let assertion = signed.verify(&verifying_key).unwrap();
+        assert_eq!(assertion.nonce.as_deref(), Some("rp-4711"));
+        assert_eq!(assertion.revocation_reason.as_deref(), Some("key_compromise"));
+        
+        // A MITM flipping the status invalidates the signature
+        let mut forged = signed.clone();
+        forged.assertion = forged.assertion.replace("\"status\":\"revoked\"", "\"status\":\"valid\"");
+        assert_ne!(forged.assertion, signed.assertion);
+        assert!(forged.verify(&verifying_key).is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_verify_stream_returns_one_result_per_line() {
+        let mut issuer = BlockchainComplianceEngine::new().unwrap();
+        let mut proofs = Vec::new();
+        for entity in ["entity-stream-001", "entity-stream-002"] {
+            let proof = issuer
+                .create_compliance_proof(entity.to_string(), "SOC2".to_string(), test_compliance_data(), false)
+                .unwrap();
+            proofs.push(proof);
+        }
+        let state = create_test_state_for(&issuer).await;
+        let mut tampered = proofs[1].clone();
+        tampered.compliance_data.controls_passed -= 1;
+        let server = TestServer::new(create_router(state).await).unwrap();
+        
+        let mut body = String::new();
+        for (id, proof) in [("req-1", &proofs[0]), ("req-2", &proofs[1]), ("req-3", &tampered)] {
+            body.push_str(&serde_json::json!({ "id": id, "proof": proof }).to_string());
+            body.push('\n');
+        }
+        body.push_str("{\"id\":\"req-4\",\"proof\":\"not a proof\"}\n");
+        
+        let response = server.post("/api/v1/verify/stream")
+            .content_type(batch_verify::NDJSON_CONTENT_TYPE)
+            .bytes(body.into())
+            .await;
+        assert_eq!(response.status_code(), 200);
+        assert_eq!(response.header("content-type"), batch_verify::NDJSON_CONTENT_TYPE);
+        
+        let results: Vec<batch_verify::StreamVerifyResult> = response.text()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let ids: Vec<_> = results.iter().map(|r| r.id.as_deref().unwrap()).collect();
+        assert_eq!(ids, ["req-1", "req-2", "req-3", "req-4"]);
+        let valid: Vec<_> = results.iter().map(|r| r.is_valid).collect();
+        assert_eq!(valid, [true, true, false, false]);
+        assert!(results[3].error.is_some());
+        
+        let response = server.post("/api/v1/verify/stream").json(&proofs[0]).await;
+        assert_eq!(response.status_code(), 415);
+    }
+    
+    fn test_compliance_data() -> velocity_crypto_core::blockchain_compliance::ComplianceData {
+        let now = chrono::Utc::now().timestamp() as u64;
+        velocity_crypto_core::blockchain_compliance::ComplianceData {
+            framework: "SOC2".to_string(),
+            version: "2017".to_string(),
+            controls_assessed: 64,
+            controls_passed: 64,
+            compliance_score: 100.0,
+            evidence_count: 24,
+            assessment_date: now,
+            valid_until: now + 365 * 24 * 60 * 60,
+            assessor: "Velocity AI Engine".to_string(),
+            metadata: std::collections::HashMap::new(),
+            controls: std::collections::HashMap::new(),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_session_revocation_emits_verifiable_webhook() {
+        // Customer endpoint recording what it receives
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let receiver = Router::new().route("/hook", post(move |headers: HeaderMap, body: String| {
+            let tx = tx.clone();
+            async move {
+                tx.send((headers, body)).unwrap();
+                StatusCode::NO_CONTENT
+            }
+        }));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}/hook", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, receiver).await.unwrap() });
+        
+        let user = middleware_auth::AuthenticatedUser {
+            user_id: Uuid::new_v4(),
+            email: "security@example.com".to_string(),
+        };
+        let mut state = create_test_state().await;
+        state.webhooks = Arc::new(WebhookSender::new(vec![endpoint]));
+        let verifying_key = state.crypto.signing_key().verifying_key();
+        let app = Router::new()
+            .route("/sessions/:id", delete(|| async { StatusCode::NO_CONTENT }).layer(
+                middleware::from_fn_with_state((state.clone(), WebhookEventType::SessionRevoked), webhooks::security_event_middleware),
+            ))
+            .layer(axum::Extension(user.clone()))
+            .with_state(state);
+        let server = TestServer::new(app).unwrap();
+        
+        let response = server.delete(&format!("/sessions/{}", Uuid::new_v4())).await;
+        assert_eq!(response.status_code(), 204);
+        
+        let (headers, body) = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let signature = headers[webhooks::SIGNATURE_HEADER].to_str().unwrap();
+        assert_eq!(
+            webhooks::verify_webhook(&verifying_key, &body, signature, chrono::Utc::now().timestamp(), webhooks::DEFAULT_TOLERANCE_SECS),
+            Ok(())
+        );
+        let event: webhooks::WebhookEvent = serde_json::from_str(&body).unwrap();
+        assert_eq!(event.event_type, WebhookEventType::SessionRevoked);
+        assert_eq!(event.user_id, user.user_id);
+        assert_eq!(headers[webhooks::EVENT_ID_HEADER].to_str().unwrap(), event.id.to_string());
+    }
+    
+    #[test]
+    fn test_compliance_config_requires_an_ed25519_issuer_key() {
+        let issuer = BlockchainComplianceEngine::new().unwrap();
+        assert!(ComplianceConfig { issuer_public_key: issuer.public_key() }.validate().is_ok());
+
+        let oversized = "ab".repeat(33);
+        for issuer_public_key in ["", "not-a-key", "00", oversized.as_str()] {
+            let config = ComplianceConfig { issuer_public_key: issuer_public_key.to_string() };
+            assert!(config.validate().is_err(), "{:?} accepted", issuer_public_key);
+        }
+    }
+    
+    async fn create_test_state() -> AppState {
+        create_test_state_for(&BlockchainComplianceEngine::new().unwrap()).await
+    }
+    
+    /// Minimal app state whose compliance engine verifies proofs from `issuer`
+    async fn create_test_state_for(issuer: &BlockchainComplianceEngine) -> AppState {
         let config = Arc::new(Config::default());
-        let app_state = AppState {
+        AppState {
             config,
             db: DatabasePool::new_test().await.unwrap(),
             crypto: CryptoService::new_test().await.unwrap(),
             audit: AuditLogger::new_test().await.unwrap(),
             redis: redis::Client::open("redis://127.0.0.1/").unwrap(),
-        };
-        
-        create_router(app_state).await
+            compliance: Arc::new(BlockchainComplianceEngine::verifier(&issuer.public_key()).unwrap()),
+            credentials: Arc::new(RwLock::new(CredentialStatusRegistry::new().unwrap())),
+            rate_limits: Arc::new(RateLimitConfig::default()),
+            webhooks: Arc::new(WebhookSender::default()),
+        }
+    }
+    
+    async fn create_test_app() -> Router {
+        create_router(create_test_state().await).await
     }
 }
\ No newline at end of file
@@ -0,0 +1,292 @@
+/*!
+ * Rate Limiting Middleware (PCI DSS Requirement 8.3.4)
+ *
+ * Sliding-window log stored in Redis sorted sets, keyed by
+ * `(user_id, route, ip)`. Requests over the limit receive 429 with a
+ * `Retry-After` header. Rejected requests are not recorded, so a client
+ * regains access as soon as its oldest accepted request leaves the window.
+ *
+ * The IP is the connecting peer's. `X-Forwarded-For` is only honoured when the
+ * peer is a configured trusted proxy, and then only the hops those proxies
+ * appended, so clients cannot pick their own rate-limit key.
+ */
+
+use axum::{
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::middleware_auth::AuthenticatedUser;
+use crate::AppState;
+
+/// Maximum number of requests allowed within a window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max_requests: u32,
+    pub window: Duration,
+}
+
+impl RateLimit {
+    pub const fn new(max_requests: u32, window: Duration) -> Self {
+        Self { max_requests, window }
+    }
+}
+
+/// Per-route limits with a fallback for routes that are not listed
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    pub default: RateLimit,
+    /// Limits keyed by matched route path (e.g. `/api/v1/settings/security/password`)
+    pub routes: HashMap<String, RateLimit>,
+    /// Load balancers whose `X-Forwarded-For` entries are believed
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+impl RateLimitConfig {
+    pub fn limit_for(&self, route: &str) -> RateLimit {
+        self.routes.get(route).copied().unwrap_or(self.default)
+    }
+
+    pub fn with_route(mut self, route: &str, limit: RateLimit) -> Self {
+        self.routes.insert(route.to_string(), limit);
+        self
+    }
+
+    pub fn with_trusted_proxy(mut self, proxy: IpAddr) -> Self {
+        self.trusted_proxies.push(proxy);
+        self
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            default: RateLimit::new(120, Duration::from_secs(60)),
+            routes: HashMap::new(),
+            trusted_proxies: Vec::new(),
+        }
+        // Credential and second-factor endpoints are brute-force targets
+        .with_route("/api/v1/settings/security/password", RateLimit::new(5, Duration::from_secs(15 * 60)))
+        .with_route("/api/v1/settings/security/2fa/verify", RateLimit::new(10, Duration::from_secs(5 * 60)))
+        .with_route("/api/v1/settings/security/2fa", RateLimit::new(10, Duration::from_secs(5 * 60)))
+        // Public verification is unauthenticated, so each client IP shares one window
+        .with_route("/api/v1/compliance/verify", RateLimit::new(60, Duration::from_secs(60)))
+        .with_route("/api/v1/verify/stream", RateLimit::new(10, Duration::from_secs(60)))
+    }
+}
+
+/// Outcome of checking a request against the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDecision {
+    Allowed { remaining: u32 },
+    Limited { retry_after_secs: u64 },
+}
+
+/// Decide from the number of requests in the window (including this one) and the
+/// timestamp of the oldest of them
+pub fn evaluate(count: u32, oldest_ms: i64, now_ms: i64, limit: RateLimit) -> RateLimitDecision {
+    if count <= limit.max_requests {
+        return RateLimitDecision::Allowed {
+            remaining: limit.max_requests - count,
+        };
+    }
+
+    let window_ms = limit.window.as_millis() as i64;
+    let wait_ms = (oldest_ms + window_ms - now_ms).max(0);
+    RateLimitDecision::Limited {
+        // Round up so clients never retry a moment too early
+        retry_after_secs: ((wait_ms + 999) / 1000).max(1) as u64,
+    }
+}
+
+/// Axum middleware applying the configured limits
+///
+/// Runs after authentication where a route has it; on public routes requests
+/// are anonymous and limited by client IP alone.
+pub async fn rate_limit_middleware(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+    let user = request
+        .extensions()
+        .get::<AuthenticatedUser>()
+        .map(|user| user.user_id.to_string())
+        .unwrap_or_else(|| "anonymous".to_string());
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let ip = client_ip(request.headers(), peer, &state.rate_limits.trusted_proxies)
+        .map(|ip| ip.to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let limit = state.rate_limits.limit_for(&route);
+
+    match check_rate_limit(&state.redis, &rate_limit_key(&user, &route, &ip), limit).await {
+        Ok(RateLimitDecision::Allowed { remaining }) => {
+            let mut response = next.run(request).await;
+            let headers = response.headers_mut();
+            headers.insert("X-RateLimit-Limit", HeaderValue::from(limit.max_requests));
+            headers.insert("X-RateLimit-Remaining", HeaderValue::from(remaining));
+            response
+        }
+        Ok(RateLimitDecision::Limited { retry_after_secs }) => {
+            warn!(user_id = %user, route = %route, ip = %ip, "Rate limit exceeded");
+            let mut response = (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(serde_json::json!({
+                    "error": "rate_limited",
+                    "message": "Too many requests, please retry later",
+                    "retry_after": retry_after_secs,
+                })),
+            )
+                .into_response();
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after_secs));
+            response
+        }
+        Err(e) => {
+            // Fail open: an unavailable cache must not take the API down
+            error!(error = %e, "Rate limiter unavailable, allowing request");
+            next.run(request).await
+        }
+    }
+}
+
+/// Record the request in the sliding window and decide whether it may proceed
+pub async fn check_rate_limit(
+    redis: &redis::Client,
+    key: &str,
+    limit: RateLimit,
+) -> redis::RedisResult<RateLimitDecision> {
+    let mut conn = redis.get_multiplexed_async_connection().await?;
+    let now_ms = chrono::Utc::now().timestamp_millis();
+    let window_ms = limit.window.as_millis() as i64;
+    let member = format!("{}-{}", now_ms, Uuid::new_v4());
+
+    let (count, oldest): (u32, Vec<(String, i64)>) = redis::pipe()
+        .atomic()
+        .zrembyscore(key, 0, now_ms - window_ms)
+        .ignore()
+        .zadd(key, &member, now_ms)
+        .ignore()
+        .zcard(key)
+        .zrange_withscores(key, 0, 0)
+        .pexpire(key, window_ms)
+        .ignore()
+        .query_async(&mut conn)
+        .await?;
+
+    let oldest_ms = oldest.first().map(|(_, score)| *score).unwrap_or(now_ms);
+    let decision = evaluate(count, oldest_ms, now_ms, limit);
+    if let RateLimitDecision::Limited { .. } = decision {
+        redis::cmd("ZREM").arg(key).arg(&member).query_async::<_, ()>(&mut conn).await?;
+    }
+    Ok(decision)
+}
+
+fn rate_limit_key(user: &str, route: &str, ip: &str) -> String {
+    format!("velocity:ratelimit:{}:{}:{}", user, route, ip)
+}
+
+/// Client IP: the socket peer, or behind trusted proxies the right-most
+/// `X-Forwarded-For` hop that is not itself a trusted proxy
+///
+/// Hops left of that one were supplied by the client and are ignored. A hop that
+/// does not parse stops the walk at the last trusted address.
+fn client_ip(headers: &HeaderMap, peer: Option<IpAddr>, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+    let mut client = peer?;
+    if !trusted_proxies.contains(&client) {
+        return Some(client);
+    }
+
+    let forwarded = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .collect::<Vec<_>>();
+    for hop in forwarded.into_iter().rev() {
+        match hop.trim().parse::<IpAddr>() {
+            Ok(ip) => client = ip,
+            Err(_) => break,
+        }
+        if !trusted_proxies.contains(&client) {
+            break;
+        }
+    }
+    Some(client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_allows_up_to_limit() {
+        let limit = RateLimit::new(3, Duration::from_secs(60));
+        let now = 1_700_000_000_000;
+
+        assert_eq!(evaluate(1, now, now, limit), RateLimitDecision::Allowed { remaining: 2 });
+        assert_eq!(evaluate(3, now - 5_000, now, limit), RateLimitDecision::Allowed { remaining: 0 });
+        assert_eq!(
+            evaluate(4, now - 20_500, now, limit),
+            RateLimitDecision::Limited { retry_after_secs: 40 }
+        );
+    }
+
+    fn forwarded_for(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_client_ip_ignores_forwarded_for_from_untrusted_peer() {
+        let peer: IpAddr = "203.0.113.7".parse().unwrap();
+        let headers = forwarded_for("198.51.100.1");
+
+        assert_eq!(client_ip(&headers, Some(peer), &[]), Some(peer));
+        assert_eq!(client_ip(&headers, Some(peer), &["10.0.0.1".parse().unwrap()]), Some(peer));
+        assert_eq!(client_ip(&headers, None, &[]), None);
+    }
+
+    #[test]
+    fn test_client_ip_uses_hop_added_by_trusted_proxies() {
+        let balancer: IpAddr = "10.0.0.1".parse().unwrap();
+        let edge: IpAddr = "10.0.0.2".parse().unwrap();
+        let trusted = [balancer, edge];
+
+        // A spoofed left-most entry does not change the key
+        let headers = forwarded_for("198.51.100.1, 203.0.113.7");
+        assert_eq!(client_ip(&headers, Some(balancer), &trusted), Some("203.0.113.7".parse().unwrap()));
+
+        // Hops appended by further trusted proxies are skipped
+        let headers = forwarded_for("198.51.100.1, 203.0.113.7, 10.0.0.2");
+        assert_eq!(client_ip(&headers, Some(balancer), &trusted), Some("203.0.113.7".parse().unwrap()));
+
+        // Garbage from the client stops the walk at the last trusted address
+        let headers = forwarded_for("not-an-ip, 10.0.0.2");
+        assert_eq!(client_ip(&headers, Some(balancer), &trusted), Some(edge));
+        assert_eq!(client_ip(&HeaderMap::new(), Some(balancer), &trusted), Some(balancer));
+    }
+
+    #[test]
+    fn test_password_route_is_stricter() {
+        let config = RateLimitConfig::default();
+        let password = config.limit_for("/api/v1/settings/security/password");
+        let profile = config.limit_for("/api/v1/settings/profile");
+
+        assert!(password.max_requests < profile.max_requests);
+        assert!(password.window > profile.window);
+    }
+}
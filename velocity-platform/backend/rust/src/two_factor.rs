@@ -0,0 +1,410 @@
+/*!
+ * Two-Factor Authentication (RFC 6238 TOTP)
+ *
+ * - Secrets are 160-bit random values, returned once as base32 with an
+ *   otpauth:// provisioning URI and stored encrypted via `CryptoService`
+ * - Codes are 6 digits over 30 second steps, accepted with ±1 step drift
+ * - A code's time step can only be used once to block replay
+ * - Recovery codes are single use and stored as SHA-256 hashes
+ * - Records are written back with a compare-and-set script, so concurrent
+ *   requests cannot both spend the same code
+ * - Enrollment is confirmed with a TOTP code only, proving the authenticator
+ *   app holds the secret
+ */
+
+use axum::{extract::State, http::StatusCode, response::Json, Extension};
+use data_encoding::{BASE32_NOPAD, HEXLOWER};
+use redis::AsyncCommands;
+use ring::{
+    digest,
+    hmac,
+    rand::{SecureRandom, SystemRandom},
+};
+use serde::{Deserialize, Serialize};
+use subtle::ConstantTimeEq;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::errors::{AppError, Result};
+use crate::middleware_auth::AuthenticatedUser;
+use crate::AppState;
+
+/// Issuer shown in authenticator apps
+pub const TOTP_ISSUER: &str = "Velocity";
+/// Time step in seconds
+pub const TOTP_STEP_SECS: u64 = 30;
+/// Number of digits in a code
+pub const TOTP_DIGITS: u32 = 6;
+/// Steps of clock drift accepted either side of the current step
+pub const TOTP_ALLOWED_DRIFT: i64 = 1;
+/// Number of recovery codes issued on enable
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Times a verification is re-run against a record changed concurrently
+const MAX_UPDATE_ATTEMPTS: usize = 3;
+
+const SECRET_LENGTH: usize = 20;
+const RECOVERY_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Persisted two-factor state for a user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TwoFactorRecord {
+    /// TOTP secret encrypted by `CryptoService`
+    encrypted_secret: String,
+    /// SHA-256 hashes of unused recovery codes
+    recovery_code_hashes: Vec<String>,
+    /// Set once the user has proven possession with a first valid code
+    confirmed: bool,
+    /// Last time step accepted, to reject replayed codes
+    last_used_step: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EnableTwoFactorResponse {
+    pub secret: String,
+    pub provisioning_uri: String,
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TwoFactorCodeRequest {
+    /// 6-digit TOTP code or a recovery code
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VerifyTwoFactorResponse {
+    pub verified: bool,
+    pub recovery_code_used: bool,
+    pub recovery_codes_remaining: usize,
+}
+
+/// POST /api/v1/settings/security/2fa
+pub async fn enable_2fa(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+) -> Result<(StatusCode, Json<EnableTwoFactorResponse>)> {
+    let mut conn = state.redis.get_multiplexed_async_connection().await?;
+    if let Some((existing, _)) = load_record(&mut conn, user.user_id).await? {
+        if existing.confirmed {
+            return Err(AppError::Conflict("Two-factor authentication is already enabled".to_string()));
+        }
+    }
+
+    let secret = generate_secret()?;
+    let recovery_codes = generate_recovery_codes(RECOVERY_CODE_COUNT)?;
+    let record = TwoFactorRecord {
+        encrypted_secret: state.crypto.encrypt(&secret).await?,
+        recovery_code_hashes: recovery_codes.iter().map(|code| hash_recovery_code(code)).collect(),
+        confirmed: false,
+        last_used_step: None,
+    };
+    store_record(&mut conn, user.user_id, &record).await?;
+
+    info!(user_id = %user.user_id, "Two-factor enrollment started");
+
+    let encoded_secret = BASE32_NOPAD.encode(&secret);
+    Ok((
+        StatusCode::CREATED,
+        Json(EnableTwoFactorResponse {
+            provisioning_uri: provisioning_uri(&encoded_secret, &user.email),
+            secret: encoded_secret,
+            recovery_codes,
+        }),
+    ))
+}
+
+/// POST /api/v1/settings/security/2fa/verify
+///
+/// The first successful call confirms enrollment and must use a TOTP code.
+pub async fn verify_2fa(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Json(request): Json<TwoFactorCodeRequest>,
+) -> Result<Json<VerifyTwoFactorResponse>> {
+    let mut conn = state.redis.get_multiplexed_async_connection().await?;
+
+    for _ in 0..MAX_UPDATE_ATTEMPTS {
+        let (mut record, stored) = load_record(&mut conn, user.user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Two-factor authentication is not enabled".to_string()))?;
+
+        let confirming = !record.confirmed;
+        let recovery_code_used = check_code(&state, &mut record, &request.code, !confirming, unix_time()).await?;
+        record.confirmed = true;
+
+        if replace_record(&mut conn, user.user_id, &stored, Some(&record)).await? {
+            if confirming {
+                info!(user_id = %user.user_id, "Two-factor authentication enabled");
+            }
+            return Ok(Json(VerifyTwoFactorResponse {
+                verified: true,
+                recovery_code_used,
+                recovery_codes_remaining: record.recovery_code_hashes.len(),
+            }));
+        }
+    }
+
+    Err(AppError::Conflict("Two-factor state changed during verification".to_string()))
+}
+
+/// DELETE /api/v1/settings/security/2fa
+///
+/// Requires a current TOTP or recovery code so a hijacked session cannot strip 2FA.
+pub async fn disable_2fa(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Json(request): Json<TwoFactorCodeRequest>,
+) -> Result<StatusCode> {
+    let mut conn = state.redis.get_multiplexed_async_connection().await?;
+
+    for _ in 0..MAX_UPDATE_ATTEMPTS {
+        let (mut record, stored) = load_record(&mut conn, user.user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Two-factor authentication is not enabled".to_string()))?;
+
+        check_code(&state, &mut record, &request.code, true, unix_time()).await?;
+        if replace_record(&mut conn, user.user_id, &stored, None).await? {
+            info!(user_id = %user.user_id, "Two-factor authentication disabled");
+            return Ok(StatusCode::NO_CONTENT);
+        }
+    }
+
+    Err(AppError::Conflict("Two-factor state changed during verification".to_string()))
+}
+
+/// Accept a TOTP code or consume a recovery code, returning whether a recovery code was used
+///
+/// Only updates `record`; the caller persists it with `replace_record`, which
+/// fails if another request spent a code in the meantime.
+async fn check_code(
+    state: &AppState,
+    record: &mut TwoFactorRecord,
+    code: &str,
+    allow_recovery_code: bool,
+    now: u64,
+) -> Result<bool> {
+    let code = code.trim();
+
+    if code.len() == TOTP_DIGITS as usize && code.bytes().all(|b| b.is_ascii_digit()) {
+        let secret = state.crypto.decrypt(&record.encrypted_secret).await?;
+        return match verify_totp(&secret, code, now, record.last_used_step) {
+            Some(step) => {
+                record.last_used_step = Some(step);
+                Ok(false)
+            }
+            None => {
+                warn!("Rejected invalid or replayed TOTP code");
+                Err(AppError::Unauthorized("Invalid two-factor code".to_string()))
+            }
+        };
+    }
+
+    if !allow_recovery_code {
+        warn!("Rejected recovery code before enrollment was confirmed");
+        return Err(AppError::Unauthorized("Confirm two-factor enrollment with an authenticator code".to_string()));
+    }
+
+    let candidate = hash_recovery_code(code);
+    match record
+        .recovery_code_hashes
+        .iter()
+        .position(|stored| bool::from(stored.as_bytes().ct_eq(candidate.as_bytes())))
+    {
+        Some(index) => {
+            record.recovery_code_hashes.remove(index);
+            Ok(true)
+        }
+        None => {
+            warn!("Rejected invalid recovery code");
+            Err(AppError::Unauthorized("Invalid two-factor code".to_string()))
+        }
+    }
+}
+
+/// Compute the RFC 6238 code for a time step
+pub fn totp_code(secret: &[u8], step: u64) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret);
+    let tag = hmac::sign(&key, &step.to_be_bytes());
+    let digest = tag.as_ref();
+
+    // Dynamic truncation (RFC 4226 section 5.3)
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+
+    format!("{:0width$}", binary % 10u32.pow(TOTP_DIGITS), width = TOTP_DIGITS as usize)
+}
+
+/// Verify a code at `now` within the allowed drift, returning the matched step.
+/// Steps at or before `last_used_step` are rejected so a code cannot be replayed.
+pub fn verify_totp(secret: &[u8], code: &str, now: u64, last_used_step: Option<u64>) -> Option<u64> {
+    let current = (now / TOTP_STEP_SECS) as i64;
+
+    (-TOTP_ALLOWED_DRIFT..=TOTP_ALLOWED_DRIFT)
+        .filter_map(|offset| u64::try_from(current + offset).ok())
+        .filter(|step| last_used_step.map_or(true, |last| *step > last))
+        .find(|step| bool::from(totp_code(secret, *step).as_bytes().ct_eq(code.as_bytes())))
+}
+
+/// Build the otpauth:// URI understood by authenticator apps
+pub fn provisioning_uri(encoded_secret: &str, account: &str) -> String {
+    format!(
+        "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={digits}&period={period}",
+        issuer = TOTP_ISSUER,
+        account = percent_encode(account),
+        secret = encoded_secret,
+        digits = TOTP_DIGITS,
+        period = TOTP_STEP_SECS,
+    )
+}
+
+fn generate_secret() -> Result<Vec<u8>> {
+    let mut secret = vec![0u8; SECRET_LENGTH];
+    SystemRandom::new()
+        .fill(&mut secret)
+        .map_err(|_| AppError::Internal("Failed to generate two-factor secret".to_string()))?;
+    Ok(secret)
+}
+
+fn generate_recovery_codes(count: usize) -> Result<Vec<String>> {
+    let rng = SystemRandom::new();
+    (0..count)
+        .map(|_| {
+            let mut bytes = [0u8; 10];
+            rng.fill(&mut bytes)
+                .map_err(|_| AppError::Internal("Failed to generate recovery codes".to_string()))?;
+            let chars: String = bytes
+                .iter()
+                .map(|b| RECOVERY_CODE_ALPHABET[*b as usize % RECOVERY_CODE_ALPHABET.len()] as char)
+                .collect();
+            Ok(format!("{}-{}", &chars[..5], &chars[5..]))
+        })
+        .collect()
+}
+
+fn hash_recovery_code(code: &str) -> String {
+    let normalized = code.trim().to_ascii_uppercase();
+    HEXLOWER.encode(digest::digest(&digest::SHA256, normalized.as_bytes()).as_ref())
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'@' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn record_key(user_id: Uuid) -> String {
+    format!("velocity:2fa:{}", user_id)
+}
+
+/// The user's record together with its stored JSON, which `replace_record` compares against
+async fn load_record(
+    conn: &mut redis::aio::MultiplexedConnection,
+    user_id: Uuid,
+) -> Result<Option<(TwoFactorRecord, String)>> {
+    let stored: Option<String> = conn.get(record_key(user_id)).await?;
+    stored
+        .map(|json| {
+            serde_json::from_str(&json)
+                .map(|record| (record, json))
+                .map_err(|e| AppError::Internal(format!("Corrupt two-factor record: {}", e)))
+        })
+        .transpose()
+}
+
+async fn store_record(
+    conn: &mut redis::aio::MultiplexedConnection,
+    user_id: Uuid,
+    record: &TwoFactorRecord,
+) -> Result<()> {
+    let json = serde_json::to_string(record)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize two-factor record: {}", e)))?;
+    conn.set::<_, _, ()>(record_key(user_id), json).await?;
+    Ok(())
+}
+
+/// Replace the stored record with `record`, or delete it with `None`, only if it
+/// still holds `expected`; returns false if another request changed it first
+async fn replace_record(
+    conn: &mut redis::aio::MultiplexedConnection,
+    user_id: Uuid,
+    expected: &str,
+    record: Option<&TwoFactorRecord>,
+) -> Result<bool> {
+    let replacement = record
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|e| AppError::Internal(format!("Failed to serialize two-factor record: {}", e)))?;
+
+    let replaced: i64 = redis::Script::new(
+        r#"if redis.call("GET", KEYS[1]) ~= ARGV[1] then return 0 end
+if ARGV[2] == "" then redis.call("DEL", KEYS[1]) else redis.call("SET", KEYS[1], ARGV[2]) end
+return 1"#,
+    )
+    .key(record_key(user_id))
+    .arg(expected)
+    .arg(replacement.unwrap_or_default())
+    .invoke_async(conn)
+    .await?;
+    Ok(replaced == 1)
+}
+
+fn unix_time() -> u64 {
+    chrono::Utc::now().timestamp().max(0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 6238 Appendix B SHA-1 seed
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn test_rfc6238_vectors() {
+        // Six-digit truncations of the published eight-digit values
+        assert_eq!(totp_code(RFC_SECRET, 59 / TOTP_STEP_SECS), "287082");
+        assert_eq!(totp_code(RFC_SECRET, 1111111109 / TOTP_STEP_SECS), "081804");
+        assert_eq!(totp_code(RFC_SECRET, 1234567890 / TOTP_STEP_SECS), "005924");
+        assert_eq!(totp_code(RFC_SECRET, 20000000000 / TOTP_STEP_SECS), "353130");
+    }
+
+    #[test]
+    fn test_drift_window_and_replay() {
+        let now = 1_700_000_000;
+        let step = now / TOTP_STEP_SECS;
+
+        let previous = totp_code(RFC_SECRET, step - 1);
+        let next = totp_code(RFC_SECRET, step + 1);
+        let stale = totp_code(RFC_SECRET, step - 2);
+
+        assert_eq!(verify_totp(RFC_SECRET, &previous, now, None), Some(step - 1));
+        assert_eq!(verify_totp(RFC_SECRET, &next, now, None), Some(step + 1));
+        assert_eq!(verify_totp(RFC_SECRET, &stale, now, None), None);
+
+        let current = totp_code(RFC_SECRET, step);
+        assert_eq!(verify_totp(RFC_SECRET, &current, now, Some(step)), None);
+        assert_eq!(verify_totp(RFC_SECRET, &previous, now, Some(step)), None);
+    }
+
+    #[test]
+    fn test_provisioning_uri_and_recovery_codes() {
+        let uri = provisioning_uri("GEZDGNBVGY3TQOJQ", "jane doe@example.com");
+        assert!(uri.starts_with("otpauth://totp/Velocity:jane%20doe@example.com?secret=GEZDGNBVGY3TQOJQ"));
+        assert!(uri.contains("&digits=6&period=30"));
+
+        let codes = generate_recovery_codes(RECOVERY_CODE_COUNT).unwrap();
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        assert!(codes.iter().all(|c| c.len() == 11 && c.as_bytes()[5] == b'-'));
+        assert_eq!(hash_recovery_code(&codes[0].to_lowercase()), hash_recovery_code(&codes[0]));
+    }
+}
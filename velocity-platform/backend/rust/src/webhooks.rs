@@ -0,0 +1,391 @@
+/*!
+ * Signed Outbound Webhooks
+ *
+ * Security events are delivered as JSON with an ed25519 signature so receivers
+ * can trust the payload. Password changes and session revocations are sent to
+ * every endpoint in `VELOCITY_WEBHOOK_ENDPOINTS`. Verification recipe for receivers:
+ *
+ * 1. Read `X-Velocity-Timestamp` and `X-Velocity-Signature` (`t=<unix>,ed25519=<hex>`)
+ * 2. Reject timestamps outside the tolerance window to prevent replay
+ * 3. Build the canonical payload `"velocity:webhook:v1|<timestamp>.<raw request body>"`
+ * 4. Verify the signature over it with the public key from `GET /webhooks/verification`
+ *
+ * The domain tag keeps a webhook signature from verifying as any other message
+ * signed with the same key, such as a credential status response.
+ */
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{Json, Response},
+};
+use chrono::{DateTime, Utc};
+use data_encoding::HEXLOWER;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::errors::{AppError, Result};
+use crate::middleware_auth::AuthenticatedUser;
+use crate::AppState;
+
+pub const SIGNATURE_HEADER: &str = "X-Velocity-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Velocity-Timestamp";
+pub const EVENT_ID_HEADER: &str = "X-Velocity-Event-Id";
+/// Maximum age (or future skew) of a webhook timestamp accepted by receivers
+pub const DEFAULT_TOLERANCE_SECS: i64 = 300;
+/// Domain tag prefixed to every signed webhook payload
+pub const WEBHOOK_SIGNATURE_DOMAIN: &str = "velocity:webhook:v1";
+/// Comma-separated customer endpoints that receive security events
+pub const WEBHOOK_ENDPOINTS_ENV: &str = "VELOCITY_WEBHOOK_ENDPOINTS";
+
+/// Security events delivered to customer endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventType {
+    PasswordChanged,
+    SessionRevoked,
+    AllSessionsRevoked,
+    TwoFactorEnabled,
+    TwoFactorDisabled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    pub id: Uuid,
+    #[serde(rename = "type")]
+    pub event_type: WebhookEventType,
+    pub user_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+}
+
+impl WebhookEvent {
+    pub fn new(event_type: WebhookEventType, user_id: Uuid, data: serde_json::Value) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_type,
+            user_id,
+            occurred_at: Utc::now(),
+            data,
+        }
+    }
+}
+
+/// A serialized event with its signature headers
+#[derive(Debug, Clone)]
+pub struct SignedWebhook {
+    pub event_id: Uuid,
+    pub body: String,
+    pub timestamp: i64,
+    pub signature: String,
+}
+
+impl SignedWebhook {
+    /// Value of the `X-Velocity-Signature` header
+    pub fn signature_header(&self) -> String {
+        format!("t={},ed25519={}", self.timestamp, self.signature)
+    }
+
+    pub fn headers(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (SIGNATURE_HEADER, self.signature_header()),
+            (TIMESTAMP_HEADER, self.timestamp.to_string()),
+            (EVENT_ID_HEADER, self.event_id.to_string()),
+        ]
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum WebhookVerificationError {
+    #[error("Malformed signature header")]
+    MalformedHeader,
+    #[error("Webhook timestamp outside the tolerance window")]
+    StaleTimestamp,
+    #[error("Webhook signature does not match payload")]
+    InvalidSignature,
+}
+
+/// The exact bytes covered by a webhook signature
+pub fn canonical_payload(timestamp: i64, body: &str) -> String {
+    format!("{}|{}.{}", WEBHOOK_SIGNATURE_DOMAIN, timestamp, body)
+}
+
+/// Serialize and sign an event at `timestamp`
+pub fn sign_event(signing_key: &SigningKey, event: &WebhookEvent, timestamp: i64) -> Result<SignedWebhook> {
+    let body = serde_json::to_string(event)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize webhook event: {}", e)))?;
+    let signature = signing_key.sign(canonical_payload(timestamp, &body).as_bytes());
+
+    Ok(SignedWebhook {
+        event_id: event.id,
+        body,
+        timestamp,
+        signature: HEXLOWER.encode(&signature.to_bytes()),
+    })
+}
+
+/// Verify a received webhook the way a customer endpoint should
+pub fn verify_webhook(
+    verifying_key: &VerifyingKey,
+    body: &str,
+    signature_header: &str,
+    now: i64,
+    tolerance_secs: i64,
+) -> std::result::Result<(), WebhookVerificationError> {
+    let (timestamp, signature_hex) = parse_signature_header(signature_header)?;
+    // A negative tolerance accepts nothing rather than wrapping to a huge window
+    if now.abs_diff(timestamp) > u64::try_from(tolerance_secs).unwrap_or(0) {
+        return Err(WebhookVerificationError::StaleTimestamp);
+    }
+
+    let signature_bytes: [u8; 64] = HEXLOWER
+        .decode(signature_hex.as_bytes())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(WebhookVerificationError::MalformedHeader)?;
+    let signature = Signature::from_bytes(&signature_bytes);
+
+    verifying_key
+        .verify(canonical_payload(timestamp, body).as_bytes(), &signature)
+        .map_err(|_| WebhookVerificationError::InvalidSignature)
+}
+
+fn parse_signature_header(header: &str) -> std::result::Result<(i64, &str), WebhookVerificationError> {
+    let mut timestamp = None;
+    let mut signature = None;
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+            Some(("ed25519", value)) => signature = Some(value),
+            _ => {}
+        }
+    }
+
+    match (timestamp, signature) {
+        (Some(timestamp), Some(signature)) => Ok((timestamp, signature)),
+        _ => Err(WebhookVerificationError::MalformedHeader),
+    }
+}
+
+/// Sign and POST an event to a customer endpoint
+pub async fn deliver_event(
+    signing_key: &SigningKey,
+    client: &reqwest::Client,
+    endpoint: &str,
+    event: &WebhookEvent,
+) -> Result<reqwest::StatusCode> {
+    let signed = sign_event(signing_key, event, Utc::now().timestamp())?;
+
+    let mut request = client
+        .post(endpoint)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+    for (name, value) in signed.headers() {
+        request = request.header(name, value);
+    }
+
+    let response = request
+        .body(signed.body)
+        .send()
+        .await
+        .map_err(|e| AppError::Internal(format!("Webhook delivery failed: {}", e)))?;
+
+    let status = response.status();
+    if status.is_success() {
+        info!(event_id = %event.id, endpoint, "Webhook delivered");
+    } else {
+        warn!(event_id = %event.id, endpoint, %status, "Webhook endpoint rejected delivery");
+    }
+    Ok(status)
+}
+
+/// Customer endpoints receiving security events, with the client that posts to them
+#[derive(Debug, Clone, Default)]
+pub struct WebhookSender {
+    pub endpoints: Vec<String>,
+    client: reqwest::Client,
+}
+
+impl WebhookSender {
+    pub fn new(endpoints: Vec<String>) -> Self {
+        Self {
+            endpoints,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Endpoints listed in `VELOCITY_WEBHOOK_ENDPOINTS`; none when unset
+    pub fn from_env() -> Self {
+        let endpoints = std::env::var(WEBHOOK_ENDPOINTS_ENV)
+            .map(|endpoints| {
+                endpoints
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|endpoint| !endpoint.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self::new(endpoints)
+    }
+
+    /// Deliver `event` to every endpoint in the background; failures are logged
+    pub fn send(&self, signing_key: &SigningKey, event: WebhookEvent) {
+        for endpoint in &self.endpoints {
+            let (signing_key, client, endpoint, event) =
+                (signing_key.clone(), self.client.clone(), endpoint.clone(), event.clone());
+            tokio::spawn(async move {
+                if let Err(e) = deliver_event(&signing_key, &client, &endpoint, &event).await {
+                    warn!(event_id = %event.id, endpoint = %endpoint, error = %e, "Webhook delivery failed");
+                }
+            });
+        }
+    }
+}
+
+/// Axum middleware sending `event_type` for the authenticated user once the
+/// wrapped handler succeeds; must run after authentication
+pub async fn security_event_middleware(
+    State((state, event_type)): State<(AppState, WebhookEventType)>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let user_id = request.extensions().get::<AuthenticatedUser>().map(|user| user.user_id);
+    let response = next.run(request).await;
+
+    if let Some(user_id) = user_id.filter(|_| response.status().is_success()) {
+        let event = WebhookEvent::new(event_type, user_id, serde_json::json!({}));
+        state.webhooks.send(state.crypto.signing_key(), event);
+    }
+    response
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookVerificationRecipe {
+    pub algorithm: String,
+    pub public_key: String,
+    pub signature_header: String,
+    pub timestamp_header: String,
+    pub canonical_payload: String,
+    pub tolerance_secs: i64,
+}
+
+/// GET /api/v1/webhooks/verification
+pub async fn verification_recipe(State(state): State<AppState>) -> Json<WebhookVerificationRecipe> {
+    let public_key = state.crypto.signing_key().verifying_key();
+
+    Json(WebhookVerificationRecipe {
+        algorithm: "ed25519".to_string(),
+        public_key: HEXLOWER.encode(public_key.as_bytes()),
+        signature_header: format!("{}: t=<timestamp>,ed25519=<hex signature>", SIGNATURE_HEADER),
+        timestamp_header: TIMESTAMP_HEADER.to_string(),
+        canonical_payload: format!("{}|<timestamp>.<raw request body>", WEBHOOK_SIGNATURE_DOMAIN),
+        tolerance_secs: DEFAULT_TOLERANCE_SECS,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_key() -> SigningKey {
+        SigningKey::from_bytes(&[42u8; 32])
+    }
+
+    fn password_changed() -> WebhookEvent {
+        WebhookEvent::new(
+            WebhookEventType::PasswordChanged,
+            Uuid::new_v4(),
+            serde_json::json!({ "ip_address": "203.0.113.7" }),
+        )
+    }
+
+    #[test]
+    fn test_signed_payload_verifies() {
+        let key = test_key();
+        let now = 1_700_000_000;
+        let signed = sign_event(&key, &password_changed(), now).unwrap();
+
+        assert!(signed.body.contains("\"type\":\"password_changed\""));
+        assert_eq!(
+            verify_webhook(&key.verifying_key(), &signed.body, &signed.signature_header(), now + 10, DEFAULT_TOLERANCE_SECS),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_modified_body_fails() {
+        let key = test_key();
+        let now = 1_700_000_000;
+        let signed = sign_event(&key, &password_changed(), now).unwrap();
+        let modified = signed.body.replace("203.0.113.7", "198.51.100.1");
+
+        assert_eq!(
+            verify_webhook(&key.verifying_key(), &modified, &signed.signature_header(), now, DEFAULT_TOLERANCE_SECS),
+            Err(WebhookVerificationError::InvalidSignature)
+        );
+
+        // Shifting the timestamp to dodge the replay window also breaks the signature
+        let shifted_header = format!("t={},ed25519={}", now + 600, signed.signature);
+        assert_eq!(
+            verify_webhook(&key.verifying_key(), &signed.body, &shifted_header, now + 600, DEFAULT_TOLERANCE_SECS),
+            Err(WebhookVerificationError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_signature_over_untagged_payload_fails() {
+        let key = test_key();
+        let now = 1_700_000_000;
+        let body = serde_json::to_string(&password_changed()).unwrap();
+
+        // Same key and bytes, but signed without the webhook domain tag
+        let untagged = key.sign(format!("{}.{}", now, body).as_bytes());
+        let header = format!("t={},ed25519={}", now, HEXLOWER.encode(&untagged.to_bytes()));
+        assert_eq!(
+            verify_webhook(&key.verifying_key(), &body, &header, now, DEFAULT_TOLERANCE_SECS),
+            Err(WebhookVerificationError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_stale_and_malformed_headers_rejected() {
+        let key = test_key();
+        let now = 1_700_000_000;
+        let signed = sign_event(&key, &password_changed(), now).unwrap();
+
+        assert_eq!(
+            verify_webhook(&key.verifying_key(), &signed.body, &signed.signature_header(), now + 301, DEFAULT_TOLERANCE_SECS),
+            Err(WebhookVerificationError::StaleTimestamp)
+        );
+        assert_eq!(
+            verify_webhook(&key.verifying_key(), &signed.body, "ed25519=abcd", now, DEFAULT_TOLERANCE_SECS),
+            Err(WebhookVerificationError::MalformedHeader)
+        );
+    }
+
+    #[test]
+    fn test_extreme_timestamps_are_stale_not_overflowing() {
+        let key = test_key();
+        let now = 1_700_000_000;
+        let signed = sign_event(&key, &password_changed(), now).unwrap();
+
+        for timestamp in [i64::MIN, i64::MAX] {
+            let header = format!("t={},ed25519={}", timestamp, signed.signature);
+            assert_eq!(
+                verify_webhook(&key.verifying_key(), &signed.body, &header, now, DEFAULT_TOLERANCE_SECS),
+                Err(WebhookVerificationError::StaleTimestamp)
+            );
+        }
+        assert_eq!(
+            verify_webhook(&key.verifying_key(), &signed.body, &signed.signature_header(), i64::MIN, DEFAULT_TOLERANCE_SECS),
+            Err(WebhookVerificationError::StaleTimestamp)
+        );
+        assert_eq!(
+            verify_webhook(&key.verifying_key(), &signed.body, &signed.signature_header(), now, -1),
+            Err(WebhookVerificationError::StaleTimestamp)
+        );
+    }
+}
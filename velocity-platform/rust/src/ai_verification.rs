@@ -8,8 +8,42 @@ use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
 use std::collections::HashMap;
 use chrono::{DateTime, Utc};
+use ed25519_dalek::{Keypair, PublicKey, Signature, Signer, Verifier};
 use uuid::Uuid;
 
+/// Largest distance of a reasoning chain's total step weight from 1
+pub const REASONING_WEIGHT_TOLERANCE: f64 = 0.05;
+
+/// How far a final confidence may exceed the most confident reasoning step
+pub const REASONING_CONFIDENCE_MARGIN: f64 = 0.05;
+
+/// Limits a decision must stay within to pass a framework compliance check
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ComplianceThresholds {
+    /// Confidence below which a decision is flagged (0.0-1.0)
+    pub min_confidence: f64,
+    /// Overall risk above which a decision is flagged (0.0-1.0)
+    pub max_risk: f64,
+}
+
+impl ComplianceThresholds {
+    fn validate(&self) -> Result<(), VerificationError> {
+        if !(0.0..=1.0).contains(&self.min_confidence) || !(0.0..=1.0).contains(&self.max_risk) {
+            return Err(VerificationError::InvalidInput(format!(
+                "Compliance thresholds must be between 0.0 and 1.0: {:?}",
+                self
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Default for ComplianceThresholds {
+    fn default() -> Self {
+        ComplianceThresholds { min_confidence: 0.7, max_risk: 0.8 }
+    }
+}
+
 /// AI decision with cryptographic proof
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AIDecisionProof {
@@ -30,6 +64,9 @@ pub struct HumanOversight {
     pub review_hash: String,
     pub approval_signature: String,
     pub timestamp: String,
+    /// Ring key that signed `approval_signature`; `None` for records signed by the ring's original key
+    #[serde(default)]
+    pub key_id: Option<String>,
 }
 
 /// AI decision input data
@@ -55,6 +92,9 @@ pub struct AIDecisionOutput {
     pub risk_assessment: RiskAssessment,
     pub compliance_flags: Vec<ComplianceFlag>,
     pub processing_metrics: ProcessingMetrics,
+    /// Inference runtime's signature over `processing_metrics`
+    #[serde(default)]
+    pub metrics_attestation: Option<MetricsAttestation>,
 }
 
 /// Reasoning step in AI decision process
@@ -137,6 +177,42 @@ pub struct ProcessingMetrics {
     pub cache_misses: u32,
 }
 
+/// Inference runtime's signed statement of the metrics it measured
+///
+/// Metrics are outside the response hash, so a provider cannot make a decision
+/// look cheaper or faster without invalidating this signature instead.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MetricsAttestation {
+    pub runtime_id: String,
+    pub metrics_hash: String,
+    /// Hex ed25519 signature by the runtime's registered key
+    pub signature: String,
+}
+
+impl MetricsAttestation {
+    /// Attest `metrics` for `decision_id`, as the inference runtime does
+    pub fn sign(runtime_id: &str, decision_id: &str, metrics: &ProcessingMetrics, keypair: &Keypair) -> Self {
+        let metrics_hash = hash_processing_metrics(metrics);
+        let signature = keypair.sign(Self::signing_payload(runtime_id, decision_id, &metrics_hash).as_bytes());
+        MetricsAttestation {
+            runtime_id: runtime_id.to_string(),
+            metrics_hash,
+            signature: hex::encode(signature.to_bytes()),
+        }
+    }
+
+    fn signing_payload(runtime_id: &str, decision_id: &str, metrics_hash: &str) -> String {
+        domain_separated(DOMAIN_PROCESSING_METRICS, &encode_fields(&[runtime_id, decision_id, metrics_hash]))
+    }
+}
+
+/// SHA-256 of the canonical JSON of `metrics`
+pub fn hash_processing_metrics(metrics: &ProcessingMetrics) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(canonical_json(metrics).unwrap_or_default().as_bytes());
+    hex::encode(hasher.finalize())
+}
+
 /// Human review data
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HumanReview {
@@ -192,6 +268,12 @@ pub struct AIVerificationEngine {
     model_registry: HashMap<String, ModelInfo>,
     compliance_rules: Vec<ComplianceRule>,
     reviewers: HashMap<String, ReviewerInfo>,
+    inference_runtimes: HashMap<String, PublicKey>,
+    compliance_thresholds: ComplianceThresholds,
+    /// Frameworks re-checked under the current thresholds during verification
+    compliance_recheck: Option<Vec<String>>,
+    /// Published ring of the engine that signs decision proofs and approvals
+    proof_keys: Option<VerifyingKeys>,
 }
 
 /// Model information
@@ -240,7 +322,47 @@ impl AIVerificationEngine {
             model_registry: HashMap::new(),
             compliance_rules: Vec::new(),
             reviewers: HashMap::new(),
+            inference_runtimes: HashMap::new(),
+            compliance_thresholds: ComplianceThresholds::default(),
+            compliance_recheck: None,
+            proof_keys: None,
+        }
+    }
+
+    /// Verify decision proofs and human oversight approvals against `keys`, the
+    /// published ring of the engine that issues them; until set, none verify
+    pub fn set_proof_keys(&mut self, keys: VerifyingKeys) {
+        self.proof_keys = Some(keys);
+    }
+
+    /// Set the thresholds `perform_compliance_check` applies
+    pub fn set_compliance_thresholds(&mut self, thresholds: ComplianceThresholds) -> Result<(), VerificationError> {
+        thresholds.validate()?;
+        self.compliance_thresholds = thresholds;
+        Ok(())
+    }
+
+    /// Re-run the compliance check for `frameworks` when verifying decision proofs
+    ///
+    /// The result is compared with the flags the decision recorded for those
+    /// frameworks, so a decision the current rules would reject is reported.
+    /// `None` turns the re-check off.
+    pub fn set_compliance_recheck(&mut self, frameworks: Option<Vec<String>>) {
+        self.compliance_recheck = frameworks;
+    }
+
+    /// Trust metrics attestations from `runtime_id` signed by `public_key`
+    ///
+    /// A key cannot be replaced, since attestations it signed would stop verifying.
+    pub fn register_inference_runtime(&mut self, runtime_id: &str, public_key: PublicKey) -> Result<(), VerificationError> {
+        if self.inference_runtimes.contains_key(runtime_id) {
+            return Err(VerificationError::PolicyViolation(format!(
+                "Inference runtime {} already has a registered key",
+                runtime_id
+            )));
         }
+        self.inference_runtimes.insert(runtime_id.to_string(), public_key);
+        Ok(())
     }
 
     /// Create AI decision proof with cryptographic verification
@@ -250,7 +372,7 @@ impl AIVerificationEngine {
         output: &AIDecisionOutput,
         human_review: &HumanReview,
         crypto_engine: &mut VelocityCryptographicEngine,
-    ) -> Result<AIDecisionProof, String> {
+    ) -> Result<AIDecisionProof, VerificationError> {
         
         // Validate input and output data
         self.validate_ai_decision_data(input, output)?;
@@ -261,18 +383,17 @@ impl AIVerificationEngine {
         let response_hash = self.hash_response_data(output);
 
         // Create cryptographic proof
-        let proof_data = format!(
-            "{}{}{}{}{}",
-            input.decision_id,
-            model_hash,
-            prompt_hash,
-            response_hash,
-            output.confidence_score
-        );
+        let proof_data = encode_fields(&[
+            &input.decision_id,
+            &model_hash,
+            &prompt_hash,
+            &response_hash,
+            &output.confidence_score.to_string(),
+        ]);
         let cryptographic_proof = self.create_ai_proof(&proof_data, crypto_engine);
 
         // Create human oversight record
-        let human_oversight = self.create_human_oversight_record(human_review, &input.decision_id);
+        let human_oversight = self.create_human_oversight_record(human_review, &input.decision_id, crypto_engine);
 
         // Generate audit trail
         let audit_trail = self.generate_audit_trail(input, output, human_review);
@@ -295,25 +416,28 @@ impl AIVerificationEngine {
         proof: &AIDecisionProof,
         original_input: &AIDecisionInput,
         original_output: &AIDecisionOutput,
-    ) -> Result<AIVerificationResult, String> {
+    ) -> Result<AIVerificationResult, VerificationError> {
         
         // Verify model hash
         let expected_model_hash = self.hash_model_version(
             &original_input.model_version,
             &original_input.model_parameters,
         );
-        let model_hash_valid = expected_model_hash == proof.model_hash;
+        let model_hash_valid = ct_eq(&expected_model_hash, &proof.model_hash);
 
         // Verify prompt hash
         let expected_prompt_hash = self.hash_prompt_data(
             &original_input.prompt,
             &original_input.context_data,
         );
-        let prompt_hash_valid = expected_prompt_hash == proof.prompt_hash;
+        let prompt_hash_valid = ct_eq(&expected_prompt_hash, &proof.prompt_hash);
 
-        // Verify response hash
-        let expected_response_hash = self.hash_response_data(original_output);
-        let response_hash_valid = expected_response_hash == proof.response_hash;
+        // Verify response hash; proofs issued before metrics were attested hash them with the response
+        let response_hash_valid = ct_eq(self.hash_response_data(original_output), &proof.response_hash)
+            || ct_eq(self.hash_legacy_response_data(original_output), &proof.response_hash);
+
+        // Verify the runtime's metrics attestation, reported apart from response integrity
+        let metrics_integrity_valid = self.verify_metrics_attestation(&proof.decision_id, original_output);
 
         // Verify confidence score consistency
         let confidence_consistent = (original_output.confidence_score - proof.confidence_score).abs() < 0.001;
@@ -322,11 +446,15 @@ impl AIVerificationEngine {
         let crypto_proof_valid = self.verify_cryptographic_proof(&proof.cryptographic_proof);
 
         // Verify human oversight signature
-        let oversight_valid = self.verify_human_oversight(&proof.human_oversight, &proof.decision_id);
+        let oversight_valid = self.verify_human_oversight(&proof.human_oversight, &proof.decision_id, &proof.cryptographic_proof.timestamp);
 
         // Verify audit trail integrity
         let audit_trail_valid = self.verify_audit_trail(&proof.audit_trail, original_input, original_output);
 
+        // Re-check compliance under the current rules, reported apart from proof integrity
+        let compliance_discrepancy = self.compliance_recheck.as_ref()
+            .and_then(|frameworks| self.recheck_compliance(original_input, original_output, frameworks));
+
         // Calculate verification confidence
         let verification_confidence = self.calculate_ai_verification_confidence(
             model_hash_valid,
@@ -350,6 +478,8 @@ impl AIVerificationEngine {
                 crypto_proof_valid,
                 oversight_valid,
                 audit_trail_valid,
+                metrics_integrity_valid,
+                compliance_discrepancy,
                 risk_assessment: self.assess_decision_risk(original_output),
             },
             timestamp: Utc::now().to_rfc3339(),
@@ -384,12 +514,13 @@ impl AIVerificationEngine {
             compliance_results.insert(framework.clone(), framework_result);
         }
 
+        let compliance_score = self.calculate_compliance_score(&compliance_results);
         ComplianceCheckResult {
             overall_compliant,
             framework_results: compliance_results,
-            critical_violations,
             remediation_required: !critical_violations.is_empty(),
-            compliance_score: self.calculate_compliance_score(&compliance_results),
+            critical_violations,
+            compliance_score,
         }
     }
 
@@ -440,70 +571,117 @@ impl AIVerificationEngine {
 
     // Private helper methods
 
-    fn validate_ai_decision_data(&self, input: &AIDecisionInput, output: &AIDecisionOutput) -> Result<(), String> {
+    fn validate_ai_decision_data(&self, input: &AIDecisionInput, output: &AIDecisionOutput) -> Result<(), VerificationError> {
         if input.decision_id.is_empty() {
-            return Err("Decision ID cannot be empty".to_string());
+            return Err(VerificationError::InvalidInput("Decision ID cannot be empty".to_string()));
         }
         
         if input.model_version.is_empty() {
-            return Err("Model version cannot be empty".to_string());
+            return Err(VerificationError::InvalidInput("Model version cannot be empty".to_string()));
         }
         
         if output.confidence_score < 0.0 || output.confidence_score > 1.0 {
-            return Err("Confidence score must be between 0.0 and 1.0".to_string());
+            return Err(VerificationError::InvalidInput("Confidence score must be between 0.0 and 1.0".to_string()));
         }
         
         Ok(())
     }
 
     fn hash_model_version(&self, version: &str, parameters: &HashMap<String, serde_json::Value>) -> String {
-        let data = format!("{}{}", version, serde_json::to_string(parameters).unwrap_or_default());
+        let data = encode_fields(&[version, &canonical_json(parameters).unwrap_or_default()]);
         let mut hasher = Sha256::new();
         hasher.update(data.as_bytes());
         hex::encode(hasher.finalize())
     }
 
     fn hash_prompt_data(&self, prompt: &str, context: &HashMap<String, serde_json::Value>) -> String {
-        let data = format!("{}{}", prompt, serde_json::to_string(context).unwrap_or_default());
+        let data = encode_fields(&[prompt, &canonical_json(context).unwrap_or_default()]);
         let mut hasher = Sha256::new();
         hasher.update(data.as_bytes());
         hex::encode(hasher.finalize())
     }
 
+    /// Hash of the response content, excluding the separately attested metrics
     fn hash_response_data(&self, output: &AIDecisionOutput) -> String {
-        let data = serde_json::to_string(output).unwrap_or_default();
+        self.hash_output_without(output, &["processing_metrics", "metrics_attestation"])
+    }
+
+    fn hash_legacy_response_data(&self, output: &AIDecisionOutput) -> String {
+        self.hash_output_without(output, &["metrics_attestation"])
+    }
+
+    fn hash_output_without(&self, output: &AIDecisionOutput, excluded: &[&str]) -> String {
+        let mut value = serde_json::to_value(output).unwrap_or_default();
+        if let Some(fields) = value.as_object_mut() {
+            for field in excluded {
+                fields.remove(*field);
+            }
+        }
+        let data = canonical_json(&value).unwrap_or_default();
         let mut hasher = Sha256::new();
         hasher.update(data.as_bytes());
         hex::encode(hasher.finalize())
     }
 
-    fn create_ai_proof(&self, data: &str, _crypto_engine: &mut VelocityCryptographicEngine) -> CryptographicProof {
+    fn verify_metrics_attestation(&self, decision_id: &str, output: &AIDecisionOutput) -> bool {
+        let attestation = match &output.metrics_attestation {
+            Some(attestation) => attestation,
+            None => return false,
+        };
+        let public_key = match self.inference_runtimes.get(&attestation.runtime_id) {
+            Some(public_key) => public_key,
+            None => return false,
+        };
+        if !ct_eq(hash_processing_metrics(&output.processing_metrics), &attestation.metrics_hash) {
+            return false;
+        }
+
+        let payload = MetricsAttestation::signing_payload(&attestation.runtime_id, decision_id, &attestation.metrics_hash);
+        match hex::decode(&attestation.signature).ok().and_then(|bytes| Signature::from_bytes(&bytes).ok()) {
+            Some(signature) => public_key.verify(payload.as_bytes(), &signature).is_ok(),
+            None => false,
+        }
+    }
+
+    fn create_ai_proof(&self, data: &str, crypto_engine: &mut VelocityCryptographicEngine) -> CryptographicProof {
         let hash = {
             let mut hasher = Sha256::new();
             hasher.update(data.as_bytes());
             hex::encode(hasher.finalize())
         };
 
-        CryptographicProof {
-            id: format!("ai_proof_{}", Uuid::new_v4()),
+        let id = format!("ai_proof_{}", Uuid::new_v4());
+        let nonce = CryptographicProof::generate_nonce();
+        let timestamp = Utc::now().to_rfc3339();
+
+        let mut proof = CryptographicProof {
+            format_version: CURRENT_PROOF_FORMAT_VERSION,
+            id,
             hash: hash.clone(),
-            signature: self.sign_data(&hash),
-            timestamp: Utc::now().to_rfc3339(),
+            signature: String::new(),
+            timestamp,
             previous_hash: None,
             merkle_root: None,
             block_height: 0,
             verification_status: "verified".to_string(),
-        }
-    }
-
-    fn sign_data(&self, data: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(format!("ai_sign_{}", data).as_bytes());
-        hex::encode(hasher.finalize())
+            nonce,
+            key_id: None,
+            domain: Some(DOMAIN_AI_DECISION.to_string()),
+            timestamp_token: None,
+            pq_signature: None,
+            genesis_anchor: None,
+        };
+        crypto_engine.sign_proof(&mut proof);
+        proof
     }
 
-    fn create_human_oversight_record(&self, review: &HumanReview, decision_id: &str) -> HumanOversight {
-        let review_data = format!("{}{}{}", decision_id, review.reviewer_id, review.review_timestamp);
+    fn create_human_oversight_record(
+        &self,
+        review: &HumanReview,
+        decision_id: &str,
+        crypto_engine: &VelocityCryptographicEngine,
+    ) -> HumanOversight {
+        let review_data = encode_fields(&[decision_id, &review.reviewer_id, &review.review_timestamp]);
         let review_hash = {
             let mut hasher = Sha256::new();
             hasher.update(review_data.as_bytes());
@@ -513,8 +691,9 @@ impl AIVerificationEngine {
         HumanOversight {
             reviewer_id: review.reviewer_id.clone(),
             review_hash: review_hash.clone(),
-            approval_signature: self.sign_data(&review_hash),
+            approval_signature: crypto_engine.sign_data(&domain_separated(DOMAIN_HUMAN_REVIEW, &review_hash)),
             timestamp: review.review_timestamp.clone(),
+            key_id: Some(crypto_engine.key_ring().active_key_id().to_string()),
         }
     }
 
@@ -532,18 +711,25 @@ impl AIVerificationEngine {
     fn verify_cryptographic_proof(&self, proof: &CryptographicProof) -> bool {
         proof.verification_status == "verified" && 
         proof.hash.len() == 64 && 
-        proof.signature.len() > 0
+        self.proof_keys.as_ref().is_some_and(|keys| verify_domain_proof(keys, proof, DOMAIN_AI_DECISION))
     }
 
-    fn verify_human_oversight(&self, oversight: &HumanOversight, decision_id: &str) -> bool {
-        let expected_review_data = format!("{}{}{}", decision_id, oversight.reviewer_id, oversight.timestamp);
+    /// Whether `oversight` is the approval of `decision_id` signed by the ring
+    /// key it names, valid when the decision proof was issued at `signed_at`
+    fn verify_human_oversight(&self, oversight: &HumanOversight, decision_id: &str, signed_at: &str) -> bool {
+        let expected_review_data = encode_fields(&[decision_id, &oversight.reviewer_id, &oversight.timestamp]);
         let expected_hash = {
             let mut hasher = Sha256::new();
             hasher.update(expected_review_data.as_bytes());
             hex::encode(hasher.finalize())
         };
         
-        expected_hash == oversight.review_hash && oversight.approval_signature.len() > 0
+        let public_key = self.proof_keys.as_ref().zip(parse_proof_timestamp(signed_at))
+            .and_then(|(keys, signed_at)| keys.verifying_key(oversight.key_id.as_deref(), signed_at));
+        ct_eq(&expected_hash, &oversight.review_hash)
+            && public_key.is_some_and(|public_key| {
+                verify_hex_signature(public_key, &oversight.approval_signature, &domain_separated(DOMAIN_HUMAN_REVIEW, &expected_hash))
+            })
     }
 
     fn verify_audit_trail(&self, _trail: &[String], _input: &AIDecisionInput, _output: &AIDecisionOutput) -> bool {
@@ -551,6 +737,25 @@ impl AIVerificationEngine {
         !_trail.is_empty()
     }
 
+    /// Compare the decision's recorded flags for `frameworks` with a fresh check
+    fn recheck_compliance(&self, input: &AIDecisionInput, output: &AIDecisionOutput, frameworks: &[String]) -> Option<ComplianceDiscrepancy> {
+        let recorded_compliant = !output.compliance_flags.iter().any(|flag| frameworks.contains(&flag.framework));
+        let recheck = self.perform_compliance_check(input, output, frameworks);
+        if recorded_compliant == recheck.overall_compliant {
+            return None;
+        }
+
+        let mut violations: Vec<ComplianceFlag> = recheck.framework_results.into_values()
+            .flat_map(|result| result.violations)
+            .collect();
+        violations.sort_by(|a, b| (&a.framework, &a.flag_type).cmp(&(&b.framework, &b.flag_type)));
+        Some(ComplianceDiscrepancy {
+            recorded_compliant,
+            rechecked_compliant: recheck.overall_compliant,
+            violations,
+        })
+    }
+
     fn calculate_ai_verification_confidence(&self, model_valid: bool, prompt_valid: bool, response_valid: bool, confidence_consistent: bool, crypto_valid: bool, oversight_valid: bool, trail_valid: bool) -> f64 {
         let validations = [model_valid, prompt_valid, response_valid, confidence_consistent, crypto_valid, oversight_valid, trail_valid];
         let valid_count = validations.iter().filter(|&&v| v).count();
@@ -565,7 +770,7 @@ impl AIVerificationEngine {
         let mut violations = Vec::new();
         
         // Example compliance checks
-        if output.confidence_score < 0.7 {
+        if output.confidence_score < self.compliance_thresholds.min_confidence {
             violations.push(ComplianceFlag {
                 flag_type: "Low Confidence Score".to_string(),
                 framework: framework.to_string(),
@@ -575,7 +780,7 @@ impl AIVerificationEngine {
             });
         }
 
-        if output.risk_assessment.overall_risk_score > 0.8 {
+        if output.risk_assessment.overall_risk_score > self.compliance_thresholds.max_risk {
             violations.push(ComplianceFlag {
                 flag_type: "High Risk Decision".to_string(),
                 framework: framework.to_string(),
@@ -640,8 +845,73 @@ impl AIVerificationEngine {
     }
 }
 
+/// Check a decision's reasoning chain for internal consistency
+///
+/// A step may cite the output's risk factors or any earlier step. Weights and
+/// confidences are stored as the model reported them, so a chain that does not
+/// add up is a sign it was edited after the fact.
+pub fn verify_reasoning_chain(output: &AIDecisionOutput) -> ReasoningValidity {
+    let mut issues = Vec::new();
+    if output.reasoning_chain.is_empty() {
+        issues.push(ReasoningIssue::EmptyChain);
+        return ReasoningValidity { is_consistent: false, issues };
+    }
+
+    let mut known_factors: Vec<&str> = output.risk_assessment.risk_factors.iter()
+        .map(|factor| factor.factor_type.as_str())
+        .collect();
+    for step in &output.reasoning_chain {
+        if !(0.0..=1.0).contains(&step.weight) || !(0.0..=1.0).contains(&step.confidence) {
+            issues.push(ReasoningIssue::StepOutOfRange { step_id: step.step_id.clone() });
+        }
+        for factor in &step.input_factors {
+            if !known_factors.contains(&factor.as_str()) {
+                issues.push(ReasoningIssue::UnknownFactor { step_id: step.step_id.clone(), factor: factor.clone() });
+            }
+        }
+        known_factors.push(&step.step_id);
+    }
+
+    let total_weight: f64 = output.reasoning_chain.iter().map(|step| step.weight).sum();
+    if (total_weight - 1.0).abs() > REASONING_WEIGHT_TOLERANCE {
+        issues.push(ReasoningIssue::WeightsDoNotSum { total_weight });
+    }
+
+    let max_step_confidence = output.reasoning_chain.iter().map(|step| step.confidence).fold(f64::MIN, f64::max);
+    if output.confidence_score > max_step_confidence + REASONING_CONFIDENCE_MARGIN {
+        issues.push(ReasoningIssue::UnsupportedConfidence {
+            final_confidence: output.confidence_score,
+            max_step_confidence,
+        });
+    }
+
+    ReasoningValidity { is_consistent: issues.is_empty(), issues }
+}
+
 /// Supporting structures
 
+/// Outcome of `verify_reasoning_chain`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReasoningValidity {
+    pub is_consistent: bool,
+    pub issues: Vec<ReasoningIssue>,
+}
+
+/// Inconsistency found in a reasoning chain
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ReasoningIssue {
+    /// The decision records no reasoning at all
+    EmptyChain,
+    /// A step's weight or confidence is outside [0, 1]
+    StepOutOfRange { step_id: String },
+    /// A step cites a factor that is neither a risk factor nor an earlier step
+    UnknownFactor { step_id: String, factor: String },
+    /// Step weights are not within `REASONING_WEIGHT_TOLERANCE` of 1
+    WeightsDoNotSum { total_weight: f64 },
+    /// The final confidence exceeds every step by more than `REASONING_CONFIDENCE_MARGIN`
+    UnsupportedConfidence { final_confidence: f64, max_step_confidence: f64 },
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AIVerificationResult {
     pub is_valid: bool,
@@ -659,9 +929,27 @@ pub struct AIVerificationDetails {
     pub crypto_proof_valid: bool,
     pub oversight_valid: bool,
     pub audit_trail_valid: bool,
+    /// Whether a registered inference runtime attested the processing metrics;
+    /// not part of `is_valid`, since the response stands on its own
+    #[serde(default)]
+    pub metrics_integrity_valid: bool,
+    /// Set when a compliance re-check disagrees with the flags the decision
+    /// recorded; not part of `is_valid`, since the rules changed, not the proof
+    #[serde(default)]
+    pub compliance_discrepancy: Option<ComplianceDiscrepancy>,
     pub risk_assessment: f64,
 }
 
+/// Disagreement between a decision's recorded compliance and a re-check
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ComplianceDiscrepancy {
+    /// Whether the decision recorded no flags for the re-checked frameworks
+    pub recorded_compliant: bool,
+    pub rechecked_compliant: bool,
+    /// Violations found by the re-check
+    pub violations: Vec<ComplianceFlag>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ComplianceCheckResult {
     pub overall_compliant: bool,
@@ -689,4 +977,327 @@ pub struct AIAnalytics {
     pub confidence_distribution: HashMap<String, u32>,
     pub verification_rate: f64,
     pub risk_distribution: HashMap<String, u32>,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::OsRng;
+
+    /// The hash this module used to pass off as a signature
+    fn unkeyed_signature(data: &str) -> String {
+        hex::encode(Sha256::digest(format!("ai_sign_{}", data).as_bytes()))
+    }
+
+    fn decision_input() -> AIDecisionInput {
+        AIDecisionInput {
+            decision_id: "decision_1".to_string(),
+            model_version: "risk-model-2.1".to_string(),
+            model_parameters: HashMap::new(),
+            prompt: "Assess vendor access controls".to_string(),
+            context_data: HashMap::new(),
+            processing_timestamp: "2025-01-01T00:00:00Z".to_string(),
+            user_id: "user_1".to_string(),
+            session_id: "session_1".to_string(),
+        }
+    }
+
+    fn reasoning_step(step_id: &str, input_factors: &[&str], weight: f64, confidence: f64) -> ReasoningStep {
+        ReasoningStep {
+            step_id: step_id.to_string(),
+            description: format!("Step {}", step_id),
+            input_factors: input_factors.iter().map(|factor| factor.to_string()).collect(),
+            weight,
+            confidence,
+            reasoning_type: ReasoningType::RuleBased,
+        }
+    }
+
+    fn decision_output() -> AIDecisionOutput {
+        AIDecisionOutput {
+            response: "Access controls meet SOC 2 CC6.1".to_string(),
+            confidence_score: 0.85,
+            reasoning_chain: vec![
+                reasoning_step("log_review", &["stale_accounts"], 0.4, 0.8),
+                reasoning_step("policy_review", &["log_review"], 0.6, 0.9),
+            ],
+            alternative_responses: Vec::new(),
+            risk_assessment: RiskAssessment {
+                overall_risk_score: 0.2,
+                risk_factors: vec![RiskFactor {
+                    factor_type: "stale_accounts".to_string(),
+                    severity: 0.3,
+                    description: "Stale accounts in access logs".to_string(),
+                    likelihood: 0.2,
+                    impact: 0.4,
+                }],
+                mitigation_strategies: Vec::new(),
+                review_required: false,
+            },
+            compliance_flags: Vec::new(),
+            processing_metrics: ProcessingMetrics {
+                processing_time_ms: 1200,
+                memory_usage_mb: 512.0,
+                tokens_processed: 4096,
+                model_operations: 1_000_000,
+                cache_hits: 3,
+                cache_misses: 1,
+            },
+            metrics_attestation: None,
+        }
+    }
+
+    fn human_review() -> HumanReview {
+        HumanReview {
+            review_id: "review_1".to_string(),
+            reviewer_id: "reviewer_1".to_string(),
+            reviewer_credentials: vec!["CISA".to_string()],
+            review_timestamp: "2025-01-01T01:00:00Z".to_string(),
+            decision_validation: DecisionValidation {
+                is_valid: true,
+                accuracy_score: 0.9,
+                bias_assessment: BiasAssessment {
+                    bias_detected: false,
+                    bias_types: Vec::new(),
+                    severity_score: 0.0,
+                    mitigation_applied: false,
+                },
+                ethical_compliance: EthicalCompliance {
+                    passes_ethical_review: true,
+                    ethical_frameworks_checked: Vec::new(),
+                    concerns: Vec::new(),
+                    recommendations: Vec::new(),
+                },
+                explanation_quality: 0.8,
+            },
+            recommendations: Vec::new(),
+            approval_status: ApprovalStatus::Approved,
+        }
+    }
+
+    #[test]
+    fn test_forged_metrics_fail_metrics_integrity_only() {
+        let runtime = Keypair::generate(&mut OsRng {});
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let mut engine = AIVerificationEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        engine.register_inference_runtime("runtime_1", runtime.public).unwrap();
+        assert!(engine.register_inference_runtime("runtime_1", runtime.public).is_err());
+
+        let input = decision_input();
+        let mut output = decision_output();
+        output.metrics_attestation = Some(MetricsAttestation::sign(
+            "runtime_1",
+            &input.decision_id,
+            &output.processing_metrics,
+            &runtime,
+        ));
+        let proof = engine
+            .create_ai_decision_proof(&input, &output, &human_review(), &mut crypto_engine)
+            .unwrap();
+
+        let result = engine.verify_ai_decision_proof(&proof, &input, &output).unwrap();
+        assert!(result.is_valid);
+        assert!(result.verification_details.response_hash_valid);
+        assert!(result.verification_details.metrics_integrity_valid);
+
+        // A provider shaving its reported cost keeps the response intact but loses the attestation
+        let mut forged = output.clone();
+        forged.processing_metrics.tokens_processed = 512;
+        forged.processing_metrics.processing_time_ms = 90;
+        let result = engine.verify_ai_decision_proof(&proof, &input, &forged).unwrap();
+        assert!(result.is_valid);
+        assert!(result.verification_details.response_hash_valid);
+        assert!(!result.verification_details.metrics_integrity_valid);
+
+        // Re-hashing the forged metrics does not help without the runtime's key
+        let mut rehashed = forged.clone();
+        if let Some(attestation) = rehashed.metrics_attestation.as_mut() {
+            attestation.metrics_hash = hash_processing_metrics(&rehashed.processing_metrics);
+        }
+        let result = engine.verify_ai_decision_proof(&proof, &input, &rehashed).unwrap();
+        assert!(!result.verification_details.metrics_integrity_valid);
+
+        // The response itself is still covered
+        let mut altered = output.clone();
+        altered.response = "Access controls fail SOC 2 CC6.1".to_string();
+        let result = engine.verify_ai_decision_proof(&proof, &input, &altered).unwrap();
+        assert!(!result.verification_details.response_hash_valid);
+        assert!(result.verification_details.metrics_integrity_valid);
+    }
+
+    #[test]
+    fn test_forged_proof_and_approval_signatures_are_rejected() {
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let mut engine = AIVerificationEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let input = decision_input();
+        let output = decision_output();
+        let proof = engine
+            .create_ai_decision_proof(&input, &output, &human_review(), &mut crypto_engine)
+            .unwrap();
+        let result = engine.verify_ai_decision_proof(&proof, &input, &output).unwrap();
+        assert!(result.verification_details.crypto_proof_valid);
+        assert!(result.verification_details.oversight_valid);
+
+        // Any non-empty signature used to pass
+        let mut forged = proof.clone();
+        forged.cryptographic_proof.signature = "0".repeat(128);
+        let result = engine.verify_ai_decision_proof(&forged, &input, &output).unwrap();
+        assert!(!result.verification_details.crypto_proof_valid);
+        assert!(!result.is_valid);
+
+        // Signatures recomputed from public data alone carry no key
+        let mut rehashed = proof.clone();
+        rehashed.cryptographic_proof.signature = unkeyed_signature(&proof.cryptographic_proof.signing_payload());
+        let result = engine.verify_ai_decision_proof(&rehashed, &input, &output).unwrap();
+        assert!(!result.verification_details.crypto_proof_valid);
+
+        let mut unapproved = proof.clone();
+        unapproved.human_oversight.approval_signature = "0".repeat(128);
+        let result = engine.verify_ai_decision_proof(&unapproved, &input, &output).unwrap();
+        assert!(result.verification_details.crypto_proof_valid);
+        assert!(!result.verification_details.oversight_valid);
+        assert!(!result.is_valid);
+
+        let mut self_approved = proof.clone();
+        let approval = domain_separated(DOMAIN_HUMAN_REVIEW, &proof.human_oversight.review_hash);
+        self_approved.human_oversight.approval_signature = unkeyed_signature(&approval);
+        let result = engine.verify_ai_decision_proof(&self_approved, &input, &output).unwrap();
+        assert!(!result.verification_details.oversight_valid);
+
+        // Proofs from another engine do not verify against this ring
+        let mut other_engine = AIVerificationEngine::new();
+        other_engine.set_proof_keys(VelocityCryptographicEngine::new().key_ring().verifying_keys().clone());
+        let result = other_engine.verify_ai_decision_proof(&proof, &input, &output).unwrap();
+        assert!(!result.verification_details.crypto_proof_valid);
+        assert!(!result.verification_details.oversight_valid);
+    }
+
+    #[test]
+    fn test_unattested_metrics_are_not_trusted() {
+        let runtime = Keypair::generate(&mut OsRng {});
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let mut engine = AIVerificationEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let input = decision_input();
+        let mut output = decision_output();
+        let proof = engine
+            .create_ai_decision_proof(&input, &output, &human_review(), &mut crypto_engine)
+            .unwrap();
+        let result = engine.verify_ai_decision_proof(&proof, &input, &output).unwrap();
+        assert!(result.is_valid);
+        assert!(!result.verification_details.metrics_integrity_valid);
+
+        // Signed by a runtime the engine does not know
+        output.metrics_attestation = Some(MetricsAttestation::sign(
+            "runtime_1",
+            &input.decision_id,
+            &output.processing_metrics,
+            &runtime,
+        ));
+        let result = engine.verify_ai_decision_proof(&proof, &input, &output).unwrap();
+        assert!(result.verification_details.response_hash_valid);
+        assert!(!result.verification_details.metrics_integrity_valid);
+    }
+
+    #[test]
+    fn test_well_formed_reasoning_chain_passes() {
+        let validity = verify_reasoning_chain(&decision_output());
+        assert!(validity.is_consistent);
+        assert!(validity.issues.is_empty());
+    }
+
+    #[test]
+    fn test_manipulated_reasoning_chain_is_flagged() {
+        let mut output = decision_output();
+        output.confidence_score = 0.99;
+        output.reasoning_chain[0].weight = 0.9;
+        output.reasoning_chain[1].input_factors.push("board_approval".to_string());
+
+        let validity = verify_reasoning_chain(&output);
+        assert!(!validity.is_consistent);
+        assert_eq!(
+            validity.issues,
+            vec![
+                ReasoningIssue::UnknownFactor { step_id: "policy_review".to_string(), factor: "board_approval".to_string() },
+                ReasoningIssue::WeightsDoNotSum { total_weight: 0.9 + 0.6 },
+                ReasoningIssue::UnsupportedConfidence { final_confidence: 0.99, max_step_confidence: 0.9 },
+            ]
+        );
+
+        // Steps may only build on steps before them
+        let mut reordered = decision_output();
+        reordered.reasoning_chain.reverse();
+        assert_eq!(
+            verify_reasoning_chain(&reordered).issues,
+            vec![ReasoningIssue::UnknownFactor { step_id: "policy_review".to_string(), factor: "log_review".to_string() }]
+        );
+
+        reordered.reasoning_chain.clear();
+        assert_eq!(verify_reasoning_chain(&reordered).issues, vec![ReasoningIssue::EmptyChain]);
+    }
+
+    #[test]
+    fn test_compliance_recheck_flags_decisions_the_current_rules_reject() {
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let mut engine = AIVerificationEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let input = decision_input();
+        let output = decision_output();
+        let proof = engine
+            .create_ai_decision_proof(&input, &output, &human_review(), &mut crypto_engine)
+            .unwrap();
+        let result = engine.verify_ai_decision_proof(&proof, &input, &output).unwrap();
+        assert!(result.verification_details.compliance_discrepancy.is_none());
+
+        // Compliant under the rules it was issued under
+        engine.set_compliance_recheck(Some(vec!["SOC2".to_string()]));
+        let result = engine.verify_ai_decision_proof(&proof, &input, &output).unwrap();
+        assert!(result.is_valid);
+        assert!(result.verification_details.compliance_discrepancy.is_none());
+
+        // A stricter confidence floor rejects the same decision
+        engine.set_compliance_thresholds(ComplianceThresholds { min_confidence: 0.9, max_risk: 0.8 }).unwrap();
+        let result = engine.verify_ai_decision_proof(&proof, &input, &output).unwrap();
+        assert!(result.is_valid);
+        let discrepancy = result.verification_details.compliance_discrepancy.unwrap();
+        assert!(discrepancy.recorded_compliant);
+        assert!(!discrepancy.rechecked_compliant);
+        assert_eq!(discrepancy.violations.len(), 1);
+        assert_eq!(discrepancy.violations[0].flag_type, "Low Confidence Score");
+        assert_eq!(discrepancy.violations[0].framework, "SOC2");
+
+        assert!(engine.set_compliance_thresholds(ComplianceThresholds { min_confidence: 1.5, max_risk: 0.8 }).is_err());
+    }
+
+    #[test]
+    fn test_compliance_recheck_flags_recorded_violations_the_rules_no_longer_raise() {
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let mut engine = AIVerificationEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        engine.set_compliance_recheck(Some(vec!["SOC2".to_string()]));
+        let input = decision_input();
+        let mut output = decision_output();
+        output.compliance_flags.push(ComplianceFlag {
+            flag_type: "High Risk Decision".to_string(),
+            framework: "SOC2".to_string(),
+            severity: ComplianceSeverity::Critical,
+            description: "AI decision poses high risk, requires additional review".to_string(),
+            remediation_required: true,
+        });
+        let proof = engine
+            .create_ai_decision_proof(&input, &output, &human_review(), &mut crypto_engine)
+            .unwrap();
+
+        let discrepancy = engine.verify_ai_decision_proof(&proof, &input, &output).unwrap()
+            .verification_details.compliance_discrepancy.unwrap();
+        assert!(!discrepancy.recorded_compliant);
+        assert!(discrepancy.rechecked_compliant);
+        assert!(discrepancy.violations.is_empty());
+
+        // Flags for frameworks outside the re-check are not compared
+        engine.set_compliance_recheck(Some(vec!["ISO27001".to_string()]));
+        let result = engine.verify_ai_decision_proof(&proof, &input, &output).unwrap();
+        assert!(result.verification_details.compliance_discrepancy.is_none());
+    }
+}
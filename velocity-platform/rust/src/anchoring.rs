@@ -0,0 +1,260 @@
+//! Polygon Anchoring Module
+//!
+//! Anchors proof hashes on Polygon by submitting them to an anchoring contract,
+//! so a record's `blockchain_tx_id` points at a transaction anyone can inspect.
+//! The chain is reached through the `PolygonAnchor` trait; JSON-RPC clients that
+//! sign and broadcast transactions live outside this wasm crate and plug in via
+//! `VelocityCryptographicEngine::set_polygon_anchor`.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Digest};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+/// Polygon PoS mainnet chain id
+pub const POLYGON_MAINNET_CHAIN_ID: u64 = 137;
+
+/// Polygon Amoy testnet chain id
+pub const POLYGON_AMOY_CHAIN_ID: u64 = 80002;
+
+/// Confirmations before an anchor is treated as final, past the reorg depth
+/// Polygon PoS has historically seen
+pub const DEFAULT_MIN_CONFIRMATIONS: u64 = 128;
+
+/// Where anchoring transactions are sent
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PolygonAnchorConfig {
+    /// JSON-RPC endpoint of a Polygon node
+    pub rpc_url: String,
+    /// `0x`-prefixed address of the anchoring contract
+    pub contract_address: String,
+    pub chain_id: u64,
+    /// Confirmations an anchoring transaction needs before it verifies
+    #[serde(default = "PolygonAnchorConfig::default_min_confirmations")]
+    pub min_confirmations: u64,
+}
+
+impl PolygonAnchorConfig {
+    fn default_min_confirmations() -> u64 {
+        DEFAULT_MIN_CONFIRMATIONS
+    }
+}
+
+/// Anchoring transaction as read back from the chain
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnchorTransaction {
+    pub tx_id: String,
+    /// Proof hash the transaction submitted to the contract
+    pub proof_hash: String,
+    /// Blocks including and built on top of the transaction's block
+    pub confirmations: u64,
+}
+
+/// Errors raised while anchoring a proof hash
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorError {
+    /// The proof hash is not 32 bytes of hex
+    InvalidHash(String),
+    /// The node or contract rejected the transaction
+    Rejected(String),
+    /// The node could not be reached
+    Transport(String),
+    /// No anchoring client is configured
+    NotConfigured,
+}
+
+impl fmt::Display for AnchorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnchorError::InvalidHash(hash) => write!(f, "Invalid proof hash {}", hash),
+            AnchorError::Rejected(reason) => write!(f, "Anchoring transaction rejected: {}", reason),
+            AnchorError::Transport(reason) => write!(f, "Polygon node unreachable: {}", reason),
+            AnchorError::NotConfigured => write!(f, "No Polygon anchor configured"),
+        }
+    }
+}
+
+impl From<AnchorError> for String {
+    fn from(error: AnchorError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Client for the Polygon anchoring contract
+pub trait PolygonAnchor: Send + Sync {
+    /// Contract and network anchors are submitted to
+    fn config(&self) -> &PolygonAnchorConfig;
+
+    /// Submit `proof_hash` (hex SHA-256) to the contract and return the `0x`-prefixed
+    /// transaction hash
+    ///
+    /// Anchoring is idempotent: a hash that is already anchored returns the
+    /// transaction that first anchored it rather than submitting another.
+    fn anchor(&self, proof_hash: &str) -> Result<String, AnchorError>;
+
+    /// Look up an anchoring transaction, `None` if the chain has no such transaction
+    fn transaction(&self, tx_id: &str) -> Result<Option<AnchorTransaction>, AnchorError>;
+
+    /// Whether `tx_id` anchored `proof_hash` and has at least the configured
+    /// number of confirmations
+    ///
+    /// An unknown transaction, a transaction carrying a different hash and one that
+    /// is not yet final all return `Ok(false)`; `Err` means the chain could not be
+    /// asked.
+    fn verify_anchor(&self, proof_hash: &str, tx_id: &str) -> Result<bool, AnchorError> {
+        if !is_anchorable_hash(proof_hash) {
+            return Err(AnchorError::InvalidHash(proof_hash.to_string()));
+        }
+
+        Ok(match self.transaction(tx_id)? {
+            Some(transaction) => {
+                transaction.proof_hash.eq_ignore_ascii_case(proof_hash)
+                    && transaction.confirmations >= self.config().min_confirmations
+            }
+            None => false,
+        })
+    }
+}
+
+/// Whether `proof_hash` is a hex SHA-256 digest
+pub fn is_anchorable_hash(proof_hash: &str) -> bool {
+    proof_hash.len() == 64 && proof_hash.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// In-memory anchoring contract for tests and local development
+///
+/// Each submission is mined into its own block; `mine_blocks` builds on top of
+/// them to accrue confirmations.
+pub struct MockPolygonAnchor {
+    config: PolygonAnchorConfig,
+    chain: Mutex<MockChain>,
+}
+
+#[derive(Default)]
+struct MockChain {
+    /// Anchored proof hash to the transaction that anchored it, in lowercase
+    anchored: HashMap<String, String>,
+    /// Transaction to the proof hash it carries and the block it was mined in
+    transactions: HashMap<String, (String, u64)>,
+    head: u64,
+}
+
+impl MockPolygonAnchor {
+    pub fn new(config: PolygonAnchorConfig) -> Self {
+        MockPolygonAnchor {
+            config,
+            chain: Mutex::new(MockChain::default()),
+        }
+    }
+
+    /// Number of transactions submitted so far
+    pub fn transaction_count(&self) -> usize {
+        self.chain.lock().unwrap().transactions.len()
+    }
+
+    /// Transaction that anchored `proof_hash`, if any
+    pub fn anchored_tx(&self, proof_hash: &str) -> Option<String> {
+        self.chain.lock().unwrap().anchored.get(&proof_hash.to_ascii_lowercase()).cloned()
+    }
+
+    /// Extend the chain by `blocks` empty blocks
+    pub fn mine_blocks(&self, blocks: u64) {
+        self.chain.lock().unwrap().head += blocks;
+    }
+}
+
+impl PolygonAnchor for MockPolygonAnchor {
+    fn config(&self) -> &PolygonAnchorConfig {
+        &self.config
+    }
+
+    fn anchor(&self, proof_hash: &str) -> Result<String, AnchorError> {
+        if !is_anchorable_hash(proof_hash) {
+            return Err(AnchorError::InvalidHash(proof_hash.to_string()));
+        }
+
+        let proof_hash = proof_hash.to_ascii_lowercase();
+        let mut chain = self.chain.lock().unwrap();
+        if let Some(tx_id) = chain.anchored.get(&proof_hash) {
+            return Ok(tx_id.clone());
+        }
+
+        // Stand-in for the keccak hash of the signed transaction
+        let transaction = format!(
+            "{}|{}|{}|{}",
+            self.config.chain_id, self.config.contract_address, proof_hash, chain.transactions.len()
+        );
+        let tx_id = format!("0x{}", hex::encode(Sha256::digest(transaction.as_bytes())));
+
+        chain.head += 1;
+        let block = chain.head;
+        chain.transactions.insert(tx_id.clone(), (proof_hash.clone(), block));
+        chain.anchored.insert(proof_hash, tx_id.clone());
+        Ok(tx_id)
+    }
+
+    fn transaction(&self, tx_id: &str) -> Result<Option<AnchorTransaction>, AnchorError> {
+        let chain = self.chain.lock().unwrap();
+        Ok(chain.transactions.get(&tx_id.to_ascii_lowercase()).map(|(proof_hash, block)| AnchorTransaction {
+            tx_id: tx_id.to_ascii_lowercase(),
+            proof_hash: proof_hash.clone(),
+            confirmations: chain.head - block + 1,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amoy_config() -> PolygonAnchorConfig {
+        PolygonAnchorConfig {
+            rpc_url: "https://rpc-amoy.polygon.technology".to_string(),
+            contract_address: "0x00000000000000000000000000000000000a1c40".to_string(),
+            chain_id: POLYGON_AMOY_CHAIN_ID,
+            min_confirmations: 3,
+        }
+    }
+
+    #[test]
+    fn test_mock_anchor_is_idempotent_per_hash() {
+        let anchor = MockPolygonAnchor::new(amoy_config());
+        let first = anchor.anchor(&"ab".repeat(32)).unwrap();
+        assert_eq!(first.len(), 66);
+        assert!(first.starts_with("0x"));
+
+        assert_eq!(anchor.anchor(&"ab".repeat(32)).unwrap(), first);
+        assert_eq!(anchor.anchor(&"AB".repeat(32)).unwrap(), first);
+        assert_eq!(anchor.transaction_count(), 1);
+
+        let second = anchor.anchor(&"cd".repeat(32)).unwrap();
+        assert_ne!(second, first);
+        assert_eq!(anchor.transaction_count(), 2);
+
+        assert_eq!(anchor.anchor("not a hash"), Err(AnchorError::InvalidHash("not a hash".to_string())));
+        assert_eq!(anchor.transaction_count(), 2);
+    }
+
+    #[test]
+    fn test_verify_anchor_requires_matching_hash_and_confirmations() {
+        let anchor = MockPolygonAnchor::new(amoy_config());
+        let proof_hash = "ab".repeat(32);
+        let tx_id = anchor.anchor(&proof_hash).unwrap();
+
+        // One confirmation from the block it was mined in
+        assert_eq!(anchor.transaction(&tx_id).unwrap().unwrap().confirmations, 1);
+        assert_eq!(anchor.verify_anchor(&proof_hash, &tx_id), Ok(false));
+
+        anchor.mine_blocks(2);
+        assert_eq!(anchor.verify_anchor(&proof_hash, &tx_id), Ok(true));
+        assert_eq!(anchor.verify_anchor(&proof_hash.to_uppercase(), &tx_id), Ok(true));
+
+        // A transaction that anchored a different hash does not vouch for this one
+        let other_tx = anchor.anchor(&"cd".repeat(32)).unwrap();
+        anchor.mine_blocks(5);
+        assert_eq!(anchor.verify_anchor(&proof_hash, &other_tx), Ok(false));
+        assert_eq!(anchor.verify_anchor(&proof_hash, &format!("0x{}", "00".repeat(32))), Ok(false));
+        assert!(matches!(anchor.verify_anchor("not a hash", &tx_id), Err(AnchorError::InvalidHash(_))));
+    }
+}
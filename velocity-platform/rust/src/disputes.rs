@@ -0,0 +1,385 @@
+//! Dispute Workflow Module
+//!
+//! Challenges against professional credentials and on-chain evidence. A challenger
+//! opens a dispute, either side attaches evidence, and a resolver upholds or
+//! dismisses it with a resolution signed by the manager's key ring. Disputes left
+//! unresolved past the time limit escalate for senior review.
+
+use crate::*;
+use chrono::{DateTime, Duration, Utc};
+use ed25519_dalek::{Signature, Verifier};
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Digest};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Identifier returned by `DisputeManager::open_dispute`
+pub type DisputeId = String;
+
+/// Time a dispute may stay open before it escalates
+pub const DEFAULT_DISPUTE_TIME_LIMIT_DAYS: i64 = 14;
+
+/// What a dispute challenges
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DisputeSubject {
+    Credential(String),
+    Evidence(String),
+}
+
+impl DisputeSubject {
+    fn signing_label(&self) -> String {
+        match self {
+            DisputeSubject::Credential(id) => format!("credential:{}", id),
+            DisputeSubject::Evidence(id) => format!("evidence:{}", id),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisputeStatus {
+    Open,
+    /// Passed its time limit without a resolution
+    Escalated,
+    Resolved,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisputeOutcome {
+    /// The challenge stands: the credential is revoked or the evidence rejected
+    Upheld,
+    /// The challenge fails and the subject is verified again
+    Dismissed,
+}
+
+/// Material attached to a dispute by the challenger or the subject's owner
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DisputeEvidence {
+    pub submitted_by: String,
+    pub description: String,
+    /// Hash of the supporting document, which is stored elsewhere
+    pub content_hash: String,
+}
+
+/// Signed record of how a dispute was decided
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DisputeResolution {
+    pub dispute_id: DisputeId,
+    pub outcome: DisputeOutcome,
+    pub resolver_id: String,
+    pub resolved_at: String,
+    /// Digest of the evidence on file when the dispute was decided
+    pub evidence_digest: String,
+    pub key_id: String,
+    /// Hex ed25519 signature over `DisputeManager::resolution_payload`
+    pub signature: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Dispute {
+    pub dispute_id: DisputeId,
+    pub subject: DisputeSubject,
+    pub reason: String,
+    pub challenger_id: String,
+    pub opened_at: DateTime<Utc>,
+    /// Escalates if still open at this instant
+    pub deadline: DateTime<Utc>,
+    pub status: DisputeStatus,
+    pub evidence: Vec<DisputeEvidence>,
+    pub escalated_at: Option<DateTime<Utc>>,
+    pub resolution: Option<DisputeResolution>,
+}
+
+/// Tracks disputes and the verification state they impose on their subjects
+pub struct DisputeManager {
+    disputes: HashMap<DisputeId, Dispute>,
+    /// Most recent dispute against each subject
+    latest_by_subject: HashMap<DisputeSubject, DisputeId>,
+    time_limit: Duration,
+    key_ring: KeyRing,
+    clock: Arc<dyn Clock>,
+}
+
+impl DisputeManager {
+    pub fn new() -> Self {
+        DisputeManager {
+            disputes: HashMap::new(),
+            latest_by_subject: HashMap::new(),
+            time_limit: Duration::days(DEFAULT_DISPUTE_TIME_LIMIT_DAYS),
+            key_ring: KeyRing::generate(),
+            clock: system_clock(),
+        }
+    }
+
+    /// Read the current time from `clock` for deadlines and resolutions
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Time newly opened disputes have before they escalate
+    pub fn set_time_limit(&mut self, time_limit: Duration) {
+        self.time_limit = time_limit;
+    }
+
+    /// Challenge `subject`, which must not already have an unresolved dispute
+    pub fn open_dispute(&mut self, subject: DisputeSubject, reason: &str, challenger_id: &str) -> Result<DisputeId, String> {
+        if let Some(existing) = self.latest_by_subject.get(&subject).and_then(|id| self.disputes.get(id)) {
+            if existing.status != DisputeStatus::Resolved {
+                return Err(format!("{:?} already has unresolved dispute {}", subject, existing.dispute_id));
+            }
+        }
+
+        let opened_at = self.clock.now();
+        let dispute_id = format!("dispute_{}", Uuid::new_v4());
+        self.latest_by_subject.insert(subject.clone(), dispute_id.clone());
+        self.disputes.insert(dispute_id.clone(), Dispute {
+            dispute_id: dispute_id.clone(),
+            subject,
+            reason: reason.to_string(),
+            challenger_id: challenger_id.to_string(),
+            opened_at,
+            deadline: opened_at + self.time_limit,
+            status: DisputeStatus::Open,
+            evidence: Vec::new(),
+            escalated_at: None,
+            resolution: None,
+        });
+        Ok(dispute_id)
+    }
+
+    /// Attach `evidence` to an unresolved dispute
+    pub fn submit_dispute_evidence(&mut self, dispute_id: &str, evidence: DisputeEvidence) -> Result<(), String> {
+        let dispute = self.unresolved_dispute_mut(dispute_id)?;
+        dispute.evidence.push(evidence);
+        Ok(())
+    }
+
+    /// Decide an open or escalated dispute, signing the resolution
+    pub fn resolve_dispute(&mut self, dispute_id: &str, outcome: DisputeOutcome, resolver_id: &str) -> Result<DisputeResolution, String> {
+        let resolved_at = self.clock.now().to_rfc3339();
+        let dispute = self.unresolved_dispute_mut(dispute_id)?;
+
+        let mut resolution = DisputeResolution {
+            dispute_id: dispute.dispute_id.clone(),
+            outcome,
+            resolver_id: resolver_id.to_string(),
+            resolved_at,
+            evidence_digest: Self::evidence_digest(&dispute.evidence),
+            key_id: String::new(),
+            signature: String::new(),
+        };
+        let payload = Self::resolution_payload(&dispute.subject, &resolution);
+
+        resolution.key_id = self.key_ring.active_key_id().to_string();
+        resolution.signature = hex::encode(self.key_ring.sign(payload.as_bytes()).to_bytes());
+
+        let dispute = self.disputes.get_mut(dispute_id).expect("dispute checked above");
+        dispute.status = DisputeStatus::Resolved;
+        dispute.resolution = Some(resolution.clone());
+        Ok(resolution)
+    }
+
+    /// Escalate every open dispute whose deadline has passed, returning their ids
+    pub fn escalate_overdue_disputes(&mut self) -> Vec<DisputeId> {
+        let now = self.clock.now();
+        let mut escalated: Vec<DisputeId> = self.disputes.values_mut()
+            .filter(|dispute| dispute.status == DisputeStatus::Open && now >= dispute.deadline)
+            .map(|dispute| {
+                dispute.status = DisputeStatus::Escalated;
+                dispute.escalated_at = Some(now);
+                dispute.dispute_id.clone()
+            })
+            .collect();
+        escalated.sort();
+        escalated
+    }
+
+    pub fn dispute(&self, dispute_id: &str) -> Option<&Dispute> {
+        self.disputes.get(dispute_id)
+    }
+
+    /// Whether the dispute's resolution was signed by this manager and still
+    /// matches the dispute and its evidence
+    pub fn verify_resolution(&self, dispute_id: &str) -> bool {
+        let (dispute, resolution) = match self.disputes.get(dispute_id) {
+            Some(dispute) => match &dispute.resolution {
+                Some(resolution) => (dispute, resolution),
+                None => return false,
+            },
+            None => return false,
+        };
+
+        let signed_at = match DateTime::parse_from_rfc3339(&resolution.resolved_at) {
+            Ok(signed_at) => signed_at.with_timezone(&Utc),
+            Err(_) => return false,
+        };
+        let public_key = match self.key_ring.verifying_key(Some(&resolution.key_id), signed_at) {
+            Some(public_key) => public_key,
+            None => return false,
+        };
+        let signature = match hex::decode(&resolution.signature).ok().and_then(|bytes| Signature::from_bytes(&bytes).ok()) {
+            Some(signature) => signature,
+            None => return false,
+        };
+
+        resolution.dispute_id == dispute.dispute_id
+            && ct_eq(Self::evidence_digest(&dispute.evidence), &resolution.evidence_digest)
+            && public_key.verify(Self::resolution_payload(&dispute.subject, resolution).as_bytes(), &signature).is_ok()
+    }
+
+    /// Verification state a credential's disputes impose, `None` if it was never disputed
+    pub fn credential_status(&self, credential_id: &str) -> Option<VerificationResult> {
+        let dispute = self.latest_dispute(&DisputeSubject::Credential(credential_id.to_string()))?;
+        Some(match Self::decided_outcome(dispute) {
+            None => VerificationResult::Disputed,
+            Some(DisputeOutcome::Upheld) => VerificationResult::Revoked,
+            Some(DisputeOutcome::Dismissed) => VerificationResult::Verified,
+        })
+    }
+
+    /// Attestation state an evidence item's disputes impose, `None` if it was never disputed
+    pub fn evidence_status(&self, evidence_id: &str) -> Option<AttestationResult> {
+        let dispute = self.latest_dispute(&DisputeSubject::Evidence(evidence_id.to_string()))?;
+        Some(match Self::decided_outcome(dispute) {
+            None => AttestationResult::RequiresReview,
+            Some(DisputeOutcome::Upheld) => AttestationResult::Rejected,
+            Some(DisputeOutcome::Dismissed) => AttestationResult::Verified,
+        })
+    }
+
+    /// Public keys that sign resolutions, for relying parties
+    pub fn resolution_keys(&self) -> Vec<KeyRingEntry> {
+        self.key_ring.entries()
+    }
+
+    /// Payload covered by a resolution's signature
+    pub fn resolution_payload(subject: &DisputeSubject, resolution: &DisputeResolution) -> String {
+        let outcome = format!("{:?}", resolution.outcome);
+        domain_separated(DOMAIN_DISPUTE_RESOLUTION, &encode_fields(&[
+            &resolution.dispute_id,
+            &subject.signing_label(),
+            &outcome,
+            &resolution.resolver_id,
+            &resolution.resolved_at,
+            &resolution.evidence_digest,
+        ]))
+    }
+
+    fn evidence_digest(evidence: &[DisputeEvidence]) -> String {
+        let mut hasher = Sha256::new();
+        for item in evidence {
+            hasher.update(encode_fields(&[&item.submitted_by, &item.description, &item.content_hash]).as_bytes());
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    fn latest_dispute(&self, subject: &DisputeSubject) -> Option<&Dispute> {
+        self.latest_by_subject.get(subject).and_then(|id| self.disputes.get(id))
+    }
+
+    fn decided_outcome(dispute: &Dispute) -> Option<DisputeOutcome> {
+        dispute.resolution.as_ref().map(|resolution| resolution.outcome)
+    }
+
+    fn unresolved_dispute_mut(&mut self, dispute_id: &str) -> Result<&mut Dispute, String> {
+        let dispute = self.disputes.get_mut(dispute_id)
+            .ok_or_else(|| format!("Unknown dispute {}", dispute_id))?;
+        if dispute.status == DisputeStatus::Resolved {
+            return Err(format!("Dispute {} is already resolved", dispute_id));
+        }
+        Ok(dispute)
+    }
+}
+
+impl Default for DisputeManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager_at(start: DateTime<Utc>) -> (DisputeManager, Arc<MockClock>) {
+        let clock = Arc::new(MockClock::new(start));
+        let mut manager = DisputeManager::new();
+        manager.set_clock(clock.clone());
+        (manager, clock)
+    }
+
+    fn start() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-03-01T00:00:00Z").unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_dispute_open_then_resolve() {
+        let (mut manager, clock) = manager_at(start());
+        assert!(manager.credential_status("cred_1").is_none());
+
+        let dispute_id = manager
+            .open_dispute(DisputeSubject::Credential("cred_1".to_string()), "issuer denies issuing", "auditor_7")
+            .unwrap();
+        assert!(matches!(manager.credential_status("cred_1"), Some(VerificationResult::Disputed)));
+        assert!(manager.open_dispute(DisputeSubject::Credential("cred_1".to_string()), "again", "auditor_8").is_err());
+
+        manager.submit_dispute_evidence(&dispute_id, DisputeEvidence {
+            submitted_by: "auditor_7".to_string(),
+            description: "Issuer registry extract".to_string(),
+            content_hash: "ab".repeat(32),
+        }).unwrap();
+
+        clock.advance(Duration::days(3));
+        let resolution = manager.resolve_dispute(&dispute_id, DisputeOutcome::Upheld, "panel_1").unwrap();
+        assert_eq!(resolution.resolved_at, (start() + Duration::days(3)).to_rfc3339());
+        assert!(matches!(manager.credential_status("cred_1"), Some(VerificationResult::Revoked)));
+        assert_eq!(manager.dispute(&dispute_id).unwrap().status, DisputeStatus::Resolved);
+        assert!(manager.verify_resolution(&dispute_id));
+
+        // A resolved dispute takes no more evidence and cannot be decided twice
+        let late = DisputeEvidence {
+            submitted_by: "owner".to_string(),
+            description: "late appeal".to_string(),
+            content_hash: "cd".repeat(32),
+        };
+        assert!(manager.submit_dispute_evidence(&dispute_id, late.clone()).is_err());
+        assert!(manager.resolve_dispute(&dispute_id, DisputeOutcome::Dismissed, "panel_1").is_err());
+
+        // Evidence slipped in after signing breaks the resolution
+        manager.disputes.get_mut(&dispute_id).unwrap().evidence.push(late);
+        assert!(!manager.verify_resolution(&dispute_id));
+
+        // The subject can be challenged again once the earlier dispute is closed
+        assert!(manager.open_dispute(DisputeSubject::Credential("cred_1".to_string()), "new facts", "auditor_8").is_ok());
+    }
+
+    #[test]
+    fn test_dispute_open_then_timeout_escalates() {
+        let (mut manager, clock) = manager_at(start());
+        manager.set_time_limit(Duration::days(7));
+        let dispute_id = manager
+            .open_dispute(DisputeSubject::Evidence("evidence_1".to_string()), "hash mismatch", "validator_a")
+            .unwrap();
+        assert!(matches!(manager.evidence_status("evidence_1"), Some(AttestationResult::RequiresReview)));
+
+        clock.advance(Duration::days(7) - Duration::seconds(1));
+        assert!(manager.escalate_overdue_disputes().is_empty());
+
+        clock.advance(Duration::seconds(1));
+        assert_eq!(manager.escalate_overdue_disputes(), vec![dispute_id.clone()]);
+        let dispute = manager.dispute(&dispute_id).unwrap();
+        assert_eq!(dispute.status, DisputeStatus::Escalated);
+        assert_eq!(dispute.escalated_at, Some(start() + Duration::days(7)));
+        assert!(manager.escalate_overdue_disputes().is_empty());
+
+        // Escalated disputes still accept evidence and can be resolved
+        manager.submit_dispute_evidence(&dispute_id, DisputeEvidence {
+            submitted_by: "owner".to_string(),
+            description: "Original export".to_string(),
+            content_hash: "ef".repeat(32),
+        }).unwrap();
+        manager.resolve_dispute(&dispute_id, DisputeOutcome::Dismissed, "senior_panel").unwrap();
+        assert!(matches!(manager.evidence_status("evidence_1"), Some(AttestationResult::Verified)));
+        assert!(manager.verify_resolution(&dispute_id));
+    }
+}
@@ -0,0 +1,56 @@
+//! Verification Error Module
+//!
+//! Error type shared by the credential, trust score, AI verification and VTP
+//! engines, so callers can branch on what went wrong instead of parsing
+//! messages. Each variant displays the message the engines returned before they
+//! had a typed error.
+
+use thiserror::Error;
+
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum VerificationError {
+    /// Malformed or out-of-range input
+    #[error("{0}")]
+    InvalidInput(String),
+    /// A signature or proof did not verify
+    #[error("{0}")]
+    InvalidSignature(String),
+    /// A deadline or validity period has passed
+    #[error("{0}")]
+    Expired(String),
+    /// The subject was revoked
+    #[error("{0}")]
+    Revoked(String),
+    /// The hash chain does not link at `height`
+    #[error("Chain broken at height {height}")]
+    ChainBroken { height: u64 },
+    /// No contract, oracle or other entity with the given id
+    #[error("{0}")]
+    NotFound(String),
+    /// The request is well formed but not allowed in the current state
+    #[error("{0}")]
+    PolicyViolation(String),
+    /// A plugged-in backend is missing or failed
+    #[error("{0}")]
+    Unavailable(String),
+}
+
+impl From<VerificationError> for String {
+    fn from(error: VerificationError) -> Self {
+        error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_is_the_bare_message() {
+        assert_eq!(VerificationError::NotFound("Contract not found".to_string()).to_string(), "Contract not found");
+        assert_eq!(VerificationError::ChainBroken { height: 7 }.to_string(), "Chain broken at height 7");
+
+        let message: String = VerificationError::Expired("Attestation deadline has passed".to_string()).into();
+        assert_eq!(message, "Attestation deadline has passed");
+    }
+}
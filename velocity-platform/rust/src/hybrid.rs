@@ -0,0 +1,106 @@
+//! Hybrid Signature Module
+//!
+//! During the post-quantum transition a proof carries an ed25519 signature and,
+//! when the issuing engine has an ML-DSA-65 key, a post-quantum co-signature
+//! over the same payload. `HybridPolicy` decides which of the two a verifier
+//! needs, so nodes that only understand one generation can still accept
+//! proofs, and `HybridVerification` records which scheme a proof passed on.
+
+use serde::{Deserialize, Serialize};
+
+/// Signatures over one proof payload
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HybridSignature {
+    /// Hex ed25519 signature
+    pub classical: String,
+    /// Hex ML-DSA-65 signature, absent when the signer has no post-quantum key
+    pub pq: Option<String>,
+}
+
+/// Which signatures a proof needs to verify
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HybridPolicy {
+    /// The ed25519 signature, plus the post-quantum one if the proof carries it
+    #[default]
+    AllPresent,
+    /// Any one valid signature
+    Either,
+    /// Both signatures, present and valid
+    Both,
+}
+
+/// Scheme a proof was accepted on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SignatureScheme {
+    Classical,
+    PostQuantum,
+    Hybrid,
+}
+
+/// Outcome of checking a proof's signatures under a `HybridPolicy`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HybridVerification {
+    pub policy: HybridPolicy,
+    pub classical_valid: bool,
+    /// `None` when the proof carries no post-quantum signature
+    pub pq_valid: Option<bool>,
+    /// Scheme the proof was accepted on; `None` when it was rejected
+    pub verified_with: Option<SignatureScheme>,
+    pub is_valid: bool,
+}
+
+impl HybridVerification {
+    /// Apply `policy` to the individual signature results
+    pub fn evaluate(policy: HybridPolicy, classical_valid: bool, pq_valid: Option<bool>) -> Self {
+        let pq_passed = pq_valid == Some(true);
+        let is_valid = match policy {
+            HybridPolicy::AllPresent => classical_valid && pq_valid != Some(false),
+            HybridPolicy::Either => classical_valid || pq_passed,
+            HybridPolicy::Both => classical_valid && pq_passed,
+        };
+        let verified_with = match (is_valid, classical_valid, pq_passed) {
+            (false, _, _) => None,
+            (true, true, true) => Some(SignatureScheme::Hybrid),
+            (true, true, false) => Some(SignatureScheme::Classical),
+            (true, false, _) => Some(SignatureScheme::PostQuantum),
+        };
+        HybridVerification { policy, classical_valid, pq_valid, verified_with, is_valid }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accepted(policy: HybridPolicy, classical_valid: bool, pq_valid: Option<bool>) -> Option<SignatureScheme> {
+        HybridVerification::evaluate(policy, classical_valid, pq_valid).verified_with
+    }
+
+    #[test]
+    fn test_policies_over_signature_outcomes() {
+        use HybridPolicy::*;
+        use SignatureScheme::*;
+
+        // Both valid
+        assert_eq!(accepted(AllPresent, true, Some(true)), Some(Hybrid));
+        assert_eq!(accepted(Either, true, Some(true)), Some(Hybrid));
+        assert_eq!(accepted(Both, true, Some(true)), Some(Hybrid));
+
+        // Only the classical signature valid
+        assert_eq!(accepted(AllPresent, true, Some(false)), None);
+        assert_eq!(accepted(Either, true, Some(false)), Some(Classical));
+        assert_eq!(accepted(Both, true, Some(false)), None);
+
+        // Only the post-quantum signature valid
+        assert_eq!(accepted(AllPresent, false, Some(true)), None);
+        assert_eq!(accepted(Either, false, Some(true)), Some(PostQuantum));
+        assert_eq!(accepted(Both, false, Some(true)), None);
+
+        // Classical-only proofs from engines without a post-quantum key
+        assert_eq!(accepted(AllPresent, true, None), Some(Classical));
+        assert_eq!(accepted(Either, true, None), Some(Classical));
+        assert_eq!(accepted(Both, true, None), None);
+
+        assert!(!HybridVerification::evaluate(Either, false, Some(false)).is_valid);
+    }
+}
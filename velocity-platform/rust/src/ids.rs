@@ -0,0 +1,58 @@
+//! Identifier Module
+//!
+//! How engines name the credentials, contracts and evidence records they create.
+//! Random ids are unique per call. Deterministic ids are UUIDv5s of a namespace
+//! and the object's canonical content, so recreating the same logical object in
+//! another environment yields the same id and audit trails can be diffed.
+
+use crate::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Id assignment for objects an engine creates
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IdStrategy {
+    /// `{prefix}_{uuid v4}` (default, backward compatible)
+    #[default]
+    Random,
+    /// `{prefix}_{uuid v5}` of `namespace` and the prefix plus canonical content
+    Deterministic { namespace: Uuid },
+}
+
+impl IdStrategy {
+    /// Id for an object of kind `prefix`, derived from `content` when deterministic
+    ///
+    /// `content` should hold what identifies the logical object, not when it was
+    /// created; two objects with equal content get equal deterministic ids.
+    pub fn generate<T: Serialize + ?Sized>(&self, prefix: &str, content: &T) -> String {
+        match self {
+            IdStrategy::Random => format!("{}_{}", prefix, Uuid::new_v4()),
+            IdStrategy::Deterministic { namespace } => {
+                let name = encode_fields(&[prefix, &canonical_json(content).unwrap_or_default()]);
+                format!("{}_{}", prefix, Uuid::new_v5(namespace, name.as_bytes()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_ids_depend_on_namespace_prefix_and_content() {
+        let namespace = Uuid::new_v5(&Uuid::NAMESPACE_URL, b"https://velocity.example/audit");
+        let strategy = IdStrategy::Deterministic { namespace };
+
+        let id = strategy.generate("cred", &("prof_1", "CISA"));
+        assert_eq!(id, strategy.generate("cred", &("prof_1", "CISA")));
+        assert!(id.starts_with("cred_"));
+        assert_ne!(id, strategy.generate("cred", &("prof_2", "CISA")));
+        assert_ne!(id, strategy.generate("renewal", &("prof_1", "CISA")));
+
+        let other = IdStrategy::Deterministic { namespace: Uuid::NAMESPACE_OID };
+        assert_ne!(id, other.generate("cred", &("prof_1", "CISA")));
+
+        assert_ne!(IdStrategy::Random.generate("cred", &("prof_1", "CISA")), IdStrategy::Random.generate("cred", &("prof_1", "CISA")));
+    }
+}
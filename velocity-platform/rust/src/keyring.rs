@@ -0,0 +1,251 @@
+//! Signing Key Ring Module
+//!
+//! Active signing key plus retired public keys, each with a validity window, so
+//! proofs signed before a key rotation continue to verify afterwards. The public
+//! half is `VerifyingKeys`, which verify-only deployments load from a ring's
+//! published entries without ever holding a secret key.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Digest};
+use ed25519_dalek::{Keypair, PublicKey, Signature, Signer};
+use rand::rngs::OsRng;
+use chrono::{DateTime, Utc};
+use zeroize::Zeroizing;
+use crate::VerificationError;
+
+/// Public half of a ring key and the period in which it may sign
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KeyRingEntry {
+    pub key_id: String,
+    /// Hex-encoded ed25519 public key
+    pub public_key: String,
+    pub valid_from: DateTime<Utc>,
+    /// Set when the key is retired; `None` for the active key
+    pub valid_until: Option<DateTime<Utc>>,
+}
+
+impl KeyRingEntry {
+    /// Whether a signature made at `at` falls within this key's validity window
+    pub fn is_valid_at(&self, at: DateTime<Utc>) -> bool {
+        at >= self.valid_from && !matches!(self.valid_until, Some(until) if at > until)
+    }
+}
+
+/// Public keys of a ring with their validity windows, oldest first
+#[derive(Clone, Debug)]
+pub struct VerifyingKeys {
+    entries: Vec<(KeyRingEntry, PublicKey)>,
+}
+
+impl VerifyingKeys {
+    /// Keys from a ring's published `entries`
+    ///
+    /// Every entry's public key must parse and match its key id, so a tampered
+    /// listing cannot substitute another key under a trusted id.
+    pub fn from_entries(entries: Vec<KeyRingEntry>) -> Result<Self, VerificationError> {
+        if entries.is_empty() {
+            return Err(VerificationError::InvalidInput("No verifying keys given".to_string()));
+        }
+        let entries = entries
+            .into_iter()
+            .map(|entry| {
+                let public_key = hex::decode(&entry.public_key)
+                    .ok()
+                    .and_then(|bytes| PublicKey::from_bytes(&bytes).ok())
+                    .ok_or_else(|| VerificationError::InvalidInput(format!("Invalid public key for {}", entry.key_id)))?;
+                if KeyRing::key_id_for(&public_key) != entry.key_id {
+                    return Err(VerificationError::InvalidInput(format!(
+                        "Public key does not match key id {}",
+                        entry.key_id
+                    )));
+                }
+                Ok((entry, public_key))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(VerifyingKeys { entries })
+    }
+
+    pub fn entry(&self, key_id: &str) -> Option<&KeyRingEntry> {
+        self.entries.iter().find(|(entry, _)| entry.key_id == key_id).map(|(entry, _)| entry)
+    }
+
+    /// All keys, oldest first, for publishing to relying parties
+    pub fn entries(&self) -> Vec<KeyRingEntry> {
+        self.entries.iter().map(|(entry, _)| entry.clone()).collect()
+    }
+
+    /// Key that must have produced a signature made at `signed_at`
+    ///
+    /// Returns `None` if the key is unknown or `signed_at` lies outside its
+    /// validity window. Signatures without a key id predate the ring and can only
+    /// come from its original key.
+    pub fn verifying_key(&self, key_id: Option<&str>, signed_at: DateTime<Utc>) -> Option<&PublicKey> {
+        let (entry, public_key) = match key_id {
+            Some(key_id) => self.entries.iter().find(|(entry, _)| entry.key_id == key_id)?,
+            None => self.entries.first()?,
+        };
+        entry.is_valid_at(signed_at).then_some(public_key)
+    }
+}
+
+/// Active signing key plus retired verification keys
+pub struct KeyRing {
+    active: Keypair,
+    /// Every key ever held; the last entry is the active key
+    keys: VerifyingKeys,
+}
+
+impl KeyRing {
+    /// Ring with a freshly generated active key
+    pub fn generate() -> Self {
+        Self::generate_at(Utc::now())
+    }
+
+    fn generate_at(now: DateTime<Utc>) -> Self {
+        let active = Keypair::generate(&mut OsRng {});
+        let entry = Self::entry_for(&active.public, now);
+        KeyRing {
+            keys: VerifyingKeys { entries: vec![(entry, active.public)] },
+            active,
+        }
+    }
+
+    /// Stable key id: first 16 bytes of SHA-256 over the public key
+    pub fn key_id_for(public_key: &PublicKey) -> String {
+        format!("key_{}", hex::encode(&Sha256::digest(public_key.as_bytes())[..16]))
+    }
+
+    fn entry_for(public_key: &PublicKey, valid_from: DateTime<Utc>) -> KeyRingEntry {
+        KeyRingEntry {
+            key_id: Self::key_id_for(public_key),
+            public_key: hex::encode(public_key.as_bytes()),
+            valid_from,
+            valid_until: None,
+        }
+    }
+
+    pub fn active_key_id(&self) -> &str {
+        &self.active_entry().key_id
+    }
+
+    pub fn active_public_key(&self) -> &PublicKey {
+        &self.active.public
+    }
+
+    fn active_entry(&self) -> &KeyRingEntry {
+        &self.keys.entries.last().expect("key ring always holds the active key").0
+    }
+
+    /// Active secret key bytes, e.g. for escrow in a KMS; wiped when the buffer is dropped
+    pub fn export_active_secret(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(self.active.secret.to_bytes())
+    }
+
+    /// Sign with the active key
+    pub fn sign(&self, data: &[u8]) -> Signature {
+        self.active.sign(data)
+    }
+
+    /// Retire the active key and generate a new one, returning the new key id
+    ///
+    /// The retired key's window closes now; its public key is kept so proofs it
+    /// signed remain verifiable.
+    pub fn rotate(&mut self) -> String {
+        self.rotate_at(Utc::now())
+    }
+
+    pub fn rotate_at(&mut self, now: DateTime<Utc>) -> String {
+        if let Some((retired, _)) = self.keys.entries.last_mut() {
+            retired.valid_until = Some(now);
+        }
+
+        self.active = Keypair::generate(&mut OsRng {});
+        let entry = Self::entry_for(&self.active.public, now);
+        let key_id = entry.key_id.clone();
+        self.keys.entries.push((entry, self.active.public));
+        key_id
+    }
+
+    pub fn entry(&self, key_id: &str) -> Option<&KeyRingEntry> {
+        self.keys.entry(key_id)
+    }
+
+    /// All keys, oldest first, for publishing to relying parties
+    pub fn entries(&self) -> Vec<KeyRingEntry> {
+        self.keys.entries()
+    }
+
+    /// Public half of the ring
+    pub fn verifying_keys(&self) -> &VerifyingKeys {
+        &self.keys
+    }
+
+    /// See `VerifyingKeys::verifying_key`
+    pub fn verifying_key(&self, key_id: Option<&str>, signed_at: DateTime<Utc>) -> Option<&PublicKey> {
+        self.keys.verifying_key(key_id, signed_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::Verifier;
+
+    #[test]
+    fn test_rotation_closes_previous_window() {
+        let start = Utc::now() - chrono::Duration::days(30);
+        let rotated_at = start + chrono::Duration::days(10);
+        let mut ring = KeyRing::generate_at(start);
+        let original = ring.active_key_id().to_string();
+        let signature = ring.sign(b"payload");
+
+        let new_key = ring.rotate_at(rotated_at);
+        assert_ne!(new_key, original);
+        assert_eq!(ring.active_key_id(), new_key);
+        assert_eq!(ring.entries().len(), 2);
+
+        let before = rotated_at - chrono::Duration::days(1);
+        let after = rotated_at + chrono::Duration::days(1);
+        let old_key = ring.verifying_key(Some(&original), before).unwrap();
+        assert!(old_key.verify(b"payload", &signature).is_ok());
+        assert!(ring.verifying_key(Some(&original), after).is_none());
+        assert!(ring.verifying_key(Some(&new_key), before).is_none());
+        assert!(ring.verifying_key(Some(&new_key), after).is_some());
+        assert!(ring.verifying_key(Some("key_unknown"), after).is_none());
+
+        // Signatures without a key id resolve to the original key
+        assert_eq!(ring.verifying_key(None, before), Some(old_key));
+    }
+
+    #[test]
+    fn test_active_secret_wiped_on_drop() {
+        // In a ManuallyDrop slot the secret's memory stays owned by the test after
+        // its destructor runs, so its bytes can be inspected without reading a
+        // dropped value
+        let mut secret = std::mem::ManuallyDrop::new(KeyRing::generate().active.secret);
+        assert_ne!(secret.as_bytes(), &[0u8; 32]);
+        assert_eq!(std::mem::size_of::<ed25519_dalek::SecretKey>(), 32);
+
+        let slot: *mut ed25519_dalek::SecretKey = &mut *secret;
+        let bytes = unsafe {
+            std::ptr::drop_in_place(slot);
+            std::ptr::read(slot as *const [u8; 32])
+        };
+        assert_eq!(bytes, [0u8; 32]);
+    }
+
+    #[test]
+    fn test_exported_secret_wiped_on_drop() {
+        let ring = KeyRing::generate();
+        let mut exported = std::mem::ManuallyDrop::new(ring.export_active_secret());
+        assert_eq!(*exported, ring.active.secret.to_bytes());
+        assert_eq!(std::mem::size_of::<Zeroizing<[u8; 32]>>(), 32);
+
+        let slot: *mut Zeroizing<[u8; 32]> = &mut *exported;
+        let bytes = unsafe {
+            std::ptr::drop_in_place(slot);
+            std::ptr::read(slot as *const [u8; 32])
+        };
+        assert_eq!(bytes, [0u8; 32]);
+    }
+}
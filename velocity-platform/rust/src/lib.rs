@@ -14,31 +14,150 @@ use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
 use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signature, Signer, Verifier};
 use aes_gcm::{Aes256Gcm, Key, Nonce};
-use aes_gcm::aead::{Aead, NewAead};
+use aes_gcm::aead::{Aead, NewAead, Payload};
 use rand::rngs::OsRng;
+use rayon::prelude::*;
+use subtle::ConstantTimeEq;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use zeroize::Zeroizing;
 
+pub mod anchoring;
 pub mod blockchain;
+pub mod canonical;
+pub mod clock;
+pub mod error;
 pub mod evidence;
+pub mod hybrid;
+pub mod ids;
+pub mod keyring;
 pub mod trust_score;
+pub mod verifier;
 pub mod ai_verification;
 pub mod credentials;
+pub mod disputes;
+pub mod metrics;
+#[cfg(feature = "pq")]
+pub mod pq;
+pub mod proof_store;
+pub mod shared;
+pub mod threshold;
+pub mod timestamping;
 pub mod vtp; // Velocity Trust Protocol
+pub mod wire;
 
 // Re-export core types
+pub use anchoring::*;
 pub use blockchain::*;
+pub use canonical::*;
+pub use clock::*;
+pub use error::*;
 pub use evidence::*;
+pub use hybrid::*;
+pub use ids::*;
+pub use keyring::*;
 pub use trust_score::*;
+pub use verifier::*;
 pub use ai_verification::*;
 pub use credentials::*;
+pub use disputes::*;
+pub use metrics::*;
+#[cfg(feature = "pq")]
+pub use pq::*;
+pub use proof_store::*;
+pub use shared::*;
+pub use threshold::*;
+pub use timestamping::*;
 pub use vtp::*;
+pub use wire::*;
+
+/// Proof format v1: signature over `"{id}|{hash}|{nonce}|{timestamp}"`
+pub const PROOF_FORMAT_V1: u16 = 1;
+
+/// Proof format v2: the v1 payload prefixed with the proof's domain-separation
+/// tag, `"{domain}|{id}|{hash}|{nonce}|{timestamp}"`
+pub const PROOF_FORMAT_V2: u16 = 2;
+
+/// Proof format v3: the v2 fields plus the proof's chain position and signing
+/// key (`previous_hash`, `block_height`, `key_id` and `genesis_anchor`),
+/// field-encoded under the domain tag so links between proofs are authenticated
+pub const PROOF_FORMAT_V3: u16 = 3;
+
+/// Format version written by this build
+///
+/// A new version must be added whenever the signed-payload layout changes, with
+/// the old layout kept in `CryptographicProof::signed_payload` so previously
+/// issued proofs continue to verify.
+pub const CURRENT_PROOF_FORMAT_VERSION: u16 = PROOF_FORMAT_V3;
+
+/// Domain of evidence integrity proofs
+pub const DOMAIN_EVIDENCE: &str = "velocity:evidence:v1";
+/// Domain of trust score proofs
+pub const DOMAIN_TRUST_SCORE: &str = "velocity:trust-score:v1";
+/// Domain of AI decision proofs
+pub const DOMAIN_AI_DECISION: &str = "velocity:ai-decision:v1";
+/// Domain of professional credential proofs
+pub const DOMAIN_CREDENTIAL: &str = "velocity:credential:v1";
+/// Domain of Velocity Trust Protocol contract proofs
+pub const DOMAIN_VTP_CONTRACT: &str = "velocity:vtp-contract:v1";
+/// Domain of chain-of-custody signatures
+pub const DOMAIN_CUSTODY: &str = "velocity:custody:v1";
+/// Domain of signatures by which a custodian releases evidence to the next one
+pub const DOMAIN_CUSTODY_RELEASE: &str = "velocity:custody-release:v1";
+/// Domain of human review approval signatures
+pub const DOMAIN_HUMAN_REVIEW: &str = "velocity:human-review:v1";
+/// Domain of evidence chain admin audit log signatures
+pub const DOMAIN_ADMIN_AUDIT: &str = "velocity:admin-audit:v1";
+/// Domain of proofs over verifications appended to evidence integrity records
+pub const DOMAIN_EVIDENCE_VERIFICATION: &str = "velocity:evidence-verification:v1";
+/// Domain of credential and evidence dispute resolution signatures
+pub const DOMAIN_DISPUTE_RESOLUTION: &str = "velocity:dispute-resolution:v1";
+/// Domain of threshold-signed multi-party attestations
+pub const DOMAIN_THRESHOLD_ATTESTATION: &str = "velocity:threshold-attestation:v1";
+/// Domain of inference runtime attestations over AI processing metrics
+pub const DOMAIN_PROCESSING_METRICS: &str = "velocity:processing-metrics:v1";
+/// Domain of genesis hashes derived from a chain anchor
+pub const DOMAIN_GENESIS: &str = "velocity:genesis:v1";
+
+/// Domain of engine proofs of `proof_type`, e.g. `ai_decision` is `velocity:ai-decision:v1`
+pub fn proof_domain(proof_type: &str) -> String {
+    format!("velocity:{}:v1", proof_type.replace('_', "-"))
+}
+
+/// `message` prefixed with a domain-separation tag, ready to be signed
+///
+/// Each kind of signed payload has its own tag, so a signature issued for one
+/// kind never verifies as another even when their byte layouts coincide.
+pub fn domain_separated(domain: &str, message: &str) -> String {
+    format!("{}|{}", domain, message)
+}
+
+/// How far, in seconds, a proof's timestamp may fall behind its predecessor's
+/// before the engine treats it as backdated
+pub const DEFAULT_CLOCK_SKEW_TOLERANCE_SECONDS: i64 = 5;
+
+/// `previous_hash` of the first proof in an engine's chain
+pub const GENESIS_PROOF_HASH: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Genesis hash of a chain started from `anchor`, such as an organization and
+/// network id; `None` gives the shared `GENESIS_PROOF_HASH`
+pub fn genesis_hash_for(anchor: Option<&str>) -> String {
+    match anchor {
+        Some(anchor) => format!("0x{}", hex::encode(Sha256::digest(domain_separated(DOMAIN_GENESIS, anchor).as_bytes()))),
+        None => GENESIS_PROOF_HASH.to_string(),
+    }
+}
 
 /// Core cryptographic proof structure
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub struct CryptographicProof {
+    /// Signed-payload layout; proofs issued before versioning are v1
+    #[serde(default = "CryptographicProof::unversioned_format")]
+    pub format_version: u16,
     pub id: String,
     pub hash: String,
     pub signature: String,
@@ -47,6 +166,426 @@ pub struct CryptographicProof {
     pub merkle_root: Option<String>,
     pub block_height: u64,
     pub verification_status: String,
+    pub nonce: String,
+    /// Engine key that signed the proof; `None` for proofs issued before key rotation
+    #[serde(default)]
+    pub key_id: Option<String>,
+    /// Domain-separation tag covered by the signature; `None` for v1 proofs
+    #[serde(default)]
+    pub domain: Option<String>,
+    /// RFC 3161 token over `hash`, present when the engine has a TSA configured
+    #[serde(default)]
+    pub timestamp_token: Option<TimestampToken>,
+    /// Hex ML-DSA-65 signature over the same payload as `signature`, present
+    /// when the engine has a post-quantum key
+    #[serde(default)]
+    pub pq_signature: Option<String>,
+    /// Anchor of the chain's genesis hash, recorded on the first proof of a
+    /// chain started with `VelocityCryptographicEngine::with_genesis`
+    #[serde(default)]
+    pub genesis_anchor: Option<String>,
+}
+
+impl CryptographicProof {
+    /// Generate a random anti-replay nonce
+    pub fn generate_nonce() -> String {
+        let mut bytes = [0u8; 16];
+        rand::RngCore::fill_bytes(&mut OsRng, &mut bytes);
+        hex::encode(bytes)
+    }
+
+    /// Payload covered by the signature of a current-version proof
+    ///
+    /// Built from the proof's own fields, so a proof is signed once every field
+    /// but the signatures is set.
+    pub fn signing_payload(&self) -> String {
+        let block_height = self.block_height.to_string();
+        domain_separated(
+            self.domain.as_deref().unwrap_or(""),
+            &encode_fields(&[
+                &self.id,
+                &self.hash,
+                &self.nonce,
+                &self.timestamp,
+                self.previous_hash.as_deref().unwrap_or(""),
+                &block_height,
+                self.key_id.as_deref().unwrap_or(""),
+                self.genesis_anchor.as_deref().unwrap_or(""),
+            ]),
+        )
+    }
+
+    fn v2_signing_payload(domain: &str, id: &str, hash: &str, nonce: &str, timestamp: &str) -> String {
+        domain_separated(domain, &Self::v1_signing_payload(id, hash, nonce, timestamp))
+    }
+
+    fn v1_signing_payload(id: &str, hash: &str, nonce: &str, timestamp: &str) -> String {
+        format!("{}|{}|{}|{}", id, hash, nonce, timestamp)
+    }
+
+    /// Payload covered by this proof's signature under its `format_version`,
+    /// or `None` if the version is not supported by this build
+    pub fn signed_payload(&self) -> Option<String> {
+        match self.format_version {
+            PROOF_FORMAT_V1 => Some(Self::v1_signing_payload(&self.id, &self.hash, &self.nonce, &self.timestamp)),
+            PROOF_FORMAT_V2 => self
+                .domain
+                .as_deref()
+                .map(|domain| Self::v2_signing_payload(domain, &self.id, &self.hash, &self.nonce, &self.timestamp)),
+            PROOF_FORMAT_V3 => self.domain.as_ref().map(|_| self.signing_payload()),
+            _ => None,
+        }
+    }
+
+    /// `signed_payload` of a proof that must belong to `domain`
+    ///
+    /// `None` if the proof was issued in another domain. v1 proofs predate domain
+    /// separation and are accepted in any domain.
+    pub fn signed_payload_in(&self, domain: &str) -> Option<String> {
+        if self.format_version != PROOF_FORMAT_V1 && self.domain.as_deref() != Some(domain) {
+            return None;
+        }
+        self.signed_payload()
+    }
+
+    /// Whether the recorded genesis anchor, if any, is on a first proof and
+    /// derives its `previous_hash`
+    pub fn genesis_anchor_consistent(&self) -> bool {
+        match &self.genesis_anchor {
+            Some(anchor) => {
+                self.block_height == 1
+                    && self.previous_hash.as_deref() == Some(genesis_hash_for(Some(anchor)).as_str())
+            }
+            None => true,
+        }
+    }
+
+    /// Whether the proof sits where it claims in a chain starting from
+    /// `genesis_hash`: at height 1 exactly when it links from the genesis, with
+    /// any recorded anchor consistent
+    pub fn genesis_link_consistent(&self, genesis_hash: &str) -> bool {
+        let links_from_genesis = self.previous_hash.as_deref() == Some(genesis_hash);
+        (self.block_height == 1) == links_from_genesis && self.genesis_anchor_consistent()
+    }
+
+    /// The proof's ed25519 signature and post-quantum co-signature
+    pub fn hybrid_signature(&self) -> HybridSignature {
+        HybridSignature {
+            classical: self.signature.clone(),
+            pq: self.pq_signature.clone(),
+        }
+    }
+
+    /// Content-addressed id: SHA-256 of the canonical proof contents
+    ///
+    /// Only the fields that describe *what* is proven are included. The id itself,
+    /// nonce, timestamp, signature and chain position differ on every issuance and
+    /// would defeat deduplication.
+    pub fn content_addressed_id(proof_type: &str, hash: &str) -> String {
+        #[derive(Serialize)]
+        struct ProofContent<'a> {
+            format_version: u16,
+            proof_type: &'a str,
+            hash: &'a str,
+        }
+
+        let content = canonical_json(&ProofContent {
+            format_version: CURRENT_PROOF_FORMAT_VERSION,
+            proof_type,
+            hash,
+        })
+        .expect("proof content is always serializable");
+        format!("proof_{}", hex::encode(Sha256::digest(content.as_bytes())))
+    }
+
+    /// Compact CBOR encoding for network transport
+    ///
+    /// The signature covers `signed_payload()`, which is rebuilt from the decoded
+    /// fields, so a proof verifies identically whether it travelled as JSON or CBOR.
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(self, &mut bytes).expect("proofs are always serializable");
+        bytes
+    }
+
+    pub fn from_cbor(bytes: &[u8]) -> Result<CryptographicProof, String> {
+        ciborium::de::from_reader(bytes).map_err(|e| format!("Invalid CBOR proof: {}", e))
+    }
+
+    fn unversioned_format() -> u16 {
+        PROOF_FORMAT_V1
+    }
+}
+
+/// How the engine assigns proof ids
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProofIdScheme {
+    /// `proof_{uuid}`; every proof is unique (default, backward compatible)
+    #[default]
+    Random,
+    /// `proof_{sha256}` over the proof contents; resubmitting the same content
+    /// returns the existing proof, found through the store's id index
+    ContentAddressed,
+}
+
+/// Constant-time equality for hashes, signatures and other proof material
+///
+/// Only the lengths are compared in variable time; the contents are always
+/// compared in full so a mismatch position is not observable via timing.
+pub fn ct_eq(a: impl AsRef<[u8]>, b: impl AsRef<[u8]>) -> bool {
+    a.as_ref().ct_eq(b.as_ref()).into()
+}
+
+/// Default window in which a proof is considered fresh
+pub const DEFAULT_REPLAY_WINDOW_SECS: i64 = 300;
+
+/// Longest replay window a guard accepts; every seen pair is kept for the
+/// length of the window
+pub const MAX_REPLAY_WINDOW_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// `window_secs` as a replay window, if it lies in `1..=MAX_REPLAY_WINDOW_SECS`
+fn replay_window(window_secs: i64) -> Result<chrono::Duration, String> {
+    if !(1..=MAX_REPLAY_WINDOW_SECS).contains(&window_secs) {
+        return Err(format!(
+            "Replay window must be between 1 and {} seconds",
+            MAX_REPLAY_WINDOW_SECS
+        ));
+    }
+    Ok(chrono::Duration::seconds(window_secs))
+}
+
+/// Result of `VelocityCryptographicEngine::verify_chain_from`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChainVerificationReport {
+    /// Height the caller had already verified up to
+    pub from_height: u64,
+    /// Height of the last proof in the chain
+    pub tip_height: u64,
+    /// Hash stored at `from_height`, or `None` if the chain no longer reaches it
+    pub boundary_hash: Option<String>,
+    /// Whether the first proof above the boundary links to `boundary_hash`
+    pub boundary_linked: bool,
+    /// Heights above the boundary whose signature, hash or linkage failed
+    pub invalid_heights: Vec<u64>,
+    pub is_valid: bool,
+}
+
+/// Signature result and linkage fields of one stored proof
+struct ProofLink {
+    signature_valid: bool,
+    /// `None` if the proof's timestamp is not RFC 3339
+    timestamp: Option<DateTime<Utc>>,
+    hash: String,
+    previous_hash: Option<String>,
+    block_height: u64,
+}
+
+impl ProofLink {
+    fn new(proof: CryptographicProof, signature_valid: bool) -> Self {
+        ProofLink {
+            signature_valid: signature_valid && proof.genesis_anchor_consistent(),
+            timestamp: parse_proof_timestamp(&proof.timestamp),
+            hash: proof.hash,
+            previous_hash: proof.previous_hash,
+            block_height: proof.block_height,
+        }
+    }
+
+    /// Link for a height the store could not return, which never verifies
+    fn missing() -> Self {
+        ProofLink { signature_valid: false, timestamp: None, hash: String::new(), previous_hash: None, block_height: 0 }
+    }
+}
+
+fn parse_proof_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp).ok().map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+/// Whether `hash` is a hex SHA-256 digest
+fn is_proof_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Whether `signature_hex` is a valid ed25519 signature over `data` by `public_key`
+pub fn verify_hex_signature(public_key: &PublicKey, signature_hex: &str, data: &str) -> bool {
+    match hex::decode(signature_hex) {
+        Ok(signature_bytes) => {
+            if let Ok(signature) = Signature::from_bytes(&signature_bytes) {
+                public_key.verify(data.as_bytes(), &signature).is_ok()
+            } else {
+                false
+            }
+        }
+        Err(_) => false,
+    }
+}
+
+/// Whether `proof` belongs to `domain` and is signed by the key in `keys` it
+/// names, valid at its timestamp
+fn verify_domain_proof(keys: &VerifyingKeys, proof: &CryptographicProof, domain: &str) -> bool {
+    let payload = match proof.signed_payload_in(domain) {
+        Some(payload) => payload,
+        None => return false,
+    };
+    parse_proof_timestamp(&proof.timestamp)
+        .and_then(|signed_at| keys.verifying_key(proof.key_id.as_deref(), signed_at))
+        .is_some_and(|public_key| verify_hex_signature(public_key, &proof.signature, &payload))
+}
+
+#[cfg(feature = "pq")]
+fn verify_pq_hex_signature(public_key: Option<&[u8]>, signature_hex: &str, payload: &str) -> bool {
+    match (public_key, hex::decode(signature_hex)) {
+        (Some(public_key), Ok(signature)) => verify_ml_dsa65(public_key, payload.as_bytes(), &signature),
+        _ => false,
+    }
+}
+
+/// Builds without the `pq` feature cannot check post-quantum co-signatures
+#[cfg(not(feature = "pq"))]
+fn verify_pq_hex_signature(_public_key: Option<&[u8]>, _signature_hex: &str, _payload: &str) -> bool {
+    false
+}
+
+/// Check `proof`'s signatures over the payload defined by its format version
+/// under `policy`
+///
+/// The ed25519 signature is checked by `verify_signature` with the key in `keys`
+/// named by the proof and valid at its timestamp, the post-quantum one with
+/// `pq_public_key`.
+fn check_proof_signatures(
+    keys: &VerifyingKeys,
+    pq_public_key: Option<&[u8]>,
+    policy: HybridPolicy,
+    proof: &CryptographicProof,
+    verify_signature: impl Fn(&PublicKey, &str, &str) -> bool,
+) -> HybridVerification {
+    let payload = proof.signed_payload();
+    let classical_valid = payload
+        .as_deref()
+        .zip(parse_proof_timestamp(&proof.timestamp))
+        .and_then(|(payload, signed_at)| {
+            let public_key = keys.verifying_key(proof.key_id.as_deref(), signed_at)?;
+            Some(verify_signature(public_key, &proof.signature, payload))
+        })
+        .unwrap_or(false);
+    let pq_valid = proof.pq_signature.as_deref().map(|signature| {
+        payload.as_deref().is_some_and(|payload| verify_pq_hex_signature(pq_public_key, signature, payload))
+    });
+    HybridVerification::evaluate(policy, classical_valid, pq_valid)
+}
+
+/// Whether a proof issued at `timestamp` may follow one issued at `previous`,
+/// allowing for `tolerance` of clock skew
+fn timestamp_follows(
+    previous: Option<DateTime<Utc>>,
+    timestamp: Option<DateTime<Utc>>,
+    tolerance: chrono::Duration,
+) -> bool {
+    match (previous, timestamp) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(previous), Some(timestamp)) => timestamp + tolerance >= previous,
+    }
+}
+
+/// Combine per-proof signature results for the proofs from chain index `start`
+/// on with their hash linkage and timestamp order, starting from the proof with
+/// `previous_hash` issued at `previous_timestamp`
+fn link_chain_results(
+    start: usize,
+    previous_hash: &str,
+    previous_timestamp: Option<DateTime<Utc>>,
+    links: Vec<ProofLink>,
+    tolerance: chrono::Duration,
+) -> Vec<(usize, bool)> {
+    let mut previous_hash = previous_hash.to_string();
+    let mut previous_timestamp = previous_timestamp;
+    links
+        .into_iter()
+        .enumerate()
+        .map(|(offset, link)| {
+            let index = start + offset;
+            let linked = link.previous_hash.as_deref() == Some(previous_hash.as_str())
+                && link.block_height == index as u64 + 1;
+            let ordered = timestamp_follows(previous_timestamp, link.timestamp, tolerance);
+            let valid = link.signature_valid && linked && ordered && is_proof_hash(&link.hash);
+            previous_hash = link.hash;
+            previous_timestamp = link.timestamp;
+            (index, valid)
+        })
+        .collect()
+}
+
+/// Tracks recently seen `(id, nonce)` pairs to reject replayed proofs
+#[derive(Clone, Debug)]
+pub struct ReplayGuard {
+    window: chrono::Duration,
+    /// How far past the current time a proof may be timestamped
+    future_tolerance: chrono::Duration,
+    /// Issue time of each tracked pair
+    seen: HashMap<(String, String), DateTime<Utc>>,
+}
+
+impl ReplayGuard {
+    /// Guard with a `window_secs` window, clamped to `1..=MAX_REPLAY_WINDOW_SECS`
+    pub fn new(window_secs: i64) -> Self {
+        ReplayGuard {
+            window: chrono::Duration::seconds(window_secs.clamp(1, MAX_REPLAY_WINDOW_SECS)),
+            future_tolerance: chrono::Duration::seconds(DEFAULT_CLOCK_SKEW_TOLERANCE_SECONDS),
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Accept proofs timestamped up to `tolerance` ahead of the current time
+    pub fn set_future_tolerance(&mut self, tolerance: chrono::Duration) {
+        self.future_tolerance = tolerance;
+    }
+
+    /// Record the proof as seen, returning false if it is a replay or outside the window
+    pub fn check_and_record(&mut self, proof: &CryptographicProof) -> bool {
+        self.check_and_record_at(proof, Utc::now())
+    }
+
+    pub fn check_and_record_at(&mut self, proof: &CryptographicProof, now: DateTime<Utc>) -> bool {
+        let issued_at = match DateTime::parse_from_rfc3339(&proof.timestamp) {
+            Ok(timestamp) => timestamp.with_timezone(&Utc),
+            Err(_) => return false,
+        };
+
+        // Proofs older than the window may no longer be tracked, so they are stale.
+        // Future-dated proofs only get clock-skew leeway: one dated further ahead
+        // would stay fresh after its pair had been evicted.
+        if now - issued_at > self.window || issued_at - now > self.future_tolerance {
+            return false;
+        }
+
+        // Pairs are kept for as long as their proof is fresh
+        let window = self.window;
+        self.seen.retain(|_, issued| now - *issued <= window);
+
+        let key = (proof.id.clone(), proof.nonce.clone());
+        if self.seen.contains_key(&key) {
+            return false;
+        }
+        self.seen.insert(key, issued_at);
+        true
+    }
+
+    /// Number of tracked `(id, nonce)` pairs
+    pub fn tracked(&self) -> usize {
+        self.seen.len()
+    }
+}
+
+impl Default for ReplayGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_REPLAY_WINDOW_SECS)
+    }
+}
+
+/// `ReplayGuard::check_and_record_at` on a guard shared by `&self` verifiers
+fn check_replay(guard: &Mutex<ReplayGuard>, proof: &CryptographicProof, now: DateTime<Utc>) -> bool {
+    guard.lock().unwrap().check_and_record_at(proof, now)
 }
 
 /// Evidence integrity with cryptographic verification
@@ -60,11 +599,18 @@ pub struct EvidenceIntegrity {
     pub chain_of_custody: Vec<ChainOfCustodyEntry>,
     pub immutable_storage: ImmutableStorage,
     pub verification_history: Vec<VerificationEntry>,
+    /// Confidence across the current entries of `verification_history`;
+    /// `None` for records created before it was tracked
+    #[serde(default)]
+    pub aggregate_confidence: Option<f64>,
 }
 
 /// Chain of custody entry
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChainOfCustodyEntry {
+    /// Signature layout; entries issued before versioning are v1
+    #[serde(default = "ChainOfCustodyEntry::unversioned_format")]
+    pub format_version: u16,
     pub id: String,
     pub actor: String,
     pub action: String,
@@ -73,6 +619,131 @@ pub struct ChainOfCustodyEntry {
     pub metadata: HashMap<String, String>,
     pub ip_address: Option<String>,
     pub user_agent: Option<String>,
+    /// `entry_hash` of the preceding entry; `None` for the first entry
+    #[serde(default)]
+    pub previous_entry_hash: Option<String>,
+    /// Signature of the previous custodian over `release_payload`; set on transfers
+    #[serde(default)]
+    pub release_signature: Option<String>,
+}
+
+/// Custody format v1: `created` entries signed by the engine over the bare content hash
+pub const CUSTODY_FORMAT_V1: u16 = 1;
+
+/// Custody format v2: every entry signed by its actor over `signing_payload`
+pub const CUSTODY_FORMAT_V2: u16 = 2;
+
+/// Custody format version written by this build
+pub const CURRENT_CUSTODY_FORMAT_VERSION: u16 = CUSTODY_FORMAT_V2;
+
+/// Custody entry action recorded when the evidence is first registered
+pub const CUSTODY_ACTION_CREATED: &str = "created";
+/// Custody entry action recorded when the evidence changes hands
+pub const CUSTODY_ACTION_TRANSFERRED: &str = "transferred";
+
+impl ChainOfCustodyEntry {
+    /// Payload covered by `cryptographic_signature` for evidence with `content_hash`
+    ///
+    /// Binds the entry to the evidence, its position in the chain and every field
+    /// describing the handover, including the previous custodian in `metadata`
+    /// and, on transfers, their release signature.
+    pub fn signing_payload(&self, content_hash: &str) -> String {
+        let metadata = canonical_json(&self.metadata).expect("string maps are always serializable");
+        let mut fields = vec![
+            content_hash,
+            &self.id,
+            &self.actor,
+            &self.action,
+            &self.timestamp,
+            self.previous_entry_hash.as_deref().unwrap_or(""),
+            &metadata,
+        ];
+        if let Some(release_signature) = &self.release_signature {
+            fields.push(release_signature);
+        }
+        domain_separated(DOMAIN_CUSTODY, &encode_fields(&fields))
+    }
+
+    /// Payload `from_actor` signs to release the evidence to `to_actor`
+    ///
+    /// Bound to the entry being handed on through `previous_entry_hash`, so a
+    /// release cannot be reused once the chain has moved past it.
+    pub fn release_payload(content_hash: &str, previous_entry_hash: &str, from_actor: &str, to_actor: &str) -> String {
+        domain_separated(
+            DOMAIN_CUSTODY_RELEASE,
+            &encode_fields(&[content_hash, previous_entry_hash, from_actor, to_actor]),
+        )
+    }
+
+    /// Hash the next entry links to, covering the signed payload and the signature
+    pub fn entry_hash(&self, content_hash: &str) -> String {
+        let data = encode_fields(&[&self.signing_payload(content_hash), &self.cryptographic_signature]);
+        hex::encode(Sha256::digest(data.as_bytes()))
+    }
+
+    /// `created` entry for evidence with `content_hash`, signed by `actor`'s own custody key
+    pub fn created(content_hash: &str, actor: &str, keypair: &Keypair) -> Self {
+        let mut entry = Self::unsigned(actor, CUSTODY_ACTION_CREATED, HashMap::new(), None, None);
+        entry.sign(content_hash, keypair);
+        entry
+    }
+
+    /// `transferred` entry handing `integrity` from its current custodian to `actor`
+    ///
+    /// Carries the custodian's `release_signature` (see `EvidenceIntegrity::release_custody`)
+    /// and is signed by `actor`'s own custody key. Fails if the evidence has no custodian.
+    pub fn transfer(integrity: &EvidenceIntegrity, actor: &str, release_signature: &str, keypair: &Keypair) -> Result<Self, String> {
+        let previous = integrity
+            .chain_of_custody
+            .last()
+            .ok_or_else(|| format!("Evidence {} has no custodian", integrity.evidence_id))?;
+        let metadata = HashMap::from([("from".to_string(), previous.actor.clone())]);
+        let mut entry = Self::unsigned(
+            actor,
+            CUSTODY_ACTION_TRANSFERRED,
+            metadata,
+            Some(previous.entry_hash(&integrity.content_hash)),
+            Some(release_signature.to_string()),
+        );
+        entry.sign(&integrity.content_hash, keypair);
+        Ok(entry)
+    }
+
+    /// Sign the entry's current fields as its actor, for evidence with `content_hash`
+    pub fn sign(&mut self, content_hash: &str, keypair: &Keypair) {
+        self.cryptographic_signature = hex::encode(keypair.sign(self.signing_payload(content_hash).as_bytes()).to_bytes());
+    }
+
+    fn unsigned(
+        actor: &str,
+        action: &str,
+        metadata: HashMap<String, String>,
+        previous_entry_hash: Option<String>,
+        release_signature: Option<String>,
+    ) -> Self {
+        ChainOfCustodyEntry {
+            format_version: CURRENT_CUSTODY_FORMAT_VERSION,
+            id: format!("custody_{}", Uuid::new_v4()),
+            actor: actor.to_string(),
+            action: action.to_string(),
+            timestamp: Utc::now().to_rfc3339(),
+            cryptographic_signature: String::new(),
+            metadata,
+            ip_address: None,
+            user_agent: None,
+            previous_entry_hash,
+            release_signature,
+        }
+    }
+
+    fn unversioned_format() -> u16 {
+        CUSTODY_FORMAT_V1
+    }
+}
+
+/// Content hash evidence is registered under, for actors signing its `created` entry
+pub fn evidence_content_hash(evidence_data: &str) -> String {
+    hex::encode(Sha256::digest(evidence_data.as_bytes()))
 }
 
 /// Immutable storage reference
@@ -82,6 +753,46 @@ pub struct ImmutableStorage {
     pub blockchain_tx_id: Option<String>,
     pub storage_provider: String,
     pub replication_factor: u32,
+    /// Set when the stored content is encrypted; `ipfs_hash` then addresses the ciphertext
+    #[serde(default)]
+    pub encryption: Option<EvidenceEnvelope>,
+}
+
+/// Envelope encryption of evidence stored encrypted at rest
+///
+/// The payload is encrypted under its own data key, bound to the evidence id;
+/// only the data key wrapped with the engine's master key is kept.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EvidenceEnvelope {
+    pub algorithm: String,
+    /// Hex `nonce || ciphertext` of the data key under the master key
+    pub wrapped_data_key: String,
+}
+
+/// Algorithm of evidence payloads and their wrapped data keys
+pub const EVIDENCE_ENCRYPTION_ALGORITHM: &str = "AES-256-GCM";
+
+const AES_GCM_NONCE_LENGTH: usize = 12;
+
+/// AES-256-GCM encryption of `plaintext` bound to `aad`, as `nonce || ciphertext`
+fn aes_gcm_seal(key: &[u8; 32], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, String> {
+    let mut nonce = [0u8; AES_GCM_NONCE_LENGTH];
+    rand::RngCore::fill_bytes(&mut OsRng, &mut nonce);
+    let ciphertext = Aes256Gcm::new(Key::from_slice(key))
+        .encrypt(Nonce::from_slice(&nonce), Payload { msg: plaintext, aad })
+        .map_err(|_| "Encryption failed".to_string())?;
+    Ok([nonce.as_slice(), &ciphertext].concat())
+}
+
+/// Inverse of `aes_gcm_seal`; fails if the key, `aad` or ciphertext differ
+fn aes_gcm_open(key: &[u8; 32], sealed: &[u8], aad: &[u8]) -> Result<Vec<u8>, String> {
+    if sealed.len() < AES_GCM_NONCE_LENGTH {
+        return Err("Ciphertext is truncated".to_string());
+    }
+    let (nonce, ciphertext) = sealed.split_at(AES_GCM_NONCE_LENGTH);
+    Aes256Gcm::new(Key::from_slice(key))
+        .decrypt(Nonce::from_slice(nonce), Payload { msg: ciphertext, aad })
+        .map_err(|_| "Decryption failed".to_string())
 }
 
 /// Verification entry
@@ -95,15 +806,97 @@ pub struct VerificationEntry {
     pub cryptographic_proof: CryptographicProof,
     pub attestation: String,
     pub confidence: f64,
+    /// Id of an earlier entry by the same verifier that this one replaces
+    #[serde(default)]
+    pub supersedes: Option<String>,
+}
+
+/// Verification result of evidence that checked out
+pub const VERIFICATION_PASSED: &str = "passed";
+/// Verification result of evidence that did not check out
+pub const VERIFICATION_FAILED: &str = "failed";
+
+/// Age at which a verification carries half the weight of the newest one
+pub const VERIFICATION_HALF_LIFE_DAYS: f64 = 180.0;
+
+/// Relative weight of a verification by who performed it: regulators over
+/// independent third parties over internal reviewers over automated checks
+pub fn verifier_type_weight(verifier_type: &str) -> f64 {
+    match verifier_type {
+        "regulatory" => 4.0,
+        "third_party" => 2.0,
+        "internal" => 1.5,
+        _ => 1.0,
+    }
+}
+
+/// Overall verification outcome of an evidence record
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AggregateVerification {
+    /// Weighted confidence (0.0-1.0) that the evidence is sound
+    pub confidence: f64,
+    /// `passed` when `confidence` is at least 0.5, otherwise `failed`
+    pub outcome: String,
+    /// Verifiers whose current entry disagrees with `outcome`
+    pub dissenting_verifiers: Vec<String>,
+}
+
+impl EvidenceIntegrity {
+    /// Current custodian's release of the evidence to `to_actor`, signed with its own custody key
+    ///
+    /// Bound to the last custody entry, so it cannot be reused once the chain moves on.
+    pub fn release_custody(&self, to_actor: &str, keypair: &Keypair) -> Result<String, String> {
+        let previous = self
+            .chain_of_custody
+            .last()
+            .ok_or_else(|| format!("Evidence {} has no custodian", self.evidence_id))?;
+        let payload = ChainOfCustodyEntry::release_payload(
+            &self.content_hash,
+            &previous.entry_hash(&self.content_hash),
+            &previous.actor,
+            to_actor,
+        );
+        Ok(hex::encode(keypair.sign(payload.as_bytes()).to_bytes()))
+    }
+
+    /// Verification entries not replaced by a later superseding entry
+    pub fn current_verifications(&self) -> impl Iterator<Item = &VerificationEntry> {
+        self.verification_history.iter().filter(move |entry| {
+            !self
+                .verification_history
+                .iter()
+                .any(|later| later.supersedes.as_deref() == Some(entry.id.as_str()))
+        })
+    }
 }
 
 /// Main cryptographic verification engine
 #[wasm_bindgen]
 pub struct VelocityCryptographicEngine {
-    signing_keypair: Keypair,
-    encryption_key: Key<Aes256Gcm>,
-    proof_chain: Vec<CryptographicProof>,
-    last_block_hash: String,
+    /// Signing keys; ed25519 secret keys wipe themselves on drop
+    key_ring: KeyRing,
+    /// AES-256-GCM key, wiped when the engine is dropped
+    encryption_key: Zeroizing<[u8; 32]>,
+    proof_store: Box<dyn ProofStore>,
+    clock: Arc<dyn Clock>,
+    /// How far a proof's timestamp may precede the previous proof's
+    clock_skew_tolerance: chrono::Duration,
+    /// Locked so verification can record proofs it has seen through `&self`
+    replay_guard: Mutex<ReplayGuard>,
+    id_scheme: ProofIdScheme,
+    metrics: Arc<Metrics>,
+    timestamp_authority: Option<Arc<dyn TimestampAuthority>>,
+    polygon_anchor: Option<Arc<dyn PolygonAnchor>>,
+    /// Public key of each custody actor; actors hold their signing keys themselves
+    custody_keys: HashMap<String, PublicKey>,
+    /// Post-quantum key proofs are co-signed with
+    #[cfg(feature = "pq")]
+    pq_keypair: Option<MlDsa65Keypair>,
+    hybrid_policy: HybridPolicy,
+    /// Anchor the first proof links from; `None` for the shared zero genesis
+    genesis_anchor: Option<String>,
+    /// `previous_hash` of the first proof, derived from `genesis_anchor`
+    genesis_hash: String,
 }
 
 #[wasm_bindgen]
@@ -111,18 +904,42 @@ impl VelocityCryptographicEngine {
     /// Create new cryptographic engine instance
     #[wasm_bindgen(constructor)]
     pub fn new() -> VelocityCryptographicEngine {
-        let mut csprng = OsRng {};
-        let signing_keypair = Keypair::generate(&mut csprng);
-        let encryption_key = Aes256Gcm::generate_key(&mut csprng);
-        
+        let mut encryption_key = Zeroizing::new([0u8; 32]);
+        rand::RngCore::fill_bytes(&mut OsRng, &mut *encryption_key);
+
         VelocityCryptographicEngine {
-            signing_keypair,
+            key_ring: KeyRing::generate(),
             encryption_key,
-            proof_chain: Vec::new(),
-            last_block_hash: "0x0000000000000000000000000000000000000000000000000000000000000000".to_string(),
+            proof_store: Box::new(MemoryProofStore::new()),
+            clock: system_clock(),
+            clock_skew_tolerance: chrono::Duration::seconds(DEFAULT_CLOCK_SKEW_TOLERANCE_SECONDS),
+            replay_guard: Mutex::new(ReplayGuard::default()),
+            id_scheme: ProofIdScheme::default(),
+            metrics: Arc::new(Metrics::new()),
+            timestamp_authority: None,
+            polygon_anchor: None,
+            custody_keys: HashMap::new(),
+            #[cfg(feature = "pq")]
+            pq_keypair: None,
+            hybrid_policy: HybridPolicy::default(),
+            genesis_anchor: None,
+            genesis_hash: GENESIS_PROOF_HASH.to_string(),
         }
     }
 
+    /// Create an engine whose chain starts from a genesis hash derived from
+    /// `anchor`, e.g. `"{org_id}/{network_id}"`
+    ///
+    /// The anchor is recorded on the first proof, so chains from engines with
+    /// different anchors cannot be linked into one another.
+    #[wasm_bindgen]
+    pub fn with_genesis(anchor: &str) -> VelocityCryptographicEngine {
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.genesis_anchor = Some(anchor.to_string());
+        engine.genesis_hash = genesis_hash_for(Some(anchor));
+        engine
+    }
+
     /// Generate cryptographic proof for data
     #[wasm_bindgen]
     pub fn generate_cryptographic_proof(&mut self, data: &str, proof_type: &str) -> String {
@@ -130,20 +947,81 @@ impl VelocityCryptographicEngine {
         serde_json::to_string(&proof).unwrap_or_default()
     }
 
-    /// Verify cryptographic proof
+    /// Verify cryptographic proof, rejecting proofs already seen within the replay window
     #[wasm_bindgen]
     pub fn verify_cryptographic_proof(&self, proof_json: &str) -> bool {
-        match serde_json::from_str::<CryptographicProof>(proof_json) {
-            Ok(proof) => self.verify_proof(&proof),
+        let started = Instant::now();
+        let valid = match serde_json::from_str::<CryptographicProof>(proof_json) {
+            Ok(proof) => self.verify_proof(&proof) && check_replay(&self.replay_guard, &proof, self.clock.now()),
             Err(_) => false,
+        };
+        self.metrics.proof_verification.record(started.elapsed(), valid);
+        valid
+    }
+
+    /// Throughput and latency report as JSON
+    #[wasm_bindgen]
+    pub fn get_metrics_snapshot(&self) -> String {
+        serde_json::to_string(&self.metrics.snapshot()).unwrap_or_default()
+    }
+
+    /// Set the replay protection window in seconds
+    ///
+    /// Returns false, keeping the current window, unless `window_secs` lies in
+    /// `1..=MAX_REPLAY_WINDOW_SECS`.
+    #[wasm_bindgen]
+    pub fn set_replay_window(&mut self, window_secs: i64) -> bool {
+        if replay_window(window_secs).is_err() {
+            return false;
         }
+        let mut guard = ReplayGuard::new(window_secs);
+        guard.set_future_tolerance(self.clock_skew_tolerance);
+        self.replay_guard = Mutex::new(guard);
+        true
+    }
+
+    /// Retire the current signing key and generate a new one, returning its key id
+    ///
+    /// Proofs signed by retired keys continue to verify.
+    #[wasm_bindgen]
+    pub fn rotate_key(&mut self) -> String {
+        self.key_ring.rotate()
+    }
+
+    /// Derive proof ids from proof contents instead of random UUIDs
+    #[wasm_bindgen]
+    pub fn set_content_addressed_ids(&mut self, enabled: bool) {
+        self.id_scheme = if enabled {
+            ProofIdScheme::ContentAddressed
+        } else {
+            ProofIdScheme::Random
+        };
     }
 
     /// Create evidence integrity record
+    ///
+    /// `custody_entry` is the JSON `created` entry the registering actor signed
+    /// over `evidence_data`'s content hash with its own custody key.
     #[wasm_bindgen]
-    pub fn create_evidence_integrity(&mut self, evidence_data: &str, metadata: &str, actor_id: &str) -> String {
-        let integrity = self.verify_evidence_integrity(evidence_data, metadata, actor_id);
-        serde_json::to_string(&integrity).unwrap_or_default()
+    pub fn create_evidence_integrity(&mut self, evidence_data: &str, metadata: &str, custody_entry: &str) -> String {
+        let integrity = serde_json::from_str::<ChainOfCustodyEntry>(custody_entry)
+            .map_err(|e| e.to_string())
+            .and_then(|created| self.verify_evidence_integrity(evidence_data, metadata, created));
+        match integrity {
+            Ok(integrity) => serde_json::to_string(&integrity).unwrap_or_default(),
+            Err(_) => "{}".to_string(),
+        }
+    }
+
+    /// Register the hex-encoded ed25519 custody key `public_key` for `actor_id`
+    ///
+    /// Returns false if the key is malformed or the actor already has a key.
+    #[wasm_bindgen]
+    pub fn enroll_custody_actor(&mut self, actor_id: &str, public_key: &str) -> bool {
+        match hex::decode(public_key).ok().and_then(|bytes| PublicKey::from_bytes(&bytes).ok()) {
+            Some(public_key) => self.register_custody_actor(actor_id, public_key).is_ok(),
+            None => false,
+        }
     }
 
     /// Generate trust score proof
@@ -153,6 +1031,19 @@ impl VelocityCryptographicEngine {
         serde_json::to_string(&proof).unwrap_or_default()
     }
 
+    /// Calculate a trust score from `TrustScoreInput` JSON, returning the proof together
+    /// with its component scores, risk factors and confidence interval
+    #[wasm_bindgen]
+    pub fn calculate_trust_score_detailed(&mut self, input_json: &str) -> String {
+        let detailed = serde_json::from_str::<TrustScoreInput>(input_json)
+            .map_err(|e| VerificationError::InvalidInput(e.to_string()))
+            .and_then(|input| TrustScoreEngine::new().calculate_trust_score_detailed(&input, self));
+        match detailed {
+            Ok(detailed) => serde_json::to_string(&detailed).unwrap_or_default(),
+            Err(_) => "{}".to_string(),
+        }
+    }
+
     /// Create AI decision proof
     #[wasm_bindgen]
     pub fn create_ai_decision_proof(&mut self, decision_id: &str, model_version: &str, prompt: &str, response: &str, confidence: f64, reviewer_id: &str) -> String {
@@ -175,16 +1066,17 @@ impl VelocityCryptographicEngine {
     /// Get cryptographic analytics
     #[wasm_bindgen]
     pub fn get_cryptographic_analytics(&self) -> String {
+        let height = self.proof_store.len();
         let analytics = CryptographicAnalytics {
-            total_proofs: self.proof_chain.len() as u64,
+            total_proofs: height,
             verification_rate: self.calculate_verification_rate(),
             integrity_score: self.calculate_integrity_score(),
-            blockchain_height: self.proof_chain.len() as u64,
+            blockchain_height: height,
             network_stats: NetworkStats {
-                evidence_integrity: (self.proof_chain.len() as f64 * 0.4) as u64,
-                trust_score_proofs: (self.proof_chain.len() as f64 * 0.3) as u64,
-                ai_decision_proofs: (self.proof_chain.len() as f64 * 0.2) as u64,
-                credential_proofs: (self.proof_chain.len() as f64 * 0.1) as u64,
+                evidence_integrity: (height as f64 * 0.4) as u64,
+                trust_score_proofs: (height as f64 * 0.3) as u64,
+                ai_decision_proofs: (height as f64 * 0.2) as u64,
+                credential_proofs: (height as f64 * 0.1) as u64,
             },
         };
         serde_json::to_string(&analytics).unwrap_or_default()
@@ -207,33 +1099,177 @@ impl VelocityCryptographicEngine {
 }
 
 impl VelocityCryptographicEngine {
+    /// Signing keys, for publishing verification keys to relying parties
+    pub fn key_ring(&self) -> &KeyRing {
+        &self.key_ring
+    }
+
+    /// Read the current time from `clock` when timestamping proofs and checking
+    /// them against the replay window
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Allow a proof's timestamp to precede the previous proof's by up to
+    /// `tolerance`, absorbing skew between the clocks of engines sharing a store
+    ///
+    /// Applies to chain verification as well as to new proofs, and bounds how far
+    /// ahead of the clock a verified proof may be timestamped.
+    pub fn set_clock_skew_tolerance(&mut self, tolerance: chrono::Duration) -> Result<(), String> {
+        if tolerance < chrono::Duration::zero() {
+            return Err("Clock skew tolerance cannot be negative".to_string());
+        }
+        self.clock_skew_tolerance = tolerance;
+        self.replay_guard.get_mut().unwrap().set_future_tolerance(tolerance);
+        Ok(())
+    }
+
+    /// Anchor new proofs with tokens from `authority`, or stop timestamping with `None`
+    ///
+    /// Tokens are best effort: if the TSA fails, the proof is issued without one.
+    /// Proofs carrying a token only verify while the issuing TSA is configured.
+    pub fn set_timestamp_authority(&mut self, authority: Option<Arc<dyn TimestampAuthority>>) {
+        self.timestamp_authority = authority;
+    }
+
+    /// Anchor new evidence records' proof hashes on Polygon through `anchor`, or
+    /// record placeholder transaction ids with `None`
+    ///
+    /// Anchoring is best effort: if the transaction fails, the record is created
+    /// without a `blockchain_tx_id`.
+    pub fn set_polygon_anchor(&mut self, anchor: Option<Arc<dyn PolygonAnchor>>) {
+        self.polygon_anchor = anchor;
+    }
+
+    /// Co-sign new proofs with `keypair`, or stop with `None`
+    ///
+    /// Proofs carrying a post-quantum signature only verify while the engine
+    /// holds the key that made it.
+    #[cfg(feature = "pq")]
+    pub fn set_pq_keypair(&mut self, keypair: Option<MlDsa65Keypair>) {
+        self.pq_keypair = keypair;
+    }
+
+    /// Public key of the post-quantum co-signing key, if any
+    #[cfg(feature = "pq")]
+    pub fn pq_public_key(&self) -> Option<&[u8]> {
+        self.pq_keypair.as_ref().map(MlDsa65Keypair::public_key)
+    }
+
+    /// Require the ed25519 and post-quantum signatures of proofs as `policy` says
+    pub fn set_hybrid_policy(&mut self, policy: HybridPolicy) {
+        self.hybrid_policy = policy;
+    }
+
+    /// Verify custody entries by `actor_id` against `public_key`
+    ///
+    /// Actors sign their entries and releases with the secret half, which never
+    /// reaches the engine, and must be registered before they can create, release
+    /// or receive evidence. A key cannot be replaced, since entries it signed
+    /// would stop verifying.
+    pub fn register_custody_actor(&mut self, actor_id: &str, public_key: PublicKey) -> Result<(), String> {
+        if self.custody_keys.contains_key(actor_id) {
+            return Err(format!("Custody actor {} already has a registered key", actor_id));
+        }
+        self.custody_keys.insert(actor_id.to_string(), public_key);
+        Ok(())
+    }
+
+    /// Public key custody entries by `actor_id` are verified against
+    pub fn custody_public_key(&self, actor_id: &str) -> Option<&PublicKey> {
+        self.custody_keys.get(actor_id)
+    }
+
+    /// Anchor the engine's chain starts from, if it was created `with_genesis`
+    pub fn genesis_anchor(&self) -> Option<&str> {
+        self.genesis_anchor.as_deref()
+    }
+
+    /// `previous_hash` of the first proof in the engine's chain
+    pub fn genesis_hash(&self) -> &str {
+        &self.genesis_hash
+    }
+
+    /// Proofs issued by this engine
+    pub fn proof_store(&self) -> &dyn ProofStore {
+        self.proof_store.as_ref()
+    }
+
+    /// Keep the proof chain in `store` from now on
+    ///
+    /// New proofs extend whatever chain `store` already holds. Proofs already in
+    /// the store only verify if this engine's key ring holds the keys that signed
+    /// them.
+    pub fn set_proof_store(&mut self, store: Box<dyn ProofStore>) {
+        self.proof_store = store;
+    }
+
+    /// Shared handle to the engine's metrics
+    pub fn metrics(&self) -> Arc<Metrics> {
+        Arc::clone(&self.metrics)
+    }
+
     /// Create cryptographic proof
     fn create_proof(&mut self, data: &str, proof_type: &str) -> CryptographicProof {
+        let started = Instant::now();
         let hash = self.generate_secure_hash(data);
-        let signature = self.sign_data(&hash);
-        let timestamp = Utc::now().to_rfc3339();
+        let id = match self.id_scheme {
+            ProofIdScheme::Random => format!("proof_{}", Uuid::new_v4()),
+            ProofIdScheme::ContentAddressed => {
+                let id = CryptographicProof::content_addressed_id(proof_type, &hash);
+                if let Some(existing) = self.proof_store.get_by_id(&id) {
+                    return existing;
+                }
+                id
+            }
+        };
+        let domain = proof_domain(proof_type);
+        let nonce = CryptographicProof::generate_nonce();
+        let issued_at = self.clock.now();
+        let timestamp = issued_at.to_rfc3339();
+        let timestamp_token = self.timestamp_authority.as_ref().and_then(|authority| {
+            let digest = hex::decode(&hash).ok()?;
+            authority.timestamp(&digest).ok()
+        });
         
-        let proof = CryptographicProof {
-            id: format!("proof_{}", Uuid::new_v4()),
+        let mut proof = CryptographicProof {
+            format_version: CURRENT_PROOF_FORMAT_VERSION,
+            id,
             hash: hash.clone(),
-            signature,
+            signature: String::new(),
             timestamp,
-            previous_hash: Some(self.last_block_hash.clone()),
+            previous_hash: Some(self.proof_store.last_hash().unwrap_or_else(|| self.genesis_hash.clone())),
             merkle_root: None,
-            block_height: self.proof_chain.len() as u64 + 1,
+            block_height: self.proof_store.len() + 1,
             verification_status: "verified".to_string(),
+            nonce,
+            key_id: Some(self.key_ring.active_key_id().to_string()),
+            domain: Some(domain),
+            timestamp_token,
+            pq_signature: None,
+            genesis_anchor: self.genesis_anchor.clone().filter(|_| self.proof_store.len() == 0),
         };
-
-        self.proof_chain.push(proof.clone());
-        self.last_block_hash = hash;
+        let HybridSignature { classical, pq } = self.sign_hybrid(&proof.signing_payload());
+        proof.signature = classical;
+        proof.pq_signature = pq;
+
+        // A backdated proof, or one the store failed to keep, is still returned
+        // but marked rejected; it is not part of the chain and will not verify
+        let previous_timestamp = self.proof_store.get_by_height(self.proof_store.len())
+            .and_then(|previous| parse_proof_timestamp(&previous.timestamp));
+        let stored = timestamp_follows(previous_timestamp, Some(issued_at), self.clock_skew_tolerance)
+            && self.proof_store.append(proof.clone()).is_ok();
+        if !stored {
+            proof.verification_status = "rejected".to_string();
+        }
+        self.metrics.proof_generation.record(started.elapsed(), stored);
         
         proof
     }
 
     /// Verify cryptographic proof
     fn verify_proof(&self, proof: &CryptographicProof) -> bool {
-        // Verify signature
-        if !self.verify_signature(&proof.signature, &proof.hash) {
+        if !self.verify_proof_signature(proof) {
             return false;
         }
 
@@ -242,34 +1278,213 @@ impl VelocityCryptographicEngine {
             return false;
         }
 
-        // Verify chain integrity
-        if !self.verify_chain_integrity(proof) {
-            return false;
+        // Verify chain integrity
+        if !self.verify_chain_integrity(proof) {
+            return false;
+        }
+
+        // Verify the TSA anchor, if the proof carries one
+        if let Some(token) = &proof.timestamp_token {
+            let anchored = match &self.timestamp_authority {
+                Some(authority) => {
+                    verify_timestamp_token(authority.as_ref(), token, &proof.hash, &proof.timestamp).is_ok()
+                }
+                None => false,
+            };
+            if !anchored {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn verify_proof_signature(&self, proof: &CryptographicProof) -> bool {
+        self.verify_proof_signatures(proof).is_valid
+    }
+
+    /// Check the signatures over the payload defined by the proof's format
+    /// version under the engine's `HybridPolicy`
+    ///
+    /// The ed25519 signature is checked with the ring key named by the proof and
+    /// valid at its timestamp, the post-quantum one with the engine's ML-DSA-65 key.
+    pub fn verify_proof_signatures(&self, proof: &CryptographicProof) -> HybridVerification {
+        check_proof_signatures(
+            self.key_ring.verifying_keys(),
+            self.pq_verifying_key(),
+            self.hybrid_policy,
+            proof,
+            |public_key, signature, payload| self.verify_signature(public_key, signature, payload),
+        )
+    }
+
+    /// ed25519 signature over `payload`, co-signed if the engine has a post-quantum key
+    fn sign_hybrid(&self, payload: &str) -> HybridSignature {
+        HybridSignature {
+            classical: self.sign_data(payload),
+            pq: self.pq_sign(payload),
+        }
+    }
+
+    /// Hex ML-DSA-65 co-signature over `payload`, if the engine has a post-quantum key
+    #[cfg(feature = "pq")]
+    fn pq_sign(&self, payload: &str) -> Option<String> {
+        self.pq_keypair.as_ref().map(|keypair| hex::encode(keypair.sign(payload.as_bytes())))
+    }
+
+    #[cfg(not(feature = "pq"))]
+    fn pq_sign(&self, _payload: &str) -> Option<String> {
+        None
+    }
+
+    #[cfg(feature = "pq")]
+    fn pq_verifying_key(&self) -> Option<&[u8]> {
+        self.pq_public_key()
+    }
+
+    #[cfg(not(feature = "pq"))]
+    fn pq_verifying_key(&self) -> Option<&[u8]> {
+        None
+    }
+
+    /// Verify every proof in the chain, checking signatures in parallel
+    ///
+    /// Returns `(index, valid)` in chain order. Signatures are independent and are
+    /// checked concurrently; hash linkage is then checked in one sequential pass.
+    pub fn verify_all_signatures_parallel(&self) -> Vec<(usize, bool)> {
+        let links: Vec<ProofLink> = (1..=self.proof_store.len())
+            .into_par_iter()
+            .map(|height| self.proof_link(height))
+            .collect();
+        link_chain_results(0, &self.genesis_hash, None, links, self.clock_skew_tolerance)
+    }
+
+    /// Sequential counterpart of `verify_all_signatures_parallel`
+    pub fn verify_all_signatures(&self) -> Vec<(usize, bool)> {
+        let links: Vec<ProofLink> = (1..=self.proof_store.len())
+            .map(|height| self.proof_link(height))
+            .collect();
+        link_chain_results(0, &self.genesis_hash, None, links, self.clock_skew_tolerance)
+    }
+
+    /// Verify only the proofs above `last_verified_height`, for clients that
+    /// already verified the chain up to there
+    ///
+    /// The first new proof must link to the hash this engine stores at the
+    /// boundary. Clients should also compare `boundary_hash` with the hash they
+    /// recorded at that height: a mismatch means the chain was rewritten below
+    /// the boundary, and a height above the tip means it was cut back.
+    pub fn verify_chain_from(&self, last_verified_height: u64) -> ChainVerificationReport {
+        let tip = self.proof_store.len();
+        let boundary_hash = match last_verified_height {
+            0 => Some(self.genesis_hash.clone()),
+            height if height <= tip => self.proof_store.get_by_height(height).map(|proof| proof.hash),
+            _ => None,
+        };
+        let boundary_timestamp = self.proof_store.get_by_height(last_verified_height)
+            .and_then(|proof| parse_proof_timestamp(&proof.timestamp));
+        let Some(boundary_hash) = boundary_hash else {
+            return ChainVerificationReport {
+                from_height: last_verified_height,
+                tip_height: tip,
+                boundary_hash: None,
+                boundary_linked: false,
+                invalid_heights: Vec::new(),
+                is_valid: false,
+            };
+        };
+
+        let start = last_verified_height as usize;
+        let links: Vec<ProofLink> = (last_verified_height + 1..=tip)
+            .into_par_iter()
+            .map(|height| self.proof_link(height))
+            .collect();
+        let boundary_linked = links.first()
+            .is_none_or(|link| link.previous_hash.as_deref() == Some(boundary_hash.as_str()));
+        let invalid_heights: Vec<u64> = link_chain_results(start, &boundary_hash, boundary_timestamp, links, self.clock_skew_tolerance)
+            .into_iter()
+            .filter(|(_, valid)| !valid)
+            .map(|(index, _)| index as u64 + 1)
+            .collect();
+
+        ChainVerificationReport {
+            from_height: last_verified_height,
+            tip_height: tip,
+            is_valid: boundary_linked && invalid_heights.is_empty(),
+            boundary_hash: Some(boundary_hash),
+            boundary_linked,
+            invalid_heights,
+        }
+    }
+
+    /// Load the proof at `height` and check its signature, keeping only what
+    /// the linkage pass needs so the whole chain is never held in memory
+    fn proof_link(&self, height: u64) -> ProofLink {
+        match self.proof_store.get_by_height(height) {
+            Some(proof) => {
+                let signature_valid = self.verify_proof_signature(&proof);
+                ProofLink::new(proof, signature_valid)
+            }
+            None => ProofLink::missing(),
+        }
+    }
+
+    /// Re-issue a proof of `proof_type` in the current format
+    ///
+    /// The proof must verify under its own format version; its id, hash, nonce,
+    /// timestamp and chain position are kept and the signature is recomputed over
+    /// the current layout, which also covers the chain position. Proofs from
+    /// before domain separation are placed in `proof_type`'s domain.
+    pub fn migrate_proof(&self, old: &CryptographicProof, proof_type: &str) -> Result<CryptographicProof, String> {
+        let domain = proof_domain(proof_type);
+        if old.signed_payload_in(&domain).is_none() {
+            return Err(format!(
+                "Proof {} with format version {} cannot be migrated as a {} proof",
+                old.id, old.format_version, proof_type
+            ));
+        }
+        if !self.verify_proof(old) {
+            return Err(format!("Proof {} failed verification under format v{}", old.id, old.format_version));
         }
 
-        true
+        let mut migrated = old.clone();
+        migrated.format_version = CURRENT_PROOF_FORMAT_VERSION;
+        migrated.key_id = Some(self.key_ring.active_key_id().to_string());
+        migrated.domain = Some(domain);
+        // The old co-signature covers the old payload, so it is replaced or dropped
+        let HybridSignature { classical, pq } = self.sign_hybrid(&migrated.signing_payload());
+        migrated.signature = classical;
+        migrated.pq_signature = pq;
+        Ok(migrated)
     }
 
-    /// Create evidence integrity record
-    fn verify_evidence_integrity(&mut self, evidence_data: &str, metadata: &str, actor_id: &str) -> EvidenceIntegrity {
+    /// Create evidence integrity record starting from the actor-signed `created` entry
+    ///
+    /// Fails unless `created` is a current-format `created` entry signed over the
+    /// evidence's content hash by its actor's registered custody key.
+    fn verify_evidence_integrity(
+        &mut self,
+        evidence_data: &str,
+        metadata: &str,
+        created: ChainOfCustodyEntry,
+    ) -> Result<EvidenceIntegrity, String> {
         let evidence_id = format!("evidence_{}", Uuid::new_v4());
         let content_hash = self.generate_secure_hash(evidence_data);
+        let signed_by_actor = created.format_version == CURRENT_CUSTODY_FORMAT_VERSION
+            && created.action == CUSTODY_ACTION_CREATED
+            && created.previous_entry_hash.is_none()
+            && DateTime::parse_from_rfc3339(&created.timestamp)
+                .is_ok_and(|signed_at| self.custody_signature_valid(&created, &content_hash, signed_at.with_timezone(&Utc), true));
+        if !signed_by_actor {
+            return Err(format!("Custody entry is not a created entry signed by {}'s registered key", created.actor));
+        }
+        let initial_custody = created;
+
         let metadata_hash = self.generate_secure_hash(metadata);
-        let original_hash = self.generate_secure_hash(&format!("{}{}", content_hash, metadata_hash));
+        let original_hash = self.generate_secure_hash(&encode_fields(&[&content_hash, &metadata_hash]));
 
         let cryptographic_proof = self.create_proof(&original_hash, "evidence");
 
-        let initial_custody = ChainOfCustodyEntry {
-            id: format!("custody_{}", Uuid::new_v4()),
-            actor: actor_id.to_string(),
-            action: "created".to_string(),
-            timestamp: Utc::now().to_rfc3339(),
-            cryptographic_signature: self.sign_data(&content_hash),
-            metadata: HashMap::new(),
-            ip_address: None,
-            user_agent: None,
-        };
-
         let initial_verification = VerificationEntry {
             id: format!("verify_{}", Uuid::new_v4()),
             verifier_id: "velocity_crypto_system".to_string(),
@@ -279,22 +1494,354 @@ impl VelocityCryptographicEngine {
             cryptographic_proof: cryptographic_proof.clone(),
             attestation: "Cryptographic integrity verified at creation".to_string(),
             confidence: 0.99,
+            supersedes: None,
+        };
+
+        let immutable_storage = ImmutableStorage {
+            ipfs_hash: Some(self.generate_mock_ipfs_hash(&content_hash)),
+            blockchain_tx_id: match &self.polygon_anchor {
+                Some(anchor) => anchor.anchor(&cryptographic_proof.hash).ok(),
+                None => Some(self.generate_mock_tx_id()),
+            },
+            storage_provider: "Velocity Distributed Storage Network".to_string(),
+            replication_factor: 3,
+            encryption: None,
         };
 
-        EvidenceIntegrity {
+        let mut integrity = EvidenceIntegrity {
             evidence_id,
             original_hash,
             content_hash,
             metadata_hash,
             cryptographic_proof,
             chain_of_custody: vec![initial_custody],
-            immutable_storage: ImmutableStorage {
-                ipfs_hash: Some(self.generate_mock_ipfs_hash(&content_hash)),
-                blockchain_tx_id: Some(self.generate_mock_tx_id()),
-                storage_provider: "Velocity Distributed Storage Network".to_string(),
-                replication_factor: 3,
-            },
+            immutable_storage,
             verification_history: vec![initial_verification],
+            aggregate_confidence: None,
+        };
+        integrity.aggregate_confidence = Some(self.aggregate_confidence(&integrity));
+        Ok(integrity)
+    }
+
+    /// Sign and append a verification by `verifier_id`, updating the aggregate confidence
+    ///
+    /// `result` is `passed` or `failed`. A verifier that already has a current entry
+    /// with a different result must use `supersede_verification` instead.
+    pub fn add_verification(
+        &mut self,
+        integrity: &mut EvidenceIntegrity,
+        verifier_id: &str,
+        verifier_type: &str,
+        result: &str,
+        attestation: &str,
+        confidence: f64,
+    ) -> Result<(), String> {
+        if let Some(contradicted) = integrity
+            .current_verifications()
+            .find(|entry| entry.verifier_id == verifier_id && entry.verification_result != result)
+        {
+            return Err(format!(
+                "{} already reported {} in {}; supersede it rather than contradict it",
+                verifier_id, contradicted.verification_result, contradicted.id
+            ));
+        }
+        self.append_verification(integrity, verifier_id, verifier_type, result, attestation, confidence, None)
+    }
+
+    /// Replace the current entry `superseded_id` with a new verification by the same verifier
+    pub fn supersede_verification(
+        &mut self,
+        integrity: &mut EvidenceIntegrity,
+        superseded_id: &str,
+        result: &str,
+        attestation: &str,
+        confidence: f64,
+    ) -> Result<(), String> {
+        let superseded = integrity
+            .current_verifications()
+            .find(|entry| entry.id == superseded_id)
+            .ok_or_else(|| format!("No current verification {} on {}", superseded_id, integrity.evidence_id))?;
+        let verifier_id = superseded.verifier_id.clone();
+        let verifier_type = superseded.verifier_type.clone();
+        self.append_verification(
+            integrity,
+            &verifier_id,
+            &verifier_type,
+            result,
+            attestation,
+            confidence,
+            Some(superseded_id.to_string()),
+        )
+    }
+
+    /// Validate, sign and append a verification entry
+    #[allow(clippy::too_many_arguments)]
+    fn append_verification(
+        &mut self,
+        integrity: &mut EvidenceIntegrity,
+        verifier_id: &str,
+        verifier_type: &str,
+        result: &str,
+        attestation: &str,
+        confidence: f64,
+        supersedes: Option<String>,
+    ) -> Result<(), String> {
+        if result != VERIFICATION_PASSED && result != VERIFICATION_FAILED {
+            return Err(format!("Unknown verification result {}", result));
+        }
+        if !(0.0..=1.0).contains(&confidence) {
+            return Err(format!("Confidence {} is outside 0.0-1.0", confidence));
+        }
+
+        let verification_data = encode_fields(&[
+            &integrity.evidence_id,
+            &integrity.original_hash,
+            verifier_id,
+            verifier_type,
+            result,
+            attestation,
+            &confidence.to_string(),
+            supersedes.as_deref().unwrap_or(""),
+        ]);
+        let cryptographic_proof = self.create_proof(&verification_data, "evidence_verification");
+
+        integrity.verification_history.push(VerificationEntry {
+            id: format!("verify_{}", Uuid::new_v4()),
+            verifier_id: verifier_id.to_string(),
+            verifier_type: verifier_type.to_string(),
+            verification_result: result.to_string(),
+            timestamp: cryptographic_proof.timestamp.clone(),
+            cryptographic_proof,
+            attestation: attestation.to_string(),
+            confidence,
+            supersedes,
+        });
+        integrity.aggregate_confidence = Some(self.aggregate_confidence(integrity));
+        Ok(())
+    }
+
+    /// Weighted confidence that the evidence is sound across current verifications
+    pub fn aggregate_confidence(&self, integrity: &EvidenceIntegrity) -> f64 {
+        self.aggregate_verification(integrity).confidence
+    }
+
+    /// Combine current verifications into an overall outcome
+    ///
+    /// A passed entry contributes its confidence and a failed one the complement,
+    /// weighted by `verifier_type_weight` and halved for every
+    /// `VERIFICATION_HALF_LIFE_DAYS` it predates the newest entry. Entries with
+    /// unparseable timestamps are ignored.
+    pub fn aggregate_verification(&self, integrity: &EvidenceIntegrity) -> AggregateVerification {
+        let dated: Vec<(&VerificationEntry, DateTime<Utc>)> = integrity
+            .current_verifications()
+            .filter_map(|entry| {
+                let at = DateTime::parse_from_rfc3339(&entry.timestamp).ok()?;
+                Some((entry, at.with_timezone(&Utc)))
+            })
+            .collect();
+        let newest = match dated.iter().map(|(_, at)| *at).max() {
+            Some(newest) => newest,
+            None => {
+                return AggregateVerification {
+                    confidence: 0.0,
+                    outcome: VERIFICATION_FAILED.to_string(),
+                    dissenting_verifiers: Vec::new(),
+                }
+            }
+        };
+
+        let (weighted_sum, total_weight) = dated.iter().fold((0.0, 0.0), |(sum, total), (entry, at)| {
+            let age_days = (newest - *at).num_seconds() as f64 / 86_400.0;
+            let weight = verifier_type_weight(&entry.verifier_type) * 0.5f64.powf(age_days / VERIFICATION_HALF_LIFE_DAYS);
+            let soundness = if entry.verification_result == VERIFICATION_FAILED {
+                1.0 - entry.confidence
+            } else {
+                entry.confidence
+            };
+            (sum + soundness * weight, total + weight)
+        });
+        let confidence = weighted_sum / total_weight;
+        let outcome = if confidence >= 0.5 { VERIFICATION_PASSED } else { VERIFICATION_FAILED };
+
+        let mut dissenting_verifiers: Vec<String> = Vec::new();
+        for (entry, _) in dated.iter().filter(|(entry, _)| entry.verification_result != outcome) {
+            if !dissenting_verifiers.contains(&entry.verifier_id) {
+                dissenting_verifiers.push(entry.verifier_id.clone());
+            }
+        }
+
+        AggregateVerification {
+            confidence,
+            outcome: outcome.to_string(),
+            dissenting_verifiers,
+        }
+    }
+
+    /// Append `entry`, a transfer signed by the recipient outside the engine
+    ///
+    /// See `ChainOfCustodyEntry::transfer`. Fails if the existing chain does not
+    /// verify, or if `entry` is not a transfer from the current custodian, linked
+    /// to its entry, carrying its release to the recipient and signed by the
+    /// recipient's registered key.
+    pub fn transfer_custody(&self, integrity: &mut EvidenceIntegrity, entry: ChainOfCustodyEntry) -> Result<(), String> {
+        if !self.verify_custody_chain(integrity) {
+            return Err(format!("Chain of custody for {} does not verify", integrity.evidence_id));
+        }
+        let custodian = integrity.chain_of_custody.last().map(|previous| previous.actor.clone());
+        let (from_actor, to_actor) = (entry.metadata.get("from").cloned(), entry.actor.clone());
+        if from_actor != custodian {
+            return Err(format!(
+                "{} is not the current custodian of {}",
+                from_actor.unwrap_or_default(),
+                integrity.evidence_id
+            ));
+        }
+
+        integrity.chain_of_custody.push(entry);
+        if !self.verify_custody_chain(integrity) {
+            integrity.chain_of_custody.pop();
+            return Err(format!(
+                "Transfer of {} to {} is not released by {} and signed by the recipient",
+                integrity.evidence_id,
+                to_actor,
+                from_actor.unwrap_or_default()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Register `evidence_data` for storage encrypted at rest
+    ///
+    /// Hashes, proof and custody chain cover the plaintext exactly as for
+    /// `create_evidence_integrity`, so integrity checks are unchanged. The payload
+    /// is encrypted under a fresh data key and the record keeps only that key,
+    /// wrapped with the engine's master key, and the ciphertext's content address.
+    /// Returns the record and the ciphertext to upload.
+    pub fn create_encrypted_evidence(
+        &mut self,
+        evidence_data: &str,
+        metadata: &str,
+        created: ChainOfCustodyEntry,
+    ) -> Result<(EvidenceIntegrity, Vec<u8>), String> {
+        let mut integrity = self.verify_evidence_integrity(evidence_data, metadata, created)?;
+        let aad = integrity.evidence_id.as_bytes();
+
+        let mut data_key = Zeroizing::new([0u8; 32]);
+        rand::RngCore::fill_bytes(&mut OsRng, &mut *data_key);
+        let ciphertext = aes_gcm_seal(&data_key, evidence_data.as_bytes(), aad)?;
+        let wrapped_data_key = aes_gcm_seal(&self.encryption_key, &*data_key, aad)?;
+
+        integrity.immutable_storage.ipfs_hash = Some(self.ciphertext_address(&ciphertext));
+        integrity.immutable_storage.encryption = Some(EvidenceEnvelope {
+            algorithm: EVIDENCE_ENCRYPTION_ALGORITHM.to_string(),
+            wrapped_data_key: hex::encode(wrapped_data_key),
+        });
+        Ok((integrity, ciphertext))
+    }
+
+    /// Plaintext of encrypted evidence, for holders of the master key that wrapped its data key
+    ///
+    /// Fails unless `ciphertext` is the content the record addresses and the
+    /// decrypted payload matches the record's content hash.
+    pub fn decrypt_evidence(&self, integrity: &EvidenceIntegrity, ciphertext: &[u8]) -> Result<String, String> {
+        let envelope = integrity.immutable_storage.encryption.as_ref()
+            .ok_or_else(|| format!("Evidence {} is not encrypted", integrity.evidence_id))?;
+        if envelope.algorithm != EVIDENCE_ENCRYPTION_ALGORITHM {
+            return Err(format!("Unsupported evidence encryption {}", envelope.algorithm));
+        }
+        if integrity.immutable_storage.ipfs_hash.as_deref() != Some(self.ciphertext_address(ciphertext).as_str()) {
+            return Err("Ciphertext does not match the stored content address".to_string());
+        }
+
+        let aad = integrity.evidence_id.as_bytes();
+        let wrapped_data_key = hex::decode(&envelope.wrapped_data_key)
+            .map_err(|_| "Wrapped data key is not hex".to_string())?;
+        let data_key = Zeroizing::new(aes_gcm_open(&self.encryption_key, &wrapped_data_key, aad)?);
+        let data_key: &[u8; 32] = data_key.as_slice().try_into()
+            .map_err(|_| "Wrapped data key has the wrong length".to_string())?;
+        let plaintext = String::from_utf8(aes_gcm_open(data_key, ciphertext, aad)?)
+            .map_err(|_| "Decrypted evidence is not UTF-8".to_string())?;
+
+        if !ct_eq(self.generate_secure_hash(&plaintext).as_bytes(), integrity.content_hash.as_bytes()) {
+            return Err("Decrypted evidence does not match its content hash".to_string());
+        }
+        Ok(plaintext)
+    }
+
+    /// Content address of stored ciphertext
+    fn ciphertext_address(&self, ciphertext: &[u8]) -> String {
+        self.generate_mock_ipfs_hash(&hex::encode(Sha256::digest(ciphertext)))
+    }
+
+    /// Check that the chain of custody starts with a `created` entry, that every
+    /// later entry is a transfer from the preceding custodian, linked to the entry
+    /// before it and no earlier than it, released by the preceding custodian, and
+    /// that every entry is signed by its actor
+    pub fn verify_custody_chain(&self, integrity: &EvidenceIntegrity) -> bool {
+        let mut previous: Option<(&ChainOfCustodyEntry, DateTime<Utc>)> = None;
+        for entry in &integrity.chain_of_custody {
+            let signed_at = match DateTime::parse_from_rfc3339(&entry.timestamp) {
+                Ok(timestamp) => timestamp.with_timezone(&Utc),
+                Err(_) => return false,
+            };
+
+            let in_order = match previous {
+                None => entry.action == CUSTODY_ACTION_CREATED && entry.previous_entry_hash.is_none(),
+                Some((previous, previous_at)) => {
+                    let link = previous.entry_hash(&integrity.content_hash);
+                    entry.action == CUSTODY_ACTION_TRANSFERRED
+                        && entry.metadata.get("from") == Some(&previous.actor)
+                        && entry.previous_entry_hash.as_deref() == Some(link.as_str())
+                        && signed_at >= previous_at
+                        && self.custody_release_valid(entry, &integrity.content_hash, &link, &previous.actor)
+                }
+            };
+            if !in_order {
+                return false;
+            }
+
+            if !self.custody_signature_valid(entry, &integrity.content_hash, signed_at, previous.is_none()) {
+                return false;
+            }
+
+            previous = Some((entry, signed_at));
+        }
+        previous.is_some()
+    }
+
+    /// Whether `entry` is validly signed under its `format_version`
+    ///
+    /// v2 entries must be signed by their actor's registered key. v1 entries, issued
+    /// before actors signed their own entries, are only accepted as the first entry
+    /// of a chain, signed by the engine over the bare content hash exactly as
+    /// those builds signed it.
+    fn custody_signature_valid(&self, entry: &ChainOfCustodyEntry, content_hash: &str, signed_at: DateTime<Utc>, first: bool) -> bool {
+        let signature = &entry.cryptographic_signature;
+        match entry.format_version {
+            CUSTODY_FORMAT_V1 => {
+                first
+                    && entry.action == CUSTODY_ACTION_CREATED
+                    && match self.key_ring.verifying_key(None, signed_at) {
+                        Some(public_key) => self.verify_signature(public_key, signature, content_hash),
+                        None => false,
+                    }
+            }
+            CUSTODY_FORMAT_V2 => match self.custody_public_key(&entry.actor) {
+                Some(public_key) => self.verify_signature(public_key, signature, &entry.signing_payload(content_hash)),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether `entry` carries `from_actor`'s release of the entry hashing to `link`
+    fn custody_release_valid(&self, entry: &ChainOfCustodyEntry, content_hash: &str, link: &str, from_actor: &str) -> bool {
+        match (&entry.release_signature, self.custody_public_key(from_actor)) {
+            (Some(release_signature), Some(public_key)) => {
+                let payload = ChainOfCustodyEntry::release_payload(content_hash, link, from_actor, &entry.actor);
+                self.verify_signature(public_key, release_signature, &payload)
+            }
+            _ => false,
         }
     }
 
@@ -302,7 +1849,12 @@ impl VelocityCryptographicEngine {
     fn create_trust_score_proof(&mut self, organization_id: &str, trust_score: f64, input_data: &str) -> TrustScoreProof {
         let input_data_hash = self.generate_secure_hash(input_data);
         let algorithm_hash = self.generate_secure_hash("velocity_trust_algorithm_v1");
-        let calculation_data = format!("{}{}{}{}", trust_score, input_data_hash, algorithm_hash, Utc::now().to_rfc3339());
+        let calculation_data = encode_fields(&[
+            &trust_score.to_string(),
+            &input_data_hash,
+            &algorithm_hash,
+            &Utc::now().to_rfc3339(),
+        ]);
         let calculation_hash = self.generate_secure_hash(&calculation_data);
 
         let cryptographic_proof = self.create_proof(&calculation_hash, "trust_score");
@@ -329,10 +1881,10 @@ impl VelocityCryptographicEngine {
         let prompt_hash = self.generate_secure_hash(prompt);
         let response_hash = self.generate_secure_hash(response);
 
-        let decision_data = format!("{}{}{}{}{}", decision_id, model_hash, prompt_hash, response_hash, confidence);
+        let decision_data = encode_fields(&[decision_id, &model_hash, &prompt_hash, &response_hash, &confidence.to_string()]);
         let cryptographic_proof = self.create_proof(&decision_data, "ai_decision");
 
-        let review_data = format!("{}{}{}", decision_id, reviewer_id, Utc::now().to_rfc3339());
+        let review_data = encode_fields(&[decision_id, reviewer_id, &Utc::now().to_rfc3339()]);
         let review_hash = self.generate_secure_hash(&review_data);
 
         AIDecisionProof {
@@ -345,8 +1897,9 @@ impl VelocityCryptographicEngine {
             human_oversight: HumanOversight {
                 reviewer_id: reviewer_id.to_string(),
                 review_hash,
-                approval_signature: self.sign_data(&review_hash),
+                approval_signature: self.sign_data(&domain_separated(DOMAIN_HUMAN_REVIEW, &review_hash)),
                 timestamp: Utc::now().to_rfc3339(),
+                key_id: Some(self.key_ring.active_key_id().to_string()),
             },
             audit_trail: vec![
                 format!("Decision created: {}", Utc::now().to_rfc3339()),
@@ -390,34 +1943,38 @@ impl VelocityCryptographicEngine {
         hex::encode(hasher.finalize())
     }
 
+    /// Sign a proof assembled outside the engine with the active ring key
+    ///
+    /// `key_id` is set first, since the current payload covers it.
+    fn sign_proof(&self, proof: &mut CryptographicProof) {
+        proof.key_id = Some(self.key_ring.active_key_id().to_string());
+        proof.signature = self.sign_data(&proof.signing_payload());
+    }
+
     /// Sign data with private key
     fn sign_data(&self, data: &str) -> String {
-        let signature = self.signing_keypair.sign(data.as_bytes());
+        let signature = self.key_ring.sign(data.as_bytes());
         hex::encode(signature.to_bytes())
     }
 
     /// Verify signature
-    fn verify_signature(&self, signature_hex: &str, data: &str) -> bool {
-        match hex::decode(signature_hex) {
-            Ok(signature_bytes) => {
-                if let Ok(signature) = Signature::from_bytes(&signature_bytes) {
-                    self.signing_keypair.public.verify(data.as_bytes(), &signature).is_ok()
-                } else {
-                    false
-                }
-            }
-            Err(_) => false,
-        }
+    fn verify_signature(&self, public_key: &PublicKey, signature_hex: &str, data: &str) -> bool {
+        self.metrics.signature_verification.time(
+            || verify_hex_signature(public_key, signature_hex, data),
+            |valid| *valid,
+        )
     }
 
     /// Verify hash format
     fn verify_hash_format(&self, hash: &str) -> bool {
-        hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())
+        is_proof_hash(hash)
     }
 
     /// Verify chain integrity
     fn verify_chain_integrity(&self, proof: &CryptographicProof) -> bool {
-        self.proof_chain.iter().any(|p| p.id == proof.id)
+        let starts_own_chain = proof.genesis_link_consistent(&self.genesis_hash)
+            && (proof.block_height != 1 || proof.genesis_anchor == self.genesis_anchor);
+        starts_own_chain && self.proof_store.get_by_id(&proof.id).is_some()
     }
 
     /// Calculate Merkle root
@@ -494,15 +2051,16 @@ impl VelocityCryptographicEngine {
 
     /// Calculate verification rate
     fn calculate_verification_rate(&self) -> f64 {
-        if self.proof_chain.is_empty() {
+        if self.proof_store.is_empty() {
             return 0.0;
         }
 
-        let verified_count = self.proof_chain.iter()
+        let verified_count = (1..=self.proof_store.len())
+            .filter_map(|height| self.proof_store.get_by_height(height))
             .filter(|p| p.verification_status == "verified")
             .count();
 
-        verified_count as f64 / self.proof_chain.len() as f64
+        verified_count as f64 / self.proof_store.len() as f64
     }
 
     /// Calculate integrity score
@@ -588,4 +2146,1284 @@ pub struct InitializationStatus {
 #[wasm_bindgen(start)]
 pub fn main() {
     console_error_panic_hook::set_once();
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    /// Rewrite the engine's stored chain in place, as an attacker with write
+    /// access to the store could
+    fn tamper_chain(engine: &mut VelocityCryptographicEngine, edit: impl FnOnce(&mut Vec<CryptographicProof>)) {
+        let mut chain: Vec<CryptographicProof> = (1..=engine.proof_store().len())
+            .map(|height| engine.proof_store().get_by_height(height).unwrap())
+            .collect();
+        edit(&mut chain);
+        engine.set_proof_store(Box::new(MemoryProofStore::from(chain)));
+    }
+
+    fn proof_at(id: &str, nonce: &str, timestamp: DateTime<Utc>) -> CryptographicProof {
+        CryptographicProof {
+            format_version: CURRENT_PROOF_FORMAT_VERSION,
+            id: id.to_string(),
+            hash: "a".repeat(64),
+            signature: String::new(),
+            timestamp: timestamp.to_rfc3339(),
+            previous_hash: None,
+            merkle_root: None,
+            block_height: 1,
+            verification_status: "verified".to_string(),
+            nonce: nonce.to_string(),
+            key_id: None,
+            domain: Some(DOMAIN_EVIDENCE.to_string()),
+            timestamp_token: None,
+            pq_signature: None,
+            genesis_anchor: None,
+        }
+    }
+
+    /// `proof` re-signed the way engines issued proofs before format v2
+    fn as_v1_proof(engine: &VelocityCryptographicEngine, proof: &CryptographicProof) -> CryptographicProof {
+        let mut legacy = proof.clone();
+        legacy.format_version = PROOF_FORMAT_V1;
+        legacy.domain = None;
+        legacy.signature = engine.sign_data(&legacy.signed_payload().unwrap());
+        legacy
+    }
+
+    /// `proof` re-signed the way engines issued proofs before format v3
+    fn as_v2_proof(engine: &VelocityCryptographicEngine, proof: &CryptographicProof) -> CryptographicProof {
+        let mut legacy = proof.clone();
+        legacy.format_version = PROOF_FORMAT_V2;
+        legacy.signature = engine.sign_data(&legacy.signed_payload().unwrap());
+        legacy
+    }
+
+    #[test]
+    fn test_proof_verified_twice_is_rejected() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let proof_json = engine.generate_cryptographic_proof("evidence payload", "evidence");
+
+        assert!(engine.verify_cryptographic_proof(&proof_json));
+        assert!(!engine.verify_cryptographic_proof(&proof_json));
+    }
+
+    #[test]
+    fn test_concurrent_verifiers_share_replay_guard() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let proof_json = engine.generate_cryptographic_proof("evidence payload", "evidence");
+
+        // Verification only borrows the engine, so threads can race on one proof;
+        // exactly one of them accepts it
+        let engine = &engine;
+        let accepted = std::thread::scope(|scope| {
+            let verifiers: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| engine.verify_cryptographic_proof(&proof_json)))
+                .collect();
+            verifiers.into_iter().map(|verifier| verifier.join().unwrap()).filter(|accepted| *accepted).count()
+        });
+        assert_eq!(accepted, 1);
+    }
+
+    #[test]
+    fn test_nonce_is_covered_by_signature() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let proof_json = engine.generate_cryptographic_proof("evidence payload", "evidence");
+
+        // Swapping the nonce to slip past the replay guard breaks the signature
+        let mut proof: CryptographicProof = serde_json::from_str(&proof_json).unwrap();
+        proof.nonce = CryptographicProof::generate_nonce();
+        let tampered = serde_json::to_string(&proof).unwrap();
+
+        assert!(engine.verify_cryptographic_proof(&proof_json));
+        assert!(!engine.verify_cryptographic_proof(&tampered));
+    }
+
+    #[test]
+    fn test_unversioned_v1_proof_still_verifies() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let proof_json = engine.generate_cryptographic_proof("evidence payload", "evidence");
+        let proof = as_v1_proof(&engine, &serde_json::from_str(&proof_json).unwrap());
+
+        // Proofs issued before format versioning have no `format_version` field
+        let mut legacy = serde_json::to_value(&proof).unwrap();
+        legacy.as_object_mut().unwrap().remove("format_version");
+        legacy.as_object_mut().unwrap().remove("domain");
+        let legacy_json = legacy.to_string();
+
+        let parsed: CryptographicProof = serde_json::from_str(&legacy_json).unwrap();
+        assert_eq!(parsed.format_version, PROOF_FORMAT_V1);
+        assert!(engine.verify_cryptographic_proof(&legacy_json));
+    }
+
+    #[test]
+    fn test_unknown_format_version_is_rejected() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let proof_json = engine.generate_cryptographic_proof("evidence payload", "evidence");
+        let mut proof: CryptographicProof = serde_json::from_str(&proof_json).unwrap();
+        proof.format_version = CURRENT_PROOF_FORMAT_VERSION + 1;
+
+        assert!(proof.signed_payload().is_none());
+        assert!(!engine.verify_cryptographic_proof(&serde_json::to_string(&proof).unwrap()));
+        assert!(engine.migrate_proof(&proof, "evidence").is_err());
+    }
+
+    #[test]
+    fn test_migrate_proof_to_current_version() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let proof_json = engine.generate_cryptographic_proof("evidence payload", "evidence");
+        let current: CryptographicProof = serde_json::from_str(&proof_json).unwrap();
+        let old = as_v1_proof(&engine, &current);
+
+        let migrated = engine.migrate_proof(&old, "evidence").unwrap();
+        assert_eq!(migrated.format_version, CURRENT_PROOF_FORMAT_VERSION);
+        assert_eq!(migrated.domain.as_deref(), Some(DOMAIN_EVIDENCE));
+        assert_eq!(migrated.id, old.id);
+        assert_eq!(migrated.hash, old.hash);
+        assert!(engine.verify_cryptographic_proof(&serde_json::to_string(&migrated).unwrap()));
+
+        // A forged proof cannot be laundered through migration
+        let mut forged = old.clone();
+        forged.hash = "b".repeat(64);
+        assert!(engine.migrate_proof(&forged, "evidence").is_err());
+
+        // Nor can a current proof be moved into another domain
+        assert!(engine.migrate_proof(&current, "trust_score").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "pq")]
+    fn test_migrated_proof_is_co_signed_over_new_payload() {
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.set_pq_keypair(Some(MlDsa65Keypair::generate()));
+        engine.set_hybrid_policy(HybridPolicy::Both);
+        let current: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        let mut old = as_v1_proof(&engine, &current);
+        old.pq_signature = engine.pq_sign(&old.signed_payload().unwrap());
+        assert!(engine.verify_proof(&old));
+
+        let migrated = engine.migrate_proof(&old, "evidence").unwrap();
+        assert_ne!(migrated.pq_signature, old.pq_signature);
+        assert!(engine.verify_proof(&migrated));
+        assert_eq!(engine.verify_proof_signatures(&migrated).verified_with, Some(SignatureScheme::Hybrid));
+
+        // Without a post-quantum key the stale co-signature is dropped, not carried over
+        engine.set_pq_keypair(None);
+        engine.set_hybrid_policy(HybridPolicy::Either);
+        let migrated = engine.migrate_proof(&old, "evidence").unwrap();
+        assert!(migrated.pq_signature.is_none());
+        assert!(engine.verify_proof(&migrated));
+    }
+
+    #[test]
+    fn test_signature_does_not_verify_in_another_domain() {
+        assert_eq!(proof_domain("evidence"), DOMAIN_EVIDENCE);
+        assert_eq!(proof_domain("trust_score"), DOMAIN_TRUST_SCORE);
+        assert_eq!(proof_domain("ai_decision"), DOMAIN_AI_DECISION);
+
+        let mut engine = VelocityCryptographicEngine::new();
+        let proof_json = engine.generate_cryptographic_proof("shared payload", "ai_decision");
+        let proof: CryptographicProof = serde_json::from_str(&proof_json).unwrap();
+        assert_eq!(proof.domain.as_deref(), Some(DOMAIN_AI_DECISION));
+        assert!(proof.signed_payload_in(DOMAIN_AI_DECISION).is_some());
+        assert!(proof.signed_payload_in(DOMAIN_TRUST_SCORE).is_none());
+
+        // Relabelling an AI decision proof as a trust score proof breaks its signature
+        let mut relabelled = proof.clone();
+        relabelled.domain = Some(DOMAIN_TRUST_SCORE.to_string());
+        assert!(!engine.verify_cryptographic_proof(&serde_json::to_string(&relabelled).unwrap()));
+
+        // The same bytes signed without a domain do not verify in one either
+        let mut untagged = proof.clone();
+        untagged.signature = engine.sign_data(&CryptographicProof::v1_signing_payload(
+            &proof.id,
+            &proof.hash,
+            &proof.nonce,
+            &proof.timestamp,
+        ));
+        assert!(!engine.verify_cryptographic_proof(&serde_json::to_string(&untagged).unwrap()));
+
+        assert!(engine.verify_cryptographic_proof(&proof_json));
+    }
+
+    #[test]
+    fn test_content_addressed_ids() {
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.set_content_addressed_ids(true);
+
+        let first: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        let again: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        assert_eq!(first.id, again.id);
+        assert_eq!(first.id, CryptographicProof::content_addressed_id("evidence", &first.hash));
+        // The duplicate is returned rather than chained a second time
+        assert_eq!(again.block_height, first.block_height);
+        assert_eq!(engine.proof_store().len(), 1);
+
+        let other_data: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("other payload", "evidence")).unwrap();
+        let other_type: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence payload", "credential")).unwrap();
+        assert_ne!(other_data.id, first.id);
+        assert_ne!(other_type.id, first.id);
+        assert_eq!(engine.proof_store().len(), 3);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_content_addressed_ids_deduplicate_across_reopened_store() {
+        let path = std::env::temp_dir().join(format!("velocity-proofs-{}.jsonl", Uuid::new_v4()));
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.set_content_addressed_ids(true);
+        engine.set_proof_store(Box::new(FileProofStore::open(&path).unwrap()));
+        let first: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        engine.generate_cryptographic_proof("other payload", "evidence");
+
+        // The rebuilt id index finds the duplicate without the chain being held in memory
+        engine.set_proof_store(Box::new(FileProofStore::open(&path).unwrap()));
+        let again: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        assert_eq!(again.id, first.id);
+        assert_eq!(again.block_height, 1);
+        assert_eq!(engine.proof_store().len(), 2);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_random_ids_by_default() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let first: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        let second: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+
+        assert_ne!(first.id, second.id);
+        assert!(Uuid::parse_str(first.id.trim_start_matches("proof_")).is_ok());
+    }
+
+    #[test]
+    fn test_genesis_anchor_distinguishes_chains() {
+        let mut acme = VelocityCryptographicEngine::with_genesis("org_acme/mainnet");
+        let mut globex = VelocityCryptographicEngine::with_genesis("org_globex/mainnet");
+        let acme_first: CryptographicProof =
+            serde_json::from_str(&acme.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        let acme_second: CryptographicProof =
+            serde_json::from_str(&acme.generate_cryptographic_proof("other payload", "evidence")).unwrap();
+        let globex_first: CryptographicProof =
+            serde_json::from_str(&globex.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+
+        assert_eq!(acme.genesis_hash(), genesis_hash_for(Some("org_acme/mainnet")));
+        assert_eq!(acme_first.previous_hash.as_deref(), Some(acme.genesis_hash()));
+        assert_ne!(acme_first.previous_hash, globex_first.previous_hash);
+        assert_ne!(acme.genesis_hash(), GENESIS_PROOF_HASH);
+        assert_eq!(acme_first.genesis_anchor.as_deref(), Some("org_acme/mainnet"));
+        assert_eq!(globex_first.genesis_anchor.as_deref(), Some("org_globex/mainnet"));
+        assert!(acme_second.genesis_anchor.is_none());
+        assert!(acme.verify_chain_from(0).is_valid);
+
+        // Engines without an anchor keep the shared zero genesis
+        let mut plain = VelocityCryptographicEngine::new();
+        let plain_first: CryptographicProof =
+            serde_json::from_str(&plain.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        assert_eq!(plain_first.previous_hash.as_deref(), Some(GENESIS_PROOF_HASH));
+        assert!(plain_first.genesis_anchor.is_none());
+    }
+
+    #[test]
+    fn test_cross_chain_genesis_linkage_is_rejected() {
+        let mut acme = VelocityCryptographicEngine::with_genesis("org_acme/mainnet");
+        let first: CryptographicProof =
+            serde_json::from_str(&acme.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        acme.generate_cryptographic_proof("other payload", "evidence");
+        assert!(acme.verify_proof(&first));
+
+        // The same signed proof relinked to another chain's genesis
+        let mut relinked = first.clone();
+        relinked.previous_hash = Some(genesis_hash_for(Some("org_globex/mainnet")));
+        relinked.genesis_anchor = Some("org_globex/mainnet".to_string());
+        assert!(relinked.genesis_anchor_consistent());
+        assert!(!acme.verify_proof(&relinked));
+
+        // A relabelled anchor no longer derives the genesis it links from
+        let mut relabelled = first.clone();
+        relabelled.genesis_anchor = Some("org_globex/mainnet".to_string());
+        assert!(!relabelled.genesis_anchor_consistent());
+        assert!(!acme.verify_proof(&relabelled));
+
+        // Nor can the first proof pose as a later one to skip the genesis check
+        let mut moved = first.clone();
+        moved.block_height = 2;
+        assert!(!moved.genesis_link_consistent(&genesis_hash_for(Some("org_acme/mainnet"))));
+        assert!(!acme.verify_proof(&moved));
+
+        // A store holding another chain does not link from this engine's genesis
+        let mut chain: Vec<CryptographicProof> = (1..=acme.proof_store().len())
+            .filter_map(|height| acme.proof_store().get_by_height(height))
+            .collect();
+        chain[0] = relabelled;
+        acme.set_proof_store(Box::new(MemoryProofStore::from(chain)));
+        assert_eq!(acme.verify_all_signatures(), vec![(0, false), (1, true)]);
+    }
+
+    #[test]
+    fn test_verification_metrics() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let proofs: Vec<String> = (0..25)
+            .map(|i| engine.generate_cryptographic_proof(&format!("evidence {}", i), "evidence"))
+            .collect();
+
+        for proof_json in &proofs {
+            assert!(engine.verify_cryptographic_proof(proof_json));
+        }
+        // Replays fail after a successful signature check; garbage fails before one
+        for proof_json in &proofs[..5] {
+            assert!(!engine.verify_cryptographic_proof(proof_json));
+        }
+        assert!(!engine.verify_cryptographic_proof("not a proof"));
+
+        let snapshot = engine.metrics().snapshot();
+        assert_eq!(snapshot.proof_generation.count, 25);
+        assert_eq!(snapshot.proof_verification.count, 31);
+        assert_eq!(snapshot.proof_verification.failures, 6);
+        assert_eq!(snapshot.proof_verification.samples, 31);
+        assert_eq!(snapshot.signature_verification.count, 30);
+        assert_eq!(snapshot.signature_verification.failures, 0);
+        assert!(snapshot.proof_verification.p50_us <= snapshot.proof_verification.p99_us);
+        assert!(snapshot.proof_verification.p99_us <= snapshot.proof_verification.max_us);
+
+        let report: serde_json::Value = serde_json::from_str(&engine.get_metrics_snapshot()).unwrap();
+        assert_eq!(report["proof_verification"]["count"], 31);
+    }
+
+    #[test]
+    fn test_parallel_chain_verification_matches_sequential() {
+        let mut engine = VelocityCryptographicEngine::new();
+        for i in 0..10_000 {
+            engine.create_proof(&format!("evidence {}", i), "evidence");
+        }
+
+        let parallel = engine.verify_all_signatures_parallel();
+        assert_eq!(parallel.len(), 10_000);
+        assert!(parallel.iter().enumerate().all(|(i, &(index, valid))| index == i && valid));
+        assert_eq!(parallel, engine.verify_all_signatures());
+
+        tamper_chain(&mut engine, |chain| {
+            // A bad signature fails only its own proof
+            chain[100].signature = chain[101].signature.clone();
+            // A rewritten hash breaks its own signature and the next proof's linkage
+            chain[5_000].hash = "a".repeat(64);
+            // A rewritten back-link fails only that proof
+            chain[9_999].previous_hash = Some(GENESIS_PROOF_HASH.to_string());
+        });
+
+        let parallel = engine.verify_all_signatures_parallel();
+        assert_eq!(parallel, engine.verify_all_signatures());
+        let invalid: Vec<usize> = parallel
+            .iter()
+            .filter(|(_, valid)| !valid)
+            .map(|(index, _)| *index)
+            .collect();
+        assert_eq!(invalid, vec![100, 5_000, 5_001, 9_999]);
+    }
+
+    #[test]
+    fn test_incremental_chain_verification_matches_full() {
+        let mut engine = VelocityCryptographicEngine::new();
+        for i in 0..50 {
+            engine.create_proof(&format!("evidence {}", i), "evidence");
+        }
+        let checkpoint_hash = engine.proof_store().get_by_height(20).unwrap().hash;
+
+        let report = engine.verify_chain_from(20);
+        assert!(report.is_valid);
+        assert_eq!(report.tip_height, 50);
+        assert_eq!(report.boundary_hash.as_deref(), Some(checkpoint_hash.as_str()));
+        assert!(engine.verify_chain_from(0).is_valid);
+        assert!(engine.verify_chain_from(50).is_valid);
+
+        tamper_chain(&mut engine, |chain| {
+            chain[34].signature = chain[35].signature.clone();
+            chain[10].signature = chain[11].signature.clone();
+        });
+        let full_invalid: Vec<u64> = engine.verify_all_signatures()
+            .into_iter()
+            .filter(|(_, valid)| !valid)
+            .map(|(index, _)| index as u64 + 1)
+            .collect();
+        assert_eq!(full_invalid, vec![11, 35]);
+        let report = engine.verify_chain_from(20);
+        assert!(!report.is_valid);
+        assert!(report.boundary_linked);
+        assert_eq!(report.invalid_heights, vec![35]);
+
+        // The chain cannot have shrunk below a height the client already verified
+        let report = engine.verify_chain_from(51);
+        assert!(!report.is_valid);
+        assert_eq!(report.boundary_hash, None);
+    }
+
+    #[test]
+    fn test_incremental_verification_detects_reorg_at_boundary() {
+        let mut engine = VelocityCryptographicEngine::new();
+        for i in 0..30 {
+            engine.create_proof(&format!("evidence {}", i), "evidence");
+        }
+        let checkpoint_hash = engine.proof_store().get_by_height(20).unwrap().hash;
+
+        // The proof at the client's checkpoint is swapped for one with other content
+        let conflicting_hash = engine.generate_secure_hash("conflicting evidence");
+        tamper_chain(&mut engine, |chain| chain[19].hash = conflicting_hash);
+
+        let report = engine.verify_chain_from(20);
+        assert!(!report.is_valid);
+        assert!(!report.boundary_linked);
+        assert_eq!(report.invalid_heights, vec![21]);
+        assert_ne!(report.boundary_hash.as_deref(), Some(checkpoint_hash.as_str()));
+    }
+
+    #[test]
+    fn test_deleted_proof_with_rewritten_links_is_detected() {
+        let mut engine = VelocityCryptographicEngine::new();
+        for i in 0..30 {
+            engine.create_proof(&format!("evidence {}", i), "evidence");
+        }
+
+        // Proof 25 is deleted and every later proof renumbered, with proof 26
+        // relinked to 24, so the hash links and heights line up again
+        tamper_chain(&mut engine, |chain| {
+            let deleted = chain.remove(24);
+            chain[24].previous_hash = deleted.previous_hash;
+            for (index, proof) in chain.iter_mut().enumerate().skip(24) {
+                proof.block_height = index as u64 + 1;
+            }
+        });
+        let chain: Vec<CryptographicProof> = (1..=engine.proof_store().len())
+            .map(|height| engine.proof_store().get_by_height(height).unwrap())
+            .collect();
+
+        // The link fields are signed, so every rewritten proof fails
+        let invalid: Vec<usize> = engine.verify_all_signatures()
+            .into_iter()
+            .filter(|(_, valid)| !valid)
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(invalid, (24..29).collect::<Vec<_>>());
+
+        let report = engine.verify_chain_from(20);
+        assert!(!report.is_valid);
+        assert!(report.boundary_linked);
+        assert_eq!(report.invalid_heights, vec![25, 26, 27, 28, 29]);
+
+        let verifier = VerifierEngine::new(engine.key_ring().verifying_keys().clone());
+        let verified = verifier.verify_chain(&chain);
+        assert!(verified[..24].iter().all(|(_, valid)| *valid));
+        assert!(verified[24..].iter().all(|(_, valid)| !valid));
+
+        // Proofs issued before the links were signed still verify on their own
+        let legacy = as_v2_proof(&engine, &engine.proof_store().get_by_height(10).unwrap());
+        assert!(engine.verify_proof_signatures(&legacy).is_valid);
+        let mut relinked = legacy.clone();
+        relinked.block_height = 11;
+        assert!(engine.verify_proof_signatures(&relinked).is_valid);
+        let mut current = engine.proof_store().get_by_height(10).unwrap();
+        current.block_height = 11;
+        assert!(!engine.verify_proof_signatures(&current).is_valid);
+    }
+
+    #[test]
+    fn test_file_backed_chain_verifies_and_persists() {
+        let path = std::env::temp_dir().join(format!("velocity-engine-{}.jsonl", Uuid::new_v4()));
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.set_proof_store(Box::new(FileProofStore::open(&path).unwrap()));
+        let proofs: Vec<String> = (0..20)
+            .map(|i| engine.generate_cryptographic_proof(&format!("evidence {}", i), "evidence"))
+            .collect();
+        assert!(engine.verify_all_signatures().iter().all(|(_, valid)| *valid));
+        assert!(engine.verify_chain_from(10).is_valid);
+        assert!(engine.verify_cryptographic_proof(&proofs[7]));
+
+        // The same engine picks the chain back up from disk and keeps extending it
+        engine.set_proof_store(Box::new(FileProofStore::open(&path).unwrap()));
+        assert_eq!(engine.proof_store().len(), 20);
+        let next: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence 20", "evidence")).unwrap();
+        assert_eq!(next.block_height, 21);
+        assert_eq!(next.previous_hash, engine.proof_store().get_by_height(20).map(|proof| proof.hash));
+        assert!(engine.verify_all_signatures_parallel().iter().all(|(_, valid)| *valid));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_backdated_proof_is_rejected() {
+        let start = DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z").unwrap().with_timezone(&Utc);
+        let clock = Arc::new(MockClock::new(start));
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.set_clock(clock.clone());
+        engine.generate_cryptographic_proof("evidence 0", "evidence");
+
+        clock.set(start - chrono::Duration::seconds(DEFAULT_CLOCK_SKEW_TOLERANCE_SECONDS + 1));
+        let backdated_json = engine.generate_cryptographic_proof("evidence 1", "evidence");
+        let backdated: CryptographicProof = serde_json::from_str(&backdated_json).unwrap();
+        assert_eq!(backdated.verification_status, "rejected");
+        assert_eq!(engine.proof_store().len(), 1);
+        assert!(!engine.verify_cryptographic_proof(&backdated_json));
+
+        // The chain carries on from the last accepted proof once the clock recovers
+        clock.set(start + chrono::Duration::seconds(1));
+        let next: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence 2", "evidence")).unwrap();
+        assert_eq!(next.verification_status, "verified");
+        assert_eq!(next.block_height, 2);
+        assert!(engine.verify_all_signatures().iter().all(|(_, valid)| *valid));
+
+        assert!(engine.set_clock_skew_tolerance(chrono::Duration::seconds(-1)).is_err());
+    }
+
+    #[test]
+    fn test_timestamp_within_skew_tolerance_is_accepted() {
+        let start = DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z").unwrap().with_timezone(&Utc);
+        let clock = Arc::new(MockClock::new(start));
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.set_clock(clock.clone());
+        for i in 0..3 {
+            engine.generate_cryptographic_proof(&format!("evidence {}", i), "evidence");
+            clock.advance(chrono::Duration::seconds(10));
+        }
+
+        clock.set(start + chrono::Duration::seconds(17));
+        let skewed_json = engine.generate_cryptographic_proof("evidence 3", "evidence");
+        assert!(engine.verify_cryptographic_proof(&skewed_json));
+        assert_eq!(engine.proof_store().len(), 4);
+        assert!(engine.verify_all_signatures_parallel().iter().all(|(_, valid)| *valid));
+        assert!(engine.verify_chain_from(3).is_valid);
+
+        // Without tolerance the same chain fails at the regressing proof only
+        engine.set_clock_skew_tolerance(chrono::Duration::zero()).unwrap();
+        let invalid: Vec<usize> = engine.verify_all_signatures()
+            .into_iter()
+            .filter(|(_, valid)| !valid)
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(invalid, vec![3]);
+        assert_eq!(engine.verify_chain_from(3).invalid_heights, vec![4]);
+        assert!(engine.verify_chain_from(4).is_valid);
+    }
+
+    #[test]
+    #[cfg(feature = "pq")]
+    fn test_pq_co_signed_proof_verifies() {
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.set_pq_keypair(Some(MlDsa65Keypair::generate()));
+        let proof_json = engine.generate_cryptographic_proof("evidence payload", "evidence");
+        let proof: CryptographicProof = serde_json::from_str(&proof_json).unwrap();
+        let signature = hex::decode(proof.pq_signature.as_ref().unwrap()).unwrap();
+        assert!(verify_ml_dsa65(engine.pq_public_key().unwrap(), proof.signed_payload().unwrap().as_bytes(), &signature));
+        assert!(engine.verify_cryptographic_proof(&proof_json));
+
+        // A co-signature over other content fails even though the ed25519 signature holds
+        let other: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("other payload", "evidence")).unwrap();
+        let mut swapped = proof.clone();
+        swapped.pq_signature = other.pq_signature;
+        assert!(!engine.verify_proof(&swapped));
+
+        // Without the key the co-signature cannot be checked, so the proof is rejected
+        engine.set_pq_keypair(None);
+        assert!(!engine.verify_proof(&proof));
+    }
+
+    #[test]
+    #[cfg(feature = "pq")]
+    fn test_hybrid_policies_record_scheme_used() {
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.set_pq_keypair(Some(MlDsa65Keypair::generate()));
+        let proof: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("evidence payload", "evidence")).unwrap();
+        let other: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("other payload", "evidence")).unwrap();
+        assert_eq!(proof.hybrid_signature().pq, proof.pq_signature);
+
+        let mut classical_only = proof.clone();
+        classical_only.pq_signature = other.pq_signature.clone();
+        let mut pq_only = proof.clone();
+        pq_only.signature = other.signature.clone();
+
+        let outcome = |engine: &mut VelocityCryptographicEngine, policy, proof: &CryptographicProof| {
+            engine.set_hybrid_policy(policy);
+            engine.verify_proof_signatures(proof).verified_with
+        };
+        for policy in [HybridPolicy::AllPresent, HybridPolicy::Either, HybridPolicy::Both] {
+            assert_eq!(outcome(&mut engine, policy, &proof), Some(SignatureScheme::Hybrid));
+        }
+        assert_eq!(outcome(&mut engine, HybridPolicy::AllPresent, &classical_only), None);
+        assert_eq!(outcome(&mut engine, HybridPolicy::Either, &classical_only), Some(SignatureScheme::Classical));
+        assert_eq!(outcome(&mut engine, HybridPolicy::Both, &classical_only), None);
+        assert_eq!(outcome(&mut engine, HybridPolicy::AllPresent, &pq_only), None);
+        assert_eq!(outcome(&mut engine, HybridPolicy::Either, &pq_only), Some(SignatureScheme::PostQuantum));
+        assert_eq!(outcome(&mut engine, HybridPolicy::Both, &pq_only), None);
+
+        // Under Either the proof as a whole verifies on its post-quantum signature alone
+        engine.set_hybrid_policy(HybridPolicy::Either);
+        assert!(engine.verify_proof(&pq_only));
+    }
+
+    #[test]
+    fn test_proof_verifies_after_key_rotation() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let before_json = engine.generate_cryptographic_proof("before rotation", "evidence");
+        let before: CryptographicProof = serde_json::from_str(&before_json).unwrap();
+        let original_key = before.key_id.clone().unwrap();
+        assert_eq!(original_key, engine.key_ring().active_key_id());
+
+        let new_key = engine.rotate_key();
+        assert_ne!(new_key, original_key);
+
+        let after: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("after rotation", "evidence")).unwrap();
+        assert_eq!(after.key_id.as_deref(), Some(new_key.as_str()));
+        assert!(engine.verify_cryptographic_proof(&before_json));
+        assert!(engine.verify_proof(&after));
+        assert!(engine.verify_all_signatures().iter().all(|(_, valid)| *valid));
+
+        // A proof claiming the retired key but dated after its retirement is rejected
+        let mut backdated_key = after.clone();
+        backdated_key.key_id = Some(original_key);
+        assert!(!engine.verify_proof(&backdated_key));
+
+        let mut unknown_key = after;
+        unknown_key.key_id = Some("key_unknown".to_string());
+        assert!(!engine.verify_proof(&unknown_key));
+    }
+
+    #[test]
+    fn test_timestamp_token_verification() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let authority = Arc::new(MockTimestampAuthority::new("test-tsa"));
+        engine.set_timestamp_authority(Some(authority.clone()));
+
+        let anchored: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("anchored evidence", "evidence")).unwrap();
+        let other: CryptographicProof =
+            serde_json::from_str(&engine.generate_cryptographic_proof("other evidence", "evidence")).unwrap();
+        let token = anchored.timestamp_token.clone().unwrap();
+        assert_eq!(token.message_imprint, anchored.hash);
+        assert!(engine.verify_proof(&anchored));
+
+        // A token for different content does not anchor this proof
+        let mut mismatched = other.clone();
+        mismatched.timestamp_token = Some(token.clone());
+        assert!(!engine.verify_proof(&mismatched));
+
+        // A genuine token whose TSA time is far from the claimed timestamp is rejected
+        let mut backdated = other;
+        let digest = hex::decode(&backdated.hash).unwrap();
+        backdated.timestamp_token = Some(authority.timestamp_at(&digest, Utc::now() + chrono::Duration::hours(1)));
+        assert!(!engine.verify_proof(&backdated));
+
+        // Tokens cannot be checked once the TSA is no longer configured
+        engine.set_timestamp_authority(None);
+        assert!(!engine.verify_proof(&anchored));
+    }
+
+    #[test]
+    fn test_cbor_round_trip_and_cross_format_verification() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let proof_json = engine.generate_cryptographic_proof("cbor evidence", "evidence");
+        let proof: CryptographicProof = serde_json::from_str(&proof_json).unwrap();
+
+        let cbor = proof.to_cbor();
+        assert!(cbor.len() < proof_json.len());
+        let decoded = CryptographicProof::from_cbor(&cbor).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(&proof).unwrap());
+        assert!(engine.verify_proof(&decoded));
+
+        // JSON -> CBOR -> JSON still verifies through the JSON entry point
+        assert!(engine.verify_cryptographic_proof(&serde_json::to_string(&decoded).unwrap()));
+
+        // A modified hash inside the CBOR bytes decodes but no longer verifies
+        let mut tampered = cbor.clone();
+        let at = tampered.windows(64).position(|window| window == proof.hash.as_bytes()).unwrap();
+        tampered[at] = if tampered[at] == b'0' { b'1' } else { b'0' };
+        assert!(!engine.verify_proof(&CryptographicProof::from_cbor(&tampered).unwrap()));
+
+        assert!(CryptographicProof::from_cbor(&cbor[..cbor.len() / 2]).is_err());
+    }
+
+    /// Custody key `actor` holds itself, derived from its name so tests can re-create it
+    fn actor_key(actor: &str) -> Keypair {
+        let secret = SecretKey::from_bytes(&Sha256::digest(actor.as_bytes())).unwrap();
+        let public = PublicKey::from(&secret);
+        Keypair { secret, public }
+    }
+
+    /// Engine with custody keys registered for the actors the custody tests use
+    fn custody_engine() -> VelocityCryptographicEngine {
+        let mut engine = VelocityCryptographicEngine::new();
+        for actor in ["collector", "analyst", "auditor", "archive"] {
+            engine.register_custody_actor(actor, actor_key(actor).public).unwrap();
+        }
+        engine
+    }
+
+    /// `created` entry `actor` signs for `evidence_data`, as JSON
+    fn created_by(actor: &str, evidence_data: &str) -> String {
+        let created = ChainOfCustodyEntry::created(&evidence_content_hash(evidence_data), actor, &actor_key(actor));
+        serde_json::to_string(&created).unwrap()
+    }
+
+    fn new_evidence(engine: &mut VelocityCryptographicEngine) -> EvidenceIntegrity {
+        serde_json::from_str(&engine.create_evidence_integrity("evidence payload", "{}", &created_by("collector", "evidence payload"))).unwrap()
+    }
+
+    /// `from` releases the evidence to `to`, which signs for it, each with its own key
+    fn transfer(engine: &VelocityCryptographicEngine, integrity: &mut EvidenceIntegrity, from: &str, to: &str) -> Result<(), String> {
+        let release = integrity.release_custody(to, &actor_key(from))?;
+        let entry = ChainOfCustodyEntry::transfer(integrity, to, &release, &actor_key(to))?;
+        engine.transfer_custody(integrity, entry)
+    }
+
+    #[test]
+    fn test_multi_hop_custody_chain_verifies() {
+        let mut engine = custody_engine();
+        let mut integrity = new_evidence(&mut engine);
+        assert!(engine.verify_custody_chain(&integrity));
+
+        transfer(&engine, &mut integrity, "collector", "analyst").unwrap();
+        transfer(&engine, &mut integrity, "analyst", "auditor").unwrap();
+        transfer(&engine, &mut integrity, "auditor", "archive").unwrap();
+
+        let custodians: Vec<&str> = integrity.chain_of_custody.iter().map(|e| e.actor.as_str()).collect();
+        assert_eq!(custodians, ["collector", "analyst", "auditor", "archive"]);
+        assert!(engine.verify_custody_chain(&integrity));
+
+        // Only the current custodian can hand the evidence on
+        assert!(transfer(&engine, &mut integrity, "auditor", "attacker").is_err());
+        assert_eq!(integrity.chain_of_custody.len(), 4);
+
+        // Survives a round trip through JSON
+        let decoded: EvidenceIntegrity = serde_json::from_str(&serde_json::to_string(&integrity).unwrap()).unwrap();
+        assert!(engine.verify_custody_chain(&decoded));
+    }
+
+    #[test]
+    fn test_inserted_or_forged_custody_entries_are_detected() {
+        let mut engine = custody_engine();
+        let mut integrity = new_evidence(&mut engine);
+        transfer(&engine, &mut integrity, "collector", "analyst").unwrap();
+        transfer(&engine, &mut integrity, "analyst", "auditor").unwrap();
+
+        // An entry signed by an unregistered actor, spliced in with a valid-looking link
+        let other = VelocityCryptographicEngine::new();
+        let mut inserted = integrity.clone();
+        let mut prefix = integrity.clone();
+        prefix.chain_of_custody.truncate(2);
+        let forged = ChainOfCustodyEntry::transfer(&prefix, "attacker", "", &actor_key("attacker")).unwrap();
+        inserted.chain_of_custody.insert(2, forged);
+        assert!(!engine.verify_custody_chain(&inserted));
+        assert!(transfer(&engine, &mut inserted, "auditor", "archive").is_err());
+
+        // Rewriting who handed over breaks the signature
+        let mut relabelled = integrity.clone();
+        relabelled.chain_of_custody[2].metadata.insert("from".to_string(), "collector".to_string());
+        assert!(!engine.verify_custody_chain(&relabelled));
+
+        // So does changing the recipient
+        let mut redirected = integrity.clone();
+        redirected.chain_of_custody[2].actor = "attacker".to_string();
+        assert!(!engine.verify_custody_chain(&redirected));
+
+        // Dropping a hop breaks the link
+        let mut skipped = integrity.clone();
+        skipped.chain_of_custody.remove(1);
+        assert!(!engine.verify_custody_chain(&skipped));
+
+        // Entries are bound to their evidence
+        let mut other_evidence = new_evidence(&mut engine);
+        other_evidence.chain_of_custody = integrity.chain_of_custody.clone();
+        other_evidence.content_hash = engine.generate_secure_hash("other payload");
+        assert!(!engine.verify_custody_chain(&other_evidence));
+
+        // An engine that cannot verify the chain will not extend it
+        assert!(transfer(&other, &mut integrity, "auditor", "archive").is_err());
+        assert!(engine.verify_custody_chain(&integrity));
+    }
+
+    #[test]
+    fn test_transfer_without_release_by_custodian_is_rejected() {
+        let mut engine = custody_engine();
+        let mut integrity = new_evidence(&mut engine);
+        transfer(&engine, &mut integrity, "collector", "analyst").unwrap();
+        let attacker = actor_key("attacker");
+        engine.register_custody_actor("attacker", attacker.public).unwrap();
+
+        // A transfer appended without the releasing custodian's signature
+        let mut entry = ChainOfCustodyEntry::transfer(&integrity, "attacker", "", &attacker).unwrap();
+        entry.release_signature = None;
+        entry.sign(&integrity.content_hash, &attacker);
+        let mut unreleased = integrity.clone();
+        unreleased.chain_of_custody.push(entry.clone());
+        assert!(!engine.verify_custody_chain(&unreleased));
+        assert!(engine.transfer_custody(&mut integrity.clone(), entry).is_err());
+
+        // Or with a release the recipient signed for itself
+        let forged_release = integrity.release_custody("attacker", &attacker).unwrap();
+        let entry = ChainOfCustodyEntry::transfer(&integrity, "attacker", &forged_release, &attacker).unwrap();
+        let mut forged = integrity.clone();
+        forged.chain_of_custody.push(entry.clone());
+        assert!(!engine.verify_custody_chain(&forged));
+        assert!(engine.transfer_custody(&mut integrity.clone(), entry).is_err());
+
+        // A release names its recipient and cannot be redirected
+        let release = integrity.release_custody("auditor", &actor_key("analyst")).unwrap();
+        let redirected = ChainOfCustodyEntry::transfer(&integrity, "attacker", &release, &attacker).unwrap();
+        assert!(engine.transfer_custody(&mut integrity.clone(), redirected).is_err());
+
+        // Nor replayed once the chain has moved on
+        let entry = ChainOfCustodyEntry::transfer(&integrity, "auditor", &release, &actor_key("auditor")).unwrap();
+        engine.transfer_custody(&mut integrity, entry).unwrap();
+        assert!(engine.verify_custody_chain(&integrity));
+        transfer(&engine, &mut integrity, "auditor", "analyst").unwrap();
+        let replayed = ChainOfCustodyEntry::transfer(&integrity, "auditor", &release, &actor_key("auditor")).unwrap();
+        assert!(engine.transfer_custody(&mut integrity, replayed).is_err());
+        assert_eq!(integrity.chain_of_custody.len(), 4);
+        assert!(engine.verify_custody_chain(&integrity));
+    }
+
+    #[test]
+    fn test_swapping_custody_actors_invalidates_both_signatures() {
+        let mut engine = custody_engine();
+        let mut integrity = new_evidence(&mut engine);
+        transfer(&engine, &mut integrity, "collector", "analyst").unwrap();
+        transfer(&engine, &mut integrity, "analyst", "auditor").unwrap();
+
+        let signature_valid = |integrity: &EvidenceIntegrity, index: usize| {
+            let entry = &integrity.chain_of_custody[index];
+            let signed_at = DateTime::parse_from_rfc3339(&entry.timestamp).unwrap().with_timezone(&Utc);
+            engine.custody_signature_valid(entry, &integrity.content_hash, signed_at, index == 0)
+        };
+        assert!((0..3).all(|index| signature_valid(&integrity, index)));
+
+        let mut swapped = integrity.clone();
+        let (first, second) = swapped.chain_of_custody.split_at_mut(2);
+        std::mem::swap(&mut first[1].actor, &mut second[0].actor);
+        assert!(!signature_valid(&swapped, 1));
+        assert!(!signature_valid(&swapped, 2));
+        assert!(!engine.verify_custody_chain(&swapped));
+
+        // Each actor signs with its own key, not the engine's
+        let analyst_key = engine.custody_public_key("analyst").unwrap();
+        assert_ne!(analyst_key, engine.custody_public_key("auditor").unwrap());
+        assert_ne!(analyst_key, engine.key_ring().active_public_key());
+    }
+
+    #[test]
+    fn test_registered_custody_key_signs_entries() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let public_key = actor_key("collector").public;
+        engine.register_custody_actor("collector", public_key).unwrap();
+        assert!(engine.register_custody_actor("collector", actor_key("analyst").public).is_err());
+
+        let integrity = new_evidence(&mut engine);
+        let created = &integrity.chain_of_custody[0];
+        let signature = Signature::from_bytes(&hex::decode(&created.cryptographic_signature).unwrap()).unwrap();
+        assert!(public_key.verify(created.signing_payload(&integrity.content_hash).as_bytes(), &signature).is_ok());
+        assert!(engine.verify_custody_chain(&integrity));
+
+        // Unregistered actors are refused
+        assert_eq!(engine.create_evidence_integrity("evidence payload", "{}", &created_by("stranger", "evidence payload")), "{}");
+        let created = serde_json::from_str(&created_by("stranger", "evidence payload")).unwrap();
+        assert!(engine.create_encrypted_evidence("evidence payload", "{}", created).is_err());
+        let mut integrity = integrity;
+        assert!(transfer(&engine, &mut integrity, "collector", "stranger").is_err());
+        assert!(engine.custody_public_key("stranger").is_none());
+        assert_eq!(integrity.chain_of_custody.len(), 1);
+
+        // A created entry only registers the evidence it was signed for
+        assert_eq!(engine.create_evidence_integrity("other payload", "{}", &created_by("collector", "evidence payload")), "{}");
+
+        // Enrollment takes the actor's public key; the engine never holds its secret
+        let stranger = hex::encode(actor_key("stranger").public.to_bytes());
+        assert!(!engine.enroll_custody_actor("stranger", "not a key"));
+        assert!(engine.enroll_custody_actor("stranger", &stranger));
+        assert!(!engine.enroll_custody_actor("stranger", &stranger));
+        transfer(&engine, &mut integrity, "collector", "stranger").unwrap();
+        assert!(engine.verify_custody_chain(&integrity));
+    }
+
+    #[test]
+    fn test_changing_created_entry_actor_is_rejected() {
+        let mut engine = custody_engine();
+        let integrity = new_evidence(&mut engine);
+
+        for actor in ["analyst", "stranger"] {
+            let mut reassigned = integrity.clone();
+            reassigned.chain_of_custody[0].actor = actor.to_string();
+            assert!(!engine.verify_custody_chain(&reassigned));
+
+            // Claiming the legacy format does not fall back to the engine signature
+            reassigned.chain_of_custody[0].format_version = CUSTODY_FORMAT_V1;
+            assert!(!engine.verify_custody_chain(&reassigned));
+        }
+    }
+
+    #[test]
+    fn test_appended_verifications_update_aggregate_confidence() {
+        let mut engine = custody_engine();
+        let mut integrity = new_evidence(&mut engine);
+        assert_eq!(integrity.aggregate_confidence, Some(0.99));
+
+        engine
+            .add_verification(&mut integrity, "auditor_1", "third_party", VERIFICATION_PASSED, "Sampled and matched", 0.9)
+            .unwrap();
+        engine
+            .add_verification(&mut integrity, "regulator_1", "regulatory", VERIFICATION_FAILED, "Hash mismatch on replay", 0.8)
+            .unwrap();
+        assert_eq!(integrity.verification_history.len(), 3);
+        let expected = (0.99 * 1.0 + 0.9 * 2.0 + (1.0 - 0.8) * 4.0) / 7.0;
+        assert!((integrity.aggregate_confidence.unwrap() - expected).abs() < 1e-6);
+
+        // Each appended entry carries its own proof in the verification domain
+        let appended = &integrity.verification_history[1];
+        assert_eq!(appended.cryptographic_proof.domain.as_deref(), Some(DOMAIN_EVIDENCE_VERIFICATION));
+        assert!(engine.verify_proof(&appended.cryptographic_proof));
+
+        // Reaffirming is fine; contradicting without superseding is not
+        engine
+            .add_verification(&mut integrity, "auditor_1", "third_party", VERIFICATION_PASSED, "Resampled", 0.9)
+            .unwrap();
+        assert!(engine
+            .add_verification(&mut integrity, "auditor_1", "third_party", VERIFICATION_FAILED, "Changed my mind", 0.7)
+            .is_err());
+        assert_eq!(integrity.verification_history.len(), 4);
+
+        assert!(engine.add_verification(&mut integrity, "auditor_2", "third_party", "maybe", "", 0.5).is_err());
+        assert!(engine.add_verification(&mut integrity, "auditor_2", "third_party", VERIFICATION_PASSED, "", 1.5).is_err());
+    }
+
+    #[test]
+    fn test_superseding_verification_replaces_earlier_entry() {
+        let mut engine = custody_engine();
+        let mut integrity = new_evidence(&mut engine);
+        engine
+            .add_verification(&mut integrity, "regulator_1", "regulatory", VERIFICATION_FAILED, "Hash mismatch", 0.8)
+            .unwrap();
+        let failed_id = integrity.verification_history[1].id.clone();
+
+        engine
+            .supersede_verification(&mut integrity, &failed_id, VERIFICATION_PASSED, "Mismatch was a replay error", 0.95)
+            .unwrap();
+        let superseding = integrity.verification_history.last().unwrap();
+        assert_eq!(superseding.supersedes.as_deref(), Some(failed_id.as_str()));
+        assert_eq!(superseding.verifier_id, "regulator_1");
+
+        // The failed entry stays in the history but no longer counts
+        assert_eq!(integrity.verification_history.len(), 3);
+        assert_eq!(integrity.current_verifications().count(), 2);
+        assert!((integrity.aggregate_confidence.unwrap() - (0.99 * 1.0 + 0.95 * 4.0) / 5.0).abs() < 1e-6);
+
+        // A superseded entry cannot be superseded again
+        assert!(engine
+            .supersede_verification(&mut integrity, &failed_id, VERIFICATION_FAILED, "", 0.5)
+            .is_err());
+    }
+
+    #[test]
+    fn test_regulatory_failure_outweighs_system_passes() {
+        let mut engine = custody_engine();
+        let mut integrity = new_evidence(&mut engine);
+        for i in 0..3 {
+            let verifier = format!("scanner_{}", i);
+            engine
+                .add_verification(&mut integrity, &verifier, "system", VERIFICATION_PASSED, "Automated check", 0.7)
+                .unwrap();
+        }
+        let before = engine.aggregate_verification(&integrity);
+        assert_eq!(before.outcome, VERIFICATION_PASSED);
+        assert!(before.dissenting_verifiers.is_empty());
+
+        engine
+            .add_verification(&mut integrity, "regulator_1", "regulatory", VERIFICATION_FAILED, "Tampering found", 0.95)
+            .unwrap();
+        let after = engine.aggregate_verification(&integrity);
+        assert!(after.confidence < 0.5, "aggregate {}", after.confidence);
+        assert_eq!(after.outcome, VERIFICATION_FAILED);
+        assert_eq!(
+            after.dissenting_verifiers,
+            ["velocity_crypto_system", "scanner_0", "scanner_1", "scanner_2"]
+        );
+        assert_eq!(integrity.aggregate_confidence, Some(after.confidence));
+    }
+
+    #[test]
+    fn test_older_verifications_count_for_less() {
+        let mut engine = custody_engine();
+        let mut integrity = new_evidence(&mut engine);
+        engine
+            .add_verification(&mut integrity, "regulator_1", "regulatory", VERIFICATION_FAILED, "Tampering found", 0.95)
+            .unwrap();
+        engine
+            .add_verification(&mut integrity, "auditor_1", "third_party", VERIFICATION_PASSED, "Clean re-audit", 0.9)
+            .unwrap();
+        assert_eq!(engine.aggregate_verification(&integrity).outcome, VERIFICATION_FAILED);
+
+        // Two half-lives later the regulator's finding weighs 1.0 rather than 4.0
+        let newest = DateTime::parse_from_rfc3339(&integrity.verification_history[2].timestamp).unwrap();
+        let aged = newest - chrono::Duration::days(2 * VERIFICATION_HALF_LIFE_DAYS as i64);
+        integrity.verification_history[0].timestamp = aged.to_rfc3339();
+        integrity.verification_history[1].timestamp = aged.to_rfc3339();
+
+        let aggregate = engine.aggregate_verification(&integrity);
+        let expected = (0.99 * 0.25 + 0.05 * 1.0 + 0.9 * 2.0) / (0.25 + 1.0 + 2.0);
+        assert!((aggregate.confidence - expected).abs() < 1e-6);
+        assert_eq!(aggregate.outcome, VERIFICATION_PASSED);
+        assert_eq!(aggregate.dissenting_verifiers, ["regulator_1"]);
+    }
+
+    #[test]
+    fn test_encrypted_evidence_round_trips() {
+        let mut engine = custody_engine();
+        let payload = "board minutes: acquisition approved";
+        let created = serde_json::from_str(&created_by("collector", payload)).unwrap();
+        let (integrity, ciphertext) = engine.create_encrypted_evidence(payload, "{}", created).unwrap();
+
+        // Integrity is computed over the plaintext, so it verifies without decrypting
+        assert_eq!(integrity.content_hash, engine.generate_secure_hash(payload));
+        assert!(engine.verify_custody_chain(&integrity));
+        assert!(engine.verify_proof(&integrity.cryptographic_proof));
+
+        let envelope = integrity.immutable_storage.encryption.as_ref().unwrap();
+        assert_eq!(envelope.algorithm, EVIDENCE_ENCRYPTION_ALGORITHM);
+        assert!(!ciphertext.windows(payload.len()).any(|window| window == payload.as_bytes()));
+        assert_ne!(integrity.immutable_storage.ipfs_hash, Some(engine.generate_mock_ipfs_hash(&integrity.content_hash)));
+        assert_eq!(engine.decrypt_evidence(&integrity, &ciphertext).unwrap(), payload);
+
+        let mut tampered = ciphertext.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(engine.decrypt_evidence(&integrity, &tampered).is_err());
+
+        // Another engine's master key cannot unwrap the data key
+        let other = VelocityCryptographicEngine::new();
+        assert!(other.decrypt_evidence(&integrity, &ciphertext).is_err());
+
+        // Each data key is bound to its evidence id
+        let created = serde_json::from_str(&created_by("collector", "other payload")).unwrap();
+        let (mut moved, _) = engine.create_encrypted_evidence("other payload", "{}", created).unwrap();
+        moved.immutable_storage = integrity.immutable_storage.clone();
+        assert!(engine.decrypt_evidence(&moved, &ciphertext).is_err());
+
+        let plaintext_record = new_evidence(&mut engine);
+        assert!(engine.decrypt_evidence(&plaintext_record, &ciphertext).is_err());
+    }
+
+    #[test]
+    fn test_evidence_records_anchor_transaction() {
+        let mut engine = custody_engine();
+        let anchor = Arc::new(MockPolygonAnchor::new(PolygonAnchorConfig {
+            rpc_url: "http://localhost:8545".to_string(),
+            contract_address: "0x00000000000000000000000000000000000a1c40".to_string(),
+            chain_id: POLYGON_AMOY_CHAIN_ID,
+            min_confirmations: DEFAULT_MIN_CONFIRMATIONS,
+        }));
+        engine.set_polygon_anchor(Some(anchor.clone()));
+
+        let integrity = new_evidence(&mut engine);
+        let tx_id = integrity.immutable_storage.blockchain_tx_id.clone().unwrap();
+        assert_eq!(anchor.anchored_tx(&integrity.cryptographic_proof.hash), Some(tx_id.clone()));
+
+        // The same evidence has the same proof hash and is not anchored twice
+        let again = new_evidence(&mut engine);
+        assert_eq!(again.cryptographic_proof.hash, integrity.cryptographic_proof.hash);
+        assert_eq!(again.immutable_storage.blockchain_tx_id, Some(tx_id));
+        assert_eq!(anchor.transaction_count(), 1);
+
+        let other: EvidenceIntegrity =
+            serde_json::from_str(&engine.create_evidence_integrity("other payload", "{}", &created_by("collector", "other payload"))).unwrap();
+        assert_ne!(other.immutable_storage.blockchain_tx_id, again.immutable_storage.blockchain_tx_id);
+        assert_eq!(anchor.transaction_count(), 2);
+    }
+
+    #[test]
+    fn test_legacy_created_entry_still_verifies() {
+        let mut engine = custody_engine();
+        let mut integrity = new_evidence(&mut engine);
+
+        // Records issued before actors signed their own entries carried an engine
+        // signature over only the content hash, and no `format_version`
+        let legacy_signature = engine.sign_data(&integrity.content_hash);
+        integrity.chain_of_custody[0].cryptographic_signature = legacy_signature.clone();
+        assert!(!engine.verify_custody_chain(&integrity));
+
+        let mut legacy = serde_json::to_value(&integrity).unwrap();
+        legacy["chain_of_custody"][0].as_object_mut().unwrap().remove("format_version");
+        let mut integrity: EvidenceIntegrity = serde_json::from_value(legacy).unwrap();
+        assert_eq!(integrity.chain_of_custody[0].format_version, CUSTODY_FORMAT_V1);
+        assert!(engine.verify_custody_chain(&integrity));
+
+        // That form is only ever valid for the creation entry
+        let mut later = integrity.clone();
+        transfer(&engine, &mut later, "collector", "analyst").unwrap();
+        later.chain_of_custody[1].cryptographic_signature = legacy_signature;
+        later.chain_of_custody[1].format_version = CUSTODY_FORMAT_V1;
+        assert!(!engine.verify_custody_chain(&later));
+
+        transfer(&engine, &mut integrity, "collector", "analyst").unwrap();
+        assert!(engine.verify_custody_chain(&integrity));
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let signature = "9f".repeat(32);
+        assert!(ct_eq(&signature, "9f".repeat(32)));
+        assert!(!ct_eq(&signature, format!("{}9e", "9f".repeat(31))));
+        assert!(!ct_eq(&signature, &signature[..62]));
+        assert!(ct_eq(Vec::<u8>::new(), ""));
+    }
+
+    #[test]
+    fn test_replay_guard_window() {
+        let mut guard = ReplayGuard::new(60);
+        let now = Utc::now();
+
+        let proof = proof_at("proof_1", "nonce_a", now);
+        assert!(guard.check_and_record_at(&proof, now));
+        assert!(!guard.check_and_record_at(&proof, now + chrono::Duration::seconds(30)));
+
+        // Same id with a fresh nonce is a distinct proof
+        assert!(guard.check_and_record_at(&proof_at("proof_1", "nonce_b", now), now));
+
+        // Proofs issued outside the window are stale
+        let stale = proof_at("proof_2", "nonce_c", now - chrono::Duration::seconds(120));
+        assert!(!guard.check_and_record_at(&stale, now));
+
+        // Once the window has passed, tracked entries are evicted and the proof itself is stale
+        let later = now + chrono::Duration::seconds(90);
+        assert!(!guard.check_and_record_at(&proof, later));
+        assert!(guard.check_and_record_at(&proof_at("proof_3", "nonce_d", later), later));
+        assert_eq!(guard.tracked(), 1);
+    }
+
+    #[test]
+    fn test_replay_guard_rejects_future_dated_proofs() {
+        let mut guard = ReplayGuard::new(60);
+        let now = Utc::now();
+
+        // Dated inside the window but beyond the skew tolerance
+        let ahead = proof_at("proof_1", "nonce_a", now + chrono::Duration::seconds(50));
+        assert!(!guard.check_and_record_at(&ahead, now));
+        assert_eq!(guard.tracked(), 0);
+
+        // Skewed proofs are tracked until they go stale, so they cannot be replayed
+        let skewed_at = now + chrono::Duration::seconds(DEFAULT_CLOCK_SKEW_TOLERANCE_SECONDS);
+        let skewed = proof_at("proof_2", "nonce_b", skewed_at);
+        assert!(guard.check_and_record_at(&skewed, now));
+        let last_fresh = skewed_at + chrono::Duration::seconds(60);
+        assert!(!guard.check_and_record_at(&skewed, last_fresh));
+
+        guard.set_future_tolerance(chrono::Duration::seconds(50));
+        assert!(guard.check_and_record_at(&ahead, now));
+    }
+
+    #[test]
+    fn test_replay_window_out_of_range_is_rejected() {
+        let mut engine = VelocityCryptographicEngine::new();
+        for window_secs in [0, -1, i64::MIN, i64::MAX, MAX_REPLAY_WINDOW_SECS + 1] {
+            assert!(!engine.set_replay_window(window_secs), "window {}", window_secs);
+        }
+        assert!(engine.set_replay_window(MAX_REPLAY_WINDOW_SECS));
+        assert!(engine.set_replay_window(1));
+
+        // The window in force is the last one accepted
+        let now = Utc::now();
+        let proof = proof_at("proof_1", "nonce_a", now - chrono::Duration::seconds(2));
+        assert!(!engine.set_replay_window(-60));
+        assert!(!check_replay(&engine.replay_guard, &proof, now));
+
+        // Guards built directly clamp instead of panicking
+        let mut guard = ReplayGuard::new(i64::MIN);
+        assert!(!guard.check_and_record_at(&proof, now));
+        let mut guard = ReplayGuard::new(i64::MAX);
+        assert!(guard.check_and_record_at(&proof, now));
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn test_detailed_trust_score_includes_component_scores() {
+        let mut engine = VelocityCryptographicEngine::new();
+        let input = serde_json::json!({
+            "organization_id": "org_detailed",
+            "compliance_scores": { "SOC2": 0.92, "ISO27001": 0.88 },
+            "audit_results": [{
+                "audit_id": "audit_1",
+                "framework": "SOC2",
+                "score": 91.0,
+                "auditor_id": "auditor_1",
+                "completion_date": "2024-12-01T00:00:00Z",
+                "findings_count": 2,
+                "remediation_status": "closed",
+            }],
+            "certification_status": {
+                "SOC2": {
+                    "is_active": true,
+                    "expiration_date": null,
+                    "issuing_authority": "AICPA",
+                    "confidence_level": 0.95,
+                },
+            },
+            "historical_performance": [{
+                "metric_name": "uptime",
+                "value": 0.99,
+                "measurement_date": "2024-12-01T00:00:00Z",
+                "source": "monitoring",
+            }],
+            "peer_comparisons": [{
+                "industry_segment": "fintech",
+                "percentile_ranking": 80.0,
+                "anonymized_peer_data": [],
+                "comparison_hash": "",
+            }],
+            "calculation_timestamp": "2025-01-01T00:00:00Z",
+        });
+
+        let output = engine.calculate_trust_score_detailed(&input.to_string());
+        let detailed: DetailedTrustScore = serde_json::from_str(&output).unwrap();
+        for component in ["compliance_framework", "audit_results", "certifications", "historical_performance", "peer_comparison"] {
+            assert!(detailed.component_scores.contains_key(component), "missing component score {}", component);
+        }
+        assert_eq!(detailed.component_scores.len(), 5);
+        let (lower, upper) = detailed.confidence_interval;
+        assert!(lower <= detailed.proof.trust_score && detailed.proof.trust_score <= upper);
+        assert_eq!(detailed.proof.organization_id, "org_detailed");
+
+        assert_eq!(engine.calculate_trust_score_detailed("not json"), "{}");
+    }
+
+    #[test]
+    fn test_encryption_key_wiped_on_drop() {
+        // The engine sits in a ManuallyDrop slot, so after the key field's
+        // destructor runs its bytes are still the test's to inspect; the rest of
+        // the engine is leaked
+        let mut engine = std::mem::ManuallyDrop::new(VelocityCryptographicEngine::new());
+        let mut exported = std::mem::ManuallyDrop::new(engine.key_ring().export_active_secret());
+        assert_ne!(*engine.encryption_key, [0u8; 32]);
+        assert_eq!(std::mem::size_of::<Zeroizing<[u8; 32]>>(), 32);
+
+        let slot: *mut Zeroizing<[u8; 32]> = &mut engine.encryption_key;
+        let bytes = unsafe {
+            std::ptr::drop_in_place(slot);
+            std::ptr::read(slot as *const [u8; 32])
+        };
+        assert_eq!(bytes, [0u8; 32]);
+
+        // As is a signing key exported from the engine
+        assert_ne!(*exported, [0u8; 32]);
+        let slot: *mut Zeroizing<[u8; 32]> = &mut *exported;
+        let bytes = unsafe {
+            std::ptr::drop_in_place(slot);
+            std::ptr::read(slot as *const [u8; 32])
+        };
+        assert_eq!(bytes, [0u8; 32]);
+    }
+}
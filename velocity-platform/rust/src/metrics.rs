@@ -0,0 +1,147 @@
+//! Verification Metrics Module
+//!
+//! Lock-free counters and HDR latency histograms for proof generation and
+//! verification, so throughput and tail latency can be observed in production
+
+use hdrhistogram::Histogram;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Highest latency tracked by the histograms (60 s, in microseconds)
+const MAX_TRACKED_LATENCY_US: u64 = 60_000_000;
+
+/// Significant decimal digits kept by the histograms
+const HISTOGRAM_PRECISION: u8 = 3;
+
+/// Count, failures and latency distribution for one kind of operation
+pub struct OperationMetrics {
+    count: AtomicU64,
+    failures: AtomicU64,
+    latency_us: Mutex<Histogram<u64>>,
+}
+
+impl OperationMetrics {
+    fn new() -> Self {
+        OperationMetrics {
+            count: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+            latency_us: Mutex::new(
+                Histogram::new_with_bounds(1, MAX_TRACKED_LATENCY_US, HISTOGRAM_PRECISION)
+                    .expect("static histogram bounds are valid"),
+            ),
+        }
+    }
+
+    /// Record one operation and its outcome
+    pub fn record(&self, elapsed: Duration, success: bool) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        if !success {
+            self.failures.fetch_add(1, Ordering::Relaxed);
+        }
+
+        // Latencies beyond the tracked range are clamped rather than dropped
+        let micros = (elapsed.as_micros() as u64).clamp(1, MAX_TRACKED_LATENCY_US);
+        if let Ok(mut histogram) = self.latency_us.lock() {
+            histogram.saturating_record(micros);
+        }
+    }
+
+    /// Time `operation` and record whether it succeeded
+    pub fn time<T>(&self, operation: impl FnOnce() -> T, succeeded: impl FnOnce(&T) -> bool) -> T {
+        let started = Instant::now();
+        let result = operation();
+        self.record(started.elapsed(), succeeded(&result));
+        result
+    }
+
+    pub fn snapshot(&self) -> OperationSnapshot {
+        let (samples, p50_us, p99_us, max_us) = match self.latency_us.lock() {
+            Ok(histogram) if !histogram.is_empty() => (
+                histogram.len(),
+                histogram.value_at_quantile(0.5),
+                histogram.value_at_quantile(0.99),
+                histogram.max(),
+            ),
+            _ => (0, 0, 0, 0),
+        };
+
+        OperationSnapshot {
+            count: self.count.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            samples,
+            p50_us,
+            p99_us,
+            max_us,
+        }
+    }
+}
+
+/// Instrumentation shared by the proof and evidence engines
+pub struct Metrics {
+    pub proof_generation: OperationMetrics,
+    pub proof_verification: OperationMetrics,
+    pub signature_verification: OperationMetrics,
+    pub evidence_verification: OperationMetrics,
+    started: Instant,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Metrics {
+            proof_generation: OperationMetrics::new(),
+            proof_verification: OperationMetrics::new(),
+            signature_verification: OperationMetrics::new(),
+            evidence_verification: OperationMetrics::new(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Serializable point-in-time report
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let uptime_secs = self.started.elapsed().as_secs_f64();
+        let proof_verification = self.proof_verification.snapshot();
+        let verifications_per_sec = if uptime_secs > 0.0 {
+            proof_verification.count as f64 / uptime_secs
+        } else {
+            0.0
+        };
+
+        MetricsSnapshot {
+            uptime_secs,
+            verifications_per_sec,
+            proof_generation: self.proof_generation.snapshot(),
+            proof_verification,
+            signature_verification: self.signature_verification.snapshot(),
+            evidence_verification: self.evidence_verification.snapshot(),
+        }
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OperationSnapshot {
+    pub count: u64,
+    pub failures: u64,
+    /// Latency samples recorded in the histogram
+    pub samples: u64,
+    pub p50_us: u64,
+    pub p99_us: u64,
+    pub max_us: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    pub uptime_secs: f64,
+    pub verifications_per_sec: f64,
+    pub proof_generation: OperationSnapshot,
+    pub proof_verification: OperationSnapshot,
+    pub signature_verification: OperationSnapshot,
+    pub evidence_verification: OperationSnapshot,
+}
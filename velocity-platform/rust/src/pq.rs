@@ -0,0 +1,83 @@
+//! Post-Quantum Signature Module
+//!
+//! ML-DSA-65 (FIPS 204) keys an engine can co-sign its proofs with, next to the
+//! ed25519 key ring, for relying parties that require quantum-resistant
+//! signatures. Only built with the `pq` feature.
+
+use crate::*;
+use pqcrypto_mldsa::mldsa65;
+use pqcrypto_traits::sign::{DetachedSignature as _, PublicKey as _, SecretKey as _};
+use zeroize::Zeroizing;
+
+/// ML-DSA-65 signing key; the secret half is wiped on drop
+pub struct MlDsa65Keypair {
+    public_key: Vec<u8>,
+    secret_key: Zeroizing<Vec<u8>>,
+}
+
+impl MlDsa65Keypair {
+    pub fn generate() -> Self {
+        let (public_key, secret_key) = mldsa65::keypair();
+        MlDsa65Keypair {
+            public_key: public_key.as_bytes().to_vec(),
+            secret_key: Zeroizing::new(secret_key.as_bytes().to_vec()),
+        }
+    }
+
+    /// Key pair from raw key bytes, e.g. loaded from a KMS
+    pub fn from_bytes(public_key: &[u8], secret_key: &[u8]) -> Result<Self, VerificationError> {
+        if public_key.len() != mldsa65::public_key_bytes() || secret_key.len() != mldsa65::secret_key_bytes() {
+            return Err(VerificationError::InvalidInput(format!(
+                "ML-DSA-65 keys must be {} and {} bytes",
+                mldsa65::public_key_bytes(),
+                mldsa65::secret_key_bytes()
+            )));
+        }
+        Ok(MlDsa65Keypair {
+            public_key: public_key.to_vec(),
+            secret_key: Zeroizing::new(secret_key.to_vec()),
+        })
+    }
+
+    /// Public key relying parties verify co-signatures with
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    /// Detached signature over `message`
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        let secret_key = mldsa65::SecretKey::from_bytes(&self.secret_key).expect("length checked on construction");
+        mldsa65::detached_sign(message, &secret_key).as_bytes().to_vec()
+    }
+}
+
+/// Whether `signature` is a valid ML-DSA-65 signature over `message` by `public_key`
+pub fn verify_ml_dsa65(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let (Ok(public_key), Ok(signature)) = (
+        mldsa65::PublicKey::from_bytes(public_key),
+        mldsa65::DetachedSignature::from_bytes(signature),
+    ) else {
+        return false;
+    };
+    mldsa65::verify_detached_signature(&signature, message, &public_key).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify_round_trip() {
+        let keypair = MlDsa65Keypair::generate();
+        let signature = keypair.sign(b"evidence payload");
+        assert!(verify_ml_dsa65(keypair.public_key(), b"evidence payload", &signature));
+
+        assert!(!verify_ml_dsa65(keypair.public_key(), b"tampered payload", &signature));
+        assert!(!verify_ml_dsa65(MlDsa65Keypair::generate().public_key(), b"evidence payload", &signature));
+        assert!(!verify_ml_dsa65(keypair.public_key(), b"evidence payload", &signature[1..]));
+
+        let restored = MlDsa65Keypair::from_bytes(keypair.public_key(), &keypair.secret_key).unwrap();
+        assert!(verify_ml_dsa65(keypair.public_key(), b"other", &restored.sign(b"other")));
+        assert!(MlDsa65Keypair::from_bytes(&keypair.public_key()[1..], &keypair.secret_key).is_err());
+    }
+}
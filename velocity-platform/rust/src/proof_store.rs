@@ -0,0 +1,323 @@
+//! Proof Store Module
+//!
+//! Where an engine keeps its proof chain. `MemoryProofStore` holds the chain in
+//! a `Vec` and is the default, and the only store in the wasm build. Native
+//! builds can plug in `FileProofStore`, which appends proofs to a JSON-lines
+//! file and keeps only an index in memory, so chains outlive the process and
+//! can grow past what fits in RAM.
+
+use crate::*;
+use std::collections::HashMap;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs::{File, OpenOptions};
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Mutex;
+
+/// Append-only storage for a proof chain
+///
+/// Heights are 1-based, matching `CryptographicProof::block_height`. Stores
+/// only check that each proof extends the chain by one; signatures and hash
+/// linkage are the engine's to verify.
+pub trait ProofStore: Send + Sync {
+    /// Add `proof` at the tip; its `block_height` must be `len() + 1`
+    fn append(&mut self, proof: CryptographicProof) -> Result<(), VerificationError>;
+
+    /// Proof at `height`, or `None` past the tip
+    fn get_by_height(&self, height: u64) -> Option<CryptographicProof>;
+
+    /// Proof with `id`
+    ///
+    /// Content-addressed engines call this for every proof they create, so
+    /// backends look ids up in an index rather than scanning the chain.
+    fn get_by_id(&self, id: &str) -> Option<CryptographicProof>;
+
+    /// Number of proofs, which is also the tip height
+    fn len(&self) -> u64;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hash of the proof at the tip, or `None` for an empty chain
+    fn last_hash(&self) -> Option<String>;
+}
+
+/// Reject `proof` unless it is the next one for a store of height `len`
+fn check_extends(len: u64, proof: &CryptographicProof) -> Result<(), VerificationError> {
+    if proof.block_height != len + 1 {
+        return Err(VerificationError::InvalidInput(format!(
+            "Proof height {} does not extend store of height {}",
+            proof.block_height, len
+        )));
+    }
+    Ok(())
+}
+
+/// Proof chain held in memory
+#[derive(Clone, Debug, Default)]
+pub struct MemoryProofStore {
+    proofs: Vec<CryptographicProof>,
+    /// Index into `proofs` by proof id
+    ids: HashMap<String, usize>,
+}
+
+impl MemoryProofStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<Vec<CryptographicProof>> for MemoryProofStore {
+    /// Store holding `proofs` as they are, without checking their heights
+    fn from(proofs: Vec<CryptographicProof>) -> Self {
+        let ids = proofs.iter().enumerate().map(|(index, proof)| (proof.id.clone(), index)).collect();
+        MemoryProofStore { proofs, ids }
+    }
+}
+
+impl ProofStore for MemoryProofStore {
+    fn append(&mut self, proof: CryptographicProof) -> Result<(), VerificationError> {
+        check_extends(self.len(), &proof)?;
+        self.ids.insert(proof.id.clone(), self.proofs.len());
+        self.proofs.push(proof);
+        Ok(())
+    }
+
+    fn get_by_height(&self, height: u64) -> Option<CryptographicProof> {
+        let index = usize::try_from(height).ok()?.checked_sub(1)?;
+        self.proofs.get(index).cloned()
+    }
+
+    fn get_by_id(&self, id: &str) -> Option<CryptographicProof> {
+        self.ids.get(id).map(|&index| self.proofs[index].clone())
+    }
+
+    fn len(&self) -> u64 {
+        self.proofs.len() as u64
+    }
+
+    fn last_hash(&self) -> Option<String> {
+        self.proofs.last().map(|proof| proof.hash.clone())
+    }
+}
+
+/// Proof chain in an append-only file of one JSON proof per line
+///
+/// Memory use is a byte offset and an id index entry per proof; proofs are read
+/// back from disk on lookup. Each append is written straight to the file, but
+/// is only durable across power loss once the OS flushes it.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileProofStore {
+    path: PathBuf,
+    file: Mutex<File>,
+    /// Byte offset of each proof's line, by height - 1
+    offsets: Vec<u64>,
+    /// Length of the file, where the next proof starts
+    end: u64,
+    /// Height of each proof by id
+    ids: HashMap<String, u64>,
+    last_hash: Option<String>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileProofStore {
+    /// Open the chain at `path`, creating an empty file if there is none
+    ///
+    /// An existing file is scanned once to rebuild the index. The engine using
+    /// the store must hold the keys that signed its proofs for them to verify.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, VerificationError> {
+        let path = path.as_ref().to_path_buf();
+        let unavailable = |error: std::io::Error| {
+            VerificationError::Unavailable(format!("Proof store {}: {}", path.display(), error))
+        };
+        let file = OpenOptions::new().read(true).append(true).create(true).open(&path).map_err(unavailable)?;
+
+        let mut store = FileProofStore {
+            path: path.clone(),
+            file: Mutex::new(file.try_clone().map_err(unavailable)?),
+            offsets: Vec::new(),
+            end: 0,
+            ids: HashMap::new(),
+            last_hash: None,
+        };
+        let mut reader = BufReader::new(file);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line).map_err(unavailable)? as u64;
+            if read == 0 {
+                break;
+            }
+            let height = store.offsets.len() as u64 + 1;
+            let proof: CryptographicProof = serde_json::from_str(&line).map_err(|_| {
+                VerificationError::Unavailable(format!("Proof store {}: unreadable proof at height {}", path.display(), height))
+            })?;
+            if proof.block_height != height {
+                return Err(VerificationError::Unavailable(format!(
+                    "Proof store {}: proof at height {} claims height {}",
+                    path.display(),
+                    height,
+                    proof.block_height
+                )));
+            }
+            store.offsets.push(store.end);
+            store.end += read;
+            store.ids.insert(proof.id, height);
+            store.last_hash = Some(proof.hash);
+        }
+        Ok(store)
+    }
+
+    /// File the chain is stored in
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read_at(&self, height: u64) -> Option<CryptographicProof> {
+        let index = usize::try_from(height).ok()?.checked_sub(1)?;
+        let start = *self.offsets.get(index)?;
+        let stop = self.offsets.get(index + 1).copied().unwrap_or(self.end);
+        let mut line = vec![0u8; (stop - start) as usize];
+
+        let mut file = self.file.lock().ok()?;
+        file.seek(SeekFrom::Start(start)).ok()?;
+        file.read_exact(&mut line).ok()?;
+        serde_json::from_slice(&line).ok()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ProofStore for FileProofStore {
+    fn append(&mut self, proof: CryptographicProof) -> Result<(), VerificationError> {
+        check_extends(self.len(), &proof)?;
+        let mut line = serde_json::to_vec(&proof)
+            .map_err(|error| VerificationError::InvalidInput(format!("Unserializable proof: {}", error)))?;
+        line.push(b'\n');
+
+        let mut file = self.file.lock()
+            .map_err(|_| VerificationError::Unavailable(format!("Proof store {}: lock poisoned", self.path.display())))?;
+        file.write_all(&line)
+            .map_err(|error| VerificationError::Unavailable(format!("Proof store {}: {}", self.path.display(), error)))?;
+        drop(file);
+
+        self.offsets.push(self.end);
+        self.end += line.len() as u64;
+        self.ids.insert(proof.id, proof.block_height);
+        self.last_hash = Some(proof.hash);
+        Ok(())
+    }
+
+    fn get_by_height(&self, height: u64) -> Option<CryptographicProof> {
+        self.read_at(height)
+    }
+
+    fn get_by_id(&self, id: &str) -> Option<CryptographicProof> {
+        self.read_at(*self.ids.get(id)?)
+    }
+
+    fn len(&self) -> u64 {
+        self.offsets.len() as u64
+    }
+
+    fn last_hash(&self) -> Option<String> {
+        self.last_hash.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proof(height: u64, previous_hash: &str) -> CryptographicProof {
+        CryptographicProof {
+            format_version: CURRENT_PROOF_FORMAT_VERSION,
+            id: format!("proof_{}", height),
+            hash: format!("{:064x}", height),
+            signature: "00".repeat(64),
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+            previous_hash: Some(previous_hash.to_string()),
+            merkle_root: None,
+            block_height: height,
+            verification_status: "verified".to_string(),
+            nonce: format!("nonce_{}", height),
+            key_id: None,
+            domain: Some(proof_domain("evidence")),
+            timestamp_token: None,
+            pq_signature: None,
+            genesis_anchor: None,
+        }
+    }
+
+    /// Behaviour every backend must share
+    fn exercise_store(store: &mut dyn ProofStore) {
+        assert!(store.is_empty());
+        assert_eq!(store.last_hash(), None);
+        assert!(store.get_by_height(0).is_none());
+        assert!(store.get_by_height(1).is_none());
+
+        let mut previous = GENESIS_PROOF_HASH.to_string();
+        for height in 1..=5 {
+            let next = proof(height, &previous);
+            previous = next.hash.clone();
+            store.append(next).unwrap();
+        }
+        assert_eq!(store.len(), 5);
+        assert_eq!(store.last_hash(), Some(format!("{:064x}", 5)));
+
+        let third = store.get_by_height(3).unwrap();
+        assert_eq!(third.id, "proof_3");
+        assert_eq!(third.previous_hash, Some(format!("{:064x}", 2)));
+        assert_eq!(store.get_by_id("proof_3").unwrap().hash, third.hash);
+        assert!(store.get_by_height(6).is_none());
+        assert!(store.get_by_id("proof_6").is_none());
+
+        // Proofs can only extend the tip
+        assert!(matches!(store.append(proof(5, &previous)), Err(VerificationError::InvalidInput(_))));
+        assert!(matches!(store.append(proof(7, &previous)), Err(VerificationError::InvalidInput(_))));
+        assert_eq!(store.len(), 5);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn temp_path() -> PathBuf {
+        std::env::temp_dir().join(format!("velocity-proofs-{}.jsonl", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn test_memory_store() {
+        exercise_store(&mut MemoryProofStore::new());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_file_store() {
+        let path = temp_path();
+        exercise_store(&mut FileProofStore::open(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_file_store_reopens_existing_chain() {
+        let path = temp_path();
+        let mut store = FileProofStore::open(&path).unwrap();
+        store.append(proof(1, GENESIS_PROOF_HASH)).unwrap();
+        store.append(proof(2, &format!("{:064x}", 1))).unwrap();
+        drop(store);
+
+        let mut reopened = FileProofStore::open(&path).unwrap();
+        assert_eq!(reopened.len(), 2);
+        assert_eq!(reopened.last_hash(), Some(format!("{:064x}", 2)));
+        assert_eq!(reopened.get_by_id("proof_1").unwrap().block_height, 1);
+        reopened.append(proof(3, &format!("{:064x}", 2))).unwrap();
+        assert_eq!(reopened.get_by_height(3).unwrap().id, "proof_3");
+
+        std::fs::write(&path, "not a proof\n").unwrap();
+        assert!(matches!(FileProofStore::open(&path), Err(VerificationError::Unavailable(_))));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+//! Shared Engine Module
+//!
+//! Cloneable, thread-safe handle to a single `VelocityCryptographicEngine`, so
+//! request handlers in a multi-threaded server can issue proofs onto one chain
+//!
+//! # Locking contract
+//!
+//! Every call locks the whole engine for its duration. Proof issuance appends to
+//! the engine's proof chain, so serializing it is what guarantees each proof gets
+//! a unique block height and links to the proof issued immediately before it.
+//! Calls are short and never block on I/O other than a configured timestamp
+//! authority; one that does block holds up every other caller.
+//!
+//! The lock is not reentrant: calling back into the same `SharedEngine` from the
+//! closure passed to `with_engine` deadlocks. A panic while the lock is held
+//! poisons it and every later call panics.
+
+use crate::{CryptographicProof, Metrics, VelocityCryptographicEngine};
+use std::sync::{Arc, Mutex};
+
+/// `Send + Sync` handle to one engine; clones share the same engine and chain
+#[derive(Clone)]
+pub struct SharedEngine {
+    inner: Arc<Mutex<VelocityCryptographicEngine>>,
+}
+
+impl SharedEngine {
+    /// Handle to a freshly created engine
+    pub fn new() -> Self {
+        Self::from(VelocityCryptographicEngine::new())
+    }
+
+    /// Issue a proof of `proof_type` over `data` and append it to the chain
+    pub fn generate_proof(&self, data: &str, proof_type: &str) -> CryptographicProof {
+        self.with_engine(|engine| engine.create_proof(data, proof_type))
+    }
+
+    /// Verify a JSON proof, rejecting replays within the engine's replay window
+    pub fn verify_proof(&self, proof_json: &str) -> bool {
+        self.with_engine(|engine| engine.verify_cryptographic_proof(proof_json))
+    }
+
+    /// Number of proofs in the chain
+    pub fn chain_height(&self) -> u64 {
+        self.with_engine(|engine| engine.proof_store().len())
+    }
+
+    /// Shared handle to the engine's metrics, readable without taking the engine lock
+    pub fn metrics(&self) -> Arc<Metrics> {
+        self.with_engine(|engine| engine.metrics())
+    }
+
+    /// Run `operation` with exclusive access to the engine
+    ///
+    /// For operations without a dedicated method, such as key rotation. The lock is
+    /// held until `operation` returns.
+    pub fn with_engine<R>(&self, operation: impl FnOnce(&mut VelocityCryptographicEngine) -> R) -> R {
+        let mut engine = self.inner.lock().unwrap();
+        operation(&mut engine)
+    }
+}
+
+impl Default for SharedEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<VelocityCryptographicEngine> for SharedEngine {
+    fn from(engine: VelocityCryptographicEngine) -> Self {
+        SharedEngine {
+            inner: Arc::new(Mutex::new(engine)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GENESIS_PROOF_HASH;
+    use std::collections::HashMap;
+    use std::thread;
+
+    #[test]
+    fn test_engine_is_shareable_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedEngine>();
+    }
+
+    #[test]
+    fn test_concurrent_proofs_keep_chain_consistent() {
+        const THREADS: usize = 16;
+        const PROOFS_PER_THREAD: usize = 50;
+
+        let engine = SharedEngine::new();
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let engine = engine.clone();
+                thread::spawn(move || {
+                    (0..PROOFS_PER_THREAD)
+                        .map(|i| engine.generate_proof(&format!("evidence {} {}", t, i), "evidence"))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let proofs: Vec<CryptographicProof> = handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect();
+
+        let total = (THREADS * PROOFS_PER_THREAD) as u64;
+        assert_eq!(engine.chain_height(), total);
+
+        // Every height from 1 to total is issued exactly once
+        let by_height: HashMap<u64, &CryptographicProof> = proofs.iter().map(|p| (p.block_height, p)).collect();
+        assert_eq!(by_height.len(), proofs.len());
+        assert!((1..=total).all(|height| by_height.contains_key(&height)));
+
+        // Each proof links to the one issued at the height below it
+        for height in 1..=total {
+            let expected = match height {
+                1 => GENESIS_PROOF_HASH,
+                _ => by_height[&(height - 1)].hash.as_str(),
+            };
+            assert_eq!(by_height[&height].previous_hash.as_deref(), Some(expected));
+        }
+
+        assert!(engine.with_engine(|engine| engine.verify_all_signatures()).iter().all(|(_, valid)| *valid));
+        assert_eq!(engine.metrics().snapshot().proof_generation.count, total);
+    }
+
+    #[test]
+    fn test_clones_share_one_engine() {
+        let engine = SharedEngine::new();
+        let proof = engine.clone().generate_proof("shared payload", "evidence");
+        let proof_json = serde_json::to_string(&proof).unwrap();
+
+        assert!(engine.verify_proof(&proof_json));
+        // The replay guard is shared too
+        assert!(!engine.clone().verify_proof(&proof_json));
+    }
+}
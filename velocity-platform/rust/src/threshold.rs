@@ -0,0 +1,388 @@
+//! Threshold Signing Module
+//!
+//! FROST (RFC 9591) threshold Schnorr signatures over ed25519. A trusted dealer
+//! splits a group key into `n` shares; any `t` holders jointly produce one
+//! signature that verifies as a plain ed25519 signature under the group public
+//! key, and no coalition smaller than `t` can sign.
+//!
+//! Signing takes two rounds: each participant publishes a `SigningCommitment`
+//! from `commit`, then answers the full commitment list with a `SignatureShare`
+//! from `sign_round`. Any party holding the `ThresholdGroup` can `aggregate`.
+
+use crate::*;
+use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+use curve25519_dalek::edwards::EdwardsPoint;
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::Identity;
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha512};
+use std::collections::BTreeMap;
+use zeroize::Zeroize;
+
+/// Participant identifier, 1-based; 0 is reserved for the group secret itself
+pub type ParticipantId = u16;
+
+/// RFC 9591 context string of the FROST(Ed25519, SHA-512) ciphersuite
+const CONTEXT_STRING: &[u8] = b"FROST-ED25519-SHA512-v1";
+
+/// Public description of a threshold key
+#[derive(Clone, Debug)]
+pub struct ThresholdGroup {
+    pub threshold: u16,
+    pub participants: u16,
+    /// ed25519 public key the aggregated signatures verify under
+    pub group_public_key: [u8; 32],
+    /// Public half of each participant's share, for checking signature shares
+    verifying_shares: BTreeMap<ParticipantId, EdwardsPoint>,
+}
+
+/// One participant's share of the group secret, wiped on drop
+pub struct KeyShare {
+    pub id: ParticipantId,
+    pub threshold: u16,
+    pub group_public_key: [u8; 32],
+    secret: Scalar,
+}
+
+/// Single-use nonces from `commit`, consumed by `sign_round` and wiped on drop
+pub struct SigningNonces {
+    id: ParticipantId,
+    hiding: Scalar,
+    binding: Scalar,
+    commitment: SigningCommitment,
+}
+
+/// Round-one output a participant broadcasts to the other signers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SigningCommitment {
+    pub id: ParticipantId,
+    hiding: EdwardsPoint,
+    binding: EdwardsPoint,
+}
+
+/// Round-two output a participant sends to the aggregator
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignatureShare {
+    pub id: ParticipantId,
+    share: Scalar,
+}
+
+impl Drop for KeyShare {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl Drop for SigningNonces {
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+/// Split a fresh group key into `n` shares, any `t` of which can sign
+///
+/// The dealer sees the group secret while splitting it and must be trusted to
+/// forget it; the secret is never returned.
+pub fn keygen(t: u16, n: u16) -> Result<(ThresholdGroup, Vec<KeyShare>), VerificationError> {
+    if t < 2 || t > n {
+        return Err(VerificationError::InvalidInput(format!(
+            "Threshold must be between 2 and the participant count, got {}-of-{}",
+            t, n
+        )));
+    }
+
+    // f(x) = a_0 + a_1 x + ... + a_{t-1} x^{t-1}, with a_0 the group secret
+    let mut coefficients: Vec<Scalar> = (0..t).map(|_| random_scalar()).collect();
+    let group_public_key = (ED25519_BASEPOINT_POINT * coefficients[0]).compress().to_bytes();
+
+    let mut verifying_shares = BTreeMap::new();
+    let shares = (1..=n)
+        .map(|id| {
+            let x = Scalar::from(id as u64);
+            let secret = coefficients.iter().rev().fold(Scalar::from(0u64), |acc, coefficient| acc * x + coefficient);
+            verifying_shares.insert(id, ED25519_BASEPOINT_POINT * secret);
+            KeyShare { id, threshold: t, group_public_key, secret }
+        })
+        .collect();
+
+    for coefficient in coefficients.iter_mut() {
+        coefficient.zeroize();
+    }
+
+    Ok((
+        ThresholdGroup { threshold: t, participants: n, group_public_key, verifying_shares },
+        shares,
+    ))
+}
+
+/// Round one: fresh nonces to keep and the commitment to publish
+pub fn commit(share: &KeyShare) -> (SigningNonces, SigningCommitment) {
+    let hiding = random_scalar();
+    let binding = random_scalar();
+    let commitment = SigningCommitment {
+        id: share.id,
+        hiding: ED25519_BASEPOINT_POINT * hiding,
+        binding: ED25519_BASEPOINT_POINT * binding,
+    };
+    (SigningNonces { id: share.id, hiding, binding, commitment }, commitment)
+}
+
+/// Round two: this participant's share of the signature over `message`
+///
+/// `commitments` must come from the same signer set the aggregator will use,
+/// include this participant's own commitment, and number at least the threshold.
+pub fn sign_round(
+    share: &KeyShare,
+    nonces: SigningNonces,
+    message: &[u8],
+    commitments: &[SigningCommitment],
+) -> Result<SignatureShare, VerificationError> {
+    let commitments = sorted_commitments(commitments, share.threshold)?;
+    if nonces.id != share.id || commitments.get(&share.id) != Some(&nonces.commitment) {
+        return Err(VerificationError::InvalidInput(format!(
+            "Commitment list does not carry participant {}'s commitment",
+            share.id
+        )));
+    }
+
+    let binding_factors = binding_factors(&share.group_public_key, message, &commitments);
+    let group_commitment = group_commitment(&commitments, &binding_factors);
+    let challenge = challenge(&group_commitment, &share.group_public_key, message);
+    let lambda = lagrange_coefficient(share.id, commitments.keys());
+
+    Ok(SignatureShare {
+        id: share.id,
+        share: nonces.hiding + nonces.binding * binding_factors[&share.id] + lambda * share.secret * challenge,
+    })
+}
+
+/// Combine signature shares into a 64-byte ed25519 signature
+///
+/// Every share is checked against its participant's verifying share, so a
+/// faulty signer is named instead of producing an invalid signature.
+pub fn aggregate(
+    group: &ThresholdGroup,
+    message: &[u8],
+    commitments: &[SigningCommitment],
+    shares: &[SignatureShare],
+) -> Result<[u8; 64], VerificationError> {
+    let commitments = sorted_commitments(commitments, group.threshold)?;
+    let shares: BTreeMap<ParticipantId, Scalar> = shares.iter().map(|share| (share.id, share.share)).collect();
+    if !shares.keys().eq(commitments.keys()) {
+        return Err(VerificationError::PolicyViolation(
+            "Signature shares must come from exactly the committed signers".to_string(),
+        ));
+    }
+
+    let binding_factors = binding_factors(&group.group_public_key, message, &commitments);
+    let group_commitment = group_commitment(&commitments, &binding_factors);
+    let challenge = challenge(&group_commitment, &group.group_public_key, message);
+
+    for (id, share) in &shares {
+        let verifying_share = group.verifying_shares.get(id).ok_or_else(|| {
+            VerificationError::NotFound(format!("Participant {} is not in the group", id))
+        })?;
+        let commitment = &commitments[id];
+        let lambda = lagrange_coefficient(*id, commitments.keys());
+        let expected = commitment.hiding + commitment.binding * binding_factors[id] + verifying_share * (challenge * lambda);
+        if ED25519_BASEPOINT_POINT * share != expected {
+            return Err(VerificationError::InvalidSignature(format!("Invalid signature share from participant {}", id)));
+        }
+    }
+
+    let z = shares.values().fold(Scalar::from(0u64), |acc, share| acc + share);
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(group_commitment.compress().as_bytes());
+    signature[32..].copy_from_slice(z.as_bytes());
+    Ok(signature)
+}
+
+/// Whether `signature` is a valid ed25519 signature over `message` under the group key
+pub fn verify_group_signature(group_public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+    match (PublicKey::from_bytes(group_public_key), Signature::from_bytes(signature)) {
+        (Ok(public_key), Ok(signature)) => public_key.verify(message, &signature).is_ok(),
+        _ => false,
+    }
+}
+
+fn random_scalar() -> Scalar {
+    let mut bytes = [0u8; 64];
+    rand::RngCore::fill_bytes(&mut OsRng, &mut bytes);
+    let scalar = Scalar::from_bytes_mod_order_wide(&bytes);
+    bytes.zeroize();
+    scalar
+}
+
+fn hash_to_scalar(parts: &[&[u8]]) -> Scalar {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    Scalar::from_bytes_mod_order_wide(&hasher.finalize().into())
+}
+
+fn tagged_digest(tag: &[u8], data: &[u8]) -> [u8; 64] {
+    Sha512::new().chain_update(CONTEXT_STRING).chain_update(tag).chain_update(data).finalize().into()
+}
+
+fn identifier_bytes(id: ParticipantId) -> [u8; 32] {
+    Scalar::from(id as u64).to_bytes()
+}
+
+fn sorted_commitments(
+    commitments: &[SigningCommitment],
+    threshold: u16,
+) -> Result<BTreeMap<ParticipantId, SigningCommitment>, VerificationError> {
+    let mut sorted = BTreeMap::new();
+    for commitment in commitments {
+        if commitment.id == 0 || sorted.insert(commitment.id, *commitment).is_some() {
+            return Err(VerificationError::InvalidInput(format!("Invalid or duplicate participant {}", commitment.id)));
+        }
+    }
+    if sorted.len() < threshold as usize {
+        return Err(VerificationError::PolicyViolation(format!(
+            "{} signers cannot meet a threshold of {}",
+            sorted.len(),
+            threshold
+        )));
+    }
+    Ok(sorted)
+}
+
+/// Per-signer binding factors, tying each nonce pair to the message and signer set
+fn binding_factors(
+    group_public_key: &[u8; 32],
+    message: &[u8],
+    commitments: &BTreeMap<ParticipantId, SigningCommitment>,
+) -> BTreeMap<ParticipantId, Scalar> {
+    let mut encoded = Vec::with_capacity(commitments.len() * 96);
+    for commitment in commitments.values() {
+        encoded.extend_from_slice(&identifier_bytes(commitment.id));
+        encoded.extend_from_slice(commitment.hiding.compress().as_bytes());
+        encoded.extend_from_slice(commitment.binding.compress().as_bytes());
+    }
+    let message_digest = tagged_digest(b"msg", message);
+    let commitments_digest = tagged_digest(b"com", &encoded);
+
+    commitments.keys()
+        .map(|id| {
+            let factor = hash_to_scalar(&[
+                CONTEXT_STRING,
+                b"rho",
+                group_public_key,
+                &message_digest,
+                &commitments_digest,
+                &identifier_bytes(*id),
+            ]);
+            (*id, factor)
+        })
+        .collect()
+}
+
+fn group_commitment(
+    commitments: &BTreeMap<ParticipantId, SigningCommitment>,
+    binding_factors: &BTreeMap<ParticipantId, Scalar>,
+) -> EdwardsPoint {
+    commitments.values().fold(EdwardsPoint::identity(), |acc, commitment| {
+        acc + commitment.hiding + commitment.binding * binding_factors[&commitment.id]
+    })
+}
+
+/// The ed25519 challenge `H(R || A || M)`, so the result verifies as ed25519
+fn challenge(group_commitment: &EdwardsPoint, group_public_key: &[u8; 32], message: &[u8]) -> Scalar {
+    hash_to_scalar(&[group_commitment.compress().as_bytes(), group_public_key, message])
+}
+
+/// Lagrange coefficient at zero of `id` over the signer set
+fn lagrange_coefficient<'a>(id: ParticipantId, signers: impl Iterator<Item = &'a ParticipantId>) -> Scalar {
+    let x_i = Scalar::from(id as u64);
+    let (numerator, denominator) = signers
+        .filter(|other| **other != id)
+        .fold((Scalar::from(1u64), Scalar::from(1u64)), |(num, den), other| {
+            let x_j = Scalar::from(*other as u64);
+            (num * x_j, den * (x_j - x_i))
+        });
+    numerator * denominator.invert()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run both rounds for `signers` and return their commitments and shares
+    fn sign(shares: &[KeyShare], signers: &[ParticipantId], message: &[u8]) -> (Vec<SigningCommitment>, Vec<SignatureShare>) {
+        let signing: Vec<&KeyShare> = shares.iter().filter(|share| signers.contains(&share.id)).collect();
+        let (nonces, commitments): (Vec<SigningNonces>, Vec<SigningCommitment>) =
+            signing.iter().map(|share| commit(share)).unzip();
+        let signature_shares = signing.iter().zip(nonces)
+            .map(|(share, nonces)| sign_round(share, nonces, message, &commitments).unwrap())
+            .collect();
+        (commitments, signature_shares)
+    }
+
+    #[test]
+    fn test_threshold_signature_verifies_as_ed25519() {
+        let (group, shares) = keygen(3, 5).unwrap();
+        let message = b"attestation: org_1 soc2_compliance";
+
+        for signers in [[1, 2, 3], [2, 4, 5], [1, 3, 5]] {
+            let (commitments, signature_shares) = sign(&shares, &signers, message);
+            let signature = aggregate(&group, message, &commitments, &signature_shares).unwrap();
+            assert!(verify_group_signature(&group.group_public_key, message, &signature), "signers {:?}", signers);
+            assert!(!verify_group_signature(&group.group_public_key, b"other message", &signature));
+        }
+
+        // More than the threshold may sign too
+        let (commitments, signature_shares) = sign(&shares, &[1, 2, 3, 4, 5], message);
+        let signature = aggregate(&group, message, &commitments, &signature_shares).unwrap();
+        assert!(verify_group_signature(&group.group_public_key, message, &signature));
+    }
+
+    #[test]
+    fn test_fewer_than_threshold_cannot_sign() {
+        let (group, mut shares) = keygen(3, 5).unwrap();
+        let message = b"attestation";
+
+        let (nonces, commitment) = commit(&shares[0]);
+        let (_, other) = commit(&shares[1]);
+        assert!(matches!(
+            sign_round(&shares[0], nonces, message, &[commitment, other]),
+            Err(VerificationError::PolicyViolation(_))
+        ));
+
+        // Two honest shares interpolated as if the threshold were two recover the
+        // wrong secret, so the result does not verify under the group key
+        for share in shares.iter_mut() {
+            share.threshold = 2;
+        }
+        let mut two_of_two = group.clone();
+        two_of_two.threshold = 2;
+        let (commitments, signature_shares) = sign(&shares, &[1, 2], message);
+        let signature = aggregate(&two_of_two, message, &commitments, &signature_shares).unwrap();
+        assert!(!verify_group_signature(&group.group_public_key, message, &signature));
+
+        // And the real group refuses to aggregate them
+        assert!(matches!(
+            aggregate(&group, message, &commitments, &signature_shares),
+            Err(VerificationError::PolicyViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_aggregate_names_a_bad_share() {
+        let (group, shares) = keygen(2, 3).unwrap();
+        let message = b"attestation";
+        let (commitments, mut signature_shares) = sign(&shares, &[1, 3], message);
+        signature_shares[1].share += Scalar::from(1u64);
+
+        assert_eq!(
+            aggregate(&group, message, &commitments, &signature_shares),
+            Err(VerificationError::InvalidSignature("Invalid signature share from participant 3".to_string()))
+        );
+        assert!(aggregate(&group, message, &commitments, &signature_shares[..1]).is_err());
+        assert!(keygen(1, 3).is_err());
+        assert!(keygen(4, 3).is_err());
+    }
+}
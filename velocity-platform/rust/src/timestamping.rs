@@ -0,0 +1,164 @@
+//! Trusted Timestamping Module
+//!
+//! RFC 3161 timestamp tokens anchor a proof's hash to a time asserted by an
+//! external Time Stamping Authority, so a signer cannot backdate a proof beyond
+//! the allowed skew. The TSA is reached through the `TimestampAuthority` trait;
+//! network clients that speak the RFC 3161 HTTP transport live outside this wasm
+//! crate and plug in via `VelocityCryptographicEngine::set_timestamp_authority`.
+
+use serde::{Deserialize, Serialize};
+use ed25519_dalek::{Keypair, Signature, Signer, Verifier};
+use rand::rngs::OsRng;
+use std::sync::atomic::{AtomicU64, Ordering};
+use chrono::{DateTime, Utc};
+
+/// Largest accepted gap between a proof's own timestamp and its TSA `gen_time`
+pub const MAX_TIMESTAMP_SKEW_SECS: i64 = 300;
+
+/// Message imprint algorithm used for proof hashes
+pub const TIMESTAMP_HASH_ALGORITHM: &str = "sha256";
+
+/// Fields of an RFC 3161 `TSTInfo` needed to check a token, plus the encoded token
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimestampToken {
+    /// Name of the issuing TSA
+    pub tsa: String,
+    pub hash_algorithm: String,
+    /// Hex digest that was timestamped
+    pub message_imprint: String,
+    /// Time asserted by the TSA (RFC 3339)
+    pub gen_time: String,
+    pub serial_number: u64,
+    /// Hex-encoded token as returned by the TSA (DER `TimeStampToken` for RFC 3161 TSAs)
+    pub token: String,
+}
+
+/// Client for a Time Stamping Authority
+pub trait TimestampAuthority: Send + Sync {
+    /// Name recorded in issued tokens
+    fn name(&self) -> &str;
+
+    /// Request a token over `message_imprint`, the raw SHA-256 digest
+    fn timestamp(&self, message_imprint: &[u8]) -> Result<TimestampToken, String>;
+
+    /// Check that `token` was issued by this TSA and its fields are unmodified
+    fn verify_token(&self, token: &TimestampToken) -> Result<(), String>;
+}
+
+/// Check `token` against the TSA and the proof it is attached to
+pub fn verify_timestamp_token(
+    authority: &dyn TimestampAuthority,
+    token: &TimestampToken,
+    proof_hash: &str,
+    proof_timestamp: &str,
+) -> Result<(), String> {
+    if token.tsa != authority.name() {
+        return Err(format!("Token issued by unknown TSA {}", token.tsa));
+    }
+    if token.hash_algorithm != TIMESTAMP_HASH_ALGORITHM {
+        return Err(format!("Unsupported imprint algorithm {}", token.hash_algorithm));
+    }
+    if !crate::ct_eq(&token.message_imprint, proof_hash) {
+        return Err("Token does not cover this proof's hash".to_string());
+    }
+    authority.verify_token(token)?;
+
+    let gen_time = DateTime::parse_from_rfc3339(&token.gen_time)
+        .map_err(|e| format!("Invalid token gen_time: {}", e))?;
+    let asserted = DateTime::parse_from_rfc3339(proof_timestamp)
+        .map_err(|e| format!("Invalid proof timestamp: {}", e))?;
+    let skew = (gen_time - asserted).num_seconds().abs();
+    if skew > MAX_TIMESTAMP_SKEW_SECS {
+        return Err(format!("Proof timestamp is {}s from the TSA time", skew));
+    }
+    Ok(())
+}
+
+/// In-process TSA that signs tokens with its own ed25519 key, for tests and
+/// local development
+pub struct MockTimestampAuthority {
+    name: String,
+    keypair: Keypair,
+    next_serial: AtomicU64,
+}
+
+impl MockTimestampAuthority {
+    pub fn new(name: &str) -> Self {
+        MockTimestampAuthority {
+            name: name.to_string(),
+            keypair: Keypair::generate(&mut OsRng {}),
+            next_serial: AtomicU64::new(1),
+        }
+    }
+
+    /// Issue a token with an explicit `gen_time`
+    pub fn timestamp_at(&self, message_imprint: &[u8], gen_time: DateTime<Utc>) -> TimestampToken {
+        let mut token = TimestampToken {
+            tsa: self.name.clone(),
+            hash_algorithm: TIMESTAMP_HASH_ALGORITHM.to_string(),
+            message_imprint: hex::encode(message_imprint),
+            gen_time: gen_time.to_rfc3339(),
+            serial_number: self.next_serial.fetch_add(1, Ordering::Relaxed),
+            token: String::new(),
+        };
+        token.token = hex::encode(self.keypair.sign(Self::tst_info(&token).as_bytes()).to_bytes());
+        token
+    }
+
+    /// Stand-in for the DER `TSTInfo` covered by the TSA signature
+    fn tst_info(token: &TimestampToken) -> String {
+        format!(
+            "{}|{}|{}|{}|{}",
+            token.tsa, token.hash_algorithm, token.message_imprint, token.gen_time, token.serial_number
+        )
+    }
+}
+
+impl TimestampAuthority for MockTimestampAuthority {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn timestamp(&self, message_imprint: &[u8]) -> Result<TimestampToken, String> {
+        Ok(self.timestamp_at(message_imprint, Utc::now()))
+    }
+
+    fn verify_token(&self, token: &TimestampToken) -> Result<(), String> {
+        let signature_bytes = hex::decode(&token.token).map_err(|e| format!("Invalid token encoding: {}", e))?;
+        let signature = Signature::from_bytes(&signature_bytes).map_err(|e| format!("Invalid token signature: {}", e))?;
+        self.keypair
+            .public
+            .verify(Self::tst_info(token).as_bytes(), &signature)
+            .map_err(|_| "Token signature verification failed".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_from_other_tsa_or_tampered_is_rejected() {
+        let tsa = MockTimestampAuthority::new("tsa-a");
+        let impostor = MockTimestampAuthority::new("tsa-a");
+        let digest = [7u8; 32];
+        let proof_hash = hex::encode(digest);
+        let now = Utc::now();
+
+        let token = tsa.timestamp_at(&digest, now);
+        assert!(verify_timestamp_token(&tsa, &token, &proof_hash, &now.to_rfc3339()).is_ok());
+
+        // Same name, different key
+        let forged = impostor.timestamp_at(&digest, now);
+        assert!(verify_timestamp_token(&tsa, &forged, &proof_hash, &now.to_rfc3339()).is_err());
+
+        // Moving gen_time invalidates the TSA signature
+        let mut tampered = token.clone();
+        tampered.gen_time = (now - chrono::Duration::days(1)).to_rfc3339();
+        let backdated = (now - chrono::Duration::days(1)).to_rfc3339();
+        assert!(verify_timestamp_token(&tsa, &tampered, &proof_hash, &backdated).is_err());
+
+        let other_tsa = MockTimestampAuthority::new("tsa-b");
+        assert!(verify_timestamp_token(&other_tsa, &token, &proof_hash, &now.to_rfc3339()).is_err());
+    }
+}
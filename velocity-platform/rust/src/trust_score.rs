@@ -6,10 +6,41 @@
 use crate::*;
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
-use std::collections::HashMap;
-use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use lru::LruCache;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use uuid::Uuid;
 
+/// Default number of bootstrap resamples for the trust score confidence interval
+pub const DEFAULT_BOOTSTRAP_ITERATIONS: usize = 2_000;
+
+/// Coverage of the trust score confidence interval
+const CONFIDENCE_LEVEL: f64 = 0.95;
+
+/// Two-sided standard normal critical value for `CONFIDENCE_LEVEL`
+const CONFIDENCE_Z: f64 = 1.959_963_984_540_054;
+
+/// Margin used when there are too few components backed by input data to bootstrap
+const FIXED_CONFIDENCE_MARGIN: f64 = 0.05;
+
+/// Components backed by input data needed before the interval is bootstrapped
+const MIN_BOOTSTRAP_COMPONENTS: usize = 2;
+
+/// Default number of verified calculations remembered for fast-path verification
+pub const DEFAULT_VERIFICATION_CACHE_CAPACITY: usize = 1_024;
+
+/// Default number of fast-path verifications between forced full recomputes
+pub const DEFAULT_FULL_RECOMPUTE_INTERVAL: u64 = 100;
+
+/// Default largest trust score drop tolerated within the decline window
+pub const DEFAULT_RAPID_DECLINE_THRESHOLD: f64 = 0.15;
+
+/// Default window, in days, over which a drop counts as rapid
+pub const DEFAULT_RAPID_DECLINE_WINDOW_DAYS: i64 = 30;
+
 /// Trust score with cryptographic proof
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TrustScoreProof {
@@ -87,6 +118,77 @@ pub struct TrustScoreEngine {
     algorithm_version: String,
     weight_matrix: HashMap<String, f64>,
     benchmark_data: HashMap<String, IndustryBenchmark>,
+    bootstrap_iterations: usize,
+    verification_cache: Mutex<VerificationCache>,
+    clock: Arc<dyn Clock>,
+    decline_threshold: f64,
+    decline_window: chrono::Duration,
+    /// Published ring of the engine that signs trust score proofs
+    proof_keys: Option<VerifyingKeys>,
+}
+
+/// Recomputation of a calculation whose full verification succeeded
+#[derive(Clone, Debug)]
+struct VerifiedCalculation {
+    input_data_hash: String,
+    algorithm_hash: String,
+    recalculated_score: f64,
+}
+
+/// Bounded LRU map from `calculation_hash` to its verified recomputation
+#[derive(Debug)]
+struct VerificationCache {
+    /// None when caching is disabled
+    entries: Option<LruCache<String, VerifiedCalculation>>,
+    full_recompute_interval: u64,
+    hits_since_full_recompute: u64,
+}
+
+impl VerificationCache {
+    fn new(capacity: usize, full_recompute_interval: u64) -> Self {
+        VerificationCache {
+            entries: NonZeroUsize::new(capacity).map(LruCache::new),
+            full_recompute_interval,
+            hits_since_full_recompute: 0,
+        }
+    }
+
+    /// Cached recomputation for `proof`, or None when it must be fully recomputed.
+    /// Every `full_recompute_interval`-th hit is turned into a miss.
+    fn lookup(&mut self, proof: &TrustScoreProof) -> Option<VerifiedCalculation> {
+        let entry = self.entries.as_mut()?.get(&proof.calculation_hash)?;
+        if !ct_eq(&entry.input_data_hash, &proof.input_data_hash) || !ct_eq(&entry.algorithm_hash, &proof.algorithm_hash) {
+            return None;
+        }
+        let entry = entry.clone();
+
+        if self.hits_since_full_recompute >= self.full_recompute_interval {
+            self.hits_since_full_recompute = 0;
+            return None;
+        }
+        self.hits_since_full_recompute += 1;
+        Some(entry)
+    }
+
+    fn record(&mut self, proof: &TrustScoreProof, valid: bool, recalculated_score: f64) {
+        let entries = match self.entries.as_mut() {
+            Some(entries) => entries,
+            None => return,
+        };
+        if valid {
+            entries.put(proof.calculation_hash.clone(), VerifiedCalculation {
+                input_data_hash: proof.input_data_hash.clone(),
+                algorithm_hash: proof.algorithm_hash.clone(),
+                recalculated_score,
+            });
+        } else {
+            entries.pop(&proof.calculation_hash);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.entries.as_ref().map_or(0, LruCache::len)
+    }
 }
 
 /// Industry benchmark data
@@ -109,6 +211,15 @@ pub struct TrustScoreCalculation {
     pub risk_factors: Vec<RiskFactor>,
 }
 
+/// Trust score proof with the component scores and risk factors behind it
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DetailedTrustScore {
+    pub proof: TrustScoreProof,
+    pub component_scores: HashMap<String, f64>,
+    pub risk_factors: Vec<RiskFactor>,
+    pub confidence_interval: (f64, f64),
+}
+
 /// Calculation details
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CalculationDetails {
@@ -168,7 +279,69 @@ impl TrustScoreEngine {
             algorithm_version: "Velocity_Trust_Algorithm_v2.1".to_string(),
             weight_matrix,
             benchmark_data: HashMap::new(),
+            bootstrap_iterations: DEFAULT_BOOTSTRAP_ITERATIONS,
+            verification_cache: Mutex::new(VerificationCache::new(
+                DEFAULT_VERIFICATION_CACHE_CAPACITY,
+                DEFAULT_FULL_RECOMPUTE_INTERVAL,
+            )),
+            clock: system_clock(),
+            decline_threshold: DEFAULT_RAPID_DECLINE_THRESHOLD,
+            decline_window: chrono::Duration::days(DEFAULT_RAPID_DECLINE_WINDOW_DAYS),
+            proof_keys: None,
+        }
+    }
+
+    /// Read the current time from `clock` when timestamping proofs and verifications
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Verify trust score proofs against `keys`, the published ring of the
+    /// engine that issues them; until set, no proof verifies
+    pub fn set_proof_keys(&mut self, keys: VerifyingKeys) {
+        self.proof_keys = Some(keys);
+    }
+
+    /// Flag trends whose score falls by more than `max_decline` within `window_days`
+    pub fn set_rapid_decline_threshold(&mut self, max_decline: f64, window_days: i64) -> Result<(), VerificationError> {
+        if !(max_decline > 0.0 && max_decline <= 1.0) || window_days <= 0 {
+            return Err(VerificationError::InvalidInput(format!(
+                "Invalid rapid decline threshold: {} over {} days",
+                max_decline, window_days
+            )));
         }
+        self.decline_threshold = max_decline;
+        self.decline_window = chrono::Duration::days(window_days);
+        Ok(())
+    }
+
+    /// Set the number of resamples used for the confidence interval
+    ///
+    /// Zero disables bootstrapping and always uses the fixed margin.
+    pub fn set_bootstrap_iterations(&mut self, iterations: usize) {
+        self.bootstrap_iterations = iterations;
+    }
+
+    /// Set how many verified calculations are kept for fast-path verification,
+    /// dropping the ones already cached
+    ///
+    /// Zero disables the fast path, so every verification fully recomputes.
+    pub fn set_verification_cache_capacity(&mut self, capacity: usize) {
+        let cache = self.verification_cache.get_mut().unwrap();
+        *cache = VerificationCache::new(capacity, cache.full_recompute_interval);
+    }
+
+    /// Set how many fast-path verifications may pass before a cached calculation
+    /// is fully recomputed again
+    pub fn set_full_recompute_interval(&mut self, interval: u64) {
+        let cache = self.verification_cache.get_mut().unwrap();
+        cache.full_recompute_interval = interval;
+        cache.hits_since_full_recompute = 0;
+    }
+
+    /// Number of calculations currently cached for fast-path verification
+    pub fn verification_cache_len(&self) -> usize {
+        self.verification_cache.lock().unwrap().len()
     }
 
     /// Calculate trust score with cryptographic proof
@@ -176,12 +349,22 @@ impl TrustScoreEngine {
         &self,
         input: &TrustScoreInput,
         crypto_engine: &mut VelocityCryptographicEngine,
-    ) -> Result<TrustScoreProof, String> {
+    ) -> Result<TrustScoreProof, VerificationError> {
+        self.calculate_trust_score_detailed(input, crypto_engine)
+            .map(|detailed| detailed.proof)
+    }
+
+    /// Calculate trust score with cryptographic proof, keeping the breakdown behind it
+    pub fn calculate_trust_score_detailed(
+        &self,
+        input: &TrustScoreInput,
+        crypto_engine: &mut VelocityCryptographicEngine,
+    ) -> Result<DetailedTrustScore, VerificationError> {
         
         // Validate input data
         let validation_result = self.validate_input_data(input)?;
         if !validation_result.is_valid {
-            return Err(format!("Input validation failed: {}", validation_result.error_message));
+            return Err(VerificationError::InvalidInput(format!("Input validation failed: {}", validation_result.error_message)));
         }
 
         // Perform trust score calculation
@@ -193,14 +376,13 @@ impl TrustScoreEngine {
         let calculation_hash = self.hash_calculation(&calculation, &input_data_hash, &algorithm_hash);
 
         // Create cryptographic proof
-        let proof_data = format!(
-            "{}{}{}{}{}",
-            input.organization_id,
-            calculation.final_score,
-            input_data_hash,
-            algorithm_hash,
-            Utc::now().to_rfc3339()
-        );
+        let proof_data = encode_fields(&[
+            &input.organization_id,
+            &calculation.final_score.to_string(),
+            &input_data_hash,
+            &algorithm_hash,
+            &self.clock.now().to_rfc3339(),
+        ]);
         let cryptographic_proof = self.create_trust_proof(&proof_data, crypto_engine);
 
         // Generate benchmark verification
@@ -212,7 +394,7 @@ impl TrustScoreEngine {
         // Get historical proofs
         let historical_proofs = self.get_historical_trust_proofs(&input.organization_id);
 
-        Ok(TrustScoreProof {
+        let proof = TrustScoreProof {
             organization_id: input.organization_id.clone(),
             trust_score: calculation.final_score,
             calculation_hash,
@@ -221,35 +403,60 @@ impl TrustScoreEngine {
             cryptographic_proof,
             historical_proofs,
             benchmark_verification,
+        };
+
+        Ok(DetailedTrustScore {
+            proof,
+            component_scores: calculation.component_scores,
+            risk_factors: calculation.risk_factors,
+            confidence_interval: calculation.confidence_interval,
         })
     }
 
     /// Verify trust score proof
+    ///
+    /// The score is recomputed from `original_input` unless the same calculation
+    /// already passed a full verification with matching input and algorithm
+    /// hashes, in which case the cached recomputation is reused. The signature is
+    /// always checked.
     pub fn verify_trust_score_proof(
         &self,
         proof: &TrustScoreProof,
         original_input: &TrustScoreInput,
-    ) -> Result<TrustScoreVerificationResult, String> {
+    ) -> Result<TrustScoreVerificationResult, VerificationError> {
         
         // Verify input data hash
         let expected_input_hash = self.hash_input_data(original_input);
-        let input_hash_valid = expected_input_hash == proof.input_data_hash;
+        let input_hash_valid = ct_eq(&expected_input_hash, &proof.input_data_hash);
 
         // Verify algorithm hash
         let expected_algorithm_hash = self.hash_algorithm_version();
-        let algorithm_hash_valid = expected_algorithm_hash == proof.algorithm_hash;
-
-        // Recalculate trust score
-        let recalculation = self.perform_calculation(original_input)?;
-        let score_consistent = (recalculation.final_score - proof.trust_score).abs() < 0.001;
+        let algorithm_hash_valid = ct_eq(&expected_algorithm_hash, &proof.algorithm_hash);
 
-        // Verify calculation hash
-        let expected_calculation_hash = self.hash_calculation(
-            &recalculation,
-            &proof.input_data_hash,
-            &proof.algorithm_hash,
-        );
-        let calculation_hash_valid = expected_calculation_hash == proof.calculation_hash;
+        let cached = if input_hash_valid && algorithm_hash_valid {
+            self.verification_cache.lock().unwrap().lookup(proof)
+        } else {
+            None
+        };
+        let fast_path = cached.is_some();
+
+        let (recalculated_score, calculation_hash_valid) = match cached {
+            // The cached recomputation already matched this calculation hash
+            Some(cached) => (cached.recalculated_score, true),
+            None => {
+                // Recalculate trust score
+                let recalculation = self.perform_calculation(original_input)?;
+
+                // Verify calculation hash
+                let expected_calculation_hash = self.hash_calculation(
+                    &recalculation,
+                    &proof.input_data_hash,
+                    &proof.algorithm_hash,
+                );
+                (recalculation.final_score, ct_eq(&expected_calculation_hash, &proof.calculation_hash))
+            }
+        };
+        let score_consistent = (recalculated_score - proof.trust_score).abs() < 0.001;
 
         // Verify cryptographic proof
         let crypto_proof_valid = self.verify_cryptographic_proof(&proof.cryptographic_proof);
@@ -263,9 +470,14 @@ impl TrustScoreEngine {
             crypto_proof_valid,
         );
 
+        let is_valid = input_hash_valid && algorithm_hash_valid && score_consistent &&
+            calculation_hash_valid && crypto_proof_valid;
+        if input_hash_valid && algorithm_hash_valid && !fast_path {
+            self.verification_cache.lock().unwrap().record(proof, is_valid, recalculated_score);
+        }
+
         Ok(TrustScoreVerificationResult {
-            is_valid: input_hash_valid && algorithm_hash_valid && score_consistent && 
-                     calculation_hash_valid && crypto_proof_valid,
+            is_valid,
             verification_confidence,
             verification_details: TrustScoreVerificationDetails {
                 input_hash_valid,
@@ -273,13 +485,57 @@ impl TrustScoreEngine {
                 score_consistent,
                 calculation_hash_valid,
                 crypto_proof_valid,
-                recalculated_score: recalculation.final_score,
-                score_difference: (recalculation.final_score - proof.trust_score).abs(),
+                recalculated_score,
+                score_difference: (recalculated_score - proof.trust_score).abs(),
+                fast_path,
             },
-            timestamp: Utc::now().to_rfc3339(),
+            timestamp: self.clock.now().to_rfc3339(),
         })
     }
 
+    /// Whether `a`'s trust score is meaningfully higher or lower than `b`'s
+    ///
+    /// Each score's standard error is recovered from its confidence interval and the
+    /// difference is given a two-sided z-test at the interval's confidence level.
+    /// The effect size is the difference in units of the pooled standard error, so
+    /// it stays comparable between pairs with different amounts of input data.
+    pub fn compare_entities(a: &DetailedTrustScore, b: &DetailedTrustScore) -> TrustScoreComparison {
+        let standard_error = |score: &DetailedTrustScore| {
+            (score.confidence_interval.1 - score.confidence_interval.0).max(0.0) / (2.0 * CONFIDENCE_Z)
+        };
+        let (se_a, se_b) = (standard_error(a), standard_error(b));
+        let difference = a.proof.trust_score - b.proof.trust_score;
+        let variance = se_a.powi(2) + se_b.powi(2);
+
+        let (z_score, effect_size) = if variance > 0.0 {
+            (difference / variance.sqrt(), difference / (variance / 2.0).sqrt())
+        } else if difference == 0.0 {
+            (0.0, 0.0)
+        } else {
+            // Exact scores: any difference is certain
+            (difference.signum() * f64::INFINITY, difference.signum() * f64::INFINITY)
+        };
+        let p_value = 2.0 * (1.0 - standard_normal_cdf(z_score.abs()));
+
+        let outcome = if p_value >= 1.0 - CONFIDENCE_LEVEL {
+            ComparisonOutcome::NotSignificant
+        } else if difference > 0.0 {
+            ComparisonOutcome::AHigher
+        } else {
+            ComparisonOutcome::BHigher
+        };
+
+        TrustScoreComparison {
+            organization_a: a.proof.organization_id.clone(),
+            organization_b: b.proof.organization_id.clone(),
+            difference,
+            z_score,
+            p_value,
+            effect_size,
+            outcome,
+        }
+    }
+
     /// Get trust score trends with cryptographic verification
     pub fn get_trust_score_trends(
         &self,
@@ -318,19 +574,55 @@ impl TrustScoreEngine {
             0.0
         };
 
+        let prediction_confidence = self.calculate_prediction_confidence(&scores);
+        let next_expected_range = self.predict_next_score_range(&scores);
+        let rapid_decline = self.find_rapid_decline(&scores);
+
         TrustScoreTrends {
             organization_id: organization_id.to_string(),
             historical_scores: scores,
             trend_direction,
             volatility,
-            prediction_confidence: self.calculate_prediction_confidence(&scores),
-            next_expected_range: self.predict_next_score_range(&scores),
+            prediction_confidence,
+            next_expected_range,
+            rapid_decline,
+        }
+    }
+
+    /// Steepest drop beyond the decline threshold between two scores no further
+    /// apart than the decline window
+    fn find_rapid_decline(&self, scores: &[(String, f64)]) -> Option<RapidDeclineAlert> {
+        let timed: Vec<(chrono::DateTime<chrono::Utc>, &str, f64)> = scores.iter()
+            .filter_map(|(timestamp, score)| {
+                chrono::DateTime::parse_from_rfc3339(timestamp).ok()
+                    .map(|at| (at.with_timezone(&chrono::Utc), timestamp.as_str(), *score))
+            })
+            .collect();
+
+        let mut worst: Option<RapidDeclineAlert> = None;
+        for (i, (from_at, from_timestamp, from_score)) in timed.iter().enumerate() {
+            for (to_at, to_timestamp, to_score) in &timed[i + 1..] {
+                if *to_at - *from_at > self.decline_window {
+                    break;
+                }
+                let decline = from_score - to_score;
+                if decline > self.decline_threshold && worst.as_ref().is_none_or(|alert| decline > alert.decline) {
+                    worst = Some(RapidDeclineAlert {
+                        from_timestamp: from_timestamp.to_string(),
+                        to_timestamp: to_timestamp.to_string(),
+                        from_score: *from_score,
+                        to_score: *to_score,
+                        decline,
+                    });
+                }
+            }
         }
+        worst
     }
 
     // Private helper methods
 
-    fn validate_input_data(&self, input: &TrustScoreInput) -> Result<InputValidationResult, String> {
+    fn validate_input_data(&self, input: &TrustScoreInput) -> Result<InputValidationResult, VerificationError> {
         let mut validation_errors = Vec::new();
 
         // Validate organization ID
@@ -338,20 +630,42 @@ impl TrustScoreEngine {
             validation_errors.push("Organization ID cannot be empty".to_string());
         }
 
-        // Validate compliance scores
+        // Validate compliance scores; range checks also reject NaN, which would
+        // otherwise propagate into the final score and serialize as null
         for (framework, score) in &input.compliance_scores {
-            if *score < 0.0 || *score > 1.0 {
+            if !(0.0..=1.0).contains(score) {
                 validation_errors.push(format!("Invalid compliance score for {}: {}", framework, score));
             }
         }
 
         // Validate audit results
         for audit in &input.audit_results {
-            if audit.score < 0.0 || audit.score > 100.0 {
+            if !(0.0..=100.0).contains(&audit.score) {
                 validation_errors.push(format!("Invalid audit score: {}", audit.score));
             }
         }
 
+        // Validate certification confidence
+        for (certification, status) in &input.certification_status {
+            if !status.confidence_level.is_finite() {
+                validation_errors.push(format!("Invalid confidence for {}: {}", certification, status.confidence_level));
+            }
+        }
+
+        // Validate historical performance
+        for metric in &input.historical_performance {
+            if !metric.value.is_finite() {
+                validation_errors.push(format!("Invalid value for metric {}: {}", metric.metric_name, metric.value));
+            }
+        }
+
+        // Validate peer comparisons
+        for comparison in &input.peer_comparisons {
+            if !(0.0..=100.0).contains(&comparison.percentile_ranking) {
+                validation_errors.push(format!("Invalid percentile ranking: {}", comparison.percentile_ranking));
+            }
+        }
+
         Ok(InputValidationResult {
             is_valid: validation_errors.is_empty(),
             error_message: validation_errors.join("; "),
@@ -359,7 +673,7 @@ impl TrustScoreEngine {
         })
     }
 
-    fn perform_calculation(&self, input: &TrustScoreInput) -> Result<TrustScoreCalculation, String> {
+    fn perform_calculation(&self, input: &TrustScoreInput) -> Result<TrustScoreCalculation, VerificationError> {
         let mut component_scores = HashMap::new();
         let mut computation_steps = Vec::new();
         let mut risk_factors = Vec::new();
@@ -400,8 +714,10 @@ impl TrustScoreEngine {
         let peer_score = self.calculate_peer_score(&input.peer_comparisons);
         component_scores.insert("peer_comparison".to_string(), peer_score);
 
-        // Calculate weighted final score
+        // Calculate weighted final score, summing in key order so the result is reproducible
         let final_score = component_scores.iter()
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
             .map(|(component, score)| {
                 let weight = self.weight_matrix.get(component).unwrap_or(&0.0);
                 score * weight
@@ -427,8 +743,20 @@ impl TrustScoreEngine {
             });
         }
 
-        // Calculate confidence interval
-        let confidence_interval = self.calculate_confidence_interval(final_score, &component_scores);
+        // Calculate confidence interval from the components that have input data;
+        // defaults for missing inputs carry no information about uncertainty
+        let observed_components: BTreeMap<&str, f64> = [
+            ("compliance_framework", !input.compliance_scores.is_empty()),
+            ("audit_results", !input.audit_results.is_empty()),
+            ("certifications", !input.certification_status.is_empty()),
+            ("historical_performance", !input.historical_performance.is_empty()),
+            ("peer_comparison", !input.peer_comparisons.is_empty()),
+        ]
+        .into_iter()
+        .filter(|(_, observed)| *observed)
+        .map(|(component, _)| (component, component_scores[component]))
+        .collect();
+        let confidence_interval = self.calculate_confidence_interval(final_score, &observed_components);
 
         Ok(TrustScoreCalculation {
             final_score,
@@ -451,7 +779,7 @@ impl TrustScoreEngine {
     }
 
     fn hash_input_data(&self, input: &TrustScoreInput) -> String {
-        let serialized = serde_json::to_string(input).unwrap_or_default();
+        let serialized = canonical_json(input).unwrap_or_default();
         let mut hasher = Sha256::new();
         hasher.update(serialized.as_bytes());
         hex::encode(hasher.finalize())
@@ -464,13 +792,12 @@ impl TrustScoreEngine {
     }
 
     fn hash_calculation(&self, calculation: &TrustScoreCalculation, input_hash: &str, algorithm_hash: &str) -> String {
-        let data = format!(
-            "{}{}{}{}",
-            calculation.final_score,
-            serde_json::to_string(&calculation.component_scores).unwrap_or_default(),
+        let data = encode_fields(&[
+            &canonical_json(&calculation.final_score).unwrap_or_default(),
+            &canonical_json(&calculation.component_scores).unwrap_or_default(),
             input_hash,
-            algorithm_hash
-        );
+            algorithm_hash,
+        ]);
         let mut hasher = Sha256::new();
         hasher.update(data.as_bytes());
         hex::encode(hasher.finalize())
@@ -483,22 +810,29 @@ impl TrustScoreEngine {
             hex::encode(hasher.finalize())
         };
 
-        CryptographicProof {
-            id: format!("trust_proof_{}", Uuid::new_v4()),
+        let id = format!("trust_proof_{}", Uuid::new_v4());
+        let nonce = CryptographicProof::generate_nonce();
+        let timestamp = self.clock.now().to_rfc3339();
+
+        let mut proof = CryptographicProof {
+            format_version: CURRENT_PROOF_FORMAT_VERSION,
+            id,
             hash: hash.clone(),
-            signature: self.sign_data(&hash),
-            timestamp: Utc::now().to_rfc3339(),
+            signature: String::new(),
+            timestamp,
             previous_hash: None,
             merkle_root: None,
             block_height: 0,
             verification_status: "verified".to_string(),
-        }
-    }
-
-    fn sign_data(&self, data: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(format!("sign_{}", data).as_bytes());
-        hex::encode(hasher.finalize())
+            nonce,
+            key_id: None,
+            domain: Some(DOMAIN_TRUST_SCORE.to_string()),
+            timestamp_token: None,
+            pq_signature: None,
+            genesis_anchor: None,
+        };
+        crypto_engine.sign_proof(&mut proof);
+        proof
     }
 
     fn create_benchmark_verification(&self, calculation: &TrustScoreCalculation, org_id: &str) -> BenchmarkVerification {
@@ -533,7 +867,7 @@ impl TrustScoreEngine {
     fn verify_cryptographic_proof(&self, proof: &CryptographicProof) -> bool {
         proof.verification_status == "verified" && 
         proof.hash.len() == 64 && 
-        proof.signature.len() > 0
+        self.proof_keys.as_ref().is_some_and(|keys| verify_domain_proof(keys, proof, DOMAIN_TRUST_SCORE))
     }
 
     fn calculate_verification_confidence(&self, input_valid: bool, algo_valid: bool, score_consistent: bool, calc_valid: bool, crypto_valid: bool) -> f64 {
@@ -546,7 +880,7 @@ impl TrustScoreEngine {
         if scores.is_empty() {
             return 0.0;
         }
-        scores.values().sum::<f64>() / scores.len() as f64
+        scores.iter().collect::<BTreeMap<_, _>>().values().copied().sum::<f64>() / scores.len() as f64
     }
 
     fn calculate_audit_score(&self, audits: &[AuditResult]) -> f64 {
@@ -561,7 +895,9 @@ impl TrustScoreEngine {
         if certs.is_empty() {
             return 0.0;
         }
-        let active_score: f64 = certs.values()
+        let active_score: f64 = certs.iter()
+            .collect::<BTreeMap<_, _>>()
+            .into_values()
             .map(|c| if c.is_active { c.confidence_level } else { 0.0 })
             .sum();
         active_score / certs.len() as f64
@@ -581,9 +917,57 @@ impl TrustScoreEngine {
         comparisons.iter().map(|p| p.percentile_ranking / 100.0).sum::<f64>() / comparisons.len() as f64
     }
 
-    fn calculate_confidence_interval(&self, score: f64, _components: &HashMap<String, f64>) -> (f64, f64) {
-        let margin = 0.05; // 5% margin of error
-        ((score - margin).max(0.0), (score + margin).min(1.0))
+    /// Bootstrap confidence interval around `score`
+    ///
+    /// Each resample draws as many components as were observed, with replacement
+    /// and in proportion to their weights, and takes the mean. The spread of those
+    /// means around the observed weighted mean is applied to `score`. The RNG is
+    /// seeded from the components, so the same input always gives the same interval.
+    fn calculate_confidence_interval(&self, score: f64, components: &BTreeMap<&str, f64>) -> (f64, f64) {
+        let weighted: Vec<(f64, f64)> = components.iter()
+            .map(|(component, value)| (*value, *self.weight_matrix.get(*component).unwrap_or(&0.0)))
+            .filter(|(_, weight)| *weight > 0.0)
+            .collect();
+        let total_weight: f64 = weighted.iter().map(|(_, weight)| weight).sum();
+
+        if weighted.len() < MIN_BOOTSTRAP_COMPONENTS || self.bootstrap_iterations == 0 {
+            return ((score - FIXED_CONFIDENCE_MARGIN).max(0.0), (score + FIXED_CONFIDENCE_MARGIN).min(1.0));
+        }
+
+        let observed_mean = weighted.iter().map(|(value, weight)| value * weight).sum::<f64>() / total_weight;
+        let seed = {
+            let mut hasher = Sha256::new();
+            hasher.update(canonical_json(components).unwrap_or_default().as_bytes());
+            let digest = hasher.finalize();
+            u64::from_le_bytes(digest[..8].try_into().expect("SHA-256 digest is 32 bytes"))
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut deviations: Vec<f64> = (0..self.bootstrap_iterations)
+            .map(|_| {
+                let resample_sum: f64 = (0..weighted.len())
+                    .map(|_| {
+                        let mut target = rng.gen::<f64>() * total_weight;
+                        weighted.iter()
+                            .find(|(_, weight)| {
+                                target -= weight;
+                                target < 0.0
+                            })
+                            .unwrap_or(&weighted[weighted.len() - 1])
+                            .0
+                    })
+                    .sum();
+                resample_sum / weighted.len() as f64 - observed_mean
+            })
+            .collect();
+        deviations.sort_by(|a, b| a.total_cmp(b));
+
+        let quantile = |q: f64| deviations[((deviations.len() - 1) as f64 * q).round() as usize];
+        let alpha = 1.0 - CONFIDENCE_LEVEL;
+        let lower = score + quantile(alpha / 2.0);
+        let upper = score + quantile(1.0 - alpha / 2.0);
+
+        (lower.clamp(0.0, 1.0), upper.clamp(0.0, 1.0))
     }
 
     fn assess_input_quality(&self, input: &TrustScoreInput) -> f64 {
@@ -647,6 +1031,22 @@ impl TrustScoreEngine {
     }
 }
 
+/// Standard normal CDF via the Abramowitz and Stegun 7.1.26 approximation of erf,
+/// accurate to about 1e-7
+fn standard_normal_cdf(z: f64) -> f64 {
+    if z.is_infinite() {
+        return if z > 0.0 { 1.0 } else { 0.0 };
+    }
+
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let polynomial = t * (0.254_829_592
+        + t * (-0.284_496_736 + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - polynomial * (-x * x).exp();
+
+    if z >= 0.0 { 0.5 * (1.0 + erf) } else { 0.5 * (1.0 - erf) }
+}
+
 /// Supporting structures
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -673,6 +1073,9 @@ pub struct TrustScoreVerificationDetails {
     pub crypto_proof_valid: bool,
     pub recalculated_score: f64,
     pub score_difference: f64,
+    /// Whether the score came from the verification cache instead of a full recompute
+    #[serde(default)]
+    pub fast_path: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -683,6 +1086,41 @@ pub struct TrustScoreTrends {
     pub volatility: f64,
     pub prediction_confidence: f64,
     pub next_expected_range: (f64, f64),
+    /// Steepest drop beyond the engine's rapid decline threshold, if any
+    #[serde(default)]
+    pub rapid_decline: Option<RapidDeclineAlert>,
+}
+
+/// Interval over which a trust score fell faster than the configured threshold
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RapidDeclineAlert {
+    pub from_timestamp: String,
+    pub to_timestamp: String,
+    pub from_score: f64,
+    pub to_score: f64,
+    pub decline: f64,
+}
+
+/// Result of comparing two entities' trust scores
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TrustScoreComparison {
+    pub organization_a: String,
+    pub organization_b: String,
+    /// Score of A minus score of B
+    pub difference: f64,
+    pub z_score: f64,
+    /// Two-sided p-value of the difference
+    pub p_value: f64,
+    /// Difference in units of the pooled standard error
+    pub effect_size: f64,
+    pub outcome: ComparisonOutcome,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComparisonOutcome {
+    AHigher,
+    BHigher,
+    NotSignificant,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -691,4 +1129,411 @@ pub enum TrendDirection {
     Declining,
     Stable,
     Insufficient,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input(frameworks: &[(&str, f64)]) -> TrustScoreInput {
+        let mut compliance_scores = HashMap::new();
+        let mut certification_status = HashMap::new();
+        for (framework, score) in frameworks {
+            compliance_scores.insert(framework.to_string(), *score);
+            certification_status.insert(
+                framework.to_string(),
+                CertificationStatus {
+                    is_active: true,
+                    expiration_date: None,
+                    issuing_authority: format!("{} Authority", framework),
+                    confidence_level: *score,
+                },
+            );
+        }
+
+        TrustScoreInput {
+            organization_id: "org_canonical".to_string(),
+            compliance_scores,
+            audit_results: Vec::new(),
+            certification_status,
+            historical_performance: Vec::new(),
+            peer_comparisons: Vec::new(),
+            calculation_timestamp: "2025-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_hashes_independent_of_map_insertion_order() {
+        let engine = TrustScoreEngine::new();
+        let frameworks: Vec<(String, f64)> = (0..32)
+            .map(|i| (format!("framework_{}", i), 0.5 + i as f64 / 100.0))
+            .collect();
+        let forward: Vec<(&str, f64)> = frameworks.iter().map(|(f, s)| (f.as_str(), *s)).collect();
+        let reverse: Vec<(&str, f64)> = forward.iter().rev().cloned().collect();
+
+        let input_a = test_input(&forward);
+        let input_b = test_input(&reverse);
+        assert_eq!(engine.hash_input_data(&input_a), engine.hash_input_data(&input_b));
+
+        let algorithm_hash = engine.hash_algorithm_version();
+        let calc_a = engine.perform_calculation(&input_a).unwrap();
+        let calc_b = engine.perform_calculation(&input_b).unwrap();
+        assert_eq!(calc_a.final_score.to_bits(), calc_b.final_score.to_bits());
+        assert_eq!(
+            engine.hash_calculation(&calc_a, "input", &algorithm_hash),
+            engine.hash_calculation(&calc_b, "input", &algorithm_hash)
+        );
+    }
+
+    #[test]
+    fn test_proof_verifies_against_reordered_input() {
+        let mut engine = TrustScoreEngine::new();
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let forward = [("SOC2", 0.92), ("ISO27001", 0.88), ("GDPR", 0.75), ("HIPAA", 0.81)];
+        let reverse: Vec<(&str, f64)> = forward.iter().rev().cloned().collect();
+
+        let proof = engine.calculate_trust_score(&test_input(&forward), &mut crypto_engine).unwrap();
+        let result = engine.verify_trust_score_proof(&proof, &test_input(&reverse)).unwrap();
+
+        assert!(result.verification_details.input_hash_valid);
+        assert!(result.verification_details.calculation_hash_valid);
+        assert!(result.is_valid);
+    }
+
+    #[test]
+    fn test_timestamps_come_from_engine_clock() {
+        // Proofs are only valid once the signing key exists
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let issued_at = chrono::Utc::now();
+        let clock = Arc::new(MockClock::new(issued_at));
+        let mut engine = TrustScoreEngine::new();
+        engine.set_clock(clock.clone());
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let input = test_input(&[("SOC2", 0.92), ("ISO27001", 0.88)]);
+
+        let proof = engine.calculate_trust_score(&input, &mut crypto_engine).unwrap();
+        assert_eq!(proof.cryptographic_proof.timestamp, issued_at.to_rfc3339());
+
+        clock.advance(chrono::Duration::days(400));
+        let result = engine.verify_trust_score_proof(&proof, &input).unwrap();
+        assert_eq!(result.timestamp, (issued_at + chrono::Duration::days(400)).to_rfc3339());
+        assert!(result.is_valid);
+    }
+
+    /// Input with every component observed and set to the given scores (0.0-1.0)
+    fn full_input(compliance: f64, audit: f64, certification: f64, historical: f64, peer: f64) -> TrustScoreInput {
+        let mut input = test_input(&[("SOC2", compliance)]);
+        input.certification_status.get_mut("SOC2").unwrap().confidence_level = certification;
+        input.audit_results.push(AuditResult {
+            audit_id: "audit_1".to_string(),
+            framework: "SOC2".to_string(),
+            score: audit * 100.0,
+            auditor_id: "auditor_1".to_string(),
+            completion_date: "2024-12-01T00:00:00Z".to_string(),
+            findings_count: 0,
+            remediation_status: "closed".to_string(),
+        });
+        input.historical_performance.push(PerformanceMetric {
+            metric_name: "uptime".to_string(),
+            value: historical,
+            measurement_date: "2024-12-01T00:00:00Z".to_string(),
+            source: "monitoring".to_string(),
+        });
+        input.peer_comparisons.push(PeerComparison {
+            industry_segment: "fintech".to_string(),
+            percentile_ranking: peer * 100.0,
+            anonymized_peer_data: Vec::new(),
+            comparison_hash: String::new(),
+        });
+        input
+    }
+
+    #[test]
+    fn test_bootstrap_interval_reflects_component_spread() {
+        let engine = TrustScoreEngine::new();
+
+        let tight = engine.perform_calculation(&full_input(0.90, 0.91, 0.89, 0.90, 0.92)).unwrap();
+        let wide = engine.perform_calculation(&full_input(0.95, 0.20, 0.90, 0.10, 0.85)).unwrap();
+
+        let width = |calculation: &TrustScoreCalculation| calculation.confidence_interval.1 - calculation.confidence_interval.0;
+        assert!(width(&tight) < 0.03, "tight interval {:?}", tight.confidence_interval);
+        assert!(width(&wide) > 0.3, "wide interval {:?}", wide.confidence_interval);
+        for calculation in [&tight, &wide] {
+            let (lower, upper) = calculation.confidence_interval;
+            assert!(lower <= calculation.final_score && calculation.final_score <= upper);
+        }
+
+        // Seeded from the components, so recalculation reproduces the interval
+        let again = engine.perform_calculation(&full_input(0.95, 0.20, 0.90, 0.10, 0.85)).unwrap();
+        assert_eq!(again.confidence_interval, wide.confidence_interval);
+    }
+
+    #[test]
+    fn test_fixed_margin_without_enough_data() {
+        let mut engine = TrustScoreEngine::new();
+
+        // Only the compliance component has input data
+        let mut input = test_input(&[("SOC2", 0.9)]);
+        input.certification_status.clear();
+        let calculation = engine.perform_calculation(&input).unwrap();
+        let (lower, upper) = calculation.confidence_interval;
+        assert!((upper - lower - 2.0 * FIXED_CONFIDENCE_MARGIN).abs() < 1e-12);
+
+        engine.set_bootstrap_iterations(0);
+        let calculation = engine.perform_calculation(&full_input(0.95, 0.20, 0.90, 0.10, 0.85)).unwrap();
+        let (lower, upper) = calculation.confidence_interval;
+        assert!((upper - lower - 2.0 * FIXED_CONFIDENCE_MARGIN).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_non_finite_inputs_rejected() {
+        let engine = TrustScoreEngine::new();
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+
+        let mut nan_compliance = test_input(&[("SOC2", 0.92)]);
+        nan_compliance.compliance_scores.insert("GDPR".to_string(), f64::NAN);
+
+        let mut infinite_metric = test_input(&[("SOC2", 0.92)]);
+        infinite_metric.historical_performance.push(PerformanceMetric {
+            metric_name: "uptime".to_string(),
+            value: f64::INFINITY,
+            measurement_date: "2025-01-01T00:00:00Z".to_string(),
+            source: "monitoring".to_string(),
+        });
+
+        let mut nan_peer = test_input(&[("SOC2", 0.92)]);
+        nan_peer.peer_comparisons.push(PeerComparison {
+            industry_segment: "fintech".to_string(),
+            percentile_ranking: f64::NAN,
+            anonymized_peer_data: Vec::new(),
+            comparison_hash: String::new(),
+        });
+
+        let mut nan_certification = test_input(&[("SOC2", 0.92)]);
+        nan_certification.certification_status.get_mut("SOC2").unwrap().confidence_level = f64::NAN;
+
+        for input in [nan_compliance, infinite_metric, nan_peer, nan_certification] {
+            assert!(matches!(
+                engine.calculate_trust_score(&input, &mut crypto_engine),
+                Err(VerificationError::InvalidInput(message)) if message.starts_with("Input validation failed")
+            ));
+        }
+
+        let proof = engine.calculate_trust_score(&test_input(&[("SOC2", 0.92)]), &mut crypto_engine).unwrap();
+        assert!(serde_json::to_value(&proof).unwrap()["trust_score"].is_f64());
+    }
+
+    #[test]
+    fn test_tampered_hashes_and_signature_rejected() {
+        let mut engine = TrustScoreEngine::new();
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let input = test_input(&[("SOC2", 0.92), ("ISO27001", 0.88)]);
+        let proof = engine.calculate_trust_score(&input, &mut crypto_engine).unwrap();
+
+        let mut tampered = proof.clone();
+        tampered.calculation_hash = proof.input_data_hash.clone();
+        let result = engine.verify_trust_score_proof(&tampered, &input).unwrap();
+        assert!(!result.verification_details.calculation_hash_valid);
+        assert!(!result.is_valid);
+
+        let mut forged = proof.clone();
+        forged.cryptographic_proof.signature = "0".repeat(64);
+        let result = engine.verify_trust_score_proof(&forged, &input).unwrap();
+        assert!(!result.verification_details.crypto_proof_valid);
+
+        // A hash of the signed fields is not a signature
+        let mut rehashed = proof.clone();
+        rehashed.cryptographic_proof.signature = hex::encode(Sha256::digest(
+            format!("sign_{}", proof.cryptographic_proof.signing_payload()).as_bytes(),
+        ));
+        let result = engine.verify_trust_score_proof(&rehashed, &input).unwrap();
+        assert!(!result.verification_details.crypto_proof_valid);
+
+        let mut swapped_nonce = proof;
+        swapped_nonce.cryptographic_proof.nonce = CryptographicProof::generate_nonce();
+        let result = engine.verify_trust_score_proof(&swapped_nonce, &input).unwrap();
+        assert!(!result.verification_details.crypto_proof_valid);
+    }
+
+    #[test]
+    fn test_fast_path_matches_full_verification() {
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let mut engine = TrustScoreEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let mut uncached = TrustScoreEngine::new();
+        uncached.set_verification_cache_capacity(0);
+        uncached.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let input = test_input(&[("SOC2", 0.92), ("ISO27001", 0.88)]);
+        let proof = engine.calculate_trust_score(&input, &mut crypto_engine).unwrap();
+
+        let full = engine.verify_trust_score_proof(&proof, &input).unwrap();
+        assert!(full.is_valid);
+        assert!(!full.verification_details.fast_path);
+        let fast = engine.verify_trust_score_proof(&proof, &input).unwrap();
+        assert!(fast.verification_details.fast_path);
+        assert_eq!(fast.is_valid, full.is_valid);
+        assert_eq!(fast.verification_confidence, full.verification_confidence);
+        assert_eq!(fast.verification_details.recalculated_score, full.verification_details.recalculated_score);
+
+        // Proof-specific tampering is caught on the fast path exactly as on a full recompute
+        let mut inflated = proof.clone();
+        inflated.trust_score += 0.1;
+        let mut forged = proof.clone();
+        forged.cryptographic_proof.signature = "0".repeat(64);
+        for tampered in [&inflated, &forged] {
+            let fast = engine.verify_trust_score_proof(tampered, &input).unwrap();
+            let full = uncached.verify_trust_score_proof(tampered, &input).unwrap();
+            assert!(fast.verification_details.fast_path);
+            assert!(!full.verification_details.fast_path);
+            assert!(!fast.is_valid);
+            assert_eq!(fast.is_valid, full.is_valid);
+            assert_eq!(fast.verification_confidence, full.verification_confidence);
+        }
+
+        // A different input never takes the fast path
+        let other_input = test_input(&[("SOC2", 0.5)]);
+        let result = engine.verify_trust_score_proof(&proof, &other_input).unwrap();
+        assert!(!result.verification_details.fast_path);
+        assert!(!result.is_valid);
+    }
+
+    #[test]
+    fn test_periodic_full_recompute() {
+        let mut engine = TrustScoreEngine::new();
+        engine.set_full_recompute_interval(2);
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let input = test_input(&[("SOC2", 0.92)]);
+        let proof = engine.calculate_trust_score(&input, &mut crypto_engine).unwrap();
+
+        let fast_paths: Vec<bool> = (0..7)
+            .map(|_| engine.verify_trust_score_proof(&proof, &input).unwrap())
+            .inspect(|result| assert!(result.is_valid))
+            .map(|result| result.verification_details.fast_path)
+            .collect();
+        assert_eq!(fast_paths, [false, true, true, false, true, true, false]);
+    }
+
+    #[test]
+    fn test_verification_cache_evicts_least_recently_used() {
+        let mut engine = TrustScoreEngine::new();
+        engine.set_verification_cache_capacity(2);
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        engine.set_proof_keys(crypto_engine.key_ring().verifying_keys().clone());
+        let inputs: Vec<TrustScoreInput> = [0.6, 0.7, 0.8]
+            .iter()
+            .map(|score| test_input(&[("SOC2", *score)]))
+            .collect();
+        let proofs: Vec<TrustScoreProof> = inputs.iter()
+            .map(|input| engine.calculate_trust_score(input, &mut crypto_engine).unwrap())
+            .collect();
+        let fast_path = |i: usize| {
+            let result = engine.verify_trust_score_proof(&proofs[i], &inputs[i]).unwrap();
+            assert!(result.is_valid);
+            result.verification_details.fast_path
+        };
+
+        assert!(!fast_path(0));
+        assert!(!fast_path(1));
+        assert!(fast_path(0));
+        assert_eq!(engine.verification_cache_len(), 2);
+
+        // Proof 1 is the least recently used entry, so caching proof 2 evicts it
+        assert!(!fast_path(2));
+        assert_eq!(engine.verification_cache_len(), 2);
+        assert!(fast_path(0));
+        assert!(fast_path(2));
+        assert!(!fast_path(1));
+    }
+
+    #[test]
+    fn test_compare_entities_accounts_for_uncertainty() {
+        let engine = TrustScoreEngine::new();
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let base = engine
+            .calculate_trust_score_detailed(&test_input(&[("SOC2", 0.8), ("ISO27001", 0.9)]), &mut crypto_engine)
+            .unwrap();
+        let entity = |organization_id: &str, score: f64, interval: (f64, f64)| {
+            let mut entity = base.clone();
+            entity.proof.organization_id = organization_id.to_string();
+            entity.proof.trust_score = score;
+            entity.confidence_interval = interval;
+            entity
+        };
+
+        // Intervals overlap well beyond the difference
+        let vendor_a = entity("vendor_a", 0.74, (0.64, 0.84));
+        let vendor_b = entity("vendor_b", 0.70, (0.60, 0.80));
+        let comparison = TrustScoreEngine::compare_entities(&vendor_a, &vendor_b);
+        assert_eq!(comparison.outcome, ComparisonOutcome::NotSignificant);
+        assert!((comparison.difference - 0.04).abs() < 1e-12);
+        assert!(comparison.p_value > 0.05, "p = {}", comparison.p_value);
+        assert_eq!(comparison.organization_a, "vendor_a");
+
+        // Tight intervals far apart
+        let vendor_c = entity("vendor_c", 0.90, (0.88, 0.92));
+        let vendor_d = entity("vendor_d", 0.60, (0.57, 0.63));
+        let comparison = TrustScoreEngine::compare_entities(&vendor_c, &vendor_d);
+        assert_eq!(comparison.outcome, ComparisonOutcome::AHigher);
+        assert!(comparison.p_value < 1e-6);
+        assert!(comparison.effect_size > 10.0);
+
+        let reversed = TrustScoreEngine::compare_entities(&vendor_d, &vendor_c);
+        assert_eq!(reversed.outcome, ComparisonOutcome::BHigher);
+        assert!((reversed.z_score + comparison.z_score).abs() < 1e-12);
+        assert!((reversed.p_value - comparison.p_value).abs() < 1e-12);
+
+        let comparison = TrustScoreEngine::compare_entities(&vendor_a, &vendor_a);
+        assert_eq!(comparison.outcome, ComparisonOutcome::NotSignificant);
+        assert!((comparison.p_value - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_standard_normal_cdf() {
+        assert!((standard_normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((standard_normal_cdf(CONFIDENCE_Z) - 0.975).abs() < 1e-6);
+        assert!((standard_normal_cdf(-CONFIDENCE_Z) - 0.025).abs() < 1e-6);
+        assert_eq!(standard_normal_cdf(f64::INFINITY), 1.0);
+        assert_eq!(standard_normal_cdf(f64::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_rapid_decline_alert_reports_drop_interval() {
+        let start = chrono::DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z").unwrap().with_timezone(&chrono::Utc);
+        let mut engine = TrustScoreEngine::new();
+        engine.set_clock(Arc::new(MockClock::new(start)));
+        let proof = engine
+            .calculate_trust_score(&test_input(&[("SOC2", 0.9)]), &mut VelocityCryptographicEngine::new())
+            .unwrap();
+        let history = |series: &[(i64, f64)]| -> Vec<TrustScoreProof> {
+            series.iter()
+                .map(|(day, score)| {
+                    let mut historical = proof.clone();
+                    historical.trust_score = *score;
+                    historical.cryptographic_proof.timestamp = (start + chrono::Duration::days(*day)).to_rfc3339();
+                    historical
+                })
+                .collect()
+        };
+        let at = |day: i64| (start + chrono::Duration::days(day)).to_rfc3339();
+
+        // Gentle drift, then a sharp single-period drop between days 60 and 67
+        let proofs = history(&[(0, 0.86), (30, 0.85), (60, 0.84), (67, 0.62), (90, 0.64)]);
+        let trends = engine.get_trust_score_trends("org_1", &proofs);
+        let alert = trends.rapid_decline.unwrap();
+        assert_eq!((alert.from_timestamp.as_str(), alert.to_timestamp.as_str()), (at(60).as_str(), at(67).as_str()));
+        assert_eq!((alert.from_score, alert.to_score), (0.84, 0.62));
+
+        // A steady slide over a year is not rapid
+        let proofs = history(&[(0, 0.86), (90, 0.81), (180, 0.74), (270, 0.70), (360, 0.66)]);
+        assert!(engine.get_trust_score_trends("org_1", &proofs).rapid_decline.is_none());
+
+        // Unless the window is widened to cover it
+        engine.set_rapid_decline_threshold(0.1, 180).unwrap();
+        let alert = engine.get_trust_score_trends("org_1", &proofs).rapid_decline.unwrap();
+        assert_eq!((alert.from_timestamp, alert.to_timestamp), (at(0), at(180)));
+        assert!(engine.set_rapid_decline_threshold(0.0, 30).is_err());
+        assert!(engine.set_rapid_decline_threshold(0.1, 0).is_err());
+    }
+}
@@ -0,0 +1,291 @@
+//! Verify-Only Engine Module
+//!
+//! `VerifierEngine` checks proofs issued by a `VelocityCryptographicEngine`
+//! using nothing but the issuer's published public keys. Auditors and relying
+//! parties can run it without holding, or being able to load, a signing key:
+//! it has no key ring secret, no encryption key and no proof store, so it
+//! exposes no way to issue proofs or evidence.
+
+use crate::*;
+
+/// Proof verification against an issuer's public keys
+pub struct VerifierEngine {
+    keys: VerifyingKeys,
+    /// Issuer's ML-DSA-65 public key, for proofs carrying a co-signature
+    #[cfg(feature = "pq")]
+    pq_public_key: Option<Vec<u8>>,
+    hybrid_policy: HybridPolicy,
+    replay_guard: Mutex<ReplayGuard>,
+    clock: Arc<dyn Clock>,
+    /// How far a proof's timestamp may precede the previous proof's
+    clock_skew_tolerance: chrono::Duration,
+    timestamp_authority: Option<Arc<dyn TimestampAuthority>>,
+    /// `previous_hash` the issuer's first proof must link from
+    genesis_hash: String,
+}
+
+impl VerifierEngine {
+    pub fn new(keys: VerifyingKeys) -> Self {
+        VerifierEngine {
+            keys,
+            #[cfg(feature = "pq")]
+            pq_public_key: None,
+            hybrid_policy: HybridPolicy::default(),
+            replay_guard: Mutex::new(ReplayGuard::default()),
+            clock: system_clock(),
+            clock_skew_tolerance: chrono::Duration::seconds(DEFAULT_CLOCK_SKEW_TOLERANCE_SECONDS),
+            timestamp_authority: None,
+            genesis_hash: GENESIS_PROOF_HASH.to_string(),
+        }
+    }
+
+    /// Verifier for the key ring that published `entries`
+    pub fn from_entries(entries: Vec<KeyRingEntry>) -> Result<Self, VerificationError> {
+        Ok(Self::new(VerifyingKeys::from_entries(entries)?))
+    }
+
+    /// Keys proofs are verified against
+    pub fn verifying_keys(&self) -> &VerifyingKeys {
+        &self.keys
+    }
+
+    /// Check post-quantum co-signatures against `public_key`, or treat them as
+    /// unverifiable with `None`
+    #[cfg(feature = "pq")]
+    pub fn set_pq_public_key(&mut self, public_key: Option<Vec<u8>>) {
+        self.pq_public_key = public_key;
+    }
+
+    /// Require the ed25519 and post-quantum signatures of proofs as `policy` says
+    pub fn set_hybrid_policy(&mut self, policy: HybridPolicy) {
+        self.hybrid_policy = policy;
+    }
+
+    /// Set the replay protection window in seconds, between 1 and
+    /// `MAX_REPLAY_WINDOW_SECS`
+    pub fn set_replay_window(&mut self, window_secs: i64) -> Result<(), String> {
+        replay_window(window_secs)?;
+        let mut guard = ReplayGuard::new(window_secs);
+        guard.set_future_tolerance(self.clock_skew_tolerance);
+        self.replay_guard = Mutex::new(guard);
+        Ok(())
+    }
+
+    /// Read the current time from `clock` when checking the replay window
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Allow a proof's timestamp to precede the previous proof's in a chain by
+    /// up to `tolerance`, and a proof to be timestamped that far ahead of the
+    /// clock; should match the issuer's setting
+    pub fn set_clock_skew_tolerance(&mut self, tolerance: chrono::Duration) -> Result<(), String> {
+        if tolerance < chrono::Duration::zero() {
+            return Err("Clock skew tolerance cannot be negative".to_string());
+        }
+        self.clock_skew_tolerance = tolerance;
+        self.replay_guard.get_mut().unwrap().set_future_tolerance(tolerance);
+        Ok(())
+    }
+
+    /// Check TSA tokens on proofs against `authority`; proofs carrying a token
+    /// fail verification while none is set
+    pub fn set_timestamp_authority(&mut self, authority: Option<Arc<dyn TimestampAuthority>>) {
+        self.timestamp_authority = authority;
+    }
+
+    /// Expect the issuer's chain to start from the genesis derived from
+    /// `anchor`, as set by `VelocityCryptographicEngine::with_genesis`, or from
+    /// the shared zero genesis with `None`
+    pub fn set_genesis_anchor(&mut self, anchor: Option<&str>) {
+        self.genesis_hash = genesis_hash_for(anchor);
+    }
+
+    /// Check `proof`'s signatures under the verifier's `HybridPolicy`
+    pub fn verify_proof_signatures(&self, proof: &CryptographicProof) -> HybridVerification {
+        check_proof_signatures(&self.keys, self.pq_verifying_key(), self.hybrid_policy, proof, verify_hex_signature)
+    }
+
+    /// Verify a single proof: its signatures, hash format and TSA token, and
+    /// for a first proof, that it starts from the expected genesis
+    ///
+    /// Without the issuer's store there is no chain to look the proof up in;
+    /// use `verify_chain` to check that proofs link up.
+    pub fn verify_proof(&self, proof: &CryptographicProof) -> bool {
+        if !self.verify_proof_signatures(proof).is_valid || !is_proof_hash(&proof.hash) {
+            return false;
+        }
+        if !proof.genesis_link_consistent(&self.genesis_hash) {
+            return false;
+        }
+        match (&proof.timestamp_token, &self.timestamp_authority) {
+            (None, _) => true,
+            (Some(token), Some(authority)) => {
+                verify_timestamp_token(authority.as_ref(), token, &proof.hash, &proof.timestamp).is_ok()
+            }
+            (Some(_), None) => false,
+        }
+    }
+
+    /// Verify a JSON proof, rejecting proofs already seen within the replay window
+    pub fn verify_cryptographic_proof(&self, proof_json: &str) -> bool {
+        match serde_json::from_str::<CryptographicProof>(proof_json) {
+            Ok(proof) => self.verify_proof(&proof) && check_replay(&self.replay_guard, &proof, self.clock.now()),
+            Err(_) => false,
+        }
+    }
+
+    /// Verify a whole chain, from its first proof, as exported by the issuer
+    ///
+    /// Returns `(index, valid)` in chain order, checking signatures in parallel
+    /// and then hash linkage and timestamp order in one sequential pass.
+    pub fn verify_chain(&self, proofs: &[CryptographicProof]) -> Vec<(usize, bool)> {
+        let links: Vec<ProofLink> = proofs
+            .par_iter()
+            .map(|proof| ProofLink::new(proof.clone(), self.verify_proof_signatures(proof).is_valid))
+            .collect();
+        link_chain_results(0, &self.genesis_hash, None, links, self.clock_skew_tolerance)
+    }
+
+    #[cfg(feature = "pq")]
+    fn pq_verifying_key(&self) -> Option<&[u8]> {
+        self.pq_public_key.as_deref()
+    }
+
+    #[cfg(not(feature = "pq"))]
+    fn pq_verifying_key(&self) -> Option<&[u8]> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(engine: &mut VelocityCryptographicEngine, data: &str) -> CryptographicProof {
+        serde_json::from_str(&engine.generate_cryptographic_proof(data, "evidence")).unwrap()
+    }
+
+    fn exported_chain(engine: &VelocityCryptographicEngine) -> Vec<CryptographicProof> {
+        let store = engine.proof_store();
+        (1..=store.len()).filter_map(|height| store.get_by_height(height)).collect()
+    }
+
+    #[test]
+    fn test_verifies_proofs_from_published_keys() {
+        let mut issuer = VelocityCryptographicEngine::new();
+        let before_rotation = issue(&mut issuer, "first payload");
+        issuer.rotate_key();
+        let after_rotation = issue(&mut issuer, "second payload");
+
+        let verifier = VerifierEngine::from_entries(issuer.key_ring().entries()).unwrap();
+        assert!(verifier.verify_proof(&before_rotation));
+        assert!(verifier.verify_cryptographic_proof(&serde_json::to_string(&after_rotation).unwrap()));
+        assert_eq!(verifier.verify_proof_signatures(&after_rotation).verified_with, Some(SignatureScheme::Classical));
+
+        // Replays are rejected as on the issuer
+        assert!(!verifier.verify_cryptographic_proof(&serde_json::to_string(&after_rotation).unwrap()));
+
+        let mut tampered = after_rotation.clone();
+        tampered.hash = "0".repeat(64);
+        assert!(!verifier.verify_proof(&tampered));
+
+        // Keys from another issuer verify nothing
+        let stranger = VerifierEngine::from_entries(VelocityCryptographicEngine::new().key_ring().entries()).unwrap();
+        assert!(!stranger.verify_proof(&before_rotation));
+    }
+
+    #[test]
+    fn test_verifies_exported_chain() {
+        let mut issuer = VelocityCryptographicEngine::new();
+        for data in ["a", "b", "c"] {
+            issue(&mut issuer, data);
+        }
+        let verifier = VerifierEngine::new(issuer.key_ring().verifying_keys().clone());
+
+        let mut chain = exported_chain(&issuer);
+        assert!(verifier.verify_chain(&chain).iter().all(|(_, valid)| *valid));
+
+        // Dropping a proof breaks the link of the one after it
+        chain.remove(1);
+        assert_eq!(verifier.verify_chain(&chain), vec![(0, true), (1, false)]);
+    }
+
+    #[test]
+    fn test_checks_chain_starts_from_issuer_genesis() {
+        let mut issuer = VelocityCryptographicEngine::with_genesis("org_acme/mainnet");
+        for data in ["a", "b"] {
+            issue(&mut issuer, data);
+        }
+        let chain = exported_chain(&issuer);
+        let mut verifier = VerifierEngine::new(issuer.key_ring().verifying_keys().clone());
+
+        // Without the issuer's anchor the chain does not start from the expected genesis
+        assert_eq!(verifier.verify_chain(&chain), vec![(0, false), (1, true)]);
+        assert!(!verifier.verify_proof(&chain[0]));
+
+        verifier.set_genesis_anchor(Some("org_acme/mainnet"));
+        assert!(verifier.verify_chain(&chain).iter().all(|(_, valid)| *valid));
+        assert!(verifier.verify_proof(&chain[0]));
+
+        verifier.set_genesis_anchor(Some("org_globex/mainnet"));
+        assert_eq!(verifier.verify_chain(&chain), vec![(0, false), (1, true)]);
+    }
+
+    #[test]
+    fn test_rejects_edited_genesis_position() {
+        let mut issuer = VelocityCryptographicEngine::with_genesis("org_acme/mainnet");
+        for data in ["a", "b"] {
+            issue(&mut issuer, data);
+        }
+        let chain = exported_chain(&issuer);
+        let mut verifier = VerifierEngine::new(issuer.key_ring().verifying_keys().clone());
+        verifier.set_genesis_anchor(Some("org_acme/mainnet"));
+        assert!(verifier.verify_proof(&chain[0]));
+
+        // Moving the first proof off height 1 to skip the genesis check
+        let mut moved = chain[0].clone();
+        moved.block_height = 2;
+        assert!(!verifier.verify_proof(&moved));
+
+        // Relabelling it as the first proof of another chain
+        let mut relabelled = chain[0].clone();
+        relabelled.genesis_anchor = Some("org_globex/mainnet".to_string());
+        relabelled.previous_hash = Some(genesis_hash_for(Some("org_globex/mainnet")));
+        verifier.set_genesis_anchor(Some("org_globex/mainnet"));
+        assert!(!verifier.verify_proof(&relabelled));
+        assert_eq!(verifier.verify_chain(&[relabelled, chain[1].clone()]), vec![(0, false), (1, true)]);
+
+        // Proofs signed before the chain position was covered still cannot be moved
+        verifier.set_genesis_anchor(Some("org_acme/mainnet"));
+        let mut legacy = chain[0].clone();
+        legacy.format_version = PROOF_FORMAT_V2;
+        legacy.signature = issuer.sign_data(&legacy.signed_payload().unwrap());
+        assert!(verifier.verify_proof(&legacy));
+        legacy.block_height = 2;
+        assert!(verifier.verify_proof_signatures(&legacy).is_valid);
+        assert!(!verifier.verify_proof(&legacy));
+    }
+
+    #[test]
+    fn test_rejects_mismatched_key_listing() {
+        let issuer = VelocityCryptographicEngine::new();
+        let mut entries = issuer.key_ring().entries();
+        entries[0].public_key = hex::encode(VelocityCryptographicEngine::new().key_ring().active_public_key().as_bytes());
+        assert!(matches!(VerifierEngine::from_entries(entries), Err(VerificationError::InvalidInput(_))));
+        assert!(VerifierEngine::from_entries(Vec::new()).is_err());
+    }
+
+    #[cfg(feature = "pq")]
+    #[test]
+    fn test_checks_pq_co_signature_with_published_key() {
+        let mut issuer = VelocityCryptographicEngine::new();
+        issuer.set_pq_keypair(Some(MlDsa65Keypair::generate()));
+        let proof = issue(&mut issuer, "evidence payload");
+
+        let mut verifier = VerifierEngine::from_entries(issuer.key_ring().entries()).unwrap();
+        assert!(!verifier.verify_proof(&proof));
+        verifier.set_pq_public_key(issuer.pq_public_key().map(<[u8]>::to_vec));
+        assert_eq!(verifier.verify_proof_signatures(&proof).verified_with, Some(SignatureScheme::Hybrid));
+    }
+}
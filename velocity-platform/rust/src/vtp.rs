@@ -7,7 +7,10 @@ use crate::*;
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
 use std::collections::HashMap;
-use chrono::{DateTime, Utc};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+use chrono::DateTime;
 use uuid::Uuid;
 
 /// Smart contract for trust verification
@@ -60,7 +63,7 @@ pub struct ExecutionRule {
 }
 
 /// Contract actions
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ContractAction {
     ValidateTrustScore,
     RequestAttestation,
@@ -85,6 +88,18 @@ pub struct ContractState {
     pub pending_rewards: f64,
     pub violation_count: u32,
     pub state_variables: HashMap<String, serde_json::Value>,
+    /// Escalation rules whose trigger has matched and are not yet resolved, by rule id
+    #[serde(default)]
+    pub open_escalations: HashMap<String, OpenEscalation>,
+}
+
+/// Escalation rule that has triggered and is waiting out its timeout
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OpenEscalation {
+    /// When the trigger first matched
+    pub triggered_at: String,
+    /// Whether the escalation event has been raised
+    pub escalated: bool,
 }
 
 /// Contract status
@@ -109,6 +124,46 @@ pub struct ContractExecution {
     pub state_changes: Vec<StateChange>,
     pub timestamp: String,
     pub block_height: u64,
+    /// Outcome of each rule that was attempted, in execution order
+    #[serde(default)]
+    pub rule_results: Vec<RuleResult>,
+    /// Escalations raised by this execution
+    #[serde(default)]
+    pub escalations: Vec<EscalationEvent>,
+}
+
+/// Outcome of one rule within a contract execution
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RuleResult {
+    pub rule_id: String,
+    pub result: ExecutionResult,
+}
+
+/// Error recorded for a rule whose action outlived its `timeout_seconds`
+pub const RULE_TIMEOUT_ERROR: &str = "timeout";
+
+/// What `execute_contract` does after a rule fails or times out
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleFailurePolicy {
+    /// Skip the remaining rules and fail the execution
+    Halt,
+    /// Run the remaining rules and report a partial success
+    Continue,
+}
+
+/// Custom implementation of a contract action, such as one backed by an oracle
+///
+/// Handlers run on a worker thread against a copy of the contract state, which
+/// replaces the contract's state only if the handler finishes within the rule's
+/// `timeout_seconds`. A handler that overruns is abandoned, not interrupted, so
+/// it must not have side effects beyond the state it is given.
+pub trait ActionHandler: Send + Sync {
+    fn execute(
+        &self,
+        rule: &ExecutionRule,
+        state: &mut ContractState,
+        event_data: &HashMap<String, serde_json::Value>,
+    ) -> Result<Vec<StateChange>, String>;
 }
 
 /// Execution result
@@ -129,6 +184,108 @@ pub struct StateChange {
     pub change_reason: String,
 }
 
+/// Subscription filter matching events from every contract
+pub const ALL_CONTRACTS: &str = "*";
+
+/// Notable state transition published when a contract execution commits
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ContractEvent {
+    TrustScoreChanged {
+        contract_id: String,
+        execution_id: String,
+        old_score: f64,
+        new_score: f64,
+    },
+    ViolationRecorded {
+        contract_id: String,
+        execution_id: String,
+        violation_count: u32,
+    },
+    RewardReleased {
+        contract_id: String,
+        execution_id: String,
+        amount: f64,
+    },
+    Escalated(EscalationEvent),
+}
+
+impl ContractEvent {
+    pub fn contract_id(&self) -> &str {
+        match self {
+            ContractEvent::TrustScoreChanged { contract_id, .. }
+            | ContractEvent::ViolationRecorded { contract_id, .. }
+            | ContractEvent::RewardReleased { contract_id, .. } => contract_id,
+            ContractEvent::Escalated(escalation) => &escalation.contract_id,
+        }
+    }
+
+    /// Events implied by an execution's state changes, in the order they were made
+    fn from_state_changes(contract_id: &str, execution_id: &str, changes: &[StateChange]) -> Vec<ContractEvent> {
+        changes.iter()
+            .filter_map(|change| {
+                let old = change.old_value.as_f64()?;
+                let new = change.new_value.as_f64()?;
+                match change.variable_name.as_str() {
+                    "current_trust_score" if old != new => Some(ContractEvent::TrustScoreChanged {
+                        contract_id: contract_id.to_string(),
+                        execution_id: execution_id.to_string(),
+                        old_score: old,
+                        new_score: new,
+                    }),
+                    "violation_count" if new > old => Some(ContractEvent::ViolationRecorded {
+                        contract_id: contract_id.to_string(),
+                        execution_id: execution_id.to_string(),
+                        violation_count: new as u32,
+                    }),
+                    "pending_rewards" if new < old => Some(ContractEvent::RewardReleased {
+                        contract_id: contract_id.to_string(),
+                        execution_id: execution_id.to_string(),
+                        amount: old - new,
+                    }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Handle returned by `VTPEngine::subscribe`, used to unsubscribe
+pub type SubscriptionId = u64;
+
+/// Callback invoked synchronously for each matching event
+pub type ContractEventHandler = Box<dyn Fn(ContractEvent) + Send + Sync>;
+
+/// Fans contract events out to subscribers
+#[derive(Default)]
+pub struct ContractEventBus {
+    /// Subscription id, contract id or `ALL_CONTRACTS`, and handler
+    subscribers: Vec<(SubscriptionId, String, ContractEventHandler)>,
+    next_id: SubscriptionId,
+}
+
+impl ContractEventBus {
+    pub fn subscribe(&mut self, contract_id: &str, handler: ContractEventHandler) -> SubscriptionId {
+        self.next_id += 1;
+        self.subscribers.push((self.next_id, contract_id.to_string(), handler));
+        self.next_id
+    }
+
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let before = self.subscribers.len();
+        self.subscribers.retain(|(subscription_id, _, _)| *subscription_id != id);
+        self.subscribers.len() < before
+    }
+
+    /// Deliver `event` to every subscriber of its contract and every wildcard subscriber
+    pub fn publish(&self, event: &ContractEvent) {
+        for (_, contract_id, handler) in &self.subscribers {
+            if contract_id == ALL_CONTRACTS || contract_id == event.contract_id() {
+                handler(event.clone());
+            }
+        }
+    }
+}
+
 /// Penalty condition
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PenaltyCondition {
@@ -179,8 +336,21 @@ pub struct EscalationRule {
     pub required_signatures: u32,
 }
 
+/// Escalation raised when an escalation rule's trigger stays unresolved past its timeout
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EscalationEvent {
+    pub escalation_id: String,
+    pub contract_id: String,
+    pub rule_id: String,
+    pub escalation_target: EscalationTarget,
+    /// Signatures the target must collect to act on the escalation
+    pub required_signatures: u32,
+    pub triggered_at: String,
+    pub escalated_at: String,
+}
+
 /// Escalation targets
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EscalationTarget {
     RegulatoryCISA,
     ISACA,
@@ -201,6 +371,34 @@ pub struct MultiSigAttestation {
     pub attestation_data: AttestationData,
     pub completion_status: AttestationStatus,
     pub deadline: String,
+    /// Hex FROST group key when the attestation takes one threshold signature
+    /// in place of individually collected signatures
+    #[serde(default)]
+    pub threshold_group_key: Option<String>,
+    /// Hex aggregated group signature over `signing_message`
+    #[serde(default)]
+    pub threshold_signature: Option<String>,
+}
+
+impl MultiSigAttestation {
+    /// Message threshold signers sign, binding the attestation to its data
+    pub fn signing_message(&self) -> String {
+        let confidence_level = self.attestation_data.confidence_level.to_string();
+        let validity_period = self.attestation_data.validity_period.to_string();
+        domain_separated(
+            DOMAIN_THRESHOLD_ATTESTATION,
+            &encode_fields(&[
+                &self.attestation_id,
+                &self.contract_id,
+                &self.attestation_data.subject,
+                &self.attestation_data.claim_type,
+                &self.attestation_data.evidence_hash,
+                &confidence_level,
+                &validity_period,
+                &self.deadline,
+            ]),
+        )
+    }
 }
 
 /// Attestation signature
@@ -271,8 +469,19 @@ pub struct VTPEngine {
     oracles: HashMap<String, VelocityOracle>,
     gas_tracker: GasTracker,
     consensus_engine: ConsensusEngine,
+    clock: Arc<dyn Clock>,
+    action_handlers: HashMap<ContractAction, Arc<dyn ActionHandler>>,
+    rule_failure_policy: RuleFailurePolicy,
+    event_bus: ContractEventBus,
+    id_strategy: IdStrategy,
 }
 
+/// Gas charged for every executed rule on top of its action's cost
+pub const BASE_RULE_GAS: u64 = 1000;
+
+/// Action cost used when an action is missing from `execution_costs`
+pub const DEFAULT_ACTION_GAS: u64 = 500;
+
 /// Gas tracking for contract execution
 #[derive(Clone, Debug)]
 pub struct GasTracker {
@@ -281,6 +490,29 @@ pub struct GasTracker {
     pub total_gas_used: u64,
 }
 
+impl GasTracker {
+    /// Cost of each action, in proportion to the state it reads and writes
+    pub fn default_execution_costs() -> HashMap<ContractAction, u64> {
+        HashMap::from([
+            (ContractAction::ValidateTrustScore, 2000),
+            (ContractAction::RequestAttestation, 1500),
+            (ContractAction::UpdateTrustScore, 3000),
+            (ContractAction::TriggerAudit, DEFAULT_ACTION_GAS),
+            (ContractAction::FreezeAssets, DEFAULT_ACTION_GAS),
+            (ContractAction::ReleaseRewards, DEFAULT_ACTION_GAS),
+            (ContractAction::EscalateToRegulator, DEFAULT_ACTION_GAS),
+            (ContractAction::NotifyStakeholders, DEFAULT_ACTION_GAS),
+            (ContractAction::RevokeCertification, DEFAULT_ACTION_GAS),
+            (ContractAction::UpdateComplianceStatus, DEFAULT_ACTION_GAS),
+        ])
+    }
+
+    /// Gas a rule performing `action` consumes
+    pub fn rule_cost(&self, action: ContractAction) -> u64 {
+        BASE_RULE_GAS + self.execution_costs.get(&action).copied().unwrap_or(DEFAULT_ACTION_GAS)
+    }
+}
+
 /// Consensus engine for distributed verification
 #[derive(Clone, Debug)]
 pub struct ConsensusEngine {
@@ -317,7 +549,7 @@ impl VTPEngine {
             oracles: HashMap::new(),
             gas_tracker: GasTracker {
                 base_gas_price: 1000,
-                execution_costs: HashMap::new(),
+                execution_costs: GasTracker::default_execution_costs(),
                 total_gas_used: 0,
             },
             consensus_engine: ConsensusEngine {
@@ -325,9 +557,49 @@ impl VTPEngine {
                 consensus_threshold: 0.67,
                 block_time_seconds: 10,
             },
+            clock: system_clock(),
+            action_handlers: HashMap::new(),
+            rule_failure_policy: RuleFailurePolicy::Halt,
+            event_bus: ContractEventBus::default(),
+            id_strategy: IdStrategy::default(),
         }
     }
 
+    /// Read the current time from `clock` for deadlines, timestamps and block heights
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Assign contract, execution, attestation, oracle and escalation ids with `strategy`
+    pub fn set_id_strategy(&mut self, strategy: IdStrategy) {
+        self.id_strategy = strategy;
+    }
+
+    /// Run `action` through `handler` instead of its built-in implementation
+    pub fn register_action_handler(&mut self, action: ContractAction, handler: Arc<dyn ActionHandler>) {
+        self.action_handlers.insert(action, handler);
+    }
+
+    /// Choose whether execution stops at the first failed rule, the default, or
+    /// carries on with the rest
+    pub fn set_rule_failure_policy(&mut self, policy: RuleFailurePolicy) {
+        self.rule_failure_policy = policy;
+    }
+
+    /// Call `handler` with every event from `contract_id`, or from all contracts
+    /// when `contract_id` is `ALL_CONTRACTS`
+    ///
+    /// Handlers run on the executing thread once the execution is recorded, so a
+    /// slow handler delays `execute_contract`.
+    pub fn subscribe(&mut self, contract_id: &str, handler: ContractEventHandler) -> SubscriptionId {
+        self.event_bus.subscribe(contract_id, handler)
+    }
+
+    /// Stop delivering events to a subscription, returning whether it existed
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        self.event_bus.unsubscribe(id)
+    }
+
     /// Deploy new trust contract
     pub fn deploy_trust_contract(
         &mut self,
@@ -336,18 +608,23 @@ impl VTPEngine {
         trust_parameters: TrustParameters,
         execution_rules: Vec<ExecutionRule>,
         crypto_engine: &mut VelocityCryptographicEngine,
-    ) -> Result<VelocityTrustContract, String> {
+    ) -> Result<VelocityTrustContract, VerificationError> {
         
-        let contract_id = format!("vtp_contract_{}", Uuid::new_v4());
+        let contract_id = self.id_strategy.generate(
+            "vtp_contract",
+            &(&contract_type, organization_id, &trust_parameters, &execution_rules),
+        );
+        if self.contracts.contains_key(&contract_id) {
+            return Err(VerificationError::PolicyViolation("Contract already deployed".to_string()));
+        }
         
         // Create deployment proof
-        let deployment_data = format!(
-            "{}{}{}{}",
-            contract_id,
-            serde_json::to_string(&contract_type).unwrap_or_default(),
+        let deployment_data = encode_fields(&[
+            &contract_id,
+            &serde_json::to_string(&contract_type).unwrap_or_default(),
             organization_id,
-            Utc::now().to_rfc3339()
-        );
+            &self.clock.now().to_rfc3339(),
+        ]);
         let deployment_proof = self.create_contract_proof(&deployment_data, crypto_engine);
 
         // Initialize contract state
@@ -360,6 +637,7 @@ impl VTPEngine {
             pending_rewards: 0.0,
             violation_count: 0,
             state_variables: HashMap::new(),
+            open_escalations: HashMap::new(),
         };
 
         let contract = VelocityTrustContract {
@@ -384,45 +662,57 @@ impl VTPEngine {
         contract_id: &str,
         trigger_event: &str,
         event_data: &HashMap<String, serde_json::Value>,
-    ) -> Result<ContractExecution, String> {
+    ) -> Result<ContractExecution, VerificationError> {
         
         let contract = self.contracts.get_mut(contract_id)
-            .ok_or("Contract not found")?;
+            .ok_or_else(|| VerificationError::NotFound("Contract not found".to_string()))?;
 
         if !matches!(contract.state.status, ContractStatus::Active | ContractStatus::Deployed) {
-            return Err("Contract is not in executable state".to_string());
+            return Err(VerificationError::PolicyViolation("Contract is not in executable state".to_string()));
         }
 
-        let execution_id = format!("exec_{}", Uuid::new_v4());
+        let execution_id = self.id_strategy.generate(
+            "exec",
+            &(contract_id, contract.execution_history.len(), trigger_event, event_data),
+        );
         let mut executed_rules = Vec::new();
         let mut total_gas_used = 0u64;
         let mut state_changes = Vec::new();
 
-        // Find applicable execution rules
-        let applicable_rules: Vec<&ExecutionRule> = contract.execution_rules.iter()
-            .filter(|rule| self.evaluate_rule_condition(&rule.condition, trigger_event, event_data))
-            .collect();
-
-        // Execute rules in priority order
-        let mut sorted_rules = applicable_rules;
-        sorted_rules.sort_by_key(|rule| rule.priority);
+        // Execute applicable rules in priority order
+        let sorted_rules = self.applicable_rules(&contract.execution_rules, trigger_event, event_data);
 
-        let mut execution_result = ExecutionResult::Success;
+        let mut rule_results = Vec::new();
+        let mut failures = Vec::new();
 
-        for rule in sorted_rules {
+        for rule in &sorted_rules {
             match self.execute_rule(rule, contract, event_data) {
                 Ok((gas_used, changes)) => {
                     executed_rules.push(rule.rule_id.clone());
                     total_gas_used += gas_used;
                     state_changes.extend(changes);
+                    rule_results.push(RuleResult { rule_id: rule.rule_id.clone(), result: ExecutionResult::Success });
                 },
                 Err(error) => {
-                    execution_result = ExecutionResult::Failed(error);
-                    break;
+                    failures.push(format!("{}: {}", rule.rule_id, error));
+                    rule_results.push(RuleResult { rule_id: rule.rule_id.clone(), result: ExecutionResult::Failed(error) });
+                    if self.rule_failure_policy == RuleFailurePolicy::Halt {
+                        break;
+                    }
                 }
             }
         }
 
+        let execution_result = if failures.is_empty() {
+            ExecutionResult::Success
+        } else if self.rule_failure_policy == RuleFailurePolicy::Continue && !executed_rules.is_empty() {
+            ExecutionResult::PartialSuccess(failures.join("; "))
+        } else {
+            ExecutionResult::Failed(failures.join("; "))
+        };
+
+        let escalations = self.evaluate_escalations(contract, trigger_event, event_data);
+
         // Create execution record
         let execution = ContractExecution {
             execution_id: execution_id.clone(),
@@ -431,8 +721,10 @@ impl VTPEngine {
             gas_used: total_gas_used,
             execution_result,
             state_changes,
-            timestamp: Utc::now().to_rfc3339(),
+            timestamp: self.clock.now().to_rfc3339(),
             block_height: self.get_current_block_height(),
+            rule_results,
+            escalations,
         };
 
         // Update contract
@@ -440,9 +732,43 @@ impl VTPEngine {
         contract.state.last_execution = Some(execution_id);
         self.gas_tracker.total_gas_used += total_gas_used;
 
+        for event in ContractEvent::from_state_changes(contract_id, &execution.execution_id, &execution.state_changes) {
+            self.event_bus.publish(&event);
+        }
+        for escalation in &execution.escalations {
+            self.event_bus.publish(&ContractEvent::Escalated(escalation.clone()));
+        }
+
         Ok(execution)
     }
 
+    /// Mark an escalation rule's trigger as resolved, so it no longer escalates and
+    /// can trigger afresh; returns whether it was open
+    pub fn resolve_escalation(&mut self, contract_id: &str, rule_id: &str) -> Result<bool, VerificationError> {
+        let contract = self.contracts.get_mut(contract_id)
+            .ok_or_else(|| VerificationError::NotFound("Contract not found".to_string()))?;
+        Ok(contract.state.open_escalations.remove(rule_id).is_some())
+    }
+
+    /// Gas `execute_contract` would consume for `trigger_event`, without executing
+    ///
+    /// Sums the cost of every rule the event fires, which is what execution uses
+    /// when all of them succeed. Unknown contracts cost nothing.
+    pub fn estimate_gas(
+        &self,
+        contract_id: &str,
+        trigger_event: &str,
+        event_data: &HashMap<String, serde_json::Value>,
+    ) -> u64 {
+        self.contracts.get(contract_id)
+            .map(|contract| {
+                self.applicable_rules(&contract.execution_rules, trigger_event, event_data).iter()
+                    .map(|rule| self.gas_tracker.rule_cost(rule.action))
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+
     /// Create multi-signature attestation
     pub fn create_multisig_attestation(
         &mut self,
@@ -450,15 +776,15 @@ impl VTPEngine {
         required_signatures: u32,
         attestation_data: AttestationData,
         deadline_hours: u32,
-    ) -> Result<MultiSigAttestation, String> {
+    ) -> Result<MultiSigAttestation, VerificationError> {
         
         // Verify contract exists
         if !self.contracts.contains_key(contract_id) {
-            return Err("Contract not found".to_string());
+            return Err(VerificationError::NotFound("Contract not found".to_string()));
         }
 
-        let attestation_id = format!("multisig_{}", Uuid::new_v4());
-        let deadline = (Utc::now() + chrono::Duration::hours(deadline_hours as i64)).to_rfc3339();
+        let attestation_id = self.id_strategy.generate("multisig", &(contract_id, required_signatures, &attestation_data));
+        let deadline = (self.clock.now() + chrono::Duration::hours(deadline_hours as i64)).to_rfc3339();
 
         let attestation = MultiSigAttestation {
             attestation_id,
@@ -468,11 +794,54 @@ impl VTPEngine {
             attestation_data,
             completion_status: AttestationStatus::Pending,
             deadline,
+            threshold_group_key: None,
+            threshold_signature: None,
         };
 
         Ok(attestation)
     }
 
+    /// Create an attestation completed by one `group` threshold signature
+    pub fn create_threshold_attestation(
+        &mut self,
+        contract_id: &str,
+        group: &ThresholdGroup,
+        attestation_data: AttestationData,
+        deadline_hours: u32,
+    ) -> Result<MultiSigAttestation, VerificationError> {
+        let mut attestation =
+            self.create_multisig_attestation(contract_id, group.threshold as u32, attestation_data, deadline_hours)?;
+        attestation.threshold_group_key = Some(hex::encode(group.group_public_key));
+        Ok(attestation)
+    }
+
+    /// Submit the aggregated group signature for a threshold attestation
+    pub fn submit_threshold_signature(
+        &mut self,
+        attestation: &mut MultiSigAttestation,
+        signature: &[u8; 64],
+    ) -> Result<(), VerificationError> {
+        let group_public_key = attestation.threshold_group_key.as_deref()
+            .and_then(|key| hex::decode(key).ok())
+            .and_then(|key| <[u8; 32]>::try_from(key).ok())
+            .ok_or_else(|| VerificationError::PolicyViolation("Attestation has no threshold group key".to_string()))?;
+
+        if let Ok(deadline) = DateTime::parse_from_rfc3339(&attestation.deadline) {
+            if self.clock.now() > deadline {
+                attestation.completion_status = AttestationStatus::Expired;
+                return Err(VerificationError::Expired("Attestation deadline has passed".to_string()));
+            }
+        }
+
+        if !verify_group_signature(&group_public_key, attestation.signing_message().as_bytes(), signature) {
+            return Err(VerificationError::InvalidSignature("Invalid threshold signature".to_string()));
+        }
+
+        attestation.threshold_signature = Some(hex::encode(signature));
+        attestation.completion_status = AttestationStatus::Sufficient;
+        Ok(())
+    }
+
     /// Submit signature for multi-signature attestation
     pub fn submit_attestation_signature(
         &mut self,
@@ -481,18 +850,22 @@ impl VTPEngine {
         signer_role: &str,
         signature: &str,
         signer_trust_score: f64,
-    ) -> Result<(), String> {
+    ) -> Result<(), VerificationError> {
         
+        if attestation.threshold_group_key.is_some() {
+            return Err(VerificationError::PolicyViolation("Threshold attestations take a single group signature".to_string()));
+        }
+
         // Check if already signed
         if attestation.collected_signatures.iter().any(|sig| sig.signer_id == signer_id) {
-            return Err("Signer has already provided signature".to_string());
+            return Err(VerificationError::PolicyViolation("Signer has already provided signature".to_string()));
         }
 
         // Check deadline
         if let Ok(deadline) = DateTime::parse_from_rfc3339(&attestation.deadline) {
-            if Utc::now() > deadline {
+            if self.clock.now() > deadline {
                 attestation.completion_status = AttestationStatus::Expired;
-                return Err("Attestation deadline has passed".to_string());
+                return Err(VerificationError::Expired("Attestation deadline has passed".to_string()));
             }
         }
 
@@ -501,7 +874,7 @@ impl VTPEngine {
             signer_id: signer_id.to_string(),
             signer_role: signer_role.to_string(),
             signature: signature.to_string(),
-            timestamp: Utc::now().to_rfc3339(),
+            timestamp: self.clock.now().to_rfc3339(),
             signer_trust_score,
         };
 
@@ -522,9 +895,12 @@ impl VTPEngine {
         data_sources: Vec<String>,
         update_frequency: u32,
         consensus_mechanism: ConsensusType,
-    ) -> Result<VelocityOracle, String> {
+    ) -> Result<VelocityOracle, VerificationError> {
         
-        let oracle_id = format!("oracle_{}", Uuid::new_v4());
+        let oracle_id = self.id_strategy.generate(
+            "oracle",
+            &(&oracle_type, &data_sources, update_frequency, &consensus_mechanism),
+        );
 
         let oracle = VelocityOracle {
             oracle_id: oracle_id.clone(),
@@ -532,7 +908,7 @@ impl VTPEngine {
             data_sources,
             update_frequency,
             reputation_score: 0.8, // Initial reputation
-            last_update: Utc::now().to_rfc3339(),
+            last_update: self.clock.now().to_rfc3339(),
             consensus_mechanism,
         };
 
@@ -546,10 +922,10 @@ impl VTPEngine {
         &self,
         oracle_id: &str,
         query_parameters: &HashMap<String, String>,
-    ) -> Result<OracleResponse, String> {
+    ) -> Result<OracleResponse, VerificationError> {
         
         let oracle = self.oracles.get(oracle_id)
-            .ok_or("Oracle not found")?;
+            .ok_or_else(|| VerificationError::NotFound("Oracle not found".to_string()))?;
 
         // Simulate oracle data retrieval
         let response_data = self.simulate_oracle_query(oracle, query_parameters);
@@ -559,7 +935,7 @@ impl VTPEngine {
             query_id: format!("query_{}", Uuid::new_v4()),
             response_data,
             confidence_score: oracle.reputation_score,
-            timestamp: Utc::now().to_rfc3339(),
+            timestamp: self.clock.now().to_rfc3339(),
             consensus_achieved: true,
         })
     }
@@ -570,10 +946,10 @@ impl VTPEngine {
         node_id: &str,
         stake_amount: u64,
         initial_reputation: f64,
-    ) -> Result<(), String> {
+    ) -> Result<(), VerificationError> {
         
         if self.consensus_engine.validators.contains_key(node_id) {
-            return Err("Validator already registered".to_string());
+            return Err(VerificationError::PolicyViolation("Validator already registered".to_string()));
         }
 
         let validator = ValidatorNode {
@@ -637,16 +1013,29 @@ impl VTPEngine {
             hex::encode(hasher.finalize())
         };
 
-        CryptographicProof {
-            id: format!("vtp_proof_{}", Uuid::new_v4()),
+        let id = format!("vtp_proof_{}", Uuid::new_v4());
+        let nonce = CryptographicProof::generate_nonce();
+        let timestamp = self.clock.now().to_rfc3339();
+
+        let mut proof = CryptographicProof {
+            format_version: CURRENT_PROOF_FORMAT_VERSION,
+            id,
             hash: hash.clone(),
-            signature: self.sign_data(&hash),
-            timestamp: Utc::now().to_rfc3339(),
+            signature: String::new(),
+            timestamp,
             previous_hash: None,
             merkle_root: None,
             block_height: self.get_current_block_height(),
             verification_status: "verified".to_string(),
-        }
+            nonce,
+            key_id: None,
+            domain: Some(DOMAIN_VTP_CONTRACT.to_string()),
+            timestamp_token: None,
+            pq_signature: None,
+            genesis_anchor: None,
+        };
+        proof.signature = self.sign_data(&proof.signing_payload());
+        proof
     }
 
     fn sign_data(&self, data: &str) -> String {
@@ -660,15 +1049,85 @@ impl VTPEngine {
         condition.contains(trigger_event)
     }
 
-    fn execute_rule(&self, rule: &ExecutionRule, contract: &mut VelocityTrustContract, _event_data: &HashMap<String, serde_json::Value>) -> Result<(u64, Vec<StateChange>), String> {
-        let mut gas_used = 1000u64; // Base gas cost
+    /// Open escalations whose trigger matches `trigger_event`, and raise those
+    /// left unresolved for their `timeout_hours`
+    fn evaluate_escalations(
+        &self,
+        contract: &mut VelocityTrustContract,
+        trigger_event: &str,
+        event_data: &HashMap<String, serde_json::Value>,
+    ) -> Vec<EscalationEvent> {
+        let now = self.clock.now();
+        let mut escalations = Vec::new();
+
+        for rule in &contract.trust_parameters.escalation_rules {
+            if !contract.state.open_escalations.contains_key(&rule.rule_id)
+                && self.evaluate_rule_condition(&rule.escalation_trigger, trigger_event, event_data)
+            {
+                contract.state.open_escalations.insert(rule.rule_id.clone(), OpenEscalation {
+                    triggered_at: now.to_rfc3339(),
+                    escalated: false,
+                });
+            }
+
+            let Some(open) = contract.state.open_escalations.get_mut(&rule.rule_id) else {
+                continue;
+            };
+            let deadline = DateTime::parse_from_rfc3339(&open.triggered_at)
+                .map(|triggered_at| triggered_at + chrono::Duration::hours(rule.timeout_hours as i64));
+            if open.escalated || !deadline.is_ok_and(|deadline| now >= deadline) {
+                continue;
+            }
+
+            open.escalated = true;
+            escalations.push(EscalationEvent {
+                escalation_id: self.id_strategy.generate("escalation", &(&contract.contract_id, &rule.rule_id, &open.triggered_at)),
+                contract_id: contract.contract_id.clone(),
+                rule_id: rule.rule_id.clone(),
+                escalation_target: rule.escalation_target,
+                required_signatures: rule.required_signatures,
+                triggered_at: open.triggered_at.clone(),
+                escalated_at: now.to_rfc3339(),
+            });
+        }
+
+        escalations
+    }
+
+    /// Rules `trigger_event` fires, in priority order
+    fn applicable_rules(
+        &self,
+        rules: &[ExecutionRule],
+        trigger_event: &str,
+        event_data: &HashMap<String, serde_json::Value>,
+    ) -> Vec<ExecutionRule> {
+        let mut applicable: Vec<ExecutionRule> = rules.iter()
+            .filter(|rule| self.evaluate_rule_condition(&rule.condition, trigger_event, event_data))
+            .cloned()
+            .collect();
+        applicable.sort_by_key(|rule| rule.priority);
+        applicable
+    }
+
+    fn execute_rule(&self, rule: &ExecutionRule, contract: &mut VelocityTrustContract, event_data: &HashMap<String, serde_json::Value>) -> Result<(u64, Vec<StateChange>), String> {
+        let gas_used = self.gas_tracker.rule_cost(rule.action);
+        if gas_used > rule.gas_limit {
+            return Err(format!(
+                "Rule {} needs {} gas, over its limit of {}",
+                rule.rule_id, gas_used, rule.gas_limit
+            ));
+        }
+
+        if let Some(handler) = self.action_handlers.get(&rule.action) {
+            let state_changes = Self::run_action_handler(Arc::clone(handler), rule, contract, event_data)?;
+            return Ok((gas_used, state_changes));
+        }
+
         let mut state_changes = Vec::new();
 
         match rule.action {
             ContractAction::ValidateTrustScore => {
                 // Simulate trust score validation
-                gas_used += 2000;
-                
                 let old_score = contract.state.current_trust_score;
                 let new_score = (old_score + 0.1).min(1.0);
                 
@@ -682,7 +1141,6 @@ impl VTPEngine {
                 });
             },
             ContractAction::RequestAttestation => {
-                gas_used += 1500;
                 contract.state.attestation_count += 1;
                 
                 state_changes.push(StateChange {
@@ -693,7 +1151,6 @@ impl VTPEngine {
                 });
             },
             ContractAction::UpdateTrustScore => {
-                gas_used += 3000;
                 let old_score = contract.state.current_trust_score;
                 let new_score = self.calculate_updated_trust_score(contract);
                 
@@ -706,14 +1163,53 @@ impl VTPEngine {
                     change_reason: "Trust score updated based on new evidence".to_string(),
                 });
             },
-            _ => {
-                gas_used += 500; // Default gas cost for other actions
-            }
+            ContractAction::ReleaseRewards => {
+                let released = contract.state.pending_rewards;
+                contract.state.pending_rewards = 0.0;
+
+                state_changes.push(StateChange {
+                    variable_name: "pending_rewards".to_string(),
+                    old_value: serde_json::json!(released),
+                    new_value: serde_json::json!(0.0),
+                    change_reason: "Pending rewards released".to_string(),
+                });
+            },
+            _ => {}
         }
 
         Ok((gas_used, state_changes))
     }
 
+    /// Run `handler` on a worker thread, adopting its state only if it finishes
+    /// within the rule's timeout
+    fn run_action_handler(
+        handler: Arc<dyn ActionHandler>,
+        rule: &ExecutionRule,
+        contract: &mut VelocityTrustContract,
+        event_data: &HashMap<String, serde_json::Value>,
+    ) -> Result<Vec<StateChange>, String> {
+        let (sender, receiver) = mpsc::channel();
+        let worker_rule = rule.clone();
+        let mut state = contract.state.clone();
+        let event_data = event_data.clone();
+
+        thread::spawn(move || {
+            let result = handler.execute(&worker_rule, &mut state, &event_data);
+            // The receiver is gone if the rule already timed out
+            let _ = sender.send((state, result));
+        });
+
+        match receiver.recv_timeout(Duration::from_secs(rule.timeout_seconds as u64)) {
+            Ok((state, Ok(state_changes))) => {
+                contract.state = state;
+                Ok(state_changes)
+            },
+            Ok((_, Err(error))) => Err(error),
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(RULE_TIMEOUT_ERROR.to_string()),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err("Action handler panicked".to_string()),
+        }
+    }
+
     fn calculate_updated_trust_score(&self, contract: &VelocityTrustContract) -> f64 {
         // Simplified trust score calculation
         let base_score = contract.state.current_trust_score;
@@ -725,7 +1221,7 @@ impl VTPEngine {
 
     fn get_current_block_height(&self) -> u64 {
         // Simulate block height
-        (Utc::now().timestamp() / self.consensus_engine.block_time_seconds as i64) as u64
+        (self.clock.now().timestamp() / self.consensus_engine.block_time_seconds as i64) as u64
     }
 
     fn simulate_oracle_query(&self, oracle: &VelocityOracle, _query_parameters: &HashMap<String, String>) -> HashMap<String, serde_json::Value> {
@@ -784,4 +1280,474 @@ pub struct VTPAnalytics {
     pub oracle_count: u64,
     pub validator_count: u64,
     pub consensus_rate: f64,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trust_parameters() -> TrustParameters {
+        TrustParameters {
+            minimum_trust_score: 0.7,
+            required_attestations: 2,
+            validity_period_days: 365,
+            consensus_threshold: 0.67,
+            penalty_conditions: Vec::new(),
+            reward_conditions: Vec::new(),
+            escalation_rules: Vec::new(),
+        }
+    }
+
+    fn rule(rule_id: &str, condition: &str, action: ContractAction, priority: u32, gas_limit: u64) -> ExecutionRule {
+        ExecutionRule {
+            rule_id: rule_id.to_string(),
+            condition: condition.to_string(),
+            action,
+            priority,
+            gas_limit,
+            timeout_seconds: 30,
+        }
+    }
+
+    fn deploy(engine: &mut VTPEngine, rules: Vec<ExecutionRule>) -> VelocityTrustContract {
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        engine
+            .deploy_trust_contract(ContractType::TrustScoreValidation, "org_1", trust_parameters(), rules, &mut crypto_engine)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_gas_estimate_matches_execution() {
+        let mut engine = VTPEngine::new();
+        let contract = deploy(&mut engine, vec![
+            rule("validate", "on_evidence_submitted", ContractAction::ValidateTrustScore, 1, 10_000),
+            rule("attest", "on_evidence_submitted", ContractAction::RequestAttestation, 2, 10_000),
+            rule("notify", "on_evidence_submitted on_audit", ContractAction::NotifyStakeholders, 3, 10_000),
+            rule("update", "on_audit", ContractAction::UpdateTrustScore, 1, 10_000),
+        ]);
+        let event_data = HashMap::new();
+
+        let estimate = engine.estimate_gas(&contract.contract_id, "on_evidence_submitted", &event_data);
+        assert_eq!(estimate, 3 * BASE_RULE_GAS + 2000 + 1500 + DEFAULT_ACTION_GAS);
+
+        let execution = engine.execute_contract(&contract.contract_id, "on_evidence_submitted", &event_data).unwrap();
+        assert!(matches!(execution.execution_result, ExecutionResult::Success));
+        assert_eq!(execution.executed_rules, ["validate", "attest", "notify"]);
+        assert_eq!(execution.gas_used, estimate);
+
+        let audit_estimate = engine.estimate_gas(&contract.contract_id, "on_audit", &event_data);
+        let audit = engine.execute_contract(&contract.contract_id, "on_audit", &event_data).unwrap();
+        assert_eq!(audit.gas_used, audit_estimate);
+        assert_eq!(engine.generate_vtp_analytics().total_gas_used, estimate + audit_estimate);
+
+        assert_eq!(engine.estimate_gas(&contract.contract_id, "on_unrelated", &event_data), 0);
+        assert_eq!(engine.estimate_gas("missing", "on_audit", &event_data), 0);
+    }
+
+    #[test]
+    fn test_rule_over_gas_limit_is_rejected() {
+        let mut engine = VTPEngine::new();
+        let cost = BASE_RULE_GAS + 3000;
+        let contract = deploy(&mut engine, vec![
+            rule("attest", "on_review", ContractAction::RequestAttestation, 1, 10_000),
+            rule("update", "on_review", ContractAction::UpdateTrustScore, 2, cost - 1),
+        ]);
+
+        let execution = engine.execute_contract(&contract.contract_id, "on_review", &HashMap::new()).unwrap();
+        match &execution.execution_result {
+            ExecutionResult::Failed(reason) => assert!(reason.contains("update")),
+            other => panic!("expected gas limit failure, got {:?}", other),
+        }
+        assert_eq!(execution.executed_rules, ["attest"]);
+        assert_eq!(execution.gas_used, BASE_RULE_GAS + 1500);
+
+        // The rejected rule never touched the trust score
+        let contract = &engine.contracts[&contract.contract_id];
+        assert_eq!(contract.state.current_trust_score, 0.5);
+        assert_eq!(contract.state.attestation_count, 1);
+    }
+
+    /// Raises the violation count after `delay`
+    struct DelayedViolation {
+        delay: std::time::Duration,
+    }
+
+    impl ActionHandler for DelayedViolation {
+        fn execute(
+            &self,
+            _rule: &ExecutionRule,
+            state: &mut ContractState,
+            _event_data: &HashMap<String, serde_json::Value>,
+        ) -> Result<Vec<StateChange>, String> {
+            thread::sleep(self.delay);
+            state.violation_count += 1;
+            Ok(vec![StateChange {
+                variable_name: "violation_count".to_string(),
+                old_value: serde_json::json!(state.violation_count - 1),
+                new_value: serde_json::json!(state.violation_count),
+                change_reason: "Assets frozen".to_string(),
+            }])
+        }
+    }
+
+    fn timeout_contract(engine: &mut VTPEngine) -> VelocityTrustContract {
+        let mut slow = rule("freeze", "on_breach", ContractAction::FreezeAssets, 1, 10_000);
+        slow.timeout_seconds = 1;
+        deploy(engine, vec![slow, rule("attest", "on_breach", ContractAction::RequestAttestation, 2, 10_000)])
+    }
+
+    #[test]
+    fn test_slow_action_times_out_and_halts() {
+        let mut engine = VTPEngine::new();
+        engine.register_action_handler(
+            ContractAction::FreezeAssets,
+            Arc::new(DelayedViolation { delay: std::time::Duration::from_secs(5) }),
+        );
+        let contract = timeout_contract(&mut engine);
+
+        let execution = engine.execute_contract(&contract.contract_id, "on_breach", &HashMap::new()).unwrap();
+        assert!(matches!(&execution.execution_result, ExecutionResult::Failed(reason) if reason == "freeze: timeout"));
+        assert!(execution.executed_rules.is_empty());
+        assert_eq!(execution.rule_results.len(), 1);
+        assert!(matches!(&execution.rule_results[0].result, ExecutionResult::Failed(reason) if reason == RULE_TIMEOUT_ERROR));
+        assert_eq!(execution.gas_used, 0);
+
+        // The abandoned handler's state is never adopted
+        let state = &engine.contracts[&contract.contract_id].state;
+        assert_eq!(state.violation_count, 0);
+        assert_eq!(state.attestation_count, 0);
+    }
+
+    #[test]
+    fn test_timed_out_rule_is_skipped_under_continue_policy() {
+        let mut engine = VTPEngine::new();
+        engine.set_rule_failure_policy(RuleFailurePolicy::Continue);
+        engine.register_action_handler(
+            ContractAction::FreezeAssets,
+            Arc::new(DelayedViolation { delay: std::time::Duration::from_secs(5) }),
+        );
+        let contract = timeout_contract(&mut engine);
+
+        let execution = engine.execute_contract(&contract.contract_id, "on_breach", &HashMap::new()).unwrap();
+        assert!(matches!(&execution.execution_result, ExecutionResult::PartialSuccess(reason) if reason == "freeze: timeout"));
+        assert_eq!(execution.executed_rules, ["attest"]);
+        assert!(matches!(&execution.rule_results[0].result, ExecutionResult::Failed(reason) if reason == RULE_TIMEOUT_ERROR));
+        assert!(matches!(execution.rule_results[1].result, ExecutionResult::Success));
+        assert_eq!(execution.gas_used, BASE_RULE_GAS + 1500);
+
+        let state = &engine.contracts[&contract.contract_id].state;
+        assert_eq!(state.violation_count, 0);
+        assert_eq!(state.attestation_count, 1);
+    }
+
+    #[test]
+    fn test_action_handler_within_timeout_updates_state() {
+        let mut engine = VTPEngine::new();
+        engine.register_action_handler(
+            ContractAction::FreezeAssets,
+            Arc::new(DelayedViolation { delay: std::time::Duration::ZERO }),
+        );
+        let contract = timeout_contract(&mut engine);
+
+        let execution = engine.execute_contract(&contract.contract_id, "on_breach", &HashMap::new()).unwrap();
+        assert!(matches!(execution.execution_result, ExecutionResult::Success));
+        assert_eq!(execution.executed_rules, ["freeze", "attest"]);
+        assert_eq!(execution.state_changes[0].variable_name, "violation_count");
+        assert_eq!(engine.estimate_gas(&contract.contract_id, "on_breach", &HashMap::new()), execution.gas_used);
+
+        let state = &engine.contracts[&contract.contract_id].state;
+        assert_eq!(state.violation_count, 1);
+        assert_eq!(state.attestation_count, 1);
+    }
+
+    fn record_events(engine: &mut VTPEngine, contract_id: &str) -> (SubscriptionId, Arc<std::sync::Mutex<Vec<ContractEvent>>>) {
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let id = engine.subscribe(contract_id, Box::new(move |event| sink.lock().unwrap().push(event)));
+        (id, events)
+    }
+
+    #[test]
+    fn test_subscribers_receive_execution_events() {
+        let mut engine = VTPEngine::new();
+        engine.register_action_handler(
+            ContractAction::FreezeAssets,
+            Arc::new(DelayedViolation { delay: std::time::Duration::ZERO }),
+        );
+        let rules = vec![
+            rule("validate", "on_review", ContractAction::ValidateTrustScore, 1, 10_000),
+            rule("freeze", "on_review", ContractAction::FreezeAssets, 2, 10_000),
+            rule("release", "on_review", ContractAction::ReleaseRewards, 3, 10_000),
+            rule("attest", "on_review", ContractAction::RequestAttestation, 4, 10_000),
+        ];
+        let contract = deploy(&mut engine, rules.clone());
+        let other = deploy(&mut engine, rules);
+        engine.contracts.get_mut(&contract.contract_id).unwrap().state.pending_rewards = 250.0;
+
+        let (_, contract_events) = record_events(&mut engine, &contract.contract_id);
+        let (_, all_events) = record_events(&mut engine, ALL_CONTRACTS);
+
+        let execution = engine.execute_contract(&contract.contract_id, "on_review", &HashMap::new()).unwrap();
+        let execution_id = execution.execution_id.clone();
+        let expected = vec![
+            ContractEvent::TrustScoreChanged {
+                contract_id: contract.contract_id.clone(),
+                execution_id: execution_id.clone(),
+                old_score: 0.5,
+                new_score: 0.6,
+            },
+            ContractEvent::ViolationRecorded {
+                contract_id: contract.contract_id.clone(),
+                execution_id: execution_id.clone(),
+                violation_count: 1,
+            },
+            ContractEvent::RewardReleased {
+                contract_id: contract.contract_id.clone(),
+                execution_id,
+                amount: 250.0,
+            },
+        ];
+        assert_eq!(*contract_events.lock().unwrap(), expected);
+        assert_eq!(*all_events.lock().unwrap(), expected);
+
+        // Nothing was pending on the other contract, so no reward is released
+        engine.execute_contract(&other.contract_id, "on_review", &HashMap::new()).unwrap();
+        assert_eq!(contract_events.lock().unwrap().len(), 3);
+        let all_events = all_events.lock().unwrap();
+        assert_eq!(all_events.len(), 5);
+        assert!(all_events[3..].iter().all(|event| event.contract_id() == other.contract_id));
+    }
+
+    #[test]
+    fn test_unsubscribed_handler_stops_receiving_events() {
+        let mut engine = VTPEngine::new();
+        let contract = deploy(&mut engine, vec![rule("validate", "on_review", ContractAction::ValidateTrustScore, 1, 10_000)]);
+        let (id, events) = record_events(&mut engine, ALL_CONTRACTS);
+
+        engine.execute_contract(&contract.contract_id, "on_review", &HashMap::new()).unwrap();
+        assert_eq!(events.lock().unwrap().len(), 1);
+
+        assert!(engine.unsubscribe(id));
+        assert!(!engine.unsubscribe(id));
+        engine.execute_contract(&contract.contract_id, "on_review", &HashMap::new()).unwrap();
+        assert_eq!(events.lock().unwrap().len(), 1);
+    }
+
+    fn escalating_engine() -> (VTPEngine, Arc<MockClock>, VelocityTrustContract) {
+        let clock = Arc::new(MockClock::new(
+            DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z").unwrap().with_timezone(&chrono::Utc),
+        ));
+        let mut engine = VTPEngine::new();
+        engine.set_clock(clock.clone());
+
+        let mut parameters = trust_parameters();
+        parameters.escalation_rules.push(EscalationRule {
+            rule_id: "cisa_escalation".to_string(),
+            escalation_trigger: "on_violation".to_string(),
+            escalation_target: EscalationTarget::RegulatoryCISA,
+            timeout_hours: 72,
+            required_signatures: 3,
+        });
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let contract = engine
+            .deploy_trust_contract(ContractType::RegulatoryCompliance, "org_1", parameters, Vec::new(), &mut crypto_engine)
+            .unwrap();
+        (engine, clock, contract)
+    }
+
+    #[test]
+    fn test_persistent_violation_escalates_after_timeout() {
+        let (mut engine, clock, contract) = escalating_engine();
+        let (_, events) = record_events(&mut engine, &contract.contract_id);
+        let triggered_at = clock.now();
+
+        let first = engine.execute_contract(&contract.contract_id, "on_violation", &HashMap::new()).unwrap();
+        assert!(first.escalations.is_empty());
+
+        clock.advance(chrono::Duration::hours(71));
+        let before_timeout = engine.execute_contract(&contract.contract_id, "on_violation", &HashMap::new()).unwrap();
+        assert!(before_timeout.escalations.is_empty());
+
+        clock.advance(chrono::Duration::hours(1));
+        let at_timeout = engine.execute_contract(&contract.contract_id, "on_review", &HashMap::new()).unwrap();
+        assert_eq!(at_timeout.escalations.len(), 1);
+        let escalation = &at_timeout.escalations[0];
+        assert_eq!(escalation.escalation_target, EscalationTarget::RegulatoryCISA);
+        assert_eq!(escalation.required_signatures, 3);
+        assert_eq!(escalation.rule_id, "cisa_escalation");
+        assert_eq!(escalation.triggered_at, triggered_at.to_rfc3339());
+        assert_eq!(escalation.escalated_at, clock.now().to_rfc3339());
+        assert_eq!(*events.lock().unwrap(), [ContractEvent::Escalated(escalation.clone())]);
+
+        // An escalation is raised once until it is resolved
+        clock.advance(chrono::Duration::hours(72));
+        let later = engine.execute_contract(&contract.contract_id, "on_violation", &HashMap::new()).unwrap();
+        assert!(later.escalations.is_empty());
+        assert_eq!(events.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_resolved_violation_does_not_escalate() {
+        let (mut engine, clock, contract) = escalating_engine();
+
+        engine.execute_contract(&contract.contract_id, "on_violation", &HashMap::new()).unwrap();
+        clock.advance(chrono::Duration::hours(24));
+        assert_eq!(engine.resolve_escalation(&contract.contract_id, "cisa_escalation"), Ok(true));
+        assert_eq!(engine.resolve_escalation(&contract.contract_id, "cisa_escalation"), Ok(false));
+
+        clock.advance(chrono::Duration::hours(72));
+        let execution = engine.execute_contract(&contract.contract_id, "on_review", &HashMap::new()).unwrap();
+        assert!(execution.escalations.is_empty());
+
+        // A new violation restarts the timeout
+        engine.execute_contract(&contract.contract_id, "on_violation", &HashMap::new()).unwrap();
+        clock.advance(chrono::Duration::hours(72));
+        let execution = engine.execute_contract(&contract.contract_id, "on_review", &HashMap::new()).unwrap();
+        assert_eq!(execution.escalations.len(), 1);
+        assert!(matches!(
+            engine.resolve_escalation("missing", "cisa_escalation"),
+            Err(VerificationError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_attestation_deadline_follows_engine_clock() {
+        let created_at = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z").unwrap().with_timezone(&chrono::Utc);
+        let clock = Arc::new(MockClock::new(created_at));
+        let mut engine = VTPEngine::new();
+        engine.set_clock(clock.clone());
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+
+        let contract = engine
+            .deploy_trust_contract(
+                ContractType::MultiPartyAttestation,
+                "org_1",
+                trust_parameters(),
+                Vec::new(),
+                &mut crypto_engine,
+            )
+            .unwrap();
+        let attestation_data = AttestationData {
+            subject: "org_1".to_string(),
+            claim_type: "soc2_compliance".to_string(),
+            evidence_hash: "a".repeat(64),
+            confidence_level: 0.9,
+            validity_period: 365,
+        };
+        let mut attestation = engine
+            .create_multisig_attestation(&contract.contract_id, 2, attestation_data, 24)
+            .unwrap();
+        let deadline = created_at + chrono::Duration::hours(24);
+        assert_eq!(attestation.deadline, deadline.to_rfc3339());
+
+        clock.set(deadline);
+        engine
+            .submit_attestation_signature(&mut attestation, "signer_1", "auditor", "sig_1", 0.9)
+            .unwrap();
+        assert_eq!(attestation.collected_signatures[0].timestamp, deadline.to_rfc3339());
+        assert!(matches!(attestation.completion_status, AttestationStatus::Pending));
+
+        assert_eq!(
+            engine.submit_attestation_signature(&mut attestation, "signer_1", "auditor", "sig_1", 0.9),
+            Err(VerificationError::PolicyViolation("Signer has already provided signature".to_string()))
+        );
+
+        clock.advance(chrono::Duration::seconds(1));
+        assert!(matches!(
+            engine.submit_attestation_signature(&mut attestation, "signer_2", "auditor", "sig_2", 0.9),
+            Err(VerificationError::Expired(_))
+        ));
+        assert!(matches!(attestation.completion_status, AttestationStatus::Expired));
+        assert_eq!(attestation.collected_signatures.len(), 1);
+    }
+
+    #[test]
+    fn test_threshold_attestation_needs_group_signature() {
+        let mut engine = VTPEngine::new();
+        let mut crypto_engine = VelocityCryptographicEngine::new();
+        let contract = engine
+            .deploy_trust_contract(
+                ContractType::MultiPartyAttestation,
+                "org_1",
+                trust_parameters(),
+                Vec::new(),
+                &mut crypto_engine,
+            )
+            .unwrap();
+        let attestation_data = AttestationData {
+            subject: "org_1".to_string(),
+            claim_type: "soc2_compliance".to_string(),
+            evidence_hash: "a".repeat(64),
+            confidence_level: 0.9,
+            validity_period: 365,
+        };
+        let (group, shares) = keygen(2, 3).unwrap();
+        let mut attestation = engine
+            .create_threshold_attestation(&contract.contract_id, &group, attestation_data, 24)
+            .unwrap();
+        assert_eq!(attestation.required_signatures, 2);
+        assert!(matches!(
+            engine.submit_attestation_signature(&mut attestation, "signer_1", "auditor", "sig_1", 0.9),
+            Err(VerificationError::PolicyViolation(_))
+        ));
+
+        let message = attestation.signing_message();
+        let (nonces, commitments): (Vec<SigningNonces>, Vec<SigningCommitment>) =
+            shares[1..].iter().map(commit).unzip();
+        let signature_shares: Vec<SignatureShare> = shares[1..].iter().zip(nonces)
+            .map(|(share, nonces)| sign_round(share, nonces, message.as_bytes(), &commitments).unwrap())
+            .collect();
+        let signature = aggregate(&group, message.as_bytes(), &commitments, &signature_shares).unwrap();
+
+        let mut forged = signature;
+        forged[40] ^= 1;
+        assert!(matches!(
+            engine.submit_threshold_signature(&mut attestation, &forged),
+            Err(VerificationError::InvalidSignature(_))
+        ));
+        assert!(matches!(attestation.completion_status, AttestationStatus::Pending));
+
+        engine.submit_threshold_signature(&mut attestation, &signature).unwrap();
+        assert!(matches!(attestation.completion_status, AttestationStatus::Sufficient));
+        assert_eq!(attestation.threshold_signature, Some(hex::encode(signature)));
+    }
+
+    #[test]
+    fn test_deterministic_contract_ids_are_stable() {
+        let strategy = IdStrategy::Deterministic { namespace: Uuid::NAMESPACE_URL };
+        let deploy = || {
+            let mut engine = VTPEngine::new();
+            engine.set_id_strategy(strategy);
+            let contract = engine
+                .deploy_trust_contract(
+                    ContractType::MultiPartyAttestation,
+                    "org_1",
+                    trust_parameters(),
+                    Vec::new(),
+                    &mut VelocityCryptographicEngine::new(),
+                )
+                .unwrap();
+            let execution = engine.execute_contract(&contract.contract_id, "evidence_submitted", &HashMap::new()).unwrap();
+            (engine, contract.contract_id, execution.execution_id)
+        };
+
+        let (mut engine, contract_id, execution_id) = deploy();
+        assert_eq!(deploy().1, contract_id);
+        assert_eq!(deploy().2, execution_id);
+
+        // Each further execution of the same contract gets its own id
+        let next = engine.execute_contract(&contract_id, "evidence_submitted", &HashMap::new()).unwrap();
+        assert_ne!(next.execution_id, execution_id);
+
+        // Redeploying the same contract in one engine would reuse its id
+        assert_eq!(
+            engine.deploy_trust_contract(
+                ContractType::MultiPartyAttestation,
+                "org_1",
+                trust_parameters(),
+                Vec::new(),
+                &mut VelocityCryptographicEngine::new(),
+            ).unwrap_err(),
+            VerificationError::PolicyViolation("Contract already deployed".to_string())
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! Protobuf Wire Format Module
+//!
+//! prost types generated from `proto/velocity/v1/proof.proto`, with conversions
+//! to and from the serde types for interop with non-Rust verifier nodes.
+//! Signatures cover payloads rebuilt from fields, never encoded bytes, so a proof
+//! verifies the same after a protobuf round trip.
+
+use crate::*;
+
+/// Generated protobuf types (`velocity.v1`)
+pub mod proto {
+    include!(concat!(env!("OUT_DIR"), "/velocity.v1.rs"));
+}
+
+impl CryptographicProof {
+    pub fn to_proto(&self) -> proto::CryptographicProof {
+        proto::CryptographicProof {
+            format_version: u32::from(self.format_version),
+            id: self.id.clone(),
+            hash: self.hash.clone(),
+            signature: self.signature.clone(),
+            timestamp: self.timestamp.clone(),
+            previous_hash: self.previous_hash.clone(),
+            merkle_root: self.merkle_root.clone(),
+            block_height: self.block_height,
+            verification_status: self.verification_status.clone(),
+            nonce: self.nonce.clone(),
+            key_id: self.key_id.clone(),
+            domain: self.domain.clone(),
+            timestamp_token: self.timestamp_token.as_ref().map(TimestampToken::to_proto),
+            pq_signature: self.pq_signature.clone(),
+            genesis_anchor: self.genesis_anchor.clone(),
+        }
+    }
+
+    pub fn from_proto(message: proto::CryptographicProof) -> Result<CryptographicProof, String> {
+        let format_version = u16::try_from(message.format_version)
+            .map_err(|_| format!("Invalid proof format version {}", message.format_version))?;
+
+        Ok(CryptographicProof {
+            format_version,
+            id: message.id,
+            hash: message.hash,
+            signature: message.signature,
+            timestamp: message.timestamp,
+            previous_hash: message.previous_hash,
+            merkle_root: message.merkle_root,
+            block_height: message.block_height,
+            verification_status: message.verification_status,
+            nonce: message.nonce,
+            key_id: message.key_id,
+            domain: message.domain,
+            timestamp_token: message.timestamp_token.map(TimestampToken::from_proto),
+            pq_signature: message.pq_signature,
+            genesis_anchor: message.genesis_anchor,
+        })
+    }
+}
+
+impl TimestampToken {
+    pub fn to_proto(&self) -> proto::TimestampToken {
+        proto::TimestampToken {
+            tsa: self.tsa.clone(),
+            hash_algorithm: self.hash_algorithm.clone(),
+            message_imprint: self.message_imprint.clone(),
+            gen_time: self.gen_time.clone(),
+            serial_number: self.serial_number,
+            token: self.token.clone(),
+        }
+    }
+
+    pub fn from_proto(message: proto::TimestampToken) -> TimestampToken {
+        TimestampToken {
+            tsa: message.tsa,
+            hash_algorithm: message.hash_algorithm,
+            message_imprint: message.message_imprint,
+            gen_time: message.gen_time,
+            serial_number: message.serial_number,
+            token: message.token,
+        }
+    }
+}
+
+impl EvidenceRecord {
+    pub fn to_proto(&self) -> proto::EvidenceRecord {
+        proto::EvidenceRecord {
+            record_id: self.record_id.clone(),
+            evidence_id: self.evidence_id.clone(),
+            evidence_type: evidence_type_to_proto(&self.evidence_type) as i32,
+            content_hash: self.content_hash.clone(),
+            metadata_hash: self.metadata_hash.clone(),
+            submitter_id: self.submitter_id.clone(),
+            organization_id: self.organization_id.clone(),
+            compliance_framework: self.compliance_framework.clone(),
+            timestamp: self.timestamp.clone(),
+            cryptographic_proof: Some(self.cryptographic_proof.to_proto()),
+            attestations: self.attestations.iter().map(EvidenceAttestation::to_proto).collect(),
+            anchor_tx_id: self.anchor_tx_id.clone(),
+        }
+    }
+
+    pub fn from_proto(message: proto::EvidenceRecord) -> Result<EvidenceRecord, String> {
+        let cryptographic_proof = message
+            .cryptographic_proof
+            .ok_or("Evidence record is missing its cryptographic proof")?;
+
+        Ok(EvidenceRecord {
+            record_id: message.record_id,
+            evidence_id: message.evidence_id,
+            evidence_type: evidence_type_from_proto(message.evidence_type)?,
+            content_hash: message.content_hash,
+            metadata_hash: message.metadata_hash,
+            submitter_id: message.submitter_id,
+            organization_id: message.organization_id,
+            compliance_framework: message.compliance_framework,
+            timestamp: message.timestamp,
+            cryptographic_proof: CryptographicProof::from_proto(cryptographic_proof)?,
+            attestations: message
+                .attestations
+                .into_iter()
+                .map(EvidenceAttestation::from_proto)
+                .collect::<Result<_, _>>()?,
+            anchor_tx_id: message.anchor_tx_id,
+        })
+    }
+}
+
+impl EvidenceAttestation {
+    pub fn to_proto(&self) -> proto::EvidenceAttestation {
+        proto::EvidenceAttestation {
+            attestation_id: self.attestation_id.clone(),
+            validator_id: self.validator_id.clone(),
+            validator_type: validator_type_to_proto(self.validator_type) as i32,
+            attestation_result: attestation_result_to_proto(&self.attestation_result) as i32,
+            confidence_score: self.confidence_score,
+            timestamp: self.timestamp.clone(),
+            cryptographic_signature: self.cryptographic_signature.clone(),
+            review_notes: self.review_notes.clone(),
+        }
+    }
+
+    pub fn from_proto(message: proto::EvidenceAttestation) -> Result<EvidenceAttestation, String> {
+        Ok(EvidenceAttestation {
+            attestation_id: message.attestation_id,
+            validator_id: message.validator_id,
+            validator_type: validator_type_from_proto(message.validator_type)?,
+            attestation_result: attestation_result_from_proto(message.attestation_result)?,
+            confidence_score: message.confidence_score,
+            timestamp: message.timestamp,
+            cryptographic_signature: message.cryptographic_signature,
+            review_notes: message.review_notes,
+        })
+    }
+}
+
+fn evidence_type_to_proto(evidence_type: &EvidenceType) -> proto::EvidenceType {
+    match evidence_type {
+        EvidenceType::Document => proto::EvidenceType::Document,
+        EvidenceType::Policy => proto::EvidenceType::Policy,
+        EvidenceType::Procedure => proto::EvidenceType::Procedure,
+        EvidenceType::Assessment => proto::EvidenceType::Assessment,
+        EvidenceType::Audit => proto::EvidenceType::Audit,
+        EvidenceType::Certification => proto::EvidenceType::Certification,
+        EvidenceType::Training => proto::EvidenceType::Training,
+        EvidenceType::Incident => proto::EvidenceType::Incident,
+        EvidenceType::Control => proto::EvidenceType::Control,
+        EvidenceType::Configuration => proto::EvidenceType::Configuration,
+    }
+}
+
+fn evidence_type_from_proto(value: i32) -> Result<EvidenceType, String> {
+    match proto::EvidenceType::try_from(value) {
+        Ok(proto::EvidenceType::Document) => Ok(EvidenceType::Document),
+        Ok(proto::EvidenceType::Policy) => Ok(EvidenceType::Policy),
+        Ok(proto::EvidenceType::Procedure) => Ok(EvidenceType::Procedure),
+        Ok(proto::EvidenceType::Assessment) => Ok(EvidenceType::Assessment),
+        Ok(proto::EvidenceType::Audit) => Ok(EvidenceType::Audit),
+        Ok(proto::EvidenceType::Certification) => Ok(EvidenceType::Certification),
+        Ok(proto::EvidenceType::Training) => Ok(EvidenceType::Training),
+        Ok(proto::EvidenceType::Incident) => Ok(EvidenceType::Incident),
+        Ok(proto::EvidenceType::Control) => Ok(EvidenceType::Control),
+        Ok(proto::EvidenceType::Configuration) => Ok(EvidenceType::Configuration),
+        Ok(proto::EvidenceType::Unspecified) | Err(_) => Err(format!("Invalid evidence type {}", value)),
+    }
+}
+
+fn validator_type_to_proto(validator_type: ValidatorType) -> proto::ValidatorType {
+    match validator_type {
+        ValidatorType::Human => proto::ValidatorType::Human,
+        ValidatorType::AI => proto::ValidatorType::Ai,
+        ValidatorType::System => proto::ValidatorType::System,
+        ValidatorType::ThirdParty => proto::ValidatorType::ThirdParty,
+        ValidatorType::Regulatory => proto::ValidatorType::Regulatory,
+    }
+}
+
+fn validator_type_from_proto(value: i32) -> Result<ValidatorType, String> {
+    match proto::ValidatorType::try_from(value) {
+        Ok(proto::ValidatorType::Human) => Ok(ValidatorType::Human),
+        Ok(proto::ValidatorType::Ai) => Ok(ValidatorType::AI),
+        Ok(proto::ValidatorType::System) => Ok(ValidatorType::System),
+        Ok(proto::ValidatorType::ThirdParty) => Ok(ValidatorType::ThirdParty),
+        Ok(proto::ValidatorType::Regulatory) => Ok(ValidatorType::Regulatory),
+        Ok(proto::ValidatorType::Unspecified) | Err(_) => Err(format!("Invalid validator type {}", value)),
+    }
+}
+
+fn attestation_result_to_proto(result: &AttestationResult) -> proto::AttestationResult {
+    match result {
+        AttestationResult::Verified => proto::AttestationResult::Verified,
+        AttestationResult::Rejected => proto::AttestationResult::Rejected,
+        AttestationResult::Pending => proto::AttestationResult::Pending,
+        AttestationResult::RequiresReview => proto::AttestationResult::RequiresReview,
+        AttestationResult::Expired => proto::AttestationResult::Expired,
+    }
+}
+
+fn attestation_result_from_proto(value: i32) -> Result<AttestationResult, String> {
+    match proto::AttestationResult::try_from(value) {
+        Ok(proto::AttestationResult::Verified) => Ok(AttestationResult::Verified),
+        Ok(proto::AttestationResult::Rejected) => Ok(AttestationResult::Rejected),
+        Ok(proto::AttestationResult::Pending) => Ok(AttestationResult::Pending),
+        Ok(proto::AttestationResult::RequiresReview) => Ok(AttestationResult::RequiresReview),
+        Ok(proto::AttestationResult::Expired) => Ok(AttestationResult::Expired),
+        Ok(proto::AttestationResult::Unspecified) | Err(_) => Err(format!("Invalid attestation result {}", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_protobuf_round_trip_still_verifies() {
+        let mut engine = VelocityCryptographicEngine::new();
+        engine.set_timestamp_authority(Some(Arc::new(MockTimestampAuthority::new("test-tsa"))));
+        let proof = engine.create_proof("protobuf evidence", "evidence");
+
+        let bytes = proof.to_proto().encode_to_vec();
+        let decoded = CryptographicProof::from_proto(proto::CryptographicProof::decode(bytes.as_slice()).unwrap()).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(&proof).unwrap());
+        assert!(engine.verify_proof(&decoded));
+
+        // Fields are still bound by the signature after decoding
+        let mut message = proof.to_proto();
+        message.nonce = CryptographicProof::generate_nonce();
+        assert!(!engine.verify_proof(&CryptographicProof::from_proto(message).unwrap()));
+
+        let mut message = proof.to_proto();
+        message.format_version = u32::from(u16::MAX) + 1;
+        assert!(CryptographicProof::from_proto(message).is_err());
+    }
+}
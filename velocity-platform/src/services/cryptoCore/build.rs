@@ -0,0 +1,9 @@
+fn main() -> std::io::Result<()> {
+    // Use the vendored protoc so builds do not depend on a system install
+    std::env::set_var(
+        "PROTOC",
+        protoc_bin_vendored::protoc_bin_path().expect("vendored protoc is available for this host"),
+    );
+    println!("cargo:rerun-if-changed=proto");
+    prost_build::compile_protos(&["proto/velocity/compliance/v1/compliance.proto"], &["proto"])
+}
@@ -20,6 +20,8 @@ export enum SignatureAlgorithm {
   EcdsaP256 = 1,
   RsaPss2048 = 2,
   PolygonEcdsa = 3,
+  Secp256k1Schnorr = 4,
+  MlDsa65 = 5, // native module built with the pq feature
 }
 
 export enum TrustActivityType {
@@ -91,6 +93,7 @@ export type DistributionType =
   | { type: 'Beta'; alpha: number; beta: number }
   | { type: 'Triangular'; min: number; mode: number; max: number }
   | { type: 'Empirical'; values: number[] }
+  | { type: 'EmpiricalWeighted'; values: number[]; weights: number[] }
 
 export interface MarketConditions {
   volatility: DistributionType
@@ -115,6 +118,17 @@ export interface SimulationResult {
   polygon_verification_rate: number
   percentiles: [number, number][]
   convergence_achieved: boolean
+  /** Statistics that could not be computed from the samples and were reported as 0, and other input adjustments */
+  data_quality: string[]
+  /** Rescaling applied to factor weights that did not sum to 1.0 */
+  weight_normalization: WeightNormalization | null
+  /** Kernel density estimate of the compliance score as [x, density] pairs, when requested */
+  density: [number, number][] | null
+}
+
+export interface WeightNormalization {
+  original_sum: number
+  scale: number
 }
 
 export interface Statistics {
@@ -125,6 +139,8 @@ export interface Statistics {
   max: number
   skewness: number
   kurtosis: number
+  /** Skewness or kurtosis is undefined for these samples and reported as 0 */
+  degenerate: boolean
 }
 
 export interface ConfidenceInterval {
@@ -137,6 +153,8 @@ export interface FactorSensitivity {
   factor_name: string
   correlation_with_compliance: number
   impact_magnitude: number
+  /** Correlation is undefined because a series has no variance; reported as 0 */
+  degenerate: boolean
 }
 
 /**
@@ -358,7 +376,8 @@ export class VelocityCryptoCore {
         min: 0.45,
         max: 0.95,
         skewness: -0.3,
-        kurtosis: 2.8
+        kurtosis: 2.8,
+        degenerate: false
       },
       risk_statistics: {
         mean: 0.25,
@@ -367,7 +386,8 @@ export class VelocityCryptoCore {
         min: 0.05,
         max: 0.55,
         skewness: 0.3,
-        kurtosis: 2.8
+        kurtosis: 2.8,
+        degenerate: false
       },
       confidence_intervals: [
         { confidence_level: 0.95, lower_bound: 0.51, upper_bound: 0.99 },
@@ -376,7 +396,8 @@ export class VelocityCryptoCore {
       factor_sensitivities: scenario.compliance_factors.map(f => ({
         factor_name: f.name,
         correlation_with_compliance: 0.5 + Math.random() * 0.4,
-        impact_magnitude: f.weight * (0.5 + Math.random() * 0.5)
+        impact_magnitude: f.weight * (0.5 + Math.random() * 0.5),
+        degenerate: false
       })),
       enforcement_probability: 0.05,
       polygon_verification_rate: scenario.polygon_verification_rate,
@@ -384,7 +405,10 @@ export class VelocityCryptoCore {
         [0.01, 0.45], [0.05, 0.52], [0.10, 0.58], [0.25, 0.68],
         [0.50, 0.76], [0.75, 0.84], [0.90, 0.90], [0.95, 0.93], [0.99, 0.95]
       ],
-      convergence_achieved: iterations >= 1000
+      convergence_achieved: iterations >= 1000,
+      data_quality: [],
+      weight_normalization: null,
+      density: null
     }
   }
 
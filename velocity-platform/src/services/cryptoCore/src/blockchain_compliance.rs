@@ -5,13 +5,75 @@
 
 use sha2::{Sha256, Digest};
 use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signature, Signer, Verifier};
-use ring::{digest, signature};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, BTreeMap};
+use std::collections::{HashMap, HashSet, BTreeMap};
+use std::ops::Bound;
 use std::time::{SystemTime, UNIX_EPOCH};
-use rayon::prelude::*;
-use crate::{Result, CryptoError};
+use crate::par::*;
+use crate::{ct_eq, Result, CryptoError};
+use crate::hash_engine::HashAlgorithm;
 use crate::merkle_tree::MerkleTree;
+use crate::canonical::{canonical_json, encode_fields};
+use crate::zk::{self, EvidenceCountCommitment, EvidenceCountOpening, EvidenceSufficiencyProof};
+use rand::RngCore;
+use serde_json::Value;
+
+/// Compliance data fields that determine the evidence Merkle root. They stay
+/// disclosed so verifiers of a redacted proof can still recompute the root.
+const MERKLE_BOUND_FIELDS: [&str; 2] = ["evidence_count", "assessment_date"];
+
+/// Largest evidence count a proof may commit to. Verifiers rebuild the evidence
+/// tree from the count, so the bound also caps the work an unchecked proof costs.
+pub const MAX_EVIDENCE_COUNT: u32 = 100_000;
+
+/// Proofs returned per page when the filter does not set a page size
+pub const DEFAULT_PROOF_PAGE_SIZE: usize = 100;
+
+/// Upper bound on the page size accepted by `query_proofs`
+pub const MAX_PROOF_PAGE_SIZE: usize = 1000;
+
+/// Domain of the engine's signature over a proof's blockchain hash
+pub const DOMAIN_COMPLIANCE_PROOF: &str = "velocity:compliance-proof:v1";
+
+/// Domain of audit entry signatures
+pub const DOMAIN_AUDIT_ENTRY: &str = "velocity:audit-entry:v1";
+
+/// Domain of trusted partner verification signatures
+pub const DOMAIN_PARTNER_VERIFICATION: &str = "velocity:partner-verification:v1";
+
+/// Domain of network consensus participant signatures
+pub const DOMAIN_NETWORK_CONSENSUS: &str = "velocity:network-consensus:v1";
+
+/// Domain of the engine's signature over a compliance passport
+pub const DOMAIN_COMPLIANCE_PASSPORT: &str = "velocity:compliance-passport:v1";
+
+/// Blockchain hash over the plain concatenation of its fields, as on proofs
+/// issued before `BLOCKCHAIN_HASH_VERSION`
+pub const LEGACY_BLOCKCHAIN_HASH_VERSION: u32 = 0;
+
+/// Blockchain hash over `encode_fields` of its fields
+pub const BLOCKCHAIN_HASH_VERSION: u32 = 1;
+
+/// `message` prefixed with its domain-separation tag, so a signature over one
+/// kind of message never verifies as another
+fn domain_separated(domain: &str, message: &str) -> Vec<u8> {
+    format!("{}|{}", domain, message).into_bytes()
+}
+
+/// Names of the `ComplianceData` fields, including ones skipped when empty
+const COMPLIANCE_DATA_FIELDS: [&str; 11] = [
+    "framework",
+    "version",
+    "controls_assessed",
+    "controls_passed",
+    "compliance_score",
+    "evidence_count",
+    "assessment_date",
+    "valid_until",
+    "assessor",
+    "metadata",
+    "controls",
+];
 
 /// Compliance proof with blockchain verification
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +89,188 @@ pub struct ComplianceProof {
     pub audit_chain: Vec<AuditEntry>,
     pub cross_industry_attestation: Option<CrossIndustryAttestation>,
     pub network_consensus: Option<NetworkConsensus>,
+    /// Hex-encoded key for the per-field salts of the compliance data
+    /// commitments. Proofs without one sign `canonical_json(compliance_data)`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub disclosure_salt: String,
+    /// Hex-encoded Pedersen commitment to the evidence count, covered by the
+    /// blockchain hash. Empty on proofs issued before evidence commitments.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub evidence_commitment: String,
+    /// Encoding of the blockchain hash input; absent on legacy proofs
+    #[serde(default)]
+    pub hash_version: u32,
+}
+
+impl ComplianceProof {
+    /// Compact CBOR encoding for network transport
+    ///
+    /// Verification hashes `canonical_json` of the decoded fields rather than the
+    /// transport bytes, so CBOR-transported proofs verify exactly like JSON ones.
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(self, &mut bytes).expect("compliance proofs are always serializable");
+        bytes
+    }
+
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self> {
+        ciborium::de::from_reader(bytes)
+            .map_err(|e| CryptoError::SerializationError(format!("Invalid CBOR proof: {}", e)))
+    }
+
+    /// Copy of the proof with the named `ComplianceData` fields replaced by their
+    /// salted commitments
+    ///
+    /// The signature still verifies through `verify_redacted_proof`, while the
+    /// hidden values cannot be recovered: each commitment is keyed by a salt that
+    /// is only released for disclosed fields.
+    pub fn redact(&self, fields: &[&str]) -> Result<RedactedComplianceProof> {
+        if self.disclosure_salt.is_empty() {
+            return Err(CryptoError::InvalidInput("Proof predates selective disclosure".to_string()));
+        }
+
+        let values = compliance_data_fields(&self.compliance_data)?;
+        for field in fields {
+            if MERKLE_BOUND_FIELDS.contains(field) {
+                return Err(CryptoError::InvalidInput(format!(
+                    "{} determines the Merkle root and cannot be redacted",
+                    field
+                )));
+            }
+            if !COMPLIANCE_DATA_FIELDS.contains(field) {
+                return Err(CryptoError::InvalidInput(format!("Unknown compliance data field {}", field)));
+            }
+        }
+
+        let mut disclosed = BTreeMap::new();
+        for (field, value) in values {
+            let salt = field_salt(&self.disclosure_salt, &field)?;
+            let entry = if fields.contains(&field.as_str()) {
+                DisclosedField::Redacted { commitment: field_commitment(&field, &salt, &value)? }
+            } else {
+                DisclosedField::Disclosed { value, salt }
+            };
+            disclosed.insert(field, entry);
+        }
+
+        Ok(RedactedComplianceProof {
+            id: self.id.clone(),
+            entity_id: self.entity_id.clone(),
+            framework_type: self.framework_type.clone(),
+            fields: disclosed,
+            blockchain_hash: self.blockchain_hash.clone(),
+            merkle_root: self.merkle_root.clone(),
+            timestamp: self.timestamp,
+            verification_signature: self.verification_signature.clone(),
+            evidence_commitment: self.evidence_commitment.clone(),
+            hash_version: self.hash_version,
+        })
+    }
+}
+
+/// Compliance proof with some compliance data fields hidden behind commitments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactedComplianceProof {
+    pub id: String,
+    pub entity_id: String,
+    pub framework_type: String,
+    /// Every serialized `ComplianceData` field, keyed by name
+    pub fields: BTreeMap<String, DisclosedField>,
+    pub blockchain_hash: String,
+    pub merkle_root: String,
+    pub timestamp: u64,
+    pub verification_signature: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub evidence_commitment: String,
+    #[serde(default)]
+    pub hash_version: u32,
+}
+
+/// A compliance data field of a redacted proof
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum DisclosedField {
+    /// Revealed value with the salt that opens its commitment
+    Disclosed { value: Value, salt: String },
+    /// Commitment standing in for a hidden value
+    Redacted { commitment: String },
+}
+
+fn compliance_data_fields(compliance_data: &ComplianceData) -> Result<serde_json::Map<String, Value>> {
+    match serde_json::to_value(compliance_data) {
+        Ok(Value::Object(fields)) => Ok(fields),
+        Ok(_) => Err(CryptoError::SerializationError("Compliance data is not an object".to_string())),
+        Err(e) => Err(CryptoError::SerializationError(e.to_string())),
+    }
+}
+
+/// Per-field salt, derived so that releasing one field's salt reveals nothing
+/// about the others
+fn field_salt(disclosure_salt: &str, field: &str) -> Result<String> {
+    let key: [u8; 32] = hex::decode(disclosure_salt)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| CryptoError::InvalidInput("Disclosure salt must be 32 hex-encoded bytes".to_string()))?;
+    Ok(hex::encode(blake3::keyed_hash(&key, field.as_bytes()).as_bytes()))
+}
+
+fn field_commitment(field: &str, salt: &str, value: &Value) -> Result<String> {
+    let opening = format!("{}:{}:{}", field, salt, canonical_json(value)?);
+    Ok(hex::encode(blake3::hash(opening.as_bytes()).as_bytes()))
+}
+
+/// Digest over the sorted field commitments, which the blockchain hash covers
+/// in place of the compliance data itself
+fn commitment_root(commitments: &BTreeMap<String, String>) -> String {
+    let leaves: String = commitments.iter()
+        .map(|(field, commitment)| format!("{}={};", field, commitment))
+        .collect();
+    hex::encode(blake3::hash(leaves.as_bytes()).as_bytes())
+}
+
+/// Blockchain hash input over entity, framework, compliance data digest, Merkle
+/// root, timestamp and evidence commitment, encoded as `hash_version` specifies
+fn blockchain_hash_input(hash_version: u32, fields: [&str; 6]) -> Result<String> {
+    match hash_version {
+        BLOCKCHAIN_HASH_VERSION => Ok(encode_fields(&fields)),
+        LEGACY_BLOCKCHAIN_HASH_VERSION => Ok(fields.concat()),
+        other => Err(CryptoError::VerificationFailed(format!("Unsupported blockchain hash version {}", other))),
+    }
+}
+
+/// Hash input of an audit entry
+fn audit_entry_hash_input(timestamp: u64, action: &str, actor: &str, details: &str) -> String {
+    encode_fields(&[&timestamp.to_string(), action, actor, details])
+}
+
+/// Hash input of a consensus proof over the participant signatures
+fn consensus_proof_input(consensus_hash: &str, signatures: &[String]) -> String {
+    let mut fields = vec![consensus_hash];
+    fields.extend(signatures.iter().map(String::as_str));
+    encode_fields(&fields)
+}
+
+/// Root of the evidence tree for `evidence_count` items assessed on `assessment_date`
+fn evidence_merkle_root(evidence_count: u32, assessment_date: u64) -> Result<String> {
+    let evidence_data: Vec<String> = (0..evidence_count)
+        .map(|i| format!("evidence-{}-{}", i, assessment_date))
+        .collect();
+    Ok(MerkleTree::new(evidence_data)?.root_hash())
+}
+
+/// Compliance data component of the signed blockchain hash
+fn compliance_data_digest(compliance_data: &ComplianceData, disclosure_salt: &str) -> Result<String> {
+    if disclosure_salt.is_empty() {
+        return canonical_json(compliance_data);
+    }
+
+    let mut commitments = BTreeMap::new();
+    for (field, value) in compliance_data_fields(compliance_data)? {
+        let salt = field_salt(disclosure_salt, &field)?;
+        let commitment = field_commitment(&field, &salt, &value)?;
+        commitments.insert(field, commitment);
+    }
+    Ok(commitment_root(&commitments))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +285,31 @@ pub struct ComplianceData {
     pub valid_until: u64,
     pub assessor: String,
     pub metadata: HashMap<String, String>,
+    /// Per-control results keyed by control ID, when the assessment recorded them
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub controls: HashMap<String, ControlResult>,
+}
+
+/// Outcome of a single control within an assessment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlResult {
+    pub passed: bool,
+    /// Source framework controls this result was projected from; empty when the
+    /// control was assessed directly
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub derived_from: Vec<String>,
+}
+
+/// Equivalences between the controls of two frameworks, used to derive a proof
+/// for the target framework from an assessment against the source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlMapping {
+    pub source_framework: String,
+    pub target_framework: String,
+    /// Framework version recorded on derived proofs
+    pub target_version: String,
+    /// Target control ID -> source control IDs that must all pass to credit it
+    pub controls: HashMap<String, Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -124,12 +393,99 @@ pub struct VerificationDetails {
     pub network_consensus_valid: bool,
 }
 
+/// Criteria and position for `BlockchainComplianceEngine::query_proofs`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProofFilter {
+    pub entity_id: Option<String>,
+    pub framework_type: Option<String>,
+    /// Only proofs assessed at or before and valid after this Unix time
+    pub valid_at: Option<u64>,
+    /// `next_cursor` of the previous page; `None` starts from the oldest proof
+    pub cursor: Option<String>,
+    /// Defaults to `DEFAULT_PROOF_PAGE_SIZE`
+    pub page_size: Option<usize>,
+}
+
+/// One page of proofs in insertion order
+#[derive(Debug, Clone, Serialize)]
+pub struct ProofPage<'a> {
+    pub proofs: Vec<&'a ComplianceProof>,
+    /// Resume point after this page. Proofs stored later are always ordered
+    /// after it, so a client can keep polling with it to pick up new proofs.
+    pub next_cursor: Option<String>,
+    /// Whether matching proofs were stored after this page at query time
+    pub has_more: bool,
+}
+
+/// One entity's current compliance proofs in a single signed artifact
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompliancePassport {
+    pub entity_id: String,
+    pub issued_at: u64,
+    /// Hex Merkle root over the entries' blockchain hashes, in entry order
+    pub merkle_root: String,
+    pub entries: Vec<PassportEntry>,
+    /// Hex engine signature over the entity, issue time, root and entries
+    pub signature: String,
+}
+
+/// A proof included in a passport, with its validity window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PassportEntry {
+    pub proof_id: String,
+    pub framework_type: String,
+    pub blockchain_hash: String,
+    pub valid_from: u64,
+    pub valid_until: u64,
+}
+
+impl CompliancePassport {
+    fn signing_payload(&self) -> Result<Vec<u8>> {
+        let body = canonical_json(&(&self.entity_id, self.issued_at, &self.merkle_root, &self.entries))?;
+        Ok(domain_separated(DOMAIN_COMPLIANCE_PASSPORT, &body))
+    }
+}
+
+/// Merkle root over the blockchain hashes of `entries`
+fn passport_root(entries: &[PassportEntry]) -> Result<String> {
+    let leaves = entries.iter()
+        .map(|entry| hex::decode(&entry.blockchain_hash)
+            .map_err(|e| CryptoError::InvalidInput(format!("Invalid blockchain hash for proof {}: {}", entry.proof_id, e))))
+        .collect::<Result<Vec<Vec<u8>>>>()?;
+    Ok(MerkleTree::new(leaves, HashAlgorithm::Blake3)?.root_hash())
+}
+
+/// Signed, not yet recorded parts of a new proof
+struct SealedProof {
+    framework_type: String,
+    compliance_data: ComplianceData,
+    blockchain_hash: String,
+    merkle_root: String,
+    timestamp: u64,
+    verification_signature: String,
+    disclosure_salt: String,
+    evidence_commitment: String,
+    evidence_opening: EvidenceCountOpening,
+}
+
 /// Main blockchain compliance verification engine
 pub struct BlockchainComplianceEngine {
-    keypair: Keypair,
+    /// Signing key; None on verify-only engines
+    keypair: Option<Keypair>,
+    /// Key proofs, audit entries and passports are verified against
+    public_key: PublicKey,
     trusted_partners: HashMap<String, TrustedPartnerVerification>,
     audit_chain: Vec<AuditEntry>,
     proofs: HashMap<String, ComplianceProof>,
+    /// Proof IDs by insertion sequence, which query cursors refer to
+    proof_order: BTreeMap<u64, String>,
+    next_proof_sequence: u64,
+    /// Registered mappings keyed by (source framework, target framework)
+    control_mappings: HashMap<(String, String), ControlMapping>,
+    /// Revocation time of each revoked proof
+    revoked_proofs: HashMap<String, u64>,
+    /// Openings of the evidence count commitments of issued proofs
+    evidence_openings: HashMap<String, EvidenceCountOpening>,
 }
 
 impl BlockchainComplianceEngine {
@@ -137,18 +493,49 @@ impl BlockchainComplianceEngine {
     pub fn new() -> Result<Self> {
         let mut csprng = rand::rngs::OsRng {};
         let keypair = Keypair::generate(&mut csprng);
-        
+        let public_key = keypair.public;
+        Self::with_keys(Some(keypair), public_key)
+    }
+
+    /// Verify-only engine for proofs issued by the engine whose hex-encoded
+    /// ed25519 key is `issuer_public_key`; operations that sign fail
+    pub fn verifier(issuer_public_key: &str) -> Result<Self> {
+        let public_key = hex::decode(issuer_public_key)
+            .ok()
+            .and_then(|bytes| PublicKey::from_bytes(&bytes).ok())
+            .ok_or_else(|| CryptoError::InvalidInput("Issuer public key must be a hex-encoded ed25519 key".to_string()))?;
+        Self::with_keys(None, public_key)
+    }
+
+    fn with_keys(keypair: Option<Keypair>, public_key: PublicKey) -> Result<Self> {
         let mut engine = Self {
             keypair,
+            public_key,
             trusted_partners: HashMap::new(),
             audit_chain: Vec::new(),
             proofs: HashMap::new(),
+            proof_order: BTreeMap::new(),
+            next_proof_sequence: 0,
+            control_mappings: HashMap::new(),
+            revoked_proofs: HashMap::new(),
+            evidence_openings: HashMap::new(),
         };
         
         engine.initialize_trusted_network()?;
         Ok(engine)
     }
 
+    /// Hex-encoded ed25519 key that verifies this engine's signatures
+    pub fn public_key(&self) -> String {
+        hex::encode(self.public_key.to_bytes())
+    }
+
+    fn signer(&self) -> Result<&Keypair> {
+        self.keypair
+            .as_ref()
+            .ok_or_else(|| CryptoError::InvalidInput("Verify-only engine cannot sign".to_string()))
+    }
+
     /// Initialize trusted network with major industry partners
     fn initialize_trusted_network(&mut self) -> Result<()> {
         let partners = vec![
@@ -166,7 +553,7 @@ impl BlockchainComplianceEngine {
             
             let verification_data = format!("{}-{}", partner_id, self.current_timestamp());
             let verification_hash = self.blake3_hash(&verification_data);
-            let signature = partner_keypair.sign(verification_hash.as_bytes());
+            let signature = partner_keypair.sign(&domain_separated(DOMAIN_PARTNER_VERIFICATION, &verification_hash));
             
             let trusted_partner = TrustedPartnerVerification {
                 partner_id: partner_id.to_string(),
@@ -185,6 +572,16 @@ impl BlockchainComplianceEngine {
     }
 
     /// Create cryptographically verified compliance proof
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "crypto.create_proof",
+        skip_all,
+        fields(
+            framework = %framework_type,
+            evidence_count = compliance_data.evidence_count,
+            cross_industry = enable_cross_industry,
+            algorithm = "ed25519",
+        ),
+    ))]
     pub fn create_compliance_proof(
         &mut self,
         entity_id: String,
@@ -192,31 +589,120 @@ impl BlockchainComplianceEngine {
         compliance_data: ComplianceData,
         enable_cross_industry: bool,
     ) -> Result<ComplianceProof> {
-        let proof_id = self.generate_proof_id(&entity_id, &framework_type);
-        
+        let sealed = self.seal_proof(&entity_id, framework_type, compliance_data, self.current_timestamp())?;
+
+        // Process cross-industry attestation if enabled
+        let cross_industry_attestation = if enable_cross_industry {
+            Some(self.create_cross_industry_attestation(&entity_id, &[sealed.framework_type.clone()])?)
+        } else {
+            None
+        };
+
+        self.finish_proof(entity_id, sealed, cross_industry_attestation)
+    }
+
+    /// Create proofs for several frameworks of one entity in a single pass
+    ///
+    /// The cross-industry attestation is built once, listing every framework in
+    /// the batch, and shared by all the proofs. Network consensus is still
+    /// reached per proof since it covers each proof's blockchain hash. Evidence
+    /// trees, hashes and signatures are computed in parallel.
+    pub fn create_compliance_proofs_batch(
+        &mut self,
+        entity_id: String,
+        frameworks: Vec<(String, ComplianceData)>,
+    ) -> Result<Vec<ComplianceProof>> {
+        if frameworks.is_empty() {
+            return Err(CryptoError::InvalidInput("Proof batch must contain at least one framework".to_string()));
+        }
+        let mut seen = HashSet::new();
+        if let Some((framework_type, _)) = frameworks.iter().find(|(framework_type, _)| !seen.insert(framework_type.as_str())) {
+            return Err(CryptoError::InvalidInput(format!("Framework {} appears twice in the batch", framework_type)));
+        }
+
+        let framework_types: Vec<String> = frameworks.iter().map(|(framework_type, _)| framework_type.clone()).collect();
+        let attestation = self.create_cross_industry_attestation(&entity_id, &framework_types)?;
+
+        let timestamp = self.current_timestamp();
+        let sealed = {
+            let engine = &*self;
+            frameworks
+                .into_par_iter()
+                .map(|(framework_type, compliance_data)| engine.seal_proof(&entity_id, framework_type, compliance_data, timestamp))
+                .collect::<Result<Vec<SealedProof>>>()?
+        };
+
+        sealed
+            .into_iter()
+            .map(|sealed| self.finish_proof(entity_id.clone(), sealed, Some(attestation.clone())))
+            .collect()
+    }
+
+    /// Evidence Merkle root, field commitments, blockchain hash and signature of
+    /// a new proof; needs no mutable state, so batches seal in parallel
+    fn seal_proof(
+        &self,
+        entity_id: &str,
+        framework_type: String,
+        compliance_data: ComplianceData,
+        timestamp: u64,
+    ) -> Result<SealedProof> {
+        if compliance_data.evidence_count > MAX_EVIDENCE_COUNT {
+            return Err(CryptoError::InvalidInput(format!(
+                "Evidence count {} exceeds the maximum of {}",
+                compliance_data.evidence_count, MAX_EVIDENCE_COUNT
+            )));
+        }
+
         // Create Merkle tree for evidence integrity
-        let evidence_data: Vec<String> = (0..compliance_data.evidence_count)
-            .map(|i| format!("evidence-{}-{}", i, compliance_data.assessment_date))
-            .collect();
-        
-        let merkle_tree = MerkleTree::new(evidence_data)?;
-        let merkle_root = merkle_tree.root_hash();
+        let merkle_root = evidence_merkle_root(compliance_data.evidence_count, compliance_data.assessment_date)?;
         
+        // Commit to each compliance data field under its own salt so the proof
+        // can later be redacted without invalidating the signature
+        let mut salt = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut salt);
+        let disclosure_salt = hex::encode(salt);
+
+        // Commit to the evidence count so sufficiency can be proven without it
+        let (evidence_commitment, evidence_opening) = zk::commit_count(compliance_data.evidence_count);
+
         // Create blockchain hash using BLAKE3 for performance
-        let blockchain_data = format!(
-            "{}{}{}{}{}",
+        let blockchain_data = blockchain_hash_input(BLOCKCHAIN_HASH_VERSION, [
             entity_id,
-            framework_type,
-            serde_json::to_string(&compliance_data).map_err(|e| CryptoError::SerializationError(e.to_string()))?,
-            merkle_root,
-            self.current_timestamp()
-        );
+            &framework_type,
+            &compliance_data_digest(&compliance_data, &disclosure_salt)?,
+            &merkle_root,
+            &timestamp.to_string(),
+            &evidence_commitment,
+        ])?;
         let blockchain_hash = self.blake3_hash(&blockchain_data);
         
         // Create verification signature
-        let signature = self.keypair.sign(blockchain_hash.as_bytes());
+        let signature = self.signer()?.sign(&domain_separated(DOMAIN_COMPLIANCE_PROOF, &blockchain_hash));
         let verification_signature = hex::encode(signature.to_bytes());
-        
+
+        Ok(SealedProof {
+            framework_type,
+            compliance_data,
+            blockchain_hash,
+            merkle_root,
+            timestamp,
+            verification_signature,
+            disclosure_salt,
+            evidence_commitment,
+            evidence_opening,
+        })
+    }
+
+    /// Record, reach consensus on and store a sealed proof
+    fn finish_proof(
+        &mut self,
+        entity_id: String,
+        sealed: SealedProof,
+        cross_industry_attestation: Option<CrossIndustryAttestation>,
+    ) -> Result<ComplianceProof> {
+        let proof_id = self.generate_proof_id(&entity_id, &sealed.framework_type);
+
         // Create initial audit entry
         let audit_entry = self.create_audit_entry(
             "proof-created".to_string(),
@@ -224,45 +710,219 @@ impl BlockchainComplianceEngine {
             format!("Compliance proof created for entity {}", entity_id),
         )?;
         
-        // Process cross-industry attestation if enabled
-        let cross_industry_attestation = if enable_cross_industry {
-            Some(self.create_cross_industry_attestation(&entity_id, &framework_type)?)
-        } else {
-            None
-        };
-        
         // Create network consensus if cross-industry attestation is enabled
         let network_consensus = if cross_industry_attestation.is_some() {
-            Some(self.create_network_consensus(&proof_id, &blockchain_hash)?)
+            Some(self.create_network_consensus(&proof_id, &sealed.blockchain_hash)?)
         } else {
             None
         };
         
         let proof = ComplianceProof {
-            id: proof_id.clone(),
+            id: proof_id,
             entity_id,
-            framework_type,
-            compliance_data,
-            blockchain_hash,
-            merkle_root,
-            timestamp: self.current_timestamp(),
-            verification_signature,
+            framework_type: sealed.framework_type,
+            compliance_data: sealed.compliance_data,
+            blockchain_hash: sealed.blockchain_hash,
+            merkle_root: sealed.merkle_root,
+            timestamp: sealed.timestamp,
+            verification_signature: sealed.verification_signature,
             audit_chain: vec![audit_entry],
             cross_industry_attestation,
             network_consensus,
+            disclosure_salt: sealed.disclosure_salt,
+            evidence_commitment: sealed.evidence_commitment,
+            hash_version: BLOCKCHAIN_HASH_VERSION,
         };
         
         // Store proof
-        self.proofs.insert(proof_id, proof.clone());
+        self.evidence_openings.insert(proof.id.clone(), sealed.evidence_opening);
+        self.store_proof(proof.clone());
         
         Ok(proof)
     }
 
+    /// Register the control mapping used to derive proofs from
+    /// `source_framework` into `target_framework`, replacing any previous one
+    pub fn register_control_mapping(&mut self, mapping: ControlMapping) -> Result<()> {
+        if mapping.source_framework == mapping.target_framework {
+            return Err(CryptoError::InvalidInput("Control mapping must span two frameworks".to_string()));
+        }
+        if mapping.controls.is_empty() || mapping.controls.values().any(Vec::is_empty) {
+            return Err(CryptoError::InvalidInput(
+                "Every mapped control needs at least one source control".to_string(),
+            ));
+        }
+
+        let key = (mapping.source_framework.clone(), mapping.target_framework.clone());
+        self.control_mappings.insert(key, mapping);
+        Ok(())
+    }
+
+    /// Derive a proof for `target_framework` from a stored proof's control results
+    ///
+    /// Only target controls whose source controls were all assessed are carried
+    /// over, each marked with the source controls it was derived from, and the
+    /// score is recomputed over those controls alone.
+    pub fn derive_proof(&mut self, source_proof_id: &str, target_framework: &str) -> Result<ComplianceProof> {
+        let source = self.proofs.get(source_proof_id)
+            .ok_or_else(|| CryptoError::InvalidInput(format!("Proof {} not found", source_proof_id)))?
+            .clone();
+        let mapping = self.control_mappings
+            .get(&(source.framework_type.clone(), target_framework.to_string()))
+            .ok_or_else(|| CryptoError::InvalidInput(format!(
+                "No control mapping from {} to {}",
+                source.framework_type, target_framework
+            )))?;
+
+        let source_controls = &source.compliance_data.controls;
+        let controls: HashMap<String, ControlResult> = mapping.controls.iter()
+            .filter(|(_, mapped)| mapped.iter().all(|control| source_controls.contains_key(control)))
+            .map(|(target_control, mapped)| {
+                let result = ControlResult {
+                    passed: mapped.iter().all(|control| source_controls[control].passed),
+                    derived_from: mapped.clone(),
+                };
+                (target_control.clone(), result)
+            })
+            .collect();
+        if controls.is_empty() {
+            return Err(CryptoError::InvalidInput(format!(
+                "Proof {} has no assessed controls mapped to {}",
+                source_proof_id, target_framework
+            )));
+        }
+
+        let controls_assessed = controls.len() as u32;
+        let controls_passed = controls.values().filter(|result| result.passed).count() as u32;
+        let mut metadata = source.compliance_data.metadata.clone();
+        metadata.insert("derived_from_proof".to_string(), source.id.clone());
+
+        let compliance_data = ComplianceData {
+            framework: target_framework.to_string(),
+            version: mapping.target_version.clone(),
+            controls_assessed,
+            controls_passed,
+            compliance_score: controls_passed as f64 / controls_assessed as f64 * 100.0,
+            evidence_count: source.compliance_data.evidence_count,
+            assessment_date: source.compliance_data.assessment_date,
+            valid_until: source.compliance_data.valid_until,
+            assessor: source.compliance_data.assessor.clone(),
+            metadata,
+            controls,
+        };
+
+        let mut proof = self.create_compliance_proof(
+            source.entity_id.clone(),
+            target_framework.to_string(),
+            compliance_data,
+            source.cross_industry_attestation.is_some(),
+        )?;
+        let audit_entry = self.create_audit_entry(
+            "proof-derived".to_string(),
+            "blockchain-engine".to_string(),
+            format!("Derived from proof {} via {} control mapping", source.id, source.framework_type),
+        )?;
+        proof.audit_chain.push(audit_entry);
+        self.store_proof(proof.clone());
+
+        Ok(proof)
+    }
+
+    /// Revoke a stored proof, leaving it out of passports built from now on and
+    /// failing passports that already include it
+    pub fn revoke_proof(&mut self, proof_id: &str, reason: &str) -> Result<()> {
+        if !self.proofs.contains_key(proof_id) {
+            return Err(CryptoError::InvalidInput(format!("Proof {} not found", proof_id)));
+        }
+        if self.revoked_proofs.contains_key(proof_id) {
+            return Err(CryptoError::InvalidInput(format!("Proof {} is already revoked", proof_id)));
+        }
+
+        self.create_audit_entry(
+            "proof-revoked".to_string(),
+            "blockchain-engine".to_string(),
+            format!("Proof {} revoked: {}", proof_id, reason),
+        )?;
+        self.revoked_proofs.insert(proof_id.to_string(), self.current_timestamp());
+        Ok(())
+    }
+
+    /// Whether `proof_id` has been revoked
+    pub fn is_revoked(&self, proof_id: &str) -> bool {
+        self.revoked_proofs.contains_key(proof_id)
+    }
+
+    /// Sign a passport over every stored proof of `entity_id` that currently
+    /// verifies and is not revoked, in the order the proofs were stored
+    pub fn build_passport(&self, entity_id: &str) -> Result<CompliancePassport> {
+        let mut entries = Vec::new();
+        for proof in self.proof_order.values().map(|proof_id| &self.proofs[proof_id]) {
+            if proof.entity_id != entity_id || self.is_revoked(&proof.id) || !self.verify_proof(proof)?.is_valid {
+                continue;
+            }
+            entries.push(PassportEntry {
+                proof_id: proof.id.clone(),
+                framework_type: proof.framework_type.clone(),
+                blockchain_hash: proof.blockchain_hash.clone(),
+                valid_from: proof.compliance_data.assessment_date,
+                valid_until: proof.compliance_data.valid_until,
+            });
+        }
+        if entries.is_empty() {
+            return Err(CryptoError::InvalidInput(format!("Entity {} has no valid compliance proofs", entity_id)));
+        }
+
+        let mut passport = CompliancePassport {
+            entity_id: entity_id.to_string(),
+            issued_at: self.current_timestamp(),
+            merkle_root: passport_root(&entries)?,
+            entries,
+            signature: String::new(),
+        };
+        let signature = self.signer()?.sign(&passport.signing_payload()?);
+        passport.signature = hex::encode(signature.to_bytes());
+        Ok(passport)
+    }
+
+    /// Check a passport's signature and root, and that none of its proofs has
+    /// expired or been revoked since it was issued
+    pub fn verify_passport(&self, passport: &CompliancePassport) -> Result<bool> {
+        let signature = match hex::decode(&passport.signature).ok().and_then(|bytes| Signature::from_bytes(&bytes).ok()) {
+            Some(signature) => signature,
+            None => return Ok(false),
+        };
+        if self.public_key.verify(&passport.signing_payload()?, &signature).is_err() {
+            return Ok(false);
+        }
+        if passport.entries.is_empty() || !ct_eq(passport_root(&passport.entries)?.as_bytes(), passport.merkle_root.as_bytes()) {
+            return Ok(false);
+        }
+
+        let now = self.current_timestamp();
+        Ok(passport.entries.iter().all(|entry| now < entry.valid_until && !self.is_revoked(&entry.proof_id)))
+    }
+
     /// Verify compliance proof cryptographic integrity
     pub fn verify_compliance_proof(&self, proof_id: &str) -> Result<VerificationResult> {
         let proof = self.proofs.get(proof_id)
             .ok_or_else(|| CryptoError::InvalidInput(format!("Proof {} not found", proof_id)))?;
-            
+        
+        self.verify_proof(proof)
+    }
+
+    /// Verify a compliance proof supplied by a caller rather than looked up by ID
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "crypto.verify_proof",
+        skip_all,
+        fields(
+            proof_id = %proof.id,
+            framework = %proof.framework_type,
+            audit_entries = proof.audit_chain.len(),
+            algorithm = "ed25519",
+        ),
+    ))]
+    pub fn verify_proof(&self, proof: &ComplianceProof) -> Result<VerificationResult> {
+        let proof_id = proof.id.as_str();
         let mut verification_details = VerificationDetails {
             cryptographic_integrity: false,
             temporal_validity: false,
@@ -335,13 +995,13 @@ impl BlockchainComplianceEngine {
         })
     }
 
-    /// Create cross-industry attestation with trusted partners
+    /// Create cross-industry attestation with trusted partners over `framework_types`
     fn create_cross_industry_attestation(
         &self,
         entity_id: &str,
-        framework_type: &str,
+        framework_types: &[String],
     ) -> Result<CrossIndustryAttestation> {
-        let attestation_data = format!("{}-{}-{}", entity_id, framework_type, self.current_timestamp());
+        let attestation_data = format!("{}-{}-{}", entity_id, framework_types.join(","), self.current_timestamp());
         let network_hash = self.blake3_hash(&attestation_data);
         
         // Select relevant trusted partners (parallel processing)
@@ -359,7 +1019,7 @@ impl BlockchainComplianceEngine {
             industry_type: "multi-industry".to_string(),
             regulatory_body: "cross-regulatory".to_string(),
             attestation_level: AttestationLevel::Gold,
-            cross_validated_frameworks: vec![framework_type.to_string()],
+            cross_validated_frameworks: framework_types.to_vec(),
             trusted_partner_verifications: relevant_partners,
             network_hash,
         })
@@ -378,11 +1038,12 @@ impl BlockchainComplianceEngine {
         let consensus_threshold = ((participant_count as f64) * 0.67).ceil() as u32; // 67% consensus
         
         // Generate participant signatures (parallel processing)
+        let signer = self.signer()?;
         let participant_signatures: Vec<String> = self.trusted_partners
             .par_iter()
             .map(|(partner_id, _)| {
                 let signature_data = format!("{}-{}", consensus_hash, partner_id);
-                let signature = self.keypair.sign(signature_data.as_bytes());
+                let signature = signer.sign(&domain_separated(DOMAIN_NETWORK_CONSENSUS, &signature_data));
                 hex::encode(signature.to_bytes())
             })
             .collect();
@@ -403,46 +1064,136 @@ impl BlockchainComplianceEngine {
         })
     }
 
-    /// Verify cryptographic integrity of proof
-    fn verify_cryptographic_integrity(&self, proof: &ComplianceProof) -> Result<bool> {
-        // Verify digital signature
-        let signature_bytes = hex::decode(&proof.verification_signature)
+    /// Verify a redacted proof against the signature of the original
+    ///
+    /// Disclosed fields are opened against their commitments and redacted ones
+    /// are taken as given, so any altered value breaks the blockchain hash. The
+    /// Merkle root is recomputed from the disclosed evidence count and date,
+    /// only once the signature holds and the count is within bounds.
+    pub fn verify_redacted_proof(&self, proof: &RedactedComplianceProof) -> Result<bool> {
+        if !self.verify_signature(&proof.blockchain_hash, &proof.verification_signature)? {
+            return Ok(false);
+        }
+
+        let mut commitments = BTreeMap::new();
+        for (field, entry) in &proof.fields {
+            let commitment = match entry {
+                DisclosedField::Disclosed { value, salt } => field_commitment(field, salt, value)?,
+                DisclosedField::Redacted { commitment } => commitment.clone(),
+            };
+            commitments.insert(field.clone(), commitment);
+        }
+
+        let disclosed = |field: &str| match proof.fields.get(field) {
+            Some(DisclosedField::Disclosed { value, .. }) => value.as_u64(),
+            _ => None,
+        };
+        let evidence_count = disclosed("evidence_count").and_then(|count| u32::try_from(count).ok());
+        let (evidence_count, assessment_date) = match (evidence_count, disclosed("assessment_date")) {
+            (Some(evidence_count), Some(assessment_date)) if evidence_count <= MAX_EVIDENCE_COUNT => {
+                (evidence_count, assessment_date)
+            }
+            _ => return Ok(false),
+        };
+
+        let merkle_valid = ct_eq(evidence_merkle_root(evidence_count, assessment_date)?, &proof.merkle_root);
+
+        let blockchain_data = blockchain_hash_input(proof.hash_version, [
+            &proof.entity_id,
+            &proof.framework_type,
+            &commitment_root(&commitments),
+            &proof.merkle_root,
+            &proof.timestamp.to_string(),
+            &proof.evidence_commitment,
+        ])?;
+        let hash_valid = ct_eq(self.blake3_hash(&blockchain_data), &proof.blockchain_hash);
+
+        Ok(merkle_valid && hash_valid)
+    }
+
+    /// Prove that the evidence count of the issued proof `proof_id` is at least
+    /// `minimum`, without revealing the count
+    pub fn prove_evidence_sufficient(&self, proof_id: &str, minimum: u32) -> Result<EvidenceSufficiencyProof> {
+        let proof = self.proofs.get(proof_id)
+            .ok_or_else(|| CryptoError::InvalidInput(format!("Proof {} not found", proof_id)))?;
+        let opening = self.evidence_openings.get(proof_id)
+            .ok_or_else(|| CryptoError::InvalidInput(format!("Proof {} has no evidence commitment", proof_id)))?;
+        let commitment = EvidenceCountCommitment::of_proof(proof)
+            .ok_or_else(|| CryptoError::InvalidInput(format!("Proof {} has no evidence commitment", proof_id)))?;
+        zk::prove_evidence_sufficient(&commitment, opening, minimum)
+    }
+
+    /// Verify that `proof` is intact and that its signed evidence count
+    /// commitment hides a count of at least `minimum`
+    ///
+    /// The range proof is checked against the commitment carried in the proof,
+    /// never one supplied alongside it, so a proof over another commitment fails.
+    pub fn verify_evidence_sufficient(
+        &self,
+        proof: &ComplianceProof,
+        sufficiency: &EvidenceSufficiencyProof,
+        minimum: u32,
+    ) -> Result<bool> {
+        let commitment = match EvidenceCountCommitment::of_proof(proof) {
+            Some(commitment) => commitment,
+            None => return Ok(false),
+        };
+        Ok(self.verify_cryptographic_integrity(proof)?
+            && zk::verify_evidence_sufficient(&commitment, sufficiency, minimum))
+    }
+
+    /// Verify the engine's signature over a blockchain hash
+    fn verify_signature(&self, blockchain_hash: &str, verification_signature: &str) -> Result<bool> {
+        let signature_bytes = hex::decode(verification_signature)
             .map_err(|e| CryptoError::VerificationFailed(format!("Invalid signature format: {}", e)))?;
         
         let signature = Signature::from_bytes(&signature_bytes)
             .map_err(|e| CryptoError::VerificationFailed(format!("Invalid signature: {}", e)))?;
         
-        let signature_valid = self.keypair.public.verify(proof.blockchain_hash.as_bytes(), &signature).is_ok();
+        let message = domain_separated(DOMAIN_COMPLIANCE_PROOF, blockchain_hash);
+        Ok(self.public_key.verify(&message, &signature).is_ok())
+    }
+
+    /// Verify cryptographic integrity of proof
+    ///
+    /// The signature and evidence count bound are checked before the evidence
+    /// tree is rebuilt, so a forged count cannot make verification expensive.
+    fn verify_cryptographic_integrity(&self, proof: &ComplianceProof) -> Result<bool> {
+        // Verify digital signature
+        if !self.verify_signature(&proof.blockchain_hash, &proof.verification_signature)? {
+            return Ok(false);
+        }
+        if proof.compliance_data.evidence_count > MAX_EVIDENCE_COUNT {
+            return Ok(false);
+        }
         
         // Verify Merkle root
-        let evidence_data: Vec<String> = (0..proof.compliance_data.evidence_count)
-            .map(|i| format!("evidence-{}-{}", i, proof.compliance_data.assessment_date))
-            .collect();
-        
-        let merkle_tree = MerkleTree::new(evidence_data)?;
-        let merkle_valid = merkle_tree.root_hash() == proof.merkle_root;
+        let merkle_root = evidence_merkle_root(proof.compliance_data.evidence_count, proof.compliance_data.assessment_date)?;
+        let merkle_valid = ct_eq(&merkle_root, &proof.merkle_root);
         
         // Verify blockchain hash
-        let blockchain_data = format!(
-            "{}{}{}{}{}",
-            proof.entity_id,
-            proof.framework_type,
-            serde_json::to_string(&proof.compliance_data).map_err(|e| CryptoError::SerializationError(e.to_string()))?,
-            proof.merkle_root,
-            proof.timestamp
-        );
+        let blockchain_data = blockchain_hash_input(proof.hash_version, [
+            &proof.entity_id,
+            &proof.framework_type,
+            &compliance_data_digest(&proof.compliance_data, &proof.disclosure_salt)?,
+            &proof.merkle_root,
+            &proof.timestamp.to_string(),
+            &proof.evidence_commitment,
+        ])?;
         let expected_hash = self.blake3_hash(&blockchain_data);
-        let hash_valid = expected_hash == proof.blockchain_hash;
+        let hash_valid = ct_eq(&expected_hash, &proof.blockchain_hash);
         
-        Ok(signature_valid && merkle_valid && hash_valid)
+        Ok(merkle_valid && hash_valid)
     }
 
     /// Verify temporal validity
+    ///
+    /// Proofs dated in the future are rejected rather than treated as fresh.
     fn verify_temporal_validity(&self, proof: &ComplianceProof) -> bool {
         let current_time = self.current_timestamp();
         let max_age = 365 * 24 * 60 * 60; // 1 year in seconds
         
-        let age_valid = (current_time - proof.timestamp) <= max_age;
+        let age_valid = matches!(current_time.checked_sub(proof.timestamp), Some(age) if age <= max_age);
         let validity_valid = proof.compliance_data.valid_until > current_time;
         
         age_valid && validity_valid
@@ -470,7 +1221,8 @@ impl BlockchainComplianceEngine {
                 let public_key = PublicKey::from_bytes(&public_key_bytes).ok()?;
                 let signature = Signature::from_bytes(&signature_bytes).ok()?;
                 
-                Some(public_key.verify(partner.verification_hash.as_bytes(), &signature).is_ok())
+                let message = domain_separated(DOMAIN_PARTNER_VERIFICATION, &partner.verification_hash);
+                Some(public_key.verify(&message, &signature).is_ok())
             })
             .filter_map(|result| result)
             .filter(|&valid| valid)
@@ -502,7 +1254,8 @@ impl BlockchainComplianceEngine {
             let signature = Signature::from_bytes(&signature_bytes)
                 .map_err(|e| CryptoError::VerificationFailed(format!("Invalid audit signature format: {}", e)))?;
             
-            let signature_valid = self.keypair.public.verify(current.hash.as_bytes(), &signature).is_ok();
+            let message = domain_separated(DOMAIN_AUDIT_ENTRY, &current.hash);
+            let signature_valid = self.public_key.verify(&message, &signature).is_ok();
             if !signature_valid {
                 return Ok(false);
             }
@@ -606,13 +1359,12 @@ impl BlockchainComplianceEngine {
 
     fn create_audit_entry(&mut self, action: String, actor: String, details: String) -> Result<AuditEntry> {
         let timestamp = self.current_timestamp();
-        let entry_data = format!("{}{}{}{}", timestamp, action, actor, details);
-        let hash = self.blake3_hash(&entry_data);
+        let hash = self.blake3_hash(&audit_entry_hash_input(timestamp, &action, &actor, &details));
         let previous_hash = self.audit_chain.last()
             .map(|entry| entry.hash.clone())
             .unwrap_or_else(|| "0".to_string());
         
-        let signature = self.keypair.sign(hash.as_bytes());
+        let signature = self.signer()?.sign(&domain_separated(DOMAIN_AUDIT_ENTRY, &hash));
         let signature_hex = hex::encode(signature.to_bytes());
         
         let audit_entry = AuditEntry {
@@ -630,8 +1382,7 @@ impl BlockchainComplianceEngine {
     }
 
     fn create_consensus_proof(&self, consensus_hash: &str, signatures: &[String]) -> Result<String> {
-        let proof_data = format!("{}{}", consensus_hash, signatures.join(""));
-        Ok(self.blake3_hash(&proof_data))
+        Ok(self.blake3_hash(&consensus_proof_input(consensus_hash, signatures)))
     }
 
     fn verify_consensus_proof(&self, consensus_hash: &str, consensus_proof: &str) -> Result<bool> {
@@ -650,6 +1401,58 @@ impl BlockchainComplianceEngine {
         self.proofs.values().collect()
     }
 
+    /// Page through stored proofs matching `filter`, oldest first
+    ///
+    /// Cursors are insertion sequence numbers rather than offsets, so proofs
+    /// stored between calls never shift a page: nothing is skipped or repeated.
+    pub fn query_proofs(&self, filter: ProofFilter) -> Result<ProofPage<'_>> {
+        let page_size = filter.page_size.unwrap_or(DEFAULT_PROOF_PAGE_SIZE);
+        if page_size == 0 || page_size > MAX_PROOF_PAGE_SIZE {
+            return Err(CryptoError::InvalidInput(format!(
+                "Page size must be between 1 and {}",
+                MAX_PROOF_PAGE_SIZE
+            )));
+        }
+        let after = filter.cursor.as_deref()
+            .map(|cursor| cursor.parse::<u64>()
+                .map_err(|_| CryptoError::InvalidInput(format!("Invalid proof cursor {}", cursor))))
+            .transpose()?;
+
+        let start = after.map_or(Bound::Unbounded, Bound::Excluded);
+        let mut matching = self.proof_order
+            .range((start, Bound::Unbounded))
+            .map(|(sequence, proof_id)| (*sequence, &self.proofs[proof_id]))
+            .filter(|(_, proof)| Self::matches_filter(proof, &filter));
+
+        let page: Vec<(u64, &ComplianceProof)> = matching.by_ref().take(page_size).collect();
+        let next_cursor = page.last()
+            .map(|(sequence, _)| *sequence)
+            .or(after)
+            .map(|sequence| sequence.to_string());
+
+        Ok(ProofPage {
+            proofs: page.into_iter().map(|(_, proof)| proof).collect(),
+            next_cursor,
+            has_more: matching.next().is_some(),
+        })
+    }
+
+    fn matches_filter(proof: &ComplianceProof, filter: &ProofFilter) -> bool {
+        let data = &proof.compliance_data;
+        filter.entity_id.as_ref().is_none_or(|entity_id| &proof.entity_id == entity_id)
+            && filter.framework_type.as_ref().is_none_or(|framework| &proof.framework_type == framework)
+            && filter.valid_at.is_none_or(|at| data.assessment_date <= at && at < data.valid_until)
+    }
+
+    /// Store a proof, keeping the query position of one already stored under its ID
+    fn store_proof(&mut self, proof: ComplianceProof) {
+        if !self.proofs.contains_key(&proof.id) {
+            self.proof_order.insert(self.next_proof_sequence, proof.id.clone());
+            self.next_proof_sequence += 1;
+        }
+        self.proofs.insert(proof.id.clone(), proof);
+    }
+
     /// Get blockchain metrics
     pub fn get_metrics(&self) -> BlockchainMetrics {
         let proofs: Vec<&ComplianceProof> = self.proofs.values().collect();
@@ -700,6 +1503,7 @@ mod tests {
             valid_until: engine.current_timestamp() + (365 * 24 * 60 * 60),
             assessor: "Velocity AI Engine".to_string(),
             metadata: HashMap::new(),
+            controls: HashMap::new(),
         };
         
         let proof = engine.create_compliance_proof(
@@ -731,6 +1535,7 @@ mod tests {
             valid_until: engine.current_timestamp() + (365 * 24 * 60 * 60),
             assessor: "Velocity AI Engine".to_string(),
             metadata: HashMap::new(),
+            controls: HashMap::new(),
         };
         
         let proof = engine.create_compliance_proof(
@@ -748,4 +1553,727 @@ mod tests {
         assert!(verification.verification_details.temporal_validity);
         assert!(verification.verification_details.regulatory_compliance);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_cbor_round_trip_and_cross_format_verification() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let mut metadata = HashMap::new();
+        metadata.insert("auditor_ref".to_string(), "A-1042".to_string());
+
+        let compliance_data = ComplianceData {
+            framework: "ISO27001".to_string(),
+            version: "2022".to_string(),
+            controls_assessed: 93,
+            controls_passed: 91,
+            compliance_score: 91.0 / 93.0 * 100.0,
+            evidence_count: 64,
+            assessment_date: engine.current_timestamp(),
+            valid_until: engine.current_timestamp() + (365 * 24 * 60 * 60),
+            assessor: "Velocity AI Engine".to_string(),
+            metadata,
+            controls: HashMap::new(),
+        };
+        let proof = engine.create_compliance_proof(
+            "test-entity-003".to_string(),
+            "ISO27001".to_string(),
+            compliance_data,
+            false,
+        ).unwrap();
+
+        let json = serde_json::to_vec(&proof).unwrap();
+        let cbor = proof.to_cbor();
+        assert!(cbor.len() < json.len());
+
+        let decoded = ComplianceProof::from_cbor(&cbor).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(&proof).unwrap());
+        assert_eq!(decoded.compliance_data.compliance_score.to_bits(), proof.compliance_data.compliance_score.to_bits());
+        assert!(engine.verify_proof(&decoded).unwrap().is_valid);
+
+        // JSON -> CBOR and CBOR -> JSON both preserve what was signed
+        let from_json: ComplianceProof = serde_json::from_slice(&json).unwrap();
+        assert!(engine.verify_proof(&ComplianceProof::from_cbor(&from_json.to_cbor()).unwrap()).unwrap().is_valid);
+        let via_json: ComplianceProof = serde_json::from_slice(&serde_json::to_vec(&decoded).unwrap()).unwrap();
+        assert!(engine.verify_proof(&via_json).unwrap().is_valid);
+
+        let mut tampered = decoded;
+        tampered.compliance_data.controls_passed = 93;
+        assert!(!ComplianceProof::from_cbor(&tampered.to_cbor())
+            .map(|proof| engine.verify_proof(&proof).unwrap().is_valid)
+            .unwrap());
+        assert!(ComplianceProof::from_cbor(&cbor[..cbor.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_protobuf_round_trip_still_verifies() {
+        use crate::wire::proto;
+        use prost::Message;
+
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let mut metadata = HashMap::new();
+        metadata.insert("auditor_ref".to_string(), "A-1042".to_string());
+        metadata.insert("scope".to_string(), "production".to_string());
+
+        let compliance_data = ComplianceData {
+            framework: "ISO27001".to_string(),
+            version: "2022".to_string(),
+            controls_assessed: 93,
+            controls_passed: 91,
+            compliance_score: 91.0 / 93.0 * 100.0,
+            evidence_count: 64,
+            assessment_date: engine.current_timestamp(),
+            valid_until: engine.current_timestamp() + (365 * 24 * 60 * 60),
+            assessor: "Velocity AI Engine".to_string(),
+            metadata,
+            controls: HashMap::new(),
+        };
+        let proof = engine.create_compliance_proof(
+            "test-entity-004".to_string(),
+            "ISO27001".to_string(),
+            compliance_data,
+            true,
+        ).unwrap();
+
+        let bytes = proof.to_proto().encode_to_vec();
+        let decoded = ComplianceProof::from_proto(proto::ComplianceProof::decode(bytes.as_slice()).unwrap()).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(&proof).unwrap());
+        assert_eq!(decoded.compliance_data.compliance_score.to_bits(), proof.compliance_data.compliance_score.to_bits());
+
+        let original = engine.verify_proof(&proof).unwrap();
+        let verification = engine.verify_proof(&decoded).unwrap();
+        assert!(verification.verification_details.cryptographic_integrity);
+        assert_eq!(verification.is_valid, original.is_valid);
+
+        // Fields are still bound by the signature after decoding
+        let mut message = proof.to_proto();
+        message.compliance_data.as_mut().unwrap().controls_passed = 93;
+        let tampered = ComplianceProof::from_proto(message).unwrap();
+        assert!(!engine.verify_proof(&tampered).unwrap().verification_details.cryptographic_integrity);
+
+        let mut message = proof.to_proto();
+        message.compliance_data = None;
+        assert!(ComplianceProof::from_proto(message).is_err());
+    }
+
+    #[test]
+    fn test_compliance_hash_independent_of_metadata_order() {
+        let engine = BlockchainComplianceEngine::new().unwrap();
+        let entries: Vec<(String, String)> = (0..32)
+            .map(|i| (format!("control_{}", i), format!("evidence_ref_{}", i)))
+            .collect();
+
+        let data_with = |pairs: &mut dyn Iterator<Item = &(String, String)>| ComplianceData {
+            framework: "SOC2".to_string(),
+            version: "2017".to_string(),
+            controls_assessed: 64,
+            controls_passed: 62,
+            compliance_score: 96.875,
+            evidence_count: 128,
+            assessment_date: 1_700_000_000,
+            valid_until: 1_731_536_000,
+            assessor: "Velocity AI Engine".to_string(),
+            metadata: pairs.cloned().collect(),
+            controls: HashMap::new(),
+        };
+        let forward = data_with(&mut entries.iter());
+        let reverse = data_with(&mut entries.iter().rev());
+
+        let forward_json = canonical_json(&forward).unwrap();
+        assert_eq!(forward_json, canonical_json(&reverse).unwrap());
+        assert_eq!(engine.blake3_hash(&forward_json), engine.blake3_hash(&canonical_json(&reverse).unwrap()));
+    }
+
+    fn iso_to_soc2_mapping() -> ControlMapping {
+        let controls = [
+            ("CC6.1", vec!["A.5.15", "A.8.2"]),
+            ("CC6.2", vec!["A.8.5"]),
+            ("CC6.3", vec!["A.5.15", "A.5.18"]),
+            ("CC6.6", vec!["A.8.20"]),
+            ("CC7.2", vec!["A.8.15"]),
+            ("CC7.3", vec!["A.5.26"]),
+        ];
+        ControlMapping {
+            source_framework: "ISO27001".to_string(),
+            target_framework: "SOC2".to_string(),
+            target_version: "2017".to_string(),
+            controls: controls.into_iter()
+                .map(|(target, sources)| (target.to_string(), sources.into_iter().map(String::from).collect()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_derive_soc2_proof_from_iso_controls() {
+        use crate::wire::proto;
+        use prost::Message;
+
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        engine.register_control_mapping(iso_to_soc2_mapping()).unwrap();
+
+        // A.5.26 is never assessed, so CC7.3 cannot be credited; A.5.24 maps to nothing
+        let controls: HashMap<String, ControlResult> = [
+            ("A.5.15", true), ("A.5.18", true), ("A.5.24", true), ("A.8.2", true),
+            ("A.8.5", true), ("A.8.15", false), ("A.8.20", true),
+        ].into_iter()
+            .map(|(control, passed)| (control.to_string(), ControlResult { passed, derived_from: Vec::new() }))
+            .collect();
+        let compliance_data = ComplianceData {
+            framework: "ISO27001".to_string(),
+            version: "2022".to_string(),
+            controls_assessed: 7,
+            controls_passed: 6,
+            compliance_score: 6.0 / 7.0 * 100.0,
+            evidence_count: 48,
+            assessment_date: engine.current_timestamp(),
+            valid_until: engine.current_timestamp() + (365 * 24 * 60 * 60),
+            assessor: "External Auditor".to_string(),
+            metadata: HashMap::new(),
+            controls,
+        };
+        let source = engine.create_compliance_proof(
+            "test-entity-005".to_string(),
+            "ISO27001".to_string(),
+            compliance_data,
+            false,
+        ).unwrap();
+
+        let derived = engine.derive_proof(&source.id, "SOC2").unwrap();
+        let data = &derived.compliance_data;
+        assert_eq!(derived.framework_type, "SOC2");
+        assert_eq!(derived.entity_id, source.entity_id);
+        assert_eq!(data.version, "2017");
+        assert_eq!((data.controls_assessed, data.controls_passed), (5, 4));
+        assert_eq!(data.compliance_score, 80.0);
+        assert!(!data.controls.contains_key("CC7.3"));
+        assert!(!data.controls["CC7.2"].passed);
+        assert_eq!(data.controls["CC6.1"].derived_from, vec!["A.5.15".to_string(), "A.8.2".to_string()]);
+        assert!(data.controls.values().all(|result| !result.derived_from.is_empty()));
+        assert_eq!(data.metadata["derived_from_proof"], source.id);
+        assert_eq!(derived.audit_chain.last().unwrap().action, "proof-derived");
+
+        let verification = engine.verify_compliance_proof(&derived.id).unwrap();
+        assert!(verification.is_valid);
+
+        // Control results are signed and survive the wire format
+        let decoded = ComplianceProof::from_proto(
+            proto::ComplianceProof::decode(derived.to_proto().encode_to_vec().as_slice()).unwrap(),
+        ).unwrap();
+        assert!(engine.verify_proof(&decoded).unwrap().verification_details.cryptographic_integrity);
+        let mut tampered = decoded;
+        tampered.compliance_data.controls.get_mut("CC7.2").unwrap().passed = true;
+        assert!(!engine.verify_proof(&tampered).unwrap().verification_details.cryptographic_integrity);
+    }
+
+    #[test]
+    fn test_derive_proof_requires_mapping_and_control_results() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let compliance_data = ComplianceData {
+            framework: "ISO27001".to_string(),
+            version: "2022".to_string(),
+            controls_assessed: 93,
+            controls_passed: 91,
+            compliance_score: 91.0 / 93.0 * 100.0,
+            evidence_count: 64,
+            assessment_date: engine.current_timestamp(),
+            valid_until: engine.current_timestamp() + (365 * 24 * 60 * 60),
+            assessor: "Velocity AI Engine".to_string(),
+            metadata: HashMap::new(),
+            controls: HashMap::new(),
+        };
+        let source = engine.create_compliance_proof(
+            "test-entity-006".to_string(),
+            "ISO27001".to_string(),
+            compliance_data,
+            false,
+        ).unwrap();
+
+        assert!(engine.derive_proof(&source.id, "SOC2").is_err());
+        engine.register_control_mapping(iso_to_soc2_mapping()).unwrap();
+        // Aggregate counts alone cannot be projected onto another framework
+        assert!(engine.derive_proof(&source.id, "SOC2").is_err());
+        assert!(engine.derive_proof("missing-proof", "SOC2").is_err());
+
+        let mut mapping = iso_to_soc2_mapping();
+        mapping.controls.insert("CC8.1".to_string(), Vec::new());
+        assert!(engine.register_control_mapping(mapping).is_err());
+    }
+
+    #[test]
+    fn test_redacted_proof_verifies_without_revealing_hidden_fields() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let mut metadata = HashMap::new();
+        metadata.insert("auditor_ref".to_string(), "A-1042".to_string());
+
+        let compliance_data = ComplianceData {
+            framework: "SOC2".to_string(),
+            version: "2017".to_string(),
+            controls_assessed: 64,
+            controls_passed: 61,
+            compliance_score: 61.0 / 64.0 * 100.0,
+            evidence_count: 128,
+            assessment_date: engine.current_timestamp(),
+            valid_until: engine.current_timestamp() + (365 * 24 * 60 * 60),
+            assessor: "Confidential Assessor LLP".to_string(),
+            metadata,
+            controls: HashMap::new(),
+        };
+        let proof = engine.create_compliance_proof(
+            "test-entity-007".to_string(),
+            "SOC2".to_string(),
+            compliance_data,
+            false,
+        ).unwrap();
+        assert!(engine.verify_proof(&proof).unwrap().verification_details.cryptographic_integrity);
+
+        let hidden = ["assessor", "metadata", "controls_passed", "compliance_score"];
+        let redacted = proof.redact(&hidden).unwrap();
+        assert!(engine.verify_redacted_proof(&redacted).unwrap());
+        assert!(engine.verify_redacted_proof(&proof.redact(&[]).unwrap()).unwrap());
+
+        // Neither the values nor a key that could open their commitments are present
+        let json = serde_json::to_string(&redacted).unwrap();
+        assert!(!json.contains("Confidential Assessor"));
+        assert!(!json.contains("A-1042"));
+        assert!(!json.contains(&proof.disclosure_salt));
+        for field in hidden {
+            assert!(matches!(redacted.fields[field], DisclosedField::Redacted { .. }));
+        }
+        assert!(matches!(redacted.fields["controls_assessed"], DisclosedField::Disclosed { .. }));
+
+        // Enumerating every possible controls_passed value against the released
+        // salts never opens the commitment
+        let commitment = match &redacted.fields["controls_passed"] {
+            DisclosedField::Redacted { commitment } => commitment.clone(),
+            DisclosedField::Disclosed { .. } => unreachable!(),
+        };
+        for entry in redacted.fields.values() {
+            if let DisclosedField::Disclosed { salt, .. } = entry {
+                assert!((0..=64u32).all(|guess| {
+                    field_commitment("controls_passed", salt, &Value::from(guess)).unwrap() != commitment
+                }));
+            }
+        }
+
+        // Altering a disclosed value or a commitment breaks the signature binding
+        let mut tampered = redacted.clone();
+        tampered.fields.insert(
+            "controls_assessed".to_string(),
+            match &redacted.fields["controls_assessed"] {
+                DisclosedField::Disclosed { salt, .. } => DisclosedField::Disclosed { value: Value::from(61), salt: salt.clone() },
+                DisclosedField::Redacted { .. } => unreachable!(),
+            },
+        );
+        assert!(!engine.verify_redacted_proof(&tampered).unwrap());
+        let mut tampered = redacted.clone();
+        tampered.fields.insert("assessor".to_string(), DisclosedField::Redacted { commitment: "0".repeat(64) });
+        assert!(!engine.verify_redacted_proof(&tampered).unwrap());
+    }
+
+    #[test]
+    fn test_redaction_rejects_merkle_bound_and_unknown_fields() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let compliance_data = ComplianceData {
+            framework: "SOC2".to_string(),
+            version: "2017".to_string(),
+            controls_assessed: 64,
+            controls_passed: 64,
+            compliance_score: 100.0,
+            evidence_count: 16,
+            assessment_date: engine.current_timestamp(),
+            valid_until: engine.current_timestamp() + (365 * 24 * 60 * 60),
+            assessor: "Velocity AI Engine".to_string(),
+            metadata: HashMap::new(),
+            controls: HashMap::new(),
+        };
+        let mut proof = engine.create_compliance_proof(
+            "test-entity-008".to_string(),
+            "SOC2".to_string(),
+            compliance_data,
+            false,
+        ).unwrap();
+
+        assert!(proof.redact(&["evidence_count"]).is_err());
+        assert!(proof.redact(&["asessor"]).is_err());
+        // Redacting a field the proof omits is a no-op
+        assert!(engine.verify_redacted_proof(&proof.redact(&["controls"]).unwrap()).unwrap());
+
+        proof.disclosure_salt.clear();
+        assert!(proof.redact(&["assessor"]).is_err());
+    }
+
+    #[test]
+    fn test_evidence_sufficiency_bound_to_signed_commitment() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = engine.current_timestamp();
+        let proof = store_test_proof(&mut engine, "entity-zk", "SOC2", now, now + 365 * 24 * 60 * 60);
+        assert!(!proof.evidence_commitment.is_empty());
+
+        let sufficiency = engine.prove_evidence_sufficient(&proof.id, 1).unwrap();
+        assert!(engine.verify_evidence_sufficient(&proof, &sufficiency, 1).unwrap());
+        assert!(engine.prove_evidence_sufficient(&proof.id, 2).is_err());
+
+        // A range proof over a commitment the prover chose does not verify
+        // against the proof, and swapping that commitment in breaks the signature
+        let mut inflated = proof.compliance_data.clone();
+        inflated.evidence_count = 500;
+        let (forged, opening) = zk::commit_evidence_count(&proof.id, &inflated);
+        let forged_sufficiency = zk::prove_evidence_sufficient(&forged, &opening, 100).unwrap();
+        assert!(!engine.verify_evidence_sufficient(&proof, &forged_sufficiency, 100).unwrap());
+        let mut swapped = proof.clone();
+        swapped.evidence_commitment = forged.commitment.clone();
+        assert!(!engine.verify_evidence_sufficient(&swapped, &forged_sufficiency, 100).unwrap());
+        assert!(!engine.verify_proof(&swapped).unwrap().verification_details.cryptographic_integrity);
+
+        // The commitment survives redaction and the wire formats
+        assert!(engine.verify_redacted_proof(&proof.redact(&["assessor"]).unwrap()).unwrap());
+        let decoded = ComplianceProof::from_cbor(&proof.to_cbor()).unwrap();
+        assert!(engine.verify_evidence_sufficient(&decoded, &sufficiency, 1).unwrap());
+    }
+
+    fn store_test_proof(
+        engine: &mut BlockchainComplianceEngine,
+        entity_id: &str,
+        framework: &str,
+        assessment_date: u64,
+        valid_until: u64,
+    ) -> ComplianceProof {
+        let compliance_data = ComplianceData {
+            framework: framework.to_string(),
+            version: "2022".to_string(),
+            controls_assessed: 10,
+            controls_passed: 10,
+            compliance_score: 100.0,
+            evidence_count: 1,
+            assessment_date,
+            valid_until,
+            assessor: "Velocity AI Engine".to_string(),
+            metadata: HashMap::new(),
+            controls: HashMap::new(),
+        };
+        engine.create_compliance_proof(entity_id.to_string(), framework.to_string(), compliance_data, false).unwrap()
+    }
+
+    #[test]
+    fn test_query_proofs_filters_by_entity_framework_and_validity() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = engine.current_timestamp();
+        let year = 365 * 24 * 60 * 60;
+
+        let current_soc2 = store_test_proof(&mut engine, "entity-a", "SOC2", now - 10, now + year);
+        let current_iso = store_test_proof(&mut engine, "entity-a", "ISO27001", now - 10, now + year);
+        let expired_soc2 = store_test_proof(&mut engine, "entity-b", "SOC2", now - 2 * year, now - year);
+        let other_soc2 = store_test_proof(&mut engine, "entity-c", "SOC2", now - 10, now + year);
+
+        let ids = |page: ProofPage| page.proofs.iter().map(|proof| proof.id.clone()).collect::<Vec<_>>();
+
+        let soc2 = engine.query_proofs(ProofFilter {
+            framework_type: Some("SOC2".to_string()),
+            ..ProofFilter::default()
+        }).unwrap();
+        assert!(!soc2.has_more);
+        assert_eq!(ids(soc2), vec![current_soc2.id.clone(), expired_soc2.id.clone(), other_soc2.id.clone()]);
+
+        let valid_soc2 = engine.query_proofs(ProofFilter {
+            framework_type: Some("SOC2".to_string()),
+            valid_at: Some(now),
+            ..ProofFilter::default()
+        }).unwrap();
+        assert_eq!(ids(valid_soc2), vec![current_soc2.id.clone(), other_soc2.id.clone()]);
+
+        let entity_a = engine.query_proofs(ProofFilter {
+            entity_id: Some("entity-a".to_string()),
+            ..ProofFilter::default()
+        }).unwrap();
+        assert_eq!(ids(entity_a), vec![current_soc2.id, current_iso.id]);
+
+        let invalid_page = ProofFilter { page_size: Some(0), ..ProofFilter::default() };
+        assert!(engine.query_proofs(invalid_page).is_err());
+        let invalid_cursor = ProofFilter { cursor: Some("page-2".to_string()), ..ProofFilter::default() };
+        assert!(engine.query_proofs(invalid_cursor).is_err());
+    }
+
+    #[test]
+    fn test_query_proofs_pages_through_large_set_under_inserts() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = engine.current_timestamp();
+        for i in 0..1000 {
+            let framework = if i % 4 == 0 { "ISO27001" } else { "SOC2" };
+            store_test_proof(&mut engine, &format!("entity-{}", i), framework, now, now + 86_400);
+        }
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        let mut inserted = 0;
+        loop {
+            let page = engine.query_proofs(ProofFilter {
+                framework_type: Some("SOC2".to_string()),
+                cursor: cursor.clone(),
+                page_size: Some(64),
+                ..ProofFilter::default()
+            }).unwrap();
+            assert!(page.proofs.len() <= 64);
+            assert!(page.proofs.iter().all(|proof| proof.framework_type == "SOC2"));
+            seen.extend(page.proofs.iter().map(|proof| proof.id.clone()));
+            cursor = page.next_cursor;
+            let has_more = page.has_more;
+
+            // Proofs stored mid-iteration land after the cursor
+            if inserted < 20 {
+                store_test_proof(&mut engine, &format!("late-entity-{}", inserted), "SOC2", now, now + 86_400);
+                inserted += 1;
+            } else if !has_more {
+                break;
+            }
+        }
+
+        let expected: Vec<String> = engine.proof_order.values()
+            .filter(|id| engine.proofs[*id].framework_type == "SOC2")
+            .cloned()
+            .collect();
+        assert_eq!(expected.len(), 750 + 20);
+        assert_eq!(seen, expected);
+    }
+
+    #[test]
+    fn test_signature_from_one_domain_fails_in_another() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = engine.current_timestamp();
+        let proof = store_test_proof(&mut engine, "entity-a", "ISO27001", now, now + 3600);
+        assert!(engine.verify_cryptographic_integrity(&proof).unwrap());
+        assert!(engine.verify_audit_trail_integrity(&proof).unwrap());
+
+        // Both are genuine engine signatures over hex hashes, but in different domains
+        let entry = proof.audit_chain.last().unwrap().clone();
+        assert!(!engine.verify_signature(&entry.hash, &entry.signature).unwrap());
+
+        let mut forged = proof.clone();
+        forged.audit_chain.push(AuditEntry {
+            timestamp: now,
+            action: "proof-created".to_string(),
+            actor: "blockchain-engine".to_string(),
+            details: String::new(),
+            hash: proof.blockchain_hash.clone(),
+            previous_hash: entry.hash,
+            signature: proof.verification_signature.clone(),
+        });
+        assert!(!engine.verify_audit_trail_integrity(&forged).unwrap());
+    }
+
+    #[test]
+    fn test_audit_and_consensus_hash_inputs_separate_fields() {
+        // Each pair concatenates to the same string
+        assert_ne!(
+            audit_entry_hash_input(1, "revoke", "d1", "x"),
+            audit_entry_hash_input(1, "revoked", "1", "x")
+        );
+        assert_ne!(
+            audit_entry_hash_input(12, "3a", "b", "c"),
+            audit_entry_hash_input(1, "23a", "b", "c")
+        );
+        assert_ne!(
+            consensus_proof_input("ab", &["c".to_string()]),
+            consensus_proof_input("a", &["bc".to_string()])
+        );
+        assert_ne!(
+            consensus_proof_input("h", &["ab".to_string(), "c".to_string()]),
+            consensus_proof_input("h", &["a".to_string(), "bc".to_string()])
+        );
+        assert_ne!(
+            consensus_proof_input("h", &["ab".to_string()]),
+            consensus_proof_input("h", &["ab".to_string(), String::new()])
+        );
+    }
+
+    #[test]
+    fn test_verifier_checks_proofs_against_issuer_key() {
+        let mut issuer = BlockchainComplianceEngine::new().unwrap();
+        let now = issuer.current_timestamp();
+        let proof = store_test_proof(&mut issuer, "entity-a", "ISO27001", now, now + 3600);
+
+        let verifier = BlockchainComplianceEngine::verifier(&issuer.public_key()).unwrap();
+        let details = verifier.verify_proof(&proof).unwrap().verification_details;
+        assert!(details.cryptographic_integrity && details.audit_trail_integrity);
+
+        // A proof from any other engine fails under the issuer's key
+        let mut other = BlockchainComplianceEngine::new().unwrap();
+        let foreign = store_test_proof(&mut other, "entity-a", "ISO27001", now, now + 3600);
+        assert!(!verifier.verify_proof(&foreign).unwrap().verification_details.cryptographic_integrity);
+
+        let mut verifier = verifier;
+        assert!(verifier
+            .create_compliance_proof("entity-b".to_string(), "SOC2".to_string(), proof.compliance_data.clone(), false)
+            .is_err());
+        assert!(BlockchainComplianceEngine::verifier("not-a-key").is_err());
+    }
+
+    #[test]
+    fn test_blockchain_hash_separates_fields_and_verifies_legacy_proofs() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = engine.current_timestamp();
+        let proof = store_test_proof(&mut engine, "entity-a", "ISO27001", now, now + 3600);
+        assert_eq!(proof.hash_version, BLOCKCHAIN_HASH_VERSION);
+        assert!(engine.verify_cryptographic_integrity(&proof).unwrap());
+
+        // Moving a character across a field boundary no longer keeps the hash input
+        let mut shifted = proof.clone();
+        shifted.entity_id = "entity-aI".to_string();
+        shifted.framework_type = "SO27001".to_string();
+        assert!(!engine.verify_cryptographic_integrity(&shifted).unwrap());
+
+        // Proofs sealed over the concatenated fields still verify
+        let mut legacy = proof.clone();
+        legacy.hash_version = LEGACY_BLOCKCHAIN_HASH_VERSION;
+        let legacy_input = blockchain_hash_input(LEGACY_BLOCKCHAIN_HASH_VERSION, [
+            &legacy.entity_id,
+            &legacy.framework_type,
+            &compliance_data_digest(&legacy.compliance_data, &legacy.disclosure_salt).unwrap(),
+            &legacy.merkle_root,
+            &legacy.timestamp.to_string(),
+            &legacy.evidence_commitment,
+        ]).unwrap();
+        legacy.blockchain_hash = engine.blake3_hash(&legacy_input);
+        let signature = engine.signer().unwrap().sign(&domain_separated(DOMAIN_COMPLIANCE_PROOF, &legacy.blockchain_hash));
+        legacy.verification_signature = hex::encode(signature.to_bytes());
+        assert!(engine.verify_cryptographic_integrity(&legacy).unwrap());
+        assert!(engine.verify_redacted_proof(&legacy.redact(&["assessor"]).unwrap()).unwrap());
+
+        let mut unknown = proof.clone();
+        unknown.hash_version = BLOCKCHAIN_HASH_VERSION + 1;
+        assert!(engine.verify_cryptographic_integrity(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_forged_evidence_count_is_rejected_before_rebuilding_the_tree() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = engine.current_timestamp();
+        let proof = store_test_proof(&mut engine, "entity-a", "ISO27001", now, now + 3600);
+        let other = store_test_proof(&mut engine, "entity-b", "ISO27001", now, now + 3600);
+        let started = std::time::Instant::now();
+
+        let mut forged = proof.clone();
+        forged.compliance_data.evidence_count = u32::MAX;
+        forged.verification_signature = other.verification_signature.clone();
+        assert!(!engine.verify_cryptographic_integrity(&forged).unwrap());
+        assert!(!engine.verify_proof(&forged).unwrap().verification_details.cryptographic_integrity);
+
+        // The count is bounded even under the proof's own signature
+        forged.verification_signature = proof.verification_signature.clone();
+        assert!(!engine.verify_cryptographic_integrity(&forged).unwrap());
+
+        let mut redacted = proof.redact(&["assessor"]).unwrap();
+        if let Some(DisclosedField::Disclosed { value, .. }) = redacted.fields.get_mut("evidence_count") {
+            *value = Value::from(u32::MAX);
+        }
+        assert!(!engine.verify_redacted_proof(&redacted).unwrap());
+        redacted.verification_signature = other.verification_signature;
+        assert!(!engine.verify_redacted_proof(&redacted).unwrap());
+        assert!(started.elapsed() < std::time::Duration::from_secs(1));
+
+        let mut oversized = proof.compliance_data.clone();
+        oversized.evidence_count = MAX_EVIDENCE_COUNT + 1;
+        assert!(engine.create_compliance_proof("entity-a".to_string(), "SOC2".to_string(), oversized, false).is_err());
+    }
+
+    #[test]
+    fn test_future_dated_proofs_are_not_temporally_valid() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = engine.current_timestamp();
+        let mut proof = store_test_proof(&mut engine, "entity-a", "ISO27001", now, now + 3600);
+        assert!(engine.verify_temporal_validity(&proof));
+
+        proof.timestamp = now + 3600;
+        assert!(!engine.verify_temporal_validity(&proof));
+        proof.timestamp = u64::MAX;
+        assert!(!engine.verify_temporal_validity(&proof));
+    }
+
+    #[test]
+    fn test_batch_proofs_share_one_cross_industry_attestation() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = engine.current_timestamp();
+        let compliance_data = |framework: &str, version: &str| ComplianceData {
+            framework: framework.to_string(),
+            version: version.to_string(),
+            controls_assessed: 40,
+            controls_passed: 38,
+            compliance_score: 95.0,
+            evidence_count: 64,
+            assessment_date: now,
+            valid_until: now + (365 * 24 * 60 * 60),
+            assessor: "Velocity AI Engine".to_string(),
+            metadata: HashMap::new(),
+            controls: HashMap::new(),
+        };
+
+        let proofs = engine.create_compliance_proofs_batch(
+            "entity-a".to_string(),
+            vec![
+                ("ISO27001".to_string(), compliance_data("ISO27001", "2022")),
+                ("SOC2".to_string(), compliance_data("SOC2", "2017")),
+                ("GDPR".to_string(), compliance_data("GDPR", "2018")),
+            ],
+        ).unwrap();
+        assert_eq!(proofs.len(), 3);
+
+        let attestation = proofs[0].cross_industry_attestation.as_ref().unwrap();
+        assert_eq!(attestation.cross_validated_frameworks, vec!["ISO27001", "SOC2", "GDPR"]);
+        for proof in &proofs {
+            let shared = proof.cross_industry_attestation.as_ref().unwrap();
+            assert_eq!(shared.network_hash, attestation.network_hash);
+            assert!(proof.network_consensus.is_some());
+
+            let result = engine.verify_compliance_proof(&proof.id).unwrap();
+            assert!(result.is_valid, "{} failed verification", proof.framework_type);
+            assert!(engine.verify_proof(proof).unwrap().is_valid);
+        }
+        let ids: HashSet<&str> = proofs.iter().map(|proof| proof.id.as_str()).collect();
+        assert_eq!(ids.len(), 3);
+
+        assert!(engine.create_compliance_proofs_batch("entity-a".to_string(), Vec::new()).is_err());
+        assert!(engine.create_compliance_proofs_batch(
+            "entity-a".to_string(),
+            vec![
+                ("SOC2".to_string(), compliance_data("SOC2", "2017")),
+                ("SOC2".to_string(), compliance_data("SOC2", "2017")),
+            ],
+        ).is_err());
+    }
+
+    #[test]
+    fn test_passport_covers_current_proofs_across_frameworks() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = engine.current_timestamp();
+        let year = 365 * 24 * 60 * 60;
+
+        let soc2 = store_test_proof(&mut engine, "entity-a", "SOC2", now - 10, now + year);
+        let iso = store_test_proof(&mut engine, "entity-a", "ISO27001", now - 10, now + year);
+        let gdpr = store_test_proof(&mut engine, "entity-a", "GDPR", now - 10, now + 30);
+        store_test_proof(&mut engine, "entity-a", "HIPAA", now - 2 * year, now - year);
+        let revoked = store_test_proof(&mut engine, "entity-a", "ISAE3000", now - 10, now + year);
+        store_test_proof(&mut engine, "entity-b", "SOC2", now - 10, now + year);
+        engine.revoke_proof(&revoked.id, "assessor withdrew report").unwrap();
+        assert!(engine.revoke_proof(&revoked.id, "again").is_err());
+
+        let passport = engine.build_passport("entity-a").unwrap();
+        let included: Vec<&str> = passport.entries.iter().map(|entry| entry.proof_id.as_str()).collect();
+        assert_eq!(included, vec![soc2.id.as_str(), iso.id.as_str(), gdpr.id.as_str()]);
+        assert_eq!(passport.entries[2].valid_until, now + 30);
+        assert!(engine.verify_passport(&passport).unwrap());
+        assert!(engine.build_passport("entity-c").is_err());
+
+        // Stretching a validity window breaks the signature
+        let mut stretched = passport.clone();
+        stretched.entries[2].valid_until = now + year;
+        assert!(!engine.verify_passport(&stretched).unwrap());
+
+        // Dropping an entry breaks the root even with the signature recomputed
+        let mut trimmed = passport.clone();
+        trimmed.entries.pop();
+        trimmed.signature = hex::encode(engine.signer().unwrap().sign(&trimmed.signing_payload().unwrap()).to_bytes());
+        assert!(!engine.verify_passport(&trimmed).unwrap());
+
+        // A proof revoked after issue invalidates the passport
+        engine.revoke_proof(&iso.id, "control failure").unwrap();
+        assert!(!engine.verify_passport(&passport).unwrap());
+        assert_eq!(engine.build_passport("entity-a").unwrap().entries.len(), 2);
+    }
+}
@@ -0,0 +1,107 @@
+/// Canonical Serialization Module
+///
+/// Deterministic JSON encoding used by every hashing path that covers
+/// serialized structures. Keys are sorted and numbers use one fixed format.
+
+use crate::{CryptoError, Result};
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+/// Serialize a value to canonical JSON
+pub fn canonical_json<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    let value = serde_json::to_value(value).map_err(|e| CryptoError::SerializationError(e.to_string()))?;
+    let mut out = String::new();
+    write_canonical(&value, &mut out);
+    Ok(out)
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Number(number) => out.push_str(&canonical_number(number)),
+        other => out.push_str(&other.to_string()),
+    }
+}
+
+/// Unambiguous encoding of a tuple of fields for hashing
+///
+/// Each field is written as a netstring, `"{byte length}:{bytes},"`, so distinct
+/// tuples never encode alike where plain concatenation would make them collide.
+pub fn encode_fields(fields: &[&str]) -> String {
+    let mut out = String::new();
+    for field in fields {
+        out.push_str(&field.len().to_string());
+        out.push(':');
+        out.push_str(field);
+        out.push(',');
+    }
+    out
+}
+
+/// Integers print without a fraction; floats use the shortest round-trip form
+fn canonical_number(number: &Number) -> String {
+    if let Some(value) = number.as_i64() {
+        value.to_string()
+    } else if let Some(value) = number.as_u64() {
+        value.to_string()
+    } else {
+        let value = number.as_f64().unwrap_or(0.0);
+        // Normalise negative zero so it matches positive zero
+        if value == 0.0 {
+            "0.0".to_string()
+        } else {
+            format!("{:?}", value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_fields_separates_field_boundaries() {
+        assert_eq!(encode_fields(&["ab", "c"]), "2:ab,1:c,");
+        assert_ne!(encode_fields(&["ab", "c"]), encode_fields(&["a", "bc"]));
+        assert_ne!(encode_fields(&["a:", "b"]), encode_fields(&["a", ":b"]));
+        assert_ne!(encode_fields(&["", "ab"]), encode_fields(&["ab"]));
+        // Multi-byte characters are counted in bytes
+        assert_eq!(encode_fields(&["é"]), "2:é,");
+    }
+
+    #[test]
+    fn test_canonical_json_sorts_keys_and_fixes_numbers() {
+        let value = serde_json::json!({
+            "b": 1,
+            "a": { "z": 2.5, "y": [3, -0.0, 1e21] },
+        });
+        assert_eq!(
+            canonical_json(&value).unwrap(),
+            r#"{"a":{"y":[3,0.0,1e21],"z":2.5},"b":1}"#
+        );
+    }
+}
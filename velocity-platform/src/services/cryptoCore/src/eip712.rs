@@ -0,0 +1,409 @@
+/// EIP-712 Typed Data Verification
+///
+/// Hashes structured data the way Ethereum wallets do for `eth_signTypedData_v4`
+/// and recovers the address that signed it, so signatures from dApps on Polygon
+/// and other EVM chains can be checked against the account expected to sign.
+
+use crate::{CryptoError, Result};
+#[cfg(feature = "native-crypto")]
+use secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+#[cfg(feature = "native-crypto")]
+use secp256k1::{Message, SECP256K1};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha3::{Digest, Keccak256};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Name of the struct type describing the signing domain
+pub const EIP712_DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// Length of a recoverable `r || s || v` signature
+pub const RECOVERABLE_SIGNATURE_LENGTH: usize = 65;
+
+/// One member of a struct type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Eip712Field {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: String,
+}
+
+/// Typed data and the signature over it, in the `eth_signTypedData_v4` layout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Eip712Request {
+    /// Values for the `EIP712Domain` struct
+    pub domain: Value,
+    /// Struct definitions, including `EIP712Domain`
+    pub types: BTreeMap<String, Vec<Eip712Field>>,
+    #[serde(rename = "primaryType")]
+    pub primary_type: String,
+    pub message: Value,
+    /// 65-byte `r || s || v` signature; `v` may be 0/1 or 27/28
+    pub signature: Vec<u8>,
+}
+
+/// Keccak-256, the hash EIP-712 is defined over
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+impl Eip712Request {
+    /// Digest the wallet signed: `keccak256(0x1901 || domainSeparator || hashStruct(message))`
+    pub fn signing_hash(&self) -> Result<[u8; 32]> {
+        let mut encoded = vec![0x19, 0x01];
+        encoded.extend_from_slice(&self.domain_separator()?);
+        encoded.extend_from_slice(&self.hash_struct(&self.primary_type, &self.message)?);
+        Ok(keccak256(&encoded))
+    }
+
+    pub fn domain_separator(&self) -> Result<[u8; 32]> {
+        self.hash_struct(EIP712_DOMAIN_TYPE, &self.domain)
+    }
+
+    /// `keccak256(typeHash || encodeData(value))`
+    pub fn hash_struct(&self, type_name: &str, value: &Value) -> Result<[u8; 32]> {
+        let fields = self.struct_fields(type_name)?;
+        let object = value.as_object().ok_or_else(|| {
+            CryptoError::InvalidInput(format!("Value for {} is not an object", type_name))
+        })?;
+
+        let mut encoded = self.type_hash(type_name)?.to_vec();
+        for field in fields {
+            let member = object.get(&field.name).ok_or_else(|| {
+                CryptoError::InvalidInput(format!("{} is missing {}", type_name, field.name))
+            })?;
+            encoded.extend_from_slice(&self.encode_value(&field.field_type, member)?);
+        }
+        Ok(keccak256(&encoded))
+    }
+
+    /// `Type(member,...)` followed by every referenced struct type in name order
+    pub fn encode_type(&self, type_name: &str) -> Result<String> {
+        let mut referenced = BTreeSet::new();
+        self.collect_references(type_name, &mut referenced)?;
+        referenced.remove(type_name);
+
+        let mut encoded = String::new();
+        for name in std::iter::once(type_name).chain(referenced.iter().map(String::as_str)) {
+            let members: Vec<String> = self.struct_fields(name)?.iter()
+                .map(|field| format!("{} {}", field.field_type, field.name))
+                .collect();
+            encoded.push_str(&format!("{}({})", name, members.join(",")));
+        }
+        Ok(encoded)
+    }
+
+    pub fn type_hash(&self, type_name: &str) -> Result<[u8; 32]> {
+        Ok(keccak256(self.encode_type(type_name)?.as_bytes()))
+    }
+
+    fn struct_fields(&self, type_name: &str) -> Result<&[Eip712Field]> {
+        self.types.get(type_name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| CryptoError::InvalidInput(format!("Undefined EIP-712 type {}", type_name)))
+    }
+
+    fn collect_references(&self, type_name: &str, found: &mut BTreeSet<String>) -> Result<()> {
+        if !found.insert(type_name.to_string()) {
+            return Ok(());
+        }
+        for field in self.struct_fields(type_name)? {
+            let base = base_type(&field.field_type);
+            if self.types.contains_key(base) {
+                self.collect_references(base, found)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 32-byte encoding of one member value
+    fn encode_value(&self, field_type: &str, value: &Value) -> Result<[u8; 32]> {
+        if let Some(element_type) = array_element_type(field_type) {
+            let elements = value.as_array().ok_or_else(|| {
+                CryptoError::InvalidInput(format!("Value for {} is not an array", field_type))
+            })?;
+            let mut encoded = Vec::with_capacity(elements.len() * 32);
+            for element in elements {
+                encoded.extend_from_slice(&self.encode_value(element_type, element)?);
+            }
+            return Ok(keccak256(&encoded));
+        }
+
+        if self.types.contains_key(field_type) {
+            return self.hash_struct(field_type, value);
+        }
+
+        match field_type {
+            "string" => Ok(keccak256(expect_str(field_type, value)?.as_bytes())),
+            "bytes" => Ok(keccak256(&decode_hex(expect_str(field_type, value)?)?)),
+            "bool" => {
+                let flag = value.as_bool().ok_or_else(|| {
+                    CryptoError::InvalidInput(format!("Value for bool is not a boolean: {}", value))
+                })?;
+                Ok(encode_word(&[flag as u8]))
+            }
+            "address" => {
+                let address = decode_hex(expect_str(field_type, value)?)?;
+                if address.len() != 20 {
+                    return Err(CryptoError::InvalidInput(format!("Invalid address: {}", value)));
+                }
+                Ok(encode_word(&address))
+            }
+            _ if field_type.starts_with("bytes") => {
+                let size = type_size(field_type, "bytes", 1..=32)?;
+                let bytes = decode_hex(expect_str(field_type, value)?)?;
+                if bytes.len() != size {
+                    return Err(CryptoError::InvalidInput(format!("Value for {} has {} bytes", field_type, bytes.len())));
+                }
+                let mut word = [0u8; 32];
+                word[..size].copy_from_slice(&bytes);
+                Ok(word)
+            }
+            _ if field_type.starts_with("uint") => {
+                type_size(field_type, "uint", 8..=256)?;
+                encode_integer(value, false)
+            }
+            _ if field_type.starts_with("int") => {
+                type_size(field_type, "int", 8..=256)?;
+                encode_integer(value, true)
+            }
+            _ => Err(CryptoError::InvalidInput(format!("Unsupported EIP-712 type {}", field_type))),
+        }
+    }
+}
+
+/// Address that produced `signature` over `hash`, as `0x`-prefixed lowercase hex
+#[cfg(feature = "native-crypto")]
+pub fn recover_address(hash: &[u8; 32], signature: &[u8]) -> Result<String> {
+    if signature.len() != RECOVERABLE_SIGNATURE_LENGTH {
+        return Err(CryptoError::InvalidInput(format!(
+            "Invalid signature length: expected {}, got {}",
+            RECOVERABLE_SIGNATURE_LENGTH,
+            signature.len()
+        )));
+    }
+
+    let v = signature[64];
+    let recovery_id = RecoveryId::from_i32(i32::from(if v >= 27 { v - 27 } else { v }))
+        .map_err(|e| CryptoError::InvalidInput(format!("Invalid recovery id {}: {}", v, e)))?;
+    let signature = RecoverableSignature::from_compact(&signature[..64], recovery_id)
+        .map_err(|e| CryptoError::CryptoOperationFailed(format!("Invalid signature: {}", e)))?;
+    let public_key = SECP256K1.recover_ecdsa(&Message::from_digest(*hash), &signature)
+        .map_err(|e| CryptoError::VerificationFailed(format!("Signer recovery failed: {}", e)))?;
+
+    // The address is the last 20 bytes of the hash of the uncompressed point
+    let point = public_key.serialize_uncompressed();
+    Ok(format!("0x{}", hex::encode(&keccak256(&point[1..])[12..])))
+}
+
+/// Whether `request.signature` was made by `expected_address` over the typed data
+#[cfg(feature = "native-crypto")]
+pub fn verify_eip712(request: &Eip712Request, expected_address: &str) -> Result<bool> {
+    let signer = recover_address(&request.signing_hash()?, &request.signature)?;
+    Ok(signer.eq_ignore_ascii_case(expected_address.trim()))
+}
+
+/// Struct or atomic type with every array suffix removed
+fn base_type(field_type: &str) -> &str {
+    field_type.split('[').next().unwrap_or(field_type)
+}
+
+/// Element type of an array type such as `Person[]` or `uint256[3]`
+fn array_element_type(field_type: &str) -> Option<&str> {
+    if !field_type.ends_with(']') {
+        return None;
+    }
+    field_type.rfind('[').map(|open| &field_type[..open])
+}
+
+fn type_size(field_type: &str, prefix: &str, range: std::ops::RangeInclusive<usize>) -> Result<usize> {
+    field_type[prefix.len()..].parse::<usize>().ok()
+        .filter(|size| range.contains(size) && (prefix == "bytes" || size % 8 == 0))
+        .ok_or_else(|| CryptoError::InvalidInput(format!("Unsupported EIP-712 type {}", field_type)))
+}
+
+fn expect_str<'a>(field_type: &str, value: &'a Value) -> Result<&'a str> {
+    value.as_str().ok_or_else(|| {
+        CryptoError::InvalidInput(format!("Value for {} is not a string: {}", field_type, value))
+    })
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>> {
+    let digits = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")).unwrap_or(value);
+    hex::decode(digits).map_err(|e| CryptoError::InvalidInput(format!("Invalid hex {}: {}", value, e)))
+}
+
+/// Right-align `bytes` in a 32-byte word
+fn encode_word(bytes: &[u8]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[32 - bytes.len()..].copy_from_slice(bytes);
+    word
+}
+
+/// Big-endian two's complement word for a JSON number, decimal string or hex string
+fn encode_integer(value: &Value, signed: bool) -> Result<[u8; 32]> {
+    let text = match value {
+        Value::Number(number) => number.to_string(),
+        Value::String(text) => text.trim().to_string(),
+        _ => return Err(CryptoError::InvalidInput(format!("Invalid integer: {}", value))),
+    };
+    let (negative, magnitude) = match text.strip_prefix('-') {
+        Some(magnitude) if signed => (true, magnitude),
+        Some(_) => return Err(CryptoError::InvalidInput(format!("Negative value for unsigned integer: {}", text))),
+        None => (false, text.as_str()),
+    };
+
+    let mut word = match magnitude.strip_prefix("0x").or_else(|| magnitude.strip_prefix("0X")) {
+        Some(digits) => {
+            let padded = if digits.len() % 2 == 1 { format!("0{}", digits) } else { digits.to_string() };
+            let bytes = decode_hex(&padded)?;
+            let significant = &bytes[bytes.iter().take_while(|b| **b == 0).count()..];
+            if significant.len() > 32 {
+                return Err(CryptoError::InvalidInput(format!("Integer overflows 256 bits: {}", text)));
+            }
+            encode_word(significant)
+        }
+        None => parse_decimal(magnitude)?,
+    };
+
+    if negative {
+        // Two's complement: invert and add one
+        for byte in word.iter_mut() {
+            *byte = !*byte;
+        }
+        for byte in word.iter_mut().rev() {
+            let (sum, carry) = byte.overflowing_add(1);
+            *byte = sum;
+            if !carry {
+                break;
+            }
+        }
+    }
+    Ok(word)
+}
+
+fn parse_decimal(digits: &str) -> Result<[u8; 32]> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(CryptoError::InvalidInput(format!("Invalid integer: {}", digits)));
+    }
+
+    let mut word = [0u8; 32];
+    for digit in digits.bytes() {
+        let mut carry = u32::from(digit - b'0');
+        for byte in word.iter_mut().rev() {
+            let product = u32::from(*byte) * 10 + carry;
+            *byte = product as u8;
+            carry = product >> 8;
+        }
+        if carry != 0 {
+            return Err(CryptoError::InvalidInput(format!("Integer overflows 256 bits: {}", digits)));
+        }
+    }
+    Ok(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// The `Mail` example from the EIP-712 specification
+    fn mail_request() -> Eip712Request {
+        let signature = [
+            "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d",
+            "07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562",
+            "1c",
+        ].concat();
+
+        serde_json::from_value(json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" }
+                ],
+                "Person": [
+                    { "name": "name", "type": "string" },
+                    { "name": "wallet", "type": "address" }
+                ],
+                "Mail": [
+                    { "name": "from", "type": "Person" },
+                    { "name": "to", "type": "Person" },
+                    { "name": "contents", "type": "string" }
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            },
+            "message": {
+                "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+                "contents": "Hello, Bob!"
+            },
+            "signature": hex::decode(signature).unwrap()
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_mail_example_hashes() {
+        let request = mail_request();
+        assert_eq!(
+            request.encode_type("Mail").unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+        assert_eq!(
+            hex::encode(request.type_hash("Mail").unwrap()),
+            "a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2"
+        );
+        assert_eq!(
+            hex::encode(request.domain_separator().unwrap()),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+        assert_eq!(
+            hex::encode(request.hash_struct("Mail", &request.message).unwrap()),
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+        assert_eq!(
+            hex::encode(request.signing_hash().unwrap()),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    #[cfg(feature = "native-crypto")]
+    #[test]
+    fn test_mail_example_recovers_signer() {
+        let request = mail_request();
+        assert!(verify_eip712(&request, "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826").unwrap());
+        assert!(!verify_eip712(&request, "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB").unwrap());
+
+        // Changing the message changes the recovered signer
+        let mut tampered = request.clone();
+        tampered.message["contents"] = json!("Hello, Alice!");
+        assert!(!verify_eip712(&tampered, "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826").unwrap());
+
+        // `v` as a raw recovery id is accepted too
+        let mut raw_v = request.clone();
+        raw_v.signature[64] = 1;
+        assert!(verify_eip712(&raw_v, "0xcd2a3d9f938e13cd947ec05abc7fe734df8dd826").unwrap());
+
+        let mut truncated = request;
+        truncated.signature.pop();
+        assert!(verify_eip712(&truncated, "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826").is_err());
+    }
+
+    #[test]
+    fn test_integer_encoding() {
+        let word = |value: Value, signed: bool| hex::encode(encode_integer(&value, signed).unwrap());
+        assert_eq!(word(json!(1), false), format!("{:0>64}", "1"));
+        assert_eq!(word(json!("0x0100"), false), format!("{:0>64}", "100"));
+        assert_eq!(word(json!("1000000000000000000000"), false), format!("{:0>64}", "3635c9adc5dea00000"));
+        assert_eq!(word(json!(-1), true), "f".repeat(64));
+        assert!(encode_integer(&json!(-1), false).is_err());
+        assert!(encode_integer(&json!("1".repeat(80)), false).is_err());
+    }
+}
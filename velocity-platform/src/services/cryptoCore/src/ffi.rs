@@ -2,6 +2,13 @@
 /// 
 /// Provides C-compatible functions for the Velocity crypto core
 /// Can be compiled to both native library and WebAssembly
+///
+/// Memory ownership: every pointer returned by this module is owned by the caller
+/// and must be freed exactly once with the matching function:
+/// - `*mut VelocityCryptoResult` with `velocity_crypto_free_result` (frees data and message too)
+/// - `*mut c_char` strings with `velocity_free_string`
+/// - `*mut c_uchar` buffers detached from a result with `velocity_free_buffer`
+/// Freeing twice, or with the wrong function, is undefined behavior.
 
 use crate::hash_engine::{HashAlgorithm, HashEngine};
 use crate::merkle_tree::MerkleTree;
@@ -83,20 +90,48 @@ pub extern "C" fn velocity_crypto_free_result(result: *mut VelocityCryptoResult)
         let result = &mut *result;
         
         // Free data
-        if !result.data.is_null() && result.data_len > 0 {
-            let _ = Vec::from_raw_parts(result.data, result.data_len as usize, result.data_len as usize);
-        }
+        velocity_free_buffer(result.data, result.data_len as usize);
 
         // Free error message
-        if !result.error_message.is_null() {
-            let _ = CString::from_raw(result.error_message);
-        }
+        velocity_free_string(result.error_message);
 
         // Free the result itself
         Box::from_raw(result);
     }
 }
 
+/// Free a string returned by this library.
+///
+/// `ptr` must come from this library and must not be used or freed again afterwards.
+/// Passing null is a no-op.
+#[no_mangle]
+pub extern "C" fn velocity_free_string(ptr: *mut c_char) {
+    if ptr.is_null() {
+        return;
+    }
+
+    unsafe {
+        drop(CString::from_raw(ptr));
+    }
+}
+
+/// Free a byte buffer returned by this library, such as `VelocityCryptoResult.data`
+/// detached from its result.
+///
+/// `len` must be the length reported alongside the buffer. `ptr` must not be used or
+/// freed again afterwards. Passing null is a no-op.
+#[no_mangle]
+pub extern "C" fn velocity_free_buffer(ptr: *mut c_uchar, len: usize) {
+    if ptr.is_null() || len == 0 {
+        return;
+    }
+
+    unsafe {
+        // Buffers are allocated as boxed slices, so capacity equals length
+        drop(Box::from_raw(slice::from_raw_parts_mut(ptr, len)));
+    }
+}
+
 /// Hash data using specified algorithm
 #[no_mangle]
 pub extern "C" fn velocity_crypto_hash(
@@ -268,6 +303,85 @@ pub extern "C" fn velocity_crypto_calculate_trust_score(
     }
 }
 
+/// Calculate a trust score with an optional JSON configuration.
+///
+/// Returns the `TrustScore` as a JSON string that must be freed with `velocity_free_string`.
+/// On failure returns null and writes a `VelocityCryptoError` code to `out_error`.
+/// `config_json` may be null to use the default configuration.
+#[no_mangle]
+pub extern "C" fn velocity_trust_calculate(
+    activities_json: *const c_char,
+    config_json: *const c_char,
+    out_error: *mut c_int,
+) -> *mut c_char {
+    let set_error = |code: VelocityCryptoError| {
+        if !out_error.is_null() {
+            unsafe {
+                *out_error = code as c_int;
+            }
+        }
+    };
+
+    if activities_json.is_null() {
+        set_error(VelocityCryptoError::InvalidInput);
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        let activities: Vec<TrustActivity> = match CStr::from_ptr(activities_json).to_str() {
+            Ok(s) => match serde_json::from_str(s) {
+                Ok(a) => a,
+                Err(_) => {
+                    set_error(VelocityCryptoError::SerializationError);
+                    return ptr::null_mut();
+                }
+            },
+            Err(_) => {
+                set_error(VelocityCryptoError::InvalidInput);
+                return ptr::null_mut();
+            }
+        };
+
+        let config: TrustCalculatorConfig = if config_json.is_null() {
+            TrustCalculatorConfig::default()
+        } else {
+            match CStr::from_ptr(config_json).to_str() {
+                Ok(s) => match serde_json::from_str(s) {
+                    Ok(c) => c,
+                    Err(_) => {
+                        set_error(VelocityCryptoError::SerializationError);
+                        return ptr::null_mut();
+                    }
+                },
+                Err(_) => {
+                    set_error(VelocityCryptoError::InvalidInput);
+                    return ptr::null_mut();
+                }
+            }
+        };
+
+        let calculator = TrustCalculator::new(config);
+        let score = match calculator.calculate_trust_score(&activities) {
+            Ok(score) => score,
+            Err(_) => {
+                set_error(VelocityCryptoError::CryptoOperationFailed);
+                return ptr::null_mut();
+            }
+        };
+
+        match serde_json::to_string(&score).map(CString::new) {
+            Ok(Ok(json)) => {
+                set_error(VelocityCryptoError::Success);
+                json.into_raw()
+            }
+            _ => {
+                set_error(VelocityCryptoError::SerializationError);
+                ptr::null_mut()
+            }
+        }
+    }
+}
+
 /// Run Monte Carlo simulation
 #[no_mangle]
 pub extern "C" fn velocity_crypto_monte_carlo_simulate(
@@ -348,6 +462,8 @@ pub extern "C" fn velocity_crypto_verify_signature(
         1 => SignatureAlgorithm::EcdsaP256,
         2 => SignatureAlgorithm::RsaPss2048,
         3 => SignatureAlgorithm::PolygonEcdsa,
+        4 => SignatureAlgorithm::Secp256k1Schnorr,
+        5 => SignatureAlgorithm::MlDsa65,
         _ => {
             return Box::into_raw(Box::new(VelocityCryptoResult::error(
                 VelocityCryptoError::InvalidInput,
@@ -387,6 +503,7 @@ pub extern "C" fn velocity_crypto_verify_signature(
 pub mod wasm {
     use wasm_bindgen::prelude::*;
     use super::*;
+    use crate::trust_calculator::CalculatorState;
 
     #[wasm_bindgen]
     pub fn wasm_hash(data: &[u8], algorithm: u32) -> Result<Vec<u8>, JsValue> {
@@ -402,16 +519,96 @@ pub mod wasm {
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// Trust score with the default configuration, decayed as of `now` (unix seconds)
+    ///
+    /// wasm32 has no system clock, so callers pass the current time.
     #[wasm_bindgen]
-    pub fn wasm_calculate_trust_score(activities_json: &str) -> Result<String, JsValue> {
-        let activities: Vec<TrustActivity> = serde_json::from_str(activities_json)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    pub fn wasm_calculate_trust_score(activities_json: &str, now: u64) -> Result<String, JsValue> {
+        WasmTrustCalculator::new("{}")?.calculate_trust_score(activities_json, now)
+    }
 
-        let calculator = TrustCalculator::new(TrustCalculatorConfig::default());
-        let score = calculator.calculate_trust_score(&activities)
+    /// `TrustCalculator` for the browser, exchanging activities and scores as JSON
+    ///
+    /// Every call takes `now` (unix seconds, a JS `BigInt`) for decay and expiry,
+    /// so results are deterministic and match native scoring at the same instant.
+    #[wasm_bindgen]
+    pub struct WasmTrustCalculator {
+        calculator: TrustCalculator,
+    }
+
+    #[wasm_bindgen]
+    impl WasmTrustCalculator {
+        /// Build from a JSON `TrustCalculatorConfig`; omitted fields use their defaults
+        #[wasm_bindgen(constructor)]
+        pub fn new(config_json: &str) -> Result<WasmTrustCalculator, JsValue> {
+            let config: TrustCalculatorConfig = serde_json::from_str(config_json)
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse config: {}", e)))?;
+
+            Ok(WasmTrustCalculator { calculator: TrustCalculator::new(config) })
+        }
+
+        /// Score a JSON array of `TrustActivity`, returning `TrustScore` JSON
+        pub fn calculate_trust_score(&self, activities_json: &str, now: u64) -> Result<String, JsValue> {
+            let activities = parse_activities(activities_json)?;
+            let score = self.calculator.calculate_trust_score_at(&activities, now)
+                .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+            serde_json::to_string(&score)
+                .map_err(|e| JsValue::from_str(&e.to_string()))
+        }
+
+        /// Fold new activities into the calculator's running state, returning
+        /// the `TrustScore` JSON over everything recorded so far
+        pub fn record_activities(&mut self, activities_json: &str, now: u64) -> Result<String, JsValue> {
+            let activities = parse_activities(activities_json)?;
+            let score = self.calculator.record_activities_at(&activities, now)
+                .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+            serde_json::to_string(&score)
+                .map_err(|e| JsValue::from_str(&e.to_string()))
+        }
+
+        /// Running state as JSON, to resume with `restore` in a later session
+        pub fn snapshot(&self) -> Result<String, JsValue> {
+            let state = self.calculator.snapshot()
+                .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+            serde_json::to_string(&state)
+                .map_err(|e| JsValue::from_str(&e.to_string()))
+        }
+
+        /// Resume from `snapshot` JSON taken with the same configuration
+        pub fn restore(&mut self, state_json: &str) -> Result<(), JsValue> {
+            let state: CalculatorState = serde_json::from_str(state_json)
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse calculator state: {}", e)))?;
+
+            self.calculator.restore(state)
+                .map_err(|e| JsValue::from_str(&e.to_string()))
+        }
+    }
+
+    fn parse_activities(activities_json: &str) -> Result<Vec<TrustActivity>, JsValue> {
+        serde_json::from_str(activities_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse activities: {}", e)))
+    }
+
+    /// Run a compliance risk simulation from a JSON `ComplianceScenario` and
+    /// `MonteCarloConfig`, returning the `SimulationResult` as JSON
+    ///
+    /// Omitted config fields use their defaults. Runs sequentially on the calling
+    /// thread, so keep `iterations` modest on the main thread.
+    #[wasm_bindgen]
+    pub fn wasm_simulate_compliance_risk(scenario_json: &str, config_json: &str) -> Result<String, JsValue> {
+        let scenario: ComplianceScenario = serde_json::from_str(scenario_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse scenario: {}", e)))?;
+        let config: MonteCarloConfig = serde_json::from_str(config_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse config: {}", e)))?;
+
+        let engine = MonteCarloEngine::new(config);
+        let result = engine.simulate_compliance_risk(&scenario)
             .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
-        serde_json::to_string(&score)
+        serde_json::to_string(&result)
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 }
@@ -460,4 +657,113 @@ mod tests {
             velocity_crypto_free_result(result_ptr);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_ffi_trust_calculate() {
+        let activities = CString::new(r#"[
+            {
+                "activity_type": "RegulatoryApproval",
+                "timestamp": 1234567890,
+                "value": 0.9,
+                "confidence": 0.95,
+                "verifier_reputation": 0.8,
+                "polygon_tx_hash": "0xabc",
+                "metadata": {}
+            }
+        ]"#).unwrap();
+        let config = CString::new(r#"{"polygon_verification_boost": 2.0}"#).unwrap();
+        let mut error: c_int = -1;
+
+        let json_ptr = velocity_trust_calculate(activities.as_ptr(), config.as_ptr(), &mut error);
+        assert_eq!(error, VelocityCryptoError::Success as c_int);
+        assert!(!json_ptr.is_null());
+
+        let json = unsafe { CStr::from_ptr(json_ptr).to_str().unwrap().to_string() };
+        let score: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(score["total_activities"], 1);
+        assert_eq!(score["polygon_verified_activities"], 1);
+
+        velocity_free_string(json_ptr);
+
+        // Null config falls back to defaults
+        let json_ptr = velocity_trust_calculate(activities.as_ptr(), ptr::null(), &mut error);
+        assert_eq!(error, VelocityCryptoError::Success as c_int);
+        velocity_free_string(json_ptr);
+    }
+
+    #[test]
+    fn test_ffi_trust_calculate_malformed_json() {
+        let mut error: c_int = -1;
+
+        let malformed = CString::new("[{not json").unwrap();
+        let json_ptr = velocity_trust_calculate(malformed.as_ptr(), ptr::null(), &mut error);
+        assert!(json_ptr.is_null());
+        assert_eq!(error, VelocityCryptoError::SerializationError as c_int);
+
+        let activities = CString::new("[]").unwrap();
+        let bad_config = CString::new(r#"{"time_decay_factor": "fast"}"#).unwrap();
+        let json_ptr = velocity_trust_calculate(activities.as_ptr(), bad_config.as_ptr(), &mut error);
+        assert!(json_ptr.is_null());
+        assert_eq!(error, VelocityCryptoError::SerializationError as c_int);
+
+        let json_ptr = velocity_trust_calculate(ptr::null(), ptr::null(), &mut error);
+        assert!(json_ptr.is_null());
+        assert_eq!(error, VelocityCryptoError::InvalidInput as c_int);
+    }
+
+    /// Detach the data buffer from a result so it can be freed independently
+    fn take_result_data(result_ptr: *mut VelocityCryptoResult) -> (*mut c_uchar, usize) {
+        unsafe {
+            let result = &mut *result_ptr;
+            let detached = (result.data, result.data_len as usize);
+            result.data = ptr::null_mut();
+            result.data_len = 0;
+            detached
+        }
+    }
+
+    #[test]
+    fn test_free_buffer_round_trip() {
+        let result_ptr = Box::into_raw(Box::new(VelocityCryptoResult::success(vec![7u8; 64])));
+        let (data, len) = take_result_data(result_ptr);
+        assert_eq!(len, 64);
+
+        unsafe {
+            assert_eq!(slice::from_raw_parts(data, len), &[7u8; 64][..]);
+        }
+
+        velocity_free_buffer(data, len);
+        // The result no longer owns the buffer, so freeing it must not double-free
+        velocity_crypto_free_result(result_ptr);
+    }
+
+    #[test]
+    fn test_free_string_round_trip() {
+        let result_ptr = Box::into_raw(Box::new(VelocityCryptoResult::error(
+            VelocityCryptoError::InvalidInput,
+            "round trip",
+        )));
+
+        let message = unsafe {
+            let result = &mut *result_ptr;
+            let message = result.error_message;
+            result.error_message = ptr::null_mut();
+            message
+        };
+
+        unsafe {
+            assert_eq!(CStr::from_ptr(message).to_str().unwrap(), "round trip");
+        }
+
+        velocity_free_string(message);
+        velocity_crypto_free_result(result_ptr);
+    }
+
+    #[test]
+    fn test_free_null_is_noop() {
+        velocity_free_string(ptr::null_mut());
+        velocity_free_buffer(ptr::null_mut(), 0);
+        velocity_free_buffer(ptr::null_mut(), 16);
+        velocity_crypto_free_result(ptr::null_mut());
+    }
+}
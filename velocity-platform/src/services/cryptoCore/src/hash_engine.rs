@@ -3,14 +3,15 @@
 /// Provides optimized implementations of various hash algorithms
 /// with support for parallel processing and batch operations
 
-use crate::{CryptoError, Result};
+use crate::{ct_eq, CryptoError, Result};
 use blake3::Hasher as Blake3Hasher;
-use rayon::prelude::*;
+use crate::par::*;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256, Sha512};
 use sha3::{Sha3_256, Sha3_512};
 use std::sync::Arc;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HashAlgorithm {
     Sha256,
     Sha512,
@@ -35,13 +36,14 @@ impl HashEngine {
 
     /// Hash a single piece of data
     pub fn hash(&self, data: &[u8]) -> Result<Vec<u8>> {
-        match self.algorithm {
-            HashAlgorithm::Sha256 => Ok(Sha256::digest(data).to_vec()),
-            HashAlgorithm::Sha512 => Ok(Sha512::digest(data).to_vec()),
-            HashAlgorithm::Sha3_256 => Ok(Sha3_256::digest(data).to_vec()),
-            HashAlgorithm::Sha3_512 => Ok(Sha3_512::digest(data).to_vec()),
-            HashAlgorithm::Blake3 => Ok(blake3::hash(data).as_bytes().to_vec()),
-        }
+        let mut hasher = self.start();
+        hasher.update(data);
+        Ok(hasher.finalize())
+    }
+
+    /// Start an incremental hash for data that arrives in chunks
+    pub fn start(&self) -> Hasher {
+        Hasher::new(self.algorithm)
     }
 
     /// Hash multiple pieces of data in parallel
@@ -95,12 +97,7 @@ impl HashEngine {
 
     /// Stream hash for large files
     pub fn stream_hash(&self) -> Box<dyn StreamHasher> {
-        match self.algorithm {
-            HashAlgorithm::Sha256 => Box::new(Sha256StreamHasher::new()),
-            HashAlgorithm::Sha512 => Box::new(Sha512StreamHasher::new()),
-            HashAlgorithm::Blake3 => Box::new(Blake3StreamHasher::new()),
-            _ => Box::new(GenericStreamHasher::new(self.algorithm)),
-        }
+        Box::new(self.start())
     }
 }
 
@@ -111,110 +108,74 @@ pub trait StreamHasher: Send + Sync {
     fn reset(&mut self);
 }
 
-struct Sha256StreamHasher {
-    hasher: Sha256,
+/// Incremental hasher backed by the selected algorithm's streaming implementation
+pub struct Hasher {
+    algorithm: HashAlgorithm,
+    state: HasherState,
 }
 
-impl Sha256StreamHasher {
-    fn new() -> Self {
-        Self {
-            hasher: Sha256::new(),
-        }
-    }
+enum HasherState {
+    Sha256(Sha256),
+    Sha512(Sha512),
+    Sha3_256(Sha3_256),
+    Sha3_512(Sha3_512),
+    Blake3(Blake3Hasher),
 }
 
-impl StreamHasher for Sha256StreamHasher {
-    fn update(&mut self, data: &[u8]) {
-        self.hasher.update(data);
-    }
-
-    fn finalize(self: Box<Self>) -> Vec<u8> {
-        self.hasher.finalize().to_vec()
-    }
-
-    fn reset(&mut self) {
-        self.hasher = Sha256::new();
+impl Hasher {
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        let state = match algorithm {
+            HashAlgorithm::Sha256 => HasherState::Sha256(Sha256::new()),
+            HashAlgorithm::Sha512 => HasherState::Sha512(Sha512::new()),
+            HashAlgorithm::Sha3_256 => HasherState::Sha3_256(Sha3_256::new()),
+            HashAlgorithm::Sha3_512 => HasherState::Sha3_512(Sha3_512::new()),
+            HashAlgorithm::Blake3 => HasherState::Blake3(Blake3Hasher::new()),
+        };
+
+        Self { algorithm, state }
     }
-}
-
-struct Sha512StreamHasher {
-    hasher: Sha512,
-}
 
-impl Sha512StreamHasher {
-    fn new() -> Self {
-        Self {
-            hasher: Sha512::new(),
+    /// Feed the next chunk of data into the hash
+    pub fn update(&mut self, chunk: &[u8]) {
+        match &mut self.state {
+            HasherState::Sha256(hasher) => hasher.update(chunk),
+            HasherState::Sha512(hasher) => hasher.update(chunk),
+            HasherState::Sha3_256(hasher) => hasher.update(chunk),
+            HasherState::Sha3_512(hasher) => hasher.update(chunk),
+            HasherState::Blake3(hasher) => {
+                hasher.update(chunk);
+            }
         }
     }
-}
-
-impl StreamHasher for Sha512StreamHasher {
-    fn update(&mut self, data: &[u8]) {
-        self.hasher.update(data);
-    }
-
-    fn finalize(self: Box<Self>) -> Vec<u8> {
-        self.hasher.finalize().to_vec()
-    }
 
-    fn reset(&mut self) {
-        self.hasher = Sha512::new();
-    }
-}
-
-struct Blake3StreamHasher {
-    hasher: Blake3Hasher,
-}
-
-impl Blake3StreamHasher {
-    fn new() -> Self {
-        Self {
-            hasher: blake3::Hasher::new(),
+    /// Consume the hasher and return the digest
+    pub fn finalize(self) -> Vec<u8> {
+        match self.state {
+            HasherState::Sha256(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha512(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha3_256(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha3_512(hasher) => hasher.finalize().to_vec(),
+            HasherState::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
         }
     }
-}
-
-impl StreamHasher for Blake3StreamHasher {
-    fn update(&mut self, data: &[u8]) {
-        self.hasher.update(data);
-    }
-
-    fn finalize(self: Box<Self>) -> Vec<u8> {
-        self.hasher.finalize().as_bytes().to_vec()
-    }
 
-    fn reset(&mut self) {
-        self.hasher = blake3::Hasher::new();
+    /// Get the algorithm this hasher uses
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
     }
 }
 
-struct GenericStreamHasher {
-    algorithm: HashAlgorithm,
-    buffer: Vec<u8>,
-}
-
-impl GenericStreamHasher {
-    fn new(algorithm: HashAlgorithm) -> Self {
-        Self {
-            algorithm,
-            buffer: Vec::new(),
-        }
-    }
-}
-
-impl StreamHasher for GenericStreamHasher {
+impl StreamHasher for Hasher {
     fn update(&mut self, data: &[u8]) {
-        self.buffer.extend_from_slice(data);
+        Hasher::update(self, data);
     }
 
     fn finalize(self: Box<Self>) -> Vec<u8> {
-        let engine = HashEngine::new(self.algorithm);
-        engine.hash(&self.buffer).unwrap_or_default()
+        Hasher::finalize(*self)
     }
 
     fn reset(&mut self) {
-        self.buffer.clear();
+        *self = Hasher::new(self.algorithm);
     }
 }
 
@@ -222,7 +183,7 @@ impl StreamHasher for GenericStreamHasher {
 pub fn verify_hash(algorithm: HashAlgorithm, data: &[u8], expected_hash: &[u8]) -> bool {
     let engine = HashEngine::new(algorithm);
     match engine.hash(data) {
-        Ok(computed_hash) => computed_hash == expected_hash,
+        Ok(computed_hash) => ct_eq(&computed_hash, expected_hash),
         Err(_) => false,
     }
 }
@@ -283,4 +244,33 @@ mod tests {
         let chain_hash = engine.chain_hash(&inputs).unwrap();
         assert_eq!(chain_hash.len(), 32); // SHA256 produces 32 bytes
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        let data: Vec<u8> = (0..5_000_000u32).map(|i| (i % 251) as u8).collect();
+        // References computed outside the engine, since `hash` streams too
+        let references = vec![
+            (HashAlgorithm::Sha256, Sha256::digest(&data).to_vec()),
+            (HashAlgorithm::Sha512, Sha512::digest(&data).to_vec()),
+            (HashAlgorithm::Sha3_256, Sha3_256::digest(&data).to_vec()),
+            (HashAlgorithm::Sha3_512, Sha3_512::digest(&data).to_vec()),
+            (HashAlgorithm::Blake3, blake3::hash(&data).as_bytes().to_vec()),
+        ];
+
+        for (algo, expected) in references {
+            let engine = HashEngine::new(algo);
+            let mut hasher = engine.start();
+            for chunk in data.chunks(64 * 1024 + 7) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected, "{:?}", algo);
+            assert_eq!(engine.hash(&data).unwrap(), expected, "{:?}", algo);
+
+            let mut boxed = engine.stream_hash();
+            boxed.update(b"discarded");
+            boxed.reset();
+            boxed.update(&data);
+            assert_eq!(boxed.finalize(), expected, "{:?}", algo);
+        }
+    }
+}
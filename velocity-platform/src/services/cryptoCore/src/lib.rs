@@ -3,14 +3,22 @@
 /// High-performance cryptographic operations for the Velocity Trust Protocol
 /// Optimized for blockchain verification, Merkle tree generation, and trust scoring
 
+pub mod canonical;
 pub mod hash_engine;
 pub mod merkle_tree;
+pub mod sparse_merkle_tree;
 pub mod trust_calculator;
 pub mod signature_verifier;
+pub mod eip712;
 pub mod monte_carlo;
 pub mod blockchain_compliance;
+pub mod zk;
 pub mod ffi;
+pub mod wire;
+pub mod oscal;
+mod par;
 
+use subtle::ConstantTimeEq;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -30,13 +38,197 @@ pub enum CryptoError {
 
 pub type Result<T> = std::result::Result<T, CryptoError>;
 
+/// Constant-time equality for hashes, roots and signatures
+///
+/// Lengths are compared first; equal-length inputs are always compared in full.
+pub fn ct_eq(a: impl AsRef<[u8]>, b: impl AsRef<[u8]>) -> bool {
+    a.as_ref().ct_eq(b.as_ref()).into()
+}
+
 /// Initialize the crypto core with optimal performance settings
-pub fn initialize() {
+///
+/// Returns the number of worker threads batch operations will use. Without the
+/// `parallel` feature everything runs on the calling thread and this is 1.
+pub fn initialize() -> usize {
+    let threads = worker_threads();
+    println!("🔐 Velocity Crypto Core initialized with {} threads", threads);
+    threads
+}
+
+#[cfg(feature = "parallel")]
+fn worker_threads() -> usize {
     // Set up thread pool for parallel operations
-    rayon::ThreadPoolBuilder::new()
+    match rayon::ThreadPoolBuilder::new()
         .num_threads(num_cpus::get())
         .build_global()
-        .unwrap_or_else(|_| eprintln!("Failed to initialize thread pool"));
-    
-    println!("🔐 Velocity Crypto Core initialized with {} threads", num_cpus::get());
-}
\ No newline at end of file
+    {
+        Ok(()) => num_cpus::get(),
+        Err(e) => {
+            // Already initialized, or threads cannot be spawned in this environment;
+            // fall back to whatever pool rayon can provide
+            eprintln!("Failed to initialize thread pool: {}", e);
+            rayon::current_num_threads().max(1)
+        }
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn worker_threads() -> usize {
+    1
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ct_eq() {
+        let digest = blake3::hash(b"compliance evidence");
+        let same = blake3::hash(b"compliance evidence");
+        let other = blake3::hash(b"compliance evidencf");
+
+        assert!(ct_eq(digest.as_bytes(), same.as_bytes()));
+        assert!(!ct_eq(digest.as_bytes(), other.as_bytes()));
+        assert!(!ct_eq(&digest.as_bytes()[..31], digest.as_bytes()));
+        assert!(ct_eq("", b""));
+        assert!(ct_eq(hex::encode(digest.as_bytes()), hex::encode(same.as_bytes())));
+    }
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_core_operations_emit_spans() {
+        use crate::blockchain_compliance::{BlockchainComplianceEngine, ComplianceData};
+        use crate::monte_carlo::*;
+        use crate::signature_verifier::*;
+        use crate::trust_calculator::{TrustCalculator, TrustCalculatorConfig};
+        use std::collections::HashMap;
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing_subscriber::layer::{Context, SubscriberExt};
+        use tracing_subscriber::Layer;
+
+        /// Records each new span as its name plus `field=value` pairs
+        #[derive(Clone, Default)]
+        struct SpanRecorder(Arc<Mutex<Vec<(String, Vec<String>)>>>);
+
+        struct FieldRecorder(Vec<String>);
+
+        impl Visit for FieldRecorder {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push(format!("{}={:?}", field.name(), value));
+            }
+
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.push(format!("{}={}", field.name(), value));
+            }
+        }
+
+        impl<S: tracing::Subscriber> Layer<S> for SpanRecorder {
+            fn on_new_span(&self, attrs: &tracing::span::Attributes<'_>, _: &tracing::span::Id, _: Context<'_, S>) {
+                let mut fields = FieldRecorder(Vec::new());
+                attrs.record(&mut fields);
+                self.0.lock().unwrap().push((attrs.metadata().name().to_string(), fields.0));
+            }
+        }
+
+        let recorder = SpanRecorder::default();
+        let mut proof_id = String::new();
+        let subscriber = tracing_subscriber::registry().with(recorder.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let mut engine = BlockchainComplianceEngine::new().unwrap();
+            let now = chrono::Utc::now().timestamp() as u64;
+            let proof = engine
+                .create_compliance_proof(
+                    "entity-1".to_string(),
+                    "SOC2".to_string(),
+                    ComplianceData {
+                        framework: "SOC2".to_string(),
+                        version: "2017".to_string(),
+                        controls_assessed: 10,
+                        controls_passed: 10,
+                        compliance_score: 100.0,
+                        evidence_count: 4,
+                        assessment_date: now,
+                        valid_until: now + 86_400,
+                        assessor: "Velocity AI Engine".to_string(),
+                        metadata: HashMap::new(),
+                        controls: HashMap::new(),
+                    },
+                    false,
+                )
+                .unwrap();
+            engine.verify_proof(&proof).unwrap();
+            proof_id = proof.id;
+
+            TrustCalculator::new(TrustCalculatorConfig::default())
+                .calculate_trust_score(&[])
+                .unwrap();
+
+            let uniform = DistributionType::Uniform { min: 0.4, max: 0.6 };
+            MonteCarloEngine::new(MonteCarloConfig {
+                iterations: 50,
+                seed: Some(7),
+                ..Default::default()
+            })
+            .simulate_compliance_risk(&ComplianceScenario {
+                name: "baseline".to_string(),
+                compliance_factors: vec![ComplianceFactor {
+                    name: "controls".to_string(),
+                    base_value: 0.5,
+                    distribution: uniform.clone(),
+                    weight: 1.0,
+                    correlation_factors: vec![],
+                }],
+                market_conditions: MarketConditions {
+                    volatility: uniform.clone(),
+                    growth_rate: uniform.clone(),
+                    competition_intensity: uniform.clone(),
+                },
+                regulatory_environment: RegulatoryEnvironment {
+                    stringency: uniform.clone(),
+                    change_frequency: uniform.clone(),
+                    enforcement_probability: uniform,
+                },
+                polygon_verification_rate: 0.5,
+            })
+            .unwrap();
+
+            let request = SignatureRequest {
+                message: b"message".to_vec(),
+                signature: vec![0; 64],
+                public_key: vec![0; 32],
+                algorithm: SignatureAlgorithm::Ed25519,
+                polygon_tx_hash: None,
+            };
+            SignatureVerifier::new(false).verify_batch(&BatchSignatureRequest {
+                requests: vec![request.clone(), request],
+                fail_fast: false,
+                parallel_threshold: 10,
+                cache_public_keys: false,
+            });
+        });
+
+        let spans = recorder.0.lock().unwrap();
+        let fields_of = |name: &str| -> Vec<String> {
+            spans
+                .iter()
+                .find(|(span, _)| span == name)
+                .unwrap_or_else(|| panic!("missing span {}", name))
+                .1
+                .clone()
+        };
+
+        let create = fields_of("crypto.create_proof");
+        assert!(create.contains(&"framework=SOC2".to_string()));
+        assert!(create.contains(&"evidence_count=4".to_string()));
+        assert!(create.contains(&"algorithm=ed25519".to_string()));
+        assert!(fields_of("crypto.verify_proof").contains(&format!("proof_id={}", proof_id)));
+        assert!(fields_of("crypto.calculate_trust_score").contains(&"activity_count=0".to_string()));
+        assert!(fields_of("crypto.simulate_compliance_risk").contains(&"iterations=50".to_string()));
+
+        let batch = fields_of("crypto.verify_batch");
+        assert!(batch.contains(&"batch_size=2".to_string()));
+        assert!(batch.contains(&"parallel=false".to_string()));
+        let signature_spans: Vec<_> = spans.iter().filter(|(name, _)| name == "crypto.verify_signature").collect();
+        assert_eq!(signature_spans.len(), 2);
+        assert!(signature_spans[0].1.contains(&"algorithm=Ed25519".to_string()));
+    }
+}
@@ -2,10 +2,14 @@
 /// 
 /// Optimized for large-scale compliance verification and blockchain operations
 /// Supports parallel construction and efficient proof generation
+///
+/// Leaves and interior nodes are hashed under distinct prefixes, and the last
+/// node of an odd-sized level is promoted to the next level unchanged, so no
+/// two different leaf lists share a root.
 
-use crate::{CryptoError, Result};
+use crate::{ct_eq, CryptoError, Result};
 use crate::hash_engine::{HashAlgorithm, HashEngine};
-use rayon::prelude::*;
+use crate::par::*;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -13,7 +17,7 @@ use std::sync::Arc;
 pub struct MerkleTree {
     /// The root hash of the tree
     root: Vec<u8>,
-    /// All levels of the tree (level 0 = leaves)
+    /// All levels of the tree (level 0 = leaf hashes)
     levels: Vec<Vec<Vec<u8>>>,
     /// Hash algorithm used
     algorithm: HashAlgorithm,
@@ -21,14 +25,81 @@ pub struct MerkleTree {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MerkleProof {
-    /// The leaf value being proved
+    /// Hash of the leaf being proved
     pub leaf: Vec<u8>,
     /// The leaf index in the tree
     pub leaf_index: usize,
-    /// The sibling hashes needed to reconstruct the root
+    /// The sibling hashes needed to reconstruct the root; levels where the
+    /// node was promoted without a sibling are skipped
     pub siblings: Vec<Vec<u8>>,
     /// Directions for traversing the tree (true = right, false = left)
     pub directions: Vec<bool>,
+    /// Hash algorithm used to build the tree
+    pub algorithm: HashAlgorithm,
+}
+
+/// Proof that a tree is an append-only extension of an older tree
+///
+/// The old tree's largest complete subtrees are unchanged in the new tree, so
+/// the verifier rebuilds the old root from them and the new root from them plus
+/// the roots of subtrees holding only appended leaves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsistencyProof {
+    /// Number of leaves in the old tree
+    pub old_size: usize,
+    /// Number of leaves in the new tree
+    pub new_size: usize,
+    /// Roots of the old tree's largest complete subtrees, left to right
+    pub old_subtrees: Vec<Vec<u8>>,
+    /// Roots of the subtrees of appended leaves, left to right
+    pub appended: Vec<Vec<u8>>,
+    /// Hash algorithm used to build the tree
+    pub algorithm: HashAlgorithm,
+}
+
+/// Prefix for hashing a leaf, as in RFC 6962
+const LEAF_PREFIX: u8 = 0x00;
+/// Prefix for hashing a pair of child nodes
+const NODE_PREFIX: u8 = 0x01;
+
+/// Largest tree size a consistency proof may claim; the root of any tree up to
+/// this size sits below `usize::BITS` levels
+const MAX_CONSISTENCY_TREE_SIZE: usize = 1 << (usize::BITS - 1);
+
+/// Number of nodes at `level` of a tree of `size` leaves
+fn level_width(size: usize, level: usize) -> usize {
+    match u32::try_from(level).ok().and_then(|level| 1usize.checked_shl(level)) {
+        Some(span) => size.div_ceil(span),
+        // A node this high spans more leaves than any tree holds
+        None => size.min(1),
+    }
+}
+
+fn leaf_hash(engine: &HashEngine, leaf: &[u8]) -> Result<Vec<u8>> {
+    engine.hash(&[&[LEAF_PREFIX][..], leaf].concat())
+}
+
+fn node_hash(engine: &HashEngine, left: &[u8], right: &[u8]) -> Result<Vec<u8>> {
+    engine.hash(&[&[NODE_PREFIX][..], left, right].concat())
+}
+
+/// Parent of up to two adjacent nodes; a node without a right sibling is
+/// promoted unchanged rather than paired with itself
+fn parent_node(engine: &HashEngine, pair: &[Vec<u8>]) -> Result<Vec<u8>> {
+    match pair {
+        [left, right] => node_hash(engine, left, right),
+        [last] => Ok(last.clone()),
+        _ => Err(CryptoError::InvalidInput("A Merkle node has one or two children".to_string())),
+    }
+}
+
+/// Level of the root of a tree of `size` leaves
+fn root_level(size: usize) -> usize {
+    let mut level = 0;
+    while level_width(size, level) > 1 {
+        level += 1;
+    }
+    level
 }
 
 impl MerkleTree {
@@ -39,7 +110,8 @@ impl MerkleTree {
         }
 
         let engine = HashEngine::new(algorithm);
-        let mut levels = vec![leaves];
+        let leaf_hashes = leaves.iter().map(|leaf| leaf_hash(&engine, leaf)).collect::<Result<Vec<_>>>()?;
+        let mut levels = vec![leaf_hashes];
 
         // Build tree level by level
         while levels.last().unwrap().len() > 1 {
@@ -64,7 +136,8 @@ impl MerkleTree {
         }
 
         let engine = Arc::new(HashEngine::new(algorithm));
-        let mut levels = vec![leaves];
+        let leaf_hashes = leaves.par_iter().map(|leaf| leaf_hash(&engine, leaf)).collect::<Result<Vec<_>>>()?;
+        let mut levels = vec![leaf_hashes];
 
         // Build tree level by level with parallelization
         while levels.last().unwrap().len() > 1 {
@@ -84,45 +157,19 @@ impl MerkleTree {
 
     /// Build a single level of the tree
     fn build_level(engine: &HashEngine, current_level: &[Vec<u8>]) -> Result<Vec<Vec<u8>>> {
-        let mut next_level = Vec::new();
-
-        for i in (0..current_level.len()).step_by(2) {
-            let left = &current_level[i];
-            let right = if i + 1 < current_level.len() {
-                &current_level[i + 1]
-            } else {
-                left // Duplicate last node if odd number
-            };
-
-            let combined = [left.as_slice(), right.as_slice()].concat();
-            let hash = engine.hash(&combined)?;
-            next_level.push(hash);
-        }
-
-        Ok(next_level)
+        current_level
+            .chunks(2)
+            .map(|pair| parent_node(engine, pair))
+            .collect()
     }
 
     /// Build a level with parallel processing
     fn build_level_parallel(engine: &Arc<HashEngine>, current_level: &[Vec<u8>]) -> Result<Vec<Vec<u8>>> {
-        let pairs: Vec<_> = (0..current_level.len())
-            .step_by(2)
-            .map(|i| {
-                let left = &current_level[i];
-                let right = if i + 1 < current_level.len() {
-                    &current_level[i + 1]
-                } else {
-                    left
-                };
-                (left.clone(), right.clone())
-            })
-            .collect();
+        let pairs: Vec<&[Vec<u8>]> = current_level.chunks(2).collect();
 
         pairs
             .par_iter()
-            .map(|(left, right)| {
-                let combined = [left.as_slice(), right.as_slice()].concat();
-                engine.hash(&combined)
-            })
+            .map(|pair| parent_node(engine, pair))
             .collect()
     }
 
@@ -131,6 +178,11 @@ impl MerkleTree {
         &self.root
     }
 
+    /// Get the hex-encoded root hash of the tree
+    pub fn root_hash(&self) -> String {
+        hex::encode(&self.root)
+    }
+
     /// Get the number of leaves in the tree
     pub fn leaf_count(&self) -> usize {
         self.levels[0].len()
@@ -153,20 +205,13 @@ impl MerkleTree {
         // Traverse up the tree collecting siblings
         for level in 0..self.levels.len() - 1 {
             let is_right_node = current_index % 2 == 1;
-            directions.push(is_right_node);
-
-            let sibling_index = if is_right_node {
-                current_index - 1
-            } else {
-                // Handle case where current node is the last in level
-                if current_index + 1 < self.levels[level].len() {
-                    current_index + 1
-                } else {
-                    current_index
-                }
-            };
+            let sibling_index = if is_right_node { current_index - 1 } else { current_index + 1 };
 
-            siblings.push(self.levels[level][sibling_index].clone());
+            // The last node of an odd-sized level is promoted without a sibling
+            if let Some(sibling) = self.levels[level].get(sibling_index) {
+                siblings.push(sibling.clone());
+                directions.push(is_right_node);
+            }
             current_index /= 2;
         }
 
@@ -175,6 +220,7 @@ impl MerkleTree {
             leaf_index,
             siblings,
             directions,
+            algorithm: self.algorithm,
         })
     }
 
@@ -182,27 +228,102 @@ impl MerkleTree {
     pub fn verify_proof(&self, proof: &MerkleProof) -> Result<bool> {
         let engine = HashEngine::new(self.algorithm);
         let computed_root = Self::compute_root_from_proof(&engine, proof)?;
-        Ok(computed_root == self.root)
+        Ok(ct_eq(&computed_root, &self.root))
     }
 
     /// Compute root from a proof
     pub fn compute_root_from_proof(engine: &HashEngine, proof: &MerkleProof) -> Result<Vec<u8>> {
+        if proof.siblings.len() != proof.directions.len() {
+            return Err(CryptoError::InvalidInput(
+                "Proof siblings and directions have different lengths".to_string(),
+            ));
+        }
+
         let mut current_hash = proof.leaf.clone();
 
         for (i, sibling) in proof.siblings.iter().enumerate() {
-            let combined = if proof.directions[i] {
+            current_hash = if proof.directions[i] {
                 // Current node is on the right
-                [sibling.as_slice(), current_hash.as_slice()].concat()
+                node_hash(engine, sibling, &current_hash)?
             } else {
                 // Current node is on the left
-                [current_hash.as_slice(), sibling.as_slice()].concat()
+                node_hash(engine, &current_hash, sibling)?
             };
-            current_hash = engine.hash(&combined)?;
         }
 
         Ok(current_hash)
     }
 
+    /// Generate a proof that the tree over the first `new_size` leaves extends
+    /// the tree over the first `old_size`, without rewriting any of them
+    pub fn generate_consistency_proof(&self, old_size: usize, new_size: usize) -> Result<ConsistencyProof> {
+        if old_size == 0 || old_size > new_size || new_size > self.leaf_count() {
+            return Err(CryptoError::InvalidInput(format!(
+                "Cannot prove consistency from {} to {} leaves (tree has {} leaves)",
+                old_size,
+                new_size,
+                self.leaf_count()
+            )));
+        }
+
+        let old_subtrees = (0..self.levels.len())
+            .rev()
+            .filter(|level| old_size & (1 << level) != 0)
+            .map(|level| self.levels[level][(old_size >> level) - 1].clone())
+            .collect();
+
+        let engine = HashEngine::new(self.algorithm);
+        let mut appended = Vec::new();
+        self.collect_appended(&engine, root_level(new_size), 0, old_size, new_size, &mut appended)?;
+
+        Ok(ConsistencyProof {
+            old_size,
+            new_size,
+            old_subtrees,
+            appended,
+            algorithm: self.algorithm,
+        })
+    }
+
+    /// Collect the roots of the subtrees under the node at `level`/`index` of the
+    /// `new_size` tree that hold only leaves past `old_size`
+    fn collect_appended(
+        &self,
+        engine: &HashEngine,
+        level: usize,
+        index: usize,
+        old_size: usize,
+        new_size: usize,
+        appended: &mut Vec<Vec<u8>>,
+    ) -> Result<()> {
+        if index << level >= old_size {
+            appended.push(self.node_at(engine, level, index, new_size)?);
+        } else if (index + 1) << level > old_size {
+            self.collect_appended(engine, level - 1, 2 * index, old_size, new_size, appended)?;
+            if 2 * index + 1 < level_width(new_size, level - 1) {
+                self.collect_appended(engine, level - 1, 2 * index + 1, old_size, new_size, appended)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Node at `level`/`index` of the tree over the first `size` leaves
+    ///
+    /// Complete subtrees are shared with this tree; the last node of a level may
+    /// pair differently in a smaller tree and is rehashed.
+    fn node_at(&self, engine: &HashEngine, level: usize, index: usize, size: usize) -> Result<Vec<u8>> {
+        if (index + 1) << level <= size {
+            return Ok(self.levels[level][index].clone());
+        }
+        let left = self.node_at(engine, level - 1, 2 * index, size)?;
+        if 2 * index + 1 < level_width(size, level - 1) {
+            let right = self.node_at(engine, level - 1, 2 * index + 1, size)?;
+            node_hash(engine, &left, &right)
+        } else {
+            Ok(left)
+        }
+    }
+
     /// Create an incremental Merkle tree that can be updated efficiently
     pub fn incremental(initial_capacity: usize, algorithm: HashAlgorithm) -> IncrementalMerkleTree {
         IncrementalMerkleTree::new(initial_capacity, algorithm)
@@ -291,6 +412,240 @@ impl IncrementalMerkleTree {
     }
 }
 
+/// Streaming Merkle tree construction in O(log n) memory
+///
+/// Leaves are folded into their parents as soon as a sibling arrives, so only
+/// the left node still waiting for a sibling at each level is kept. The root
+/// matches `MerkleTree::new` over the same leaves, including its promotion of
+/// an odd node.
+pub struct MerkleTreeBuilder {
+    engine: HashEngine,
+    algorithm: HashAlgorithm,
+    /// Left node waiting for its sibling at each level
+    frontier: Vec<Option<Vec<u8>>>,
+    leaf_count: usize,
+    /// Proofs being collected for the leaves asked for, filled in as nodes combine
+    proofs: Vec<MerkleProof>,
+}
+
+/// Root produced by a `MerkleTreeBuilder`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamedMerkleRoot {
+    pub root: Vec<u8>,
+    pub leaf_count: usize,
+    pub algorithm: HashAlgorithm,
+    /// Proofs for the leaves passed to `with_retained_proofs`, in leaf order
+    pub proofs: Vec<MerkleProof>,
+}
+
+impl StreamedMerkleRoot {
+    /// Get the hex-encoded root hash
+    pub fn root_hash(&self) -> String {
+        hex::encode(&self.root)
+    }
+
+    /// Retained proof for `leaf_index`, if one was asked for
+    pub fn proof(&self, leaf_index: usize) -> Option<&MerkleProof> {
+        self.proofs.iter().find(|proof| proof.leaf_index == leaf_index)
+    }
+}
+
+impl MerkleTreeBuilder {
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        Self {
+            engine: HashEngine::new(algorithm),
+            algorithm,
+            frontier: Vec::new(),
+            leaf_count: 0,
+            proofs: Vec::new(),
+        }
+    }
+
+    /// Collect proofs for `leaf_indices` while building
+    ///
+    /// Each retained proof costs O(log n) memory, so keep the set small.
+    pub fn with_retained_proofs(mut self, leaf_indices: &[usize]) -> Self {
+        let mut leaf_indices = leaf_indices.to_vec();
+        leaf_indices.sort_unstable();
+        leaf_indices.dedup();
+
+        self.proofs = leaf_indices
+            .into_iter()
+            .map(|leaf_index| MerkleProof {
+                leaf: Vec::new(),
+                leaf_index,
+                siblings: Vec::new(),
+                directions: Vec::new(),
+                algorithm: self.algorithm,
+            })
+            .collect();
+        self
+    }
+
+    /// Add the next leaf
+    pub fn push(&mut self, leaf: Vec<u8>) -> Result<()> {
+        let mut index = self.leaf_count;
+        let leaf = leaf_hash(&self.engine, &leaf)?;
+        if let Some(proof) = self.proofs.iter_mut().find(|proof| proof.leaf_index == index) {
+            proof.leaf = leaf.clone();
+        }
+
+        // A node at an odd index completes the pair started by the waiting left node
+        let mut node = leaf;
+        let mut level = 0;
+        while index % 2 == 1 {
+            let left = self.frontier[level].take().expect("odd index always has a waiting left sibling");
+            node = self.combine(level, index - 1, left, node)?;
+            index /= 2;
+            level += 1;
+        }
+
+        if level == self.frontier.len() {
+            self.frontier.push(None);
+        }
+        self.frontier[level] = Some(node);
+        self.leaf_count += 1;
+        Ok(())
+    }
+
+    /// Number of leaves pushed so far
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// Close out the partially filled levels and return the root
+    pub fn finalize(mut self) -> Result<StreamedMerkleRoot> {
+        if self.leaf_count == 0 {
+            return Err(CryptoError::InvalidInput("Cannot create Merkle tree with no leaves".to_string()));
+        }
+        if let Some(proof) = self.proofs.iter().find(|proof| proof.leaf_index >= self.leaf_count) {
+            return Err(CryptoError::InvalidInput(format!(
+                "Leaf index {} out of bounds (tree has {} leaves)",
+                proof.leaf_index,
+                self.leaf_count
+            )));
+        }
+
+        // `carry` is the last node of the current level, built from the unpaired
+        // nodes of the levels below
+        let mut carry: Option<Vec<u8>> = None;
+        let mut count = self.leaf_count;
+        let mut level = 0;
+        while count > 1 {
+            let waiting = self.frontier.get_mut(level).and_then(Option::take);
+            carry = match (waiting, carry) {
+                (Some(left), Some(right)) => Some(self.combine(level, count - 2, left, right)?),
+                // Odd number of nodes: the last one is promoted unchanged
+                (Some(last), None) | (None, Some(last)) => Some(last),
+                (None, None) => None,
+            };
+            count = count.div_ceil(2);
+            level += 1;
+        }
+
+        let root = match carry {
+            Some(root) => root,
+            None => self.frontier[level].take().expect("a single node remains at the top level"),
+        };
+
+        Ok(StreamedMerkleRoot {
+            root,
+            leaf_count: self.leaf_count,
+            algorithm: self.algorithm,
+            proofs: self.proofs,
+        })
+    }
+
+    /// Hash a pair of nodes at `level`, recording each as the other's sibling
+    /// in any retained proof that passes through them
+    fn combine(&mut self, level: usize, left_index: usize, left: Vec<u8>, right: Vec<u8>) -> Result<Vec<u8>> {
+        for proof in self.proofs.iter_mut() {
+            let ancestor = proof.leaf_index >> level;
+            if ancestor == left_index {
+                proof.siblings.push(right.clone());
+                proof.directions.push(false);
+            } else if ancestor == left_index + 1 {
+                proof.siblings.push(left.clone());
+                proof.directions.push(true);
+            }
+        }
+
+        node_hash(&self.engine, &left, &right)
+    }
+}
+
+/// Verify a Merkle proof without the tree by hashing `leaf`, the raw leaf data,
+/// and recomputing the root from its sibling path. Odd nodes are promoted
+/// without a sibling, matching construction.
+pub fn verify_merkle_proof(leaf: &[u8], proof: &MerkleProof, root: &[u8]) -> bool {
+    let engine = HashEngine::new(proof.algorithm);
+    if !leaf_hash(&engine, leaf).is_ok_and(|hash| ct_eq(&proof.leaf, &hash)) {
+        return false;
+    }
+
+    match MerkleTree::compute_root_from_proof(&engine, proof) {
+        Ok(computed_root) => ct_eq(&computed_root, root),
+        Err(_) => false,
+    }
+}
+
+/// Verify that the tree with `new_root` extends the tree with `old_root` by
+/// appending leaves only, leaving the old leaves and their order untouched
+pub fn verify_consistency_proof(old_root: &[u8], new_root: &[u8], proof: &ConsistencyProof) -> bool {
+    if proof.old_size == 0 || proof.old_size > proof.new_size || proof.new_size > MAX_CONSISTENCY_TREE_SIZE {
+        return false;
+    }
+
+    let engine = HashEngine::new(proof.algorithm);
+    let rebuild = |size: usize, appended: &[Vec<u8>]| {
+        let mut old_subtrees = proof.old_subtrees.iter();
+        let mut appended = appended.iter();
+        let root = rebuild_consistency_node(
+            &engine,
+            root_level(size),
+            0,
+            proof.old_size,
+            size,
+            &mut old_subtrees,
+            &mut appended,
+        )?;
+        // Every node in the proof must have been used
+        (old_subtrees.next().is_none() && appended.next().is_none()).then_some(root)
+    };
+
+    match (rebuild(proof.old_size, &[]), rebuild(proof.new_size, &proof.appended)) {
+        (Some(computed_old), Some(computed_new)) => ct_eq(&computed_old, old_root) && ct_eq(&computed_new, new_root),
+        _ => false,
+    }
+}
+
+/// Recompute the node at `level`/`index` of a tree of `size` leaves from the
+/// old tree's complete subtrees and the appended subtrees, consumed left to right
+fn rebuild_consistency_node<'a>(
+    engine: &HashEngine,
+    level: usize,
+    index: usize,
+    old_size: usize,
+    size: usize,
+    old_subtrees: &mut impl Iterator<Item = &'a Vec<u8>>,
+    appended: &mut impl Iterator<Item = &'a Vec<u8>>,
+) -> Option<Vec<u8>> {
+    if (index + 1) << level <= old_size {
+        return old_subtrees.next().cloned();
+    }
+    if index << level >= old_size {
+        return appended.next().cloned();
+    }
+
+    let left = rebuild_consistency_node(engine, level - 1, 2 * index, old_size, size, old_subtrees, appended)?;
+    if 2 * index + 1 < level_width(size, level - 1) {
+        let right = rebuild_consistency_node(engine, level - 1, 2 * index + 1, old_size, size, old_subtrees, appended)?;
+        node_hash(engine, &left, &right).ok()
+    } else {
+        Some(left)
+    }
+}
+
 /// Optimized batch proof verification
 pub fn verify_proofs_batch(
     tree: &MerkleTree,
@@ -303,7 +658,7 @@ pub fn verify_proofs_batch(
         .par_iter()
         .map(|proof| {
             match MerkleTree::compute_root_from_proof(&engine, proof) {
-                Ok(computed_root) => computed_root == tree_root,
+                Ok(computed_root) => ct_eq(&computed_root, &tree_root),
                 Err(_) => false,
             }
         })
@@ -370,4 +725,195 @@ mod tests {
         let tree = MerkleTree::new_parallel(large_dataset, HashAlgorithm::Blake3).unwrap();
         assert_eq!(tree.leaf_count(), 10000);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_standalone_proof_verification_for_tree_sizes() {
+        for size in [1usize, 2, 8, 5, 7] {
+            let leaves: Vec<Vec<u8>> = (0..size)
+                .map(|i| format!("leaf_{}", i).into_bytes())
+                .collect();
+            let tree = MerkleTree::new(leaves.clone(), HashAlgorithm::Sha256).unwrap();
+
+            for (i, leaf) in leaves.iter().enumerate() {
+                let proof = tree.generate_proof(i).unwrap();
+                assert!(verify_merkle_proof(leaf, &proof, tree.root()), "size {} leaf {}", size, i);
+            }
+        }
+    }
+
+    #[test]
+    fn test_standalone_proof_rejects_wrong_leaf_and_root() {
+        let leaves: Vec<Vec<u8>> = (0..5)
+            .map(|i| format!("leaf_{}", i).into_bytes())
+            .collect();
+        let tree = MerkleTree::new(leaves.clone(), HashAlgorithm::Blake3).unwrap();
+        let other = MerkleTree::new(leaves[..4].to_vec(), HashAlgorithm::Blake3).unwrap();
+
+        let proof = tree.generate_proof(4).unwrap();
+        assert!(!verify_merkle_proof(&leaves[3], &proof, tree.root()));
+        assert!(!verify_merkle_proof(&leaves[4], &proof, other.root()));
+        assert_eq!(tree.root_hash(), hex::encode(tree.root()));
+    }
+
+    #[test]
+    fn test_duplicated_leaves_and_interior_nodes_do_not_forge_roots() {
+        let leaves: Vec<Vec<u8>> = (0..3)
+            .map(|i| format!("leaf_{}", i).into_bytes())
+            .collect();
+        let tree = MerkleTree::new(leaves.clone(), HashAlgorithm::Sha256).unwrap();
+
+        // Repeating the last leaf of an odd level is a different tree
+        let mut padded = leaves.clone();
+        padded.push(leaves[2].clone());
+        assert_ne!(MerkleTree::new(padded, HashAlgorithm::Sha256).unwrap().root(), tree.root());
+
+        // The two children of an interior node, offered as leaves, do not hash to it
+        let level_one = tree.get_level(1).unwrap();
+        let forged = MerkleTree::new(level_one.clone(), HashAlgorithm::Sha256).unwrap();
+        assert_ne!(forged.root(), tree.root());
+
+        // Nor does an interior node verify as a leaf under the real root
+        let mut proof = tree.generate_proof(0).unwrap();
+        proof.leaf = level_one[0].clone();
+        proof.siblings.remove(0);
+        proof.directions.remove(0);
+        assert!(!verify_merkle_proof(&level_one[0], &proof, tree.root()));
+
+        // The proof carries the leaf hash; the verifier is given the leaf itself
+        let proof = tree.generate_proof(2).unwrap();
+        assert_eq!(proof.siblings.len(), 1);
+        assert!(verify_merkle_proof(&leaves[2], &proof, tree.root()));
+        assert!(!verify_merkle_proof(&proof.leaf, &proof, tree.root()));
+    }
+
+    #[test]
+    fn test_streaming_builder_matches_in_memory_tree() {
+        for size in 1usize..=33 {
+            let leaves: Vec<Vec<u8>> = (0..size)
+                .map(|i| format!("leaf_{}", i).into_bytes())
+                .collect();
+            let tree = MerkleTree::new(leaves.clone(), HashAlgorithm::Sha256).unwrap();
+
+            let retained = [0, size / 2, size - 1];
+            let mut builder = MerkleTreeBuilder::new(HashAlgorithm::Sha256).with_retained_proofs(&retained);
+            for leaf in leaves.iter().cloned() {
+                builder.push(leaf).unwrap();
+            }
+            // Frontier stays logarithmic in the number of leaves
+            assert!(builder.frontier.len() <= tree.depth(), "size {}", size);
+
+            let streamed = builder.finalize().unwrap();
+            assert_eq!(streamed.root, tree.root(), "size {}", size);
+            assert_eq!(streamed.leaf_count, size);
+
+            for leaf_index in retained {
+                let proof = streamed.proof(leaf_index).unwrap();
+                let expected = tree.generate_proof(leaf_index).unwrap();
+                assert_eq!(proof.siblings, expected.siblings, "size {} leaf {}", size, leaf_index);
+                assert_eq!(proof.directions, expected.directions, "size {} leaf {}", size, leaf_index);
+                assert!(verify_merkle_proof(&leaves[leaf_index], proof, &streamed.root));
+            }
+        }
+    }
+
+    #[test]
+    fn test_consistency_proof_for_appended_leaves() {
+        let leaves: Vec<Vec<u8>> = (0..17)
+            .map(|i| format!("leaf_{}", i).into_bytes())
+            .collect();
+        let tree = MerkleTree::new(leaves.clone(), HashAlgorithm::Sha256).unwrap();
+
+        for new_size in 1..=leaves.len() {
+            let new_tree = MerkleTree::new(leaves[..new_size].to_vec(), HashAlgorithm::Sha256).unwrap();
+            for old_size in 1..=new_size {
+                let old_tree = MerkleTree::new(leaves[..old_size].to_vec(), HashAlgorithm::Sha256).unwrap();
+                let proof = tree.generate_consistency_proof(old_size, new_size).unwrap();
+                assert!(
+                    verify_consistency_proof(old_tree.root(), new_tree.root(), &proof),
+                    "old {} new {}",
+                    old_size,
+                    new_size
+                );
+                if old_size < new_size {
+                    assert!(!verify_consistency_proof(new_tree.root(), old_tree.root(), &proof));
+                }
+            }
+        }
+
+        assert!(tree.generate_consistency_proof(0, 4).is_err());
+        assert!(tree.generate_consistency_proof(5, 4).is_err());
+        assert!(tree.generate_consistency_proof(4, 18).is_err());
+    }
+
+    #[test]
+    fn test_consistency_proof_rejects_rewritten_history() {
+        let leaves: Vec<Vec<u8>> = (0..10)
+            .map(|i| format!("leaf_{}", i).into_bytes())
+            .collect();
+        let old_tree = MerkleTree::new(leaves[..6].to_vec(), HashAlgorithm::Blake3).unwrap();
+
+        // Reordering old leaves before appending yields a tree that is not an extension
+        let mut reordered = leaves.clone();
+        reordered.swap(1, 4);
+        let rewritten = MerkleTree::new(reordered, HashAlgorithm::Blake3).unwrap();
+        let proof = rewritten.generate_consistency_proof(6, 10).unwrap();
+        assert!(!verify_consistency_proof(old_tree.root(), rewritten.root(), &proof));
+
+        // A genuine proof does not stretch to a tampered new root or extra nodes
+        let tree = MerkleTree::new(leaves, HashAlgorithm::Blake3).unwrap();
+        let mut proof = tree.generate_consistency_proof(6, 10).unwrap();
+        assert!(verify_consistency_proof(old_tree.root(), tree.root(), &proof));
+        assert!(!verify_consistency_proof(old_tree.root(), rewritten.root(), &proof));
+        proof.appended.push(tree.root().to_vec());
+        assert!(!verify_consistency_proof(old_tree.root(), tree.root(), &proof));
+    }
+
+    #[test]
+    fn test_consistency_proof_with_wrong_old_size_is_rejected() {
+        let leaves: Vec<Vec<u8>> = (0..12)
+            .map(|i| format!("leaf_{}", i).into_bytes())
+            .collect();
+        let tree = MerkleTree::new(leaves.clone(), HashAlgorithm::Sha256).unwrap();
+        let old_root = |size: usize| {
+            MerkleTree::new(leaves[..size].to_vec(), HashAlgorithm::Sha256).unwrap().root().to_vec()
+        };
+
+        // A proof from `n` leaves cannot be passed off as one from `n + 1`, even
+        // where the last old leaf would sit unpaired
+        for old_size in 1..leaves.len() - 1 {
+            let mut proof = tree.generate_consistency_proof(old_size, leaves.len()).unwrap();
+            proof.old_size = old_size + 1;
+            for claimed_root in [old_root(old_size), old_root(old_size + 1)] {
+                assert!(!verify_consistency_proof(&claimed_root, tree.root(), &proof), "old {}", old_size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_consistency_proof_with_oversized_tree_is_rejected() {
+        assert_eq!(root_level(usize::MAX), usize::BITS as usize);
+        assert_eq!(level_width(usize::MAX, usize::BITS as usize + 1), 1);
+
+        let leaves: Vec<Vec<u8>> = (0..6)
+            .map(|i| format!("leaf_{}", i).into_bytes())
+            .collect();
+        let tree = MerkleTree::new(leaves, HashAlgorithm::Sha256).unwrap();
+        let old_tree = MerkleTree::new(vec![b"leaf_0".to_vec(), b"leaf_1".to_vec()], HashAlgorithm::Sha256).unwrap();
+        let mut proof = tree.generate_consistency_proof(2, 6).unwrap();
+
+        // Sizes from untrusted input fail cleanly instead of overflowing
+        for new_size in [usize::MAX, MAX_CONSISTENCY_TREE_SIZE + 1, MAX_CONSISTENCY_TREE_SIZE] {
+            proof.new_size = new_size;
+            assert!(!verify_consistency_proof(old_tree.root(), tree.root(), &proof));
+        }
+    }
+
+    #[test]
+    fn test_streaming_builder_rejects_empty_and_missing_proof_leaves() {
+        assert!(MerkleTreeBuilder::new(HashAlgorithm::Blake3).finalize().is_err());
+
+        let mut builder = MerkleTreeBuilder::new(HashAlgorithm::Blake3).with_retained_proofs(&[4]);
+        builder.push(b"only".to_vec()).unwrap();
+        assert!(builder.finalize().is_err());
+    }
+}
@@ -4,13 +4,23 @@
 /// Supports parallel simulations and GPU acceleration when available
 
 use crate::{CryptoError, Result};
-use rayon::prelude::*;
+use crate::par::*;
 use rand::{distributions::Distribution, thread_rng, Rng, SeedableRng};
-use rand_distr::{Beta, Normal, Uniform};
+use rand_distr::{Beta, Normal, Uniform, WeightedAliasIndex};
 use rand_xoshiro::Xoshiro256PlusPlus;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::sync::Arc;
 
+/// Spread below this fraction of the values' magnitude is rounding noise, not variance
+const RELATIVE_SPREAD_EPSILON: f64 = 1e-12;
+
+/// Distance from 1.0 at which factor weights are treated as unnormalized
+const WEIGHT_SUM_TOLERANCE: f64 = 1e-6;
+
+/// Bandwidths either side of the sample range over which the density is evaluated
+const DENSITY_TAIL_BANDWIDTHS: f64 = 3.0;
+
 /// Types of distributions for simulation inputs
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DistributionType {
@@ -19,6 +29,8 @@ pub enum DistributionType {
     Beta { alpha: f64, beta: f64 },
     Triangular { min: f64, mode: f64, max: f64 },
     Empirical { values: Vec<f64> },
+    /// Draws `values[i]` with probability proportional to `weights[i]`
+    EmpiricalWeighted { values: Vec<f64>, weights: Vec<f64> },
 }
 
 /// Simulation scenario for compliance risk
@@ -55,13 +67,22 @@ pub struct RegulatoryEnvironment {
 }
 
 /// Monte Carlo simulation configuration
-#[derive(Debug, Clone)]
+///
+/// Fields missing from a serialized config fall back to `Default`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MonteCarloConfig {
     pub iterations: usize,
     pub confidence_intervals: Vec<f64>,
     pub parallel_threshold: usize,
     pub seed: Option<u64>,
     pub enable_polygon_verification: bool,
+    /// Rescale factor weights that do not sum to 1.0; when false they are rejected
+    pub normalize_weights: bool,
+    /// Estimate the compliance score density; costs O(iterations * density_points)
+    pub compute_density: bool,
+    /// Number of evenly spaced points the density is evaluated at
+    pub density_points: usize,
 }
 
 impl Default for MonteCarloConfig {
@@ -72,6 +93,9 @@ impl Default for MonteCarloConfig {
             parallel_threshold: 1_000,
             seed: None,
             enable_polygon_verification: true,
+            normalize_weights: true,
+            compute_density: false,
+            density_points: 256,
         }
     }
 }
@@ -87,29 +111,100 @@ impl MonteCarloEngine {
     }
 
     /// Run compliance risk simulation
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "crypto.simulate_compliance_risk",
+        skip_all,
+        fields(
+            scenario = %scenario.name,
+            factor_count = scenario.compliance_factors.len(),
+            iterations = self.config.iterations,
+            parallel = self.runs_in_parallel(),
+        ),
+    ))]
     pub fn simulate_compliance_risk(
         &self,
         scenario: &ComplianceScenario,
     ) -> Result<SimulationResult> {
-        let results = if self.config.iterations > self.config.parallel_threshold {
-            self.run_parallel_simulation(scenario)?
+        let (scenario, weight_normalization) = self.normalize_weights(scenario)?;
+        let samplers = ScenarioSamplers::new(&scenario)?;
+
+        let results = if self.runs_in_parallel() {
+            self.run_parallel_simulation(&scenario, &samplers)?
         } else {
-            self.run_sequential_simulation(scenario)?
+            self.run_sequential_simulation(&scenario, &samplers)?
         };
 
-        self.analyze_results(results, scenario)
+        let mut result = self.analyze_results(results, &scenario)?;
+        if let Some(normalization) = &weight_normalization {
+            result.data_quality.push(format!(
+                "Factor weights summed to {}; scaled by {} to sum to 1.0",
+                normalization.original_sum, normalization.scale
+            ));
+        }
+        result.weight_normalization = weight_normalization;
+        Ok(result)
+    }
+
+    /// Check that factor weights sum to 1.0, rescaling them if `normalize_weights` is set
+    ///
+    /// Unnormalized weights mis-scale `compliance_score`, which can exceed 1.0
+    /// before clamping and skew every downstream risk estimate.
+    fn normalize_weights<'a>(
+        &self,
+        scenario: &'a ComplianceScenario,
+    ) -> Result<(Cow<'a, ComplianceScenario>, Option<WeightNormalization>)> {
+        if scenario.compliance_factors.is_empty() {
+            return Ok((Cow::Borrowed(scenario), None));
+        }
+        if let Some(factor) = scenario.compliance_factors.iter().find(|f| !(f.weight.is_finite() && f.weight >= 0.0)) {
+            return Err(CryptoError::InvalidInput(format!(
+                "Invalid weight {} for factor {}",
+                factor.weight, factor.name
+            )));
+        }
+
+        let original_sum: f64 = scenario.compliance_factors.iter().map(|f| f.weight).sum();
+        if (original_sum - 1.0).abs() <= WEIGHT_SUM_TOLERANCE {
+            return Ok((Cow::Borrowed(scenario), None));
+        }
+        if original_sum <= 0.0 {
+            return Err(CryptoError::InvalidInput("Factor weights sum to zero".to_string()));
+        }
+        if !self.config.normalize_weights {
+            return Err(CryptoError::InvalidInput(format!(
+                "Factor weights sum to {} rather than 1.0",
+                original_sum
+            )));
+        }
+
+        let scale = 1.0 / original_sum;
+        let mut normalized = scenario.clone();
+        for factor in &mut normalized.compliance_factors {
+            factor.weight *= scale;
+        }
+
+        Ok((Cow::Owned(normalized), Some(WeightNormalization { original_sum, scale })))
+    }
+
+    /// Whether `simulate_compliance_risk` takes the rayon path
+    ///
+    /// Never on wasm32: browsers give rayon no thread pool to spawn into, so
+    /// simulations there always run sequentially on the calling thread.
+    fn runs_in_parallel(&self) -> bool {
+        cfg!(not(target_arch = "wasm32")) && self.config.iterations > self.config.parallel_threshold
     }
 
     /// Run sequential simulation for smaller iteration counts
     fn run_sequential_simulation(
         &self,
         scenario: &ComplianceScenario,
+        samplers: &ScenarioSamplers,
     ) -> Result<Vec<SimulationIteration>> {
         let mut rng = self.create_rng();
         let mut results = Vec::with_capacity(self.config.iterations);
 
         for i in 0..self.config.iterations {
-            let iteration = self.simulate_single_iteration(scenario, &mut rng, i)?;
+            let iteration = self.simulate_single_iteration(scenario, samplers, &mut rng, i)?;
             results.push(iteration);
         }
 
@@ -120,6 +215,7 @@ impl MonteCarloEngine {
     fn run_parallel_simulation(
         &self,
         scenario: &ComplianceScenario,
+        samplers: &ScenarioSamplers,
     ) -> Result<Vec<SimulationIteration>> {
         let scenario = Arc::new(scenario.clone());
         let base_seed = self.config.seed.unwrap_or_else(|| thread_rng().gen());
@@ -129,7 +225,7 @@ impl MonteCarloEngine {
             .map(|i| {
                 let scenario = Arc::clone(&scenario);
                 let mut rng = Xoshiro256PlusPlus::seed_from_u64(base_seed.wrapping_add(i as u64));
-                self.simulate_single_iteration(&scenario, &mut rng, i)
+                self.simulate_single_iteration(&scenario, samplers, &mut rng, i)
             })
             .collect();
 
@@ -140,25 +236,26 @@ impl MonteCarloEngine {
     fn simulate_single_iteration(
         &self,
         scenario: &ComplianceScenario,
+        samplers: &ScenarioSamplers,
         rng: &mut impl Rng,
         iteration_id: usize,
     ) -> Result<SimulationIteration> {
         // Sample market conditions
-        let market_volatility = self.sample_distribution(&scenario.market_conditions.volatility, rng)?;
-        let market_growth = self.sample_distribution(&scenario.market_conditions.growth_rate, rng)?;
-        let competition = self.sample_distribution(&scenario.market_conditions.competition_intensity, rng)?;
+        let market_volatility = samplers.volatility.sample(self, rng)?;
+        let market_growth = samplers.growth_rate.sample(self, rng)?;
+        let competition = samplers.competition_intensity.sample(self, rng)?;
 
         // Sample regulatory environment
-        let regulatory_stringency = self.sample_distribution(&scenario.regulatory_environment.stringency, rng)?;
-        let regulatory_changes = self.sample_distribution(&scenario.regulatory_environment.change_frequency, rng)?;
-        let enforcement_prob = self.sample_distribution(&scenario.regulatory_environment.enforcement_probability, rng)?;
+        let regulatory_stringency = samplers.stringency.sample(self, rng)?;
+        let regulatory_changes = samplers.change_frequency.sample(self, rng)?;
+        let enforcement_prob = samplers.enforcement_probability.sample(self, rng)?;
 
         // Calculate compliance factors with correlations
         let mut factor_values = Vec::new();
         let mut compliance_score = 0.0;
 
-        for factor in &scenario.compliance_factors {
-            let base_sample = self.sample_distribution(&factor.distribution, rng)?;
+        for (factor, sampler) in scenario.compliance_factors.iter().zip(&samplers.factors) {
+            let base_sample = sampler.sample(self, rng)?;
             
             // Apply correlations
             let mut adjusted_value = base_sample;
@@ -254,6 +351,10 @@ impl MonteCarloEngine {
                 let idx = rng.gen_range(0..values.len());
                 Ok(values[idx])
             }
+            DistributionType::EmpiricalWeighted { values, weights } => {
+                // Simulations build the alias table once per run; see `ScenarioSamplers`
+                Ok(values[weighted_alias_index(values, weights)?.sample(rng)])
+            }
         }
     }
 
@@ -289,6 +390,22 @@ impl MonteCarloEngine {
         // Factor sensitivity analysis
         let factor_sensitivities = self.calculate_factor_sensitivities(&results);
 
+        let mut data_quality = Vec::new();
+        for (name, statistics) in [("compliance", &stats), ("risk", &risk_stats)] {
+            if statistics.degenerate {
+                data_quality.push(format!(
+                    "{} scores have too few samples or no variance; skewness and kurtosis reported as 0",
+                    name
+                ));
+            }
+        }
+        for sensitivity in factor_sensitivities.iter().filter(|s| s.degenerate) {
+            data_quality.push(format!(
+                "{} or compliance scores have no variance; correlation reported as 0",
+                sensitivity.factor_name
+            ));
+        }
+
         // Risk metrics
         let enforcement_probability = results.iter()
             .filter(|r| r.enforcement_action)
@@ -298,6 +415,16 @@ impl MonteCarloEngine {
             .filter(|r| r.polygon_verified)
             .count() as f64 / results.len() as f64;
 
+        let density = if self.config.compute_density {
+            let density = self.calculate_density(&compliance_scores);
+            if density.is_none() {
+                data_quality.push("compliance scores have no variance; density not estimated".to_string());
+            }
+            density
+        } else {
+            None
+        };
+
         Ok(SimulationResult {
             scenario_name: scenario.name.clone(),
             iterations: results.len(),
@@ -309,6 +436,9 @@ impl MonteCarloEngine {
             polygon_verification_rate,
             percentiles: self.calculate_percentiles(&compliance_scores),
             convergence_achieved: self.check_convergence(&compliance_scores),
+            data_quality,
+            weight_normalization: None,
+            density,
         })
     }
 
@@ -323,7 +453,7 @@ impl MonteCarloEngine {
         let std_dev = variance.sqrt();
         
         let mut sorted = values.to_vec();
-        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        sorted.sort_by(|a, b| a.total_cmp(b));
         
         let median = if sorted.len() % 2 == 0 {
             (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2.0
@@ -331,21 +461,25 @@ impl MonteCarloEngine {
             sorted[sorted.len() / 2]
         };
 
+        let skewness = self.calculate_skewness(values, mean, std_dev);
+        let kurtosis = self.calculate_kurtosis(values, mean, std_dev);
+
         Statistics {
             mean,
             median,
             std_dev,
             min: *sorted.first().unwrap(),
             max: *sorted.last().unwrap(),
-            skewness: self.calculate_skewness(values, mean, std_dev),
-            kurtosis: self.calculate_kurtosis(values, mean, std_dev),
+            skewness: skewness.unwrap_or(0.0),
+            kurtosis: kurtosis.unwrap_or(0.0),
+            degenerate: skewness.is_none() || kurtosis.is_none(),
         }
     }
 
-    /// Calculate skewness
-    fn calculate_skewness(&self, values: &[f64], mean: f64, std_dev: f64) -> f64 {
-        if std_dev == 0.0 {
-            return 0.0;
+    /// Calculate skewness, or `None` with fewer than 3 samples or no variance
+    fn calculate_skewness(&self, values: &[f64], mean: f64, std_dev: f64) -> Option<f64> {
+        if values.len() < 3 || !has_spread(std_dev, mean) {
+            return None;
         }
         
         let n = values.len() as f64;
@@ -353,13 +487,13 @@ impl MonteCarloEngine {
             .map(|v| ((v - mean) / std_dev).powi(3))
             .sum::<f64>();
         
-        (n / ((n - 1.0) * (n - 2.0))) * sum_cubed
+        Some((n / ((n - 1.0) * (n - 2.0))) * sum_cubed).filter(|skewness| skewness.is_finite())
     }
 
-    /// Calculate kurtosis
-    fn calculate_kurtosis(&self, values: &[f64], mean: f64, std_dev: f64) -> f64 {
-        if std_dev == 0.0 {
-            return 0.0;
+    /// Calculate excess kurtosis, or `None` with fewer than 4 samples or no variance
+    fn calculate_kurtosis(&self, values: &[f64], mean: f64, std_dev: f64) -> Option<f64> {
+        if values.len() < 4 || !has_spread(std_dev, mean) {
+            return None;
         }
         
         let n = values.len() as f64;
@@ -370,13 +504,13 @@ impl MonteCarloEngine {
         let g2 = sum_fourth / n - 3.0;
         
         // Excess kurtosis
-        (n - 1.0) / ((n - 2.0) * (n - 3.0)) * ((n + 1.0) * g2 + 6.0)
+        Some((n - 1.0) / ((n - 2.0) * (n - 3.0)) * ((n + 1.0) * g2 + 6.0)).filter(|kurtosis| kurtosis.is_finite())
     }
 
     /// Calculate confidence interval
     fn calculate_confidence_interval(&self, values: &[f64], confidence_level: f64) -> (f64, f64) {
         let mut sorted = values.to_vec();
-        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        sorted.sort_by(|a, b| a.total_cmp(b));
         
         let alpha = 1.0 - confidence_level;
         let lower_idx = ((alpha / 2.0) * sorted.len() as f64) as usize;
@@ -388,7 +522,7 @@ impl MonteCarloEngine {
     /// Calculate percentiles
     fn calculate_percentiles(&self, values: &[f64]) -> Vec<(f64, f64)> {
         let mut sorted = values.to_vec();
-        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        sorted.sort_by(|a, b| a.total_cmp(b));
         
         let percentiles = vec![0.01, 0.05, 0.10, 0.25, 0.50, 0.75, 0.90, 0.95, 0.99];
         percentiles.into_iter()
@@ -399,6 +533,48 @@ impl MonteCarloEngine {
             .collect()
     }
 
+    /// Gaussian kernel density estimate as `(x, density)` pairs, or `None` without spread
+    ///
+    /// Uses Silverman's rule of thumb for the bandwidth and evaluates
+    /// `density_points` evenly spaced points from three bandwidths below the
+    /// smallest sample to three above the largest.
+    fn calculate_density(&self, values: &[f64]) -> Option<Vec<(f64, f64)>> {
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let std_dev = (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n).sqrt();
+
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let quantile = |p: f64| sorted[((p * n) as usize).min(sorted.len() - 1)];
+        let iqr = quantile(0.75) - quantile(0.25);
+
+        // An IQR of 0 with real spread means heavy ties; fall back to the standard deviation
+        let spread = if iqr > 0.0 { std_dev.min(iqr / 1.34) } else { std_dev };
+        let bandwidth = 0.9 * spread * n.powf(-0.2);
+        if !has_spread(bandwidth, mean) {
+            return None;
+        }
+
+        let points = self.config.density_points.max(2);
+        let start = sorted[0] - DENSITY_TAIL_BANDWIDTHS * bandwidth;
+        let end = sorted[sorted.len() - 1] + DENSITY_TAIL_BANDWIDTHS * bandwidth;
+        let step = (end - start) / (points - 1) as f64;
+        let normalization = 1.0 / (n * bandwidth * (2.0 * std::f64::consts::PI).sqrt());
+
+        Some(
+            (0..points)
+                .map(|i| {
+                    let x = start + step * i as f64;
+                    let kernel_sum: f64 = sorted
+                        .iter()
+                        .map(|v| (-0.5 * ((x - v) / bandwidth).powi(2)).exp())
+                        .sum();
+                    (x, kernel_sum * normalization)
+                })
+                .collect(),
+        )
+    }
+
     /// Calculate factor sensitivities
     fn calculate_factor_sensitivities(&self, results: &[SimulationIteration]) -> Vec<FactorSensitivity> {
         let mut sensitivities = Vec::new();
@@ -417,41 +593,44 @@ impl MonteCarloEngine {
                     .collect();
                 
                 let correlation = self.calculate_correlation(&factor_values, &compliance_scores);
+                let correlation_with_compliance = correlation.unwrap_or(0.0);
                 
                 sensitivities.push(FactorSensitivity {
                     factor_name: factor.name.clone(),
-                    correlation_with_compliance: correlation,
-                    impact_magnitude: correlation.abs() * factor.weight,
+                    correlation_with_compliance,
+                    impact_magnitude: correlation_with_compliance.abs() * factor.weight,
+                    degenerate: correlation.is_none(),
                 });
             }
         }
         
         // Sort by impact magnitude
-        sensitivities.sort_by(|a, b| b.impact_magnitude.partial_cmp(&a.impact_magnitude).unwrap());
+        sensitivities.sort_by(|a, b| b.impact_magnitude.total_cmp(&a.impact_magnitude));
         sensitivities
     }
 
-    /// Calculate correlation between two vectors
-    fn calculate_correlation(&self, x: &[f64], y: &[f64]) -> f64 {
-        if x.len() != y.len() || x.is_empty() {
-            return 0.0;
+    /// Pearson correlation between two vectors, or `None` if either has no variance
+    ///
+    /// Uses centered sums; the raw-sum form cancels catastrophically for constant
+    /// inputs and can take the square root of a negative number.
+    fn calculate_correlation(&self, x: &[f64], y: &[f64]) -> Option<f64> {
+        if x.len() != y.len() || x.len() < 2 {
+            return None;
         }
         
         let n = x.len() as f64;
-        let sum_x: f64 = x.iter().sum();
-        let sum_y: f64 = y.iter().sum();
-        let sum_xy: f64 = x.iter().zip(y.iter()).map(|(a, b)| a * b).sum();
-        let sum_x2: f64 = x.iter().map(|a| a * a).sum();
-        let sum_y2: f64 = y.iter().map(|b| b * b).sum();
-        
-        let numerator = n * sum_xy - sum_x * sum_y;
-        let denominator = ((n * sum_x2 - sum_x * sum_x) * (n * sum_y2 - sum_y * sum_y)).sqrt();
+        let mean_x = x.iter().sum::<f64>() / n;
+        let mean_y = y.iter().sum::<f64>() / n;
+        let covariance: f64 = x.iter().zip(y.iter()).map(|(a, b)| (a - mean_x) * (b - mean_y)).sum();
+        let variance_x: f64 = x.iter().map(|a| (a - mean_x).powi(2)).sum();
+        let variance_y: f64 = y.iter().map(|b| (b - mean_y).powi(2)).sum();
         
-        if denominator == 0.0 {
-            0.0
-        } else {
-            numerator / denominator
+        if !has_spread((variance_x / n).sqrt(), mean_x) || !has_spread((variance_y / n).sqrt(), mean_y) {
+            return None;
         }
+        
+        let correlation = covariance / (variance_x.sqrt() * variance_y.sqrt());
+        correlation.is_finite().then(|| correlation.clamp(-1.0, 1.0))
     }
 
     /// Check convergence of simulation
@@ -477,6 +656,95 @@ impl MonteCarloEngine {
     }
 }
 
+/// Alias table for an `EmpiricalWeighted` distribution
+fn weighted_alias_index(values: &[f64], weights: &[f64]) -> Result<WeightedAliasIndex<f64>> {
+    if values.is_empty() {
+        return Err(CryptoError::InvalidInput("Empty empirical distribution".to_string()));
+    }
+    if values.len() != weights.len() {
+        return Err(CryptoError::InvalidInput(format!(
+            "Weighted empirical distribution has {} values but {} weights",
+            values.len(),
+            weights.len()
+        )));
+    }
+    if let Some(weight) = weights.iter().find(|w| !(w.is_finite() && **w >= 0.0)) {
+        return Err(CryptoError::InvalidInput(format!(
+            "Invalid weight {} in weighted empirical distribution",
+            weight
+        )));
+    }
+
+    WeightedAliasIndex::new(weights.to_vec())
+        .map_err(|e| CryptoError::InvalidInput(format!("Invalid weighted empirical distribution: {}", e)))
+}
+
+/// A scenario distribution with its per-run setup done up front
+///
+/// Weighted empirical distributions get their alias table here so each draw is O(1);
+/// everything else is sampled through `sample_distribution` as before.
+enum Sampler<'a> {
+    Direct(&'a DistributionType),
+    Weighted {
+        values: &'a [f64],
+        index: WeightedAliasIndex<f64>,
+    },
+}
+
+impl<'a> Sampler<'a> {
+    fn new(dist: &'a DistributionType) -> Result<Self> {
+        match dist {
+            DistributionType::EmpiricalWeighted { values, weights } => Ok(Sampler::Weighted {
+                values,
+                index: weighted_alias_index(values, weights)?,
+            }),
+            _ => Ok(Sampler::Direct(dist)),
+        }
+    }
+
+    fn sample(&self, engine: &MonteCarloEngine, rng: &mut impl Rng) -> Result<f64> {
+        match self {
+            Sampler::Direct(dist) => engine.sample_distribution(dist, rng),
+            Sampler::Weighted { values, index } => Ok(values[index.sample(rng)]),
+        }
+    }
+}
+
+/// Samplers for every distribution in a scenario, built once per simulation
+struct ScenarioSamplers<'a> {
+    volatility: Sampler<'a>,
+    growth_rate: Sampler<'a>,
+    competition_intensity: Sampler<'a>,
+    stringency: Sampler<'a>,
+    change_frequency: Sampler<'a>,
+    enforcement_probability: Sampler<'a>,
+    /// In `compliance_factors` order
+    factors: Vec<Sampler<'a>>,
+}
+
+impl<'a> ScenarioSamplers<'a> {
+    fn new(scenario: &'a ComplianceScenario) -> Result<Self> {
+        Ok(Self {
+            volatility: Sampler::new(&scenario.market_conditions.volatility)?,
+            growth_rate: Sampler::new(&scenario.market_conditions.growth_rate)?,
+            competition_intensity: Sampler::new(&scenario.market_conditions.competition_intensity)?,
+            stringency: Sampler::new(&scenario.regulatory_environment.stringency)?,
+            change_frequency: Sampler::new(&scenario.regulatory_environment.change_frequency)?,
+            enforcement_probability: Sampler::new(&scenario.regulatory_environment.enforcement_probability)?,
+            factors: scenario
+                .compliance_factors
+                .iter()
+                .map(|factor| Sampler::new(&factor.distribution))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+/// Whether `std_dev` is real variance rather than rounding noise around `mean`
+fn has_spread(std_dev: f64, mean: f64) -> bool {
+    std_dev.is_finite() && std_dev > RELATIVE_SPREAD_EPSILON * mean.abs().max(1.0)
+}
+
 /// Results from a single simulation iteration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulationIteration {
@@ -524,6 +792,26 @@ pub struct SimulationResult {
     pub polygon_verification_rate: f64,
     pub percentiles: Vec<(f64, f64)>,
     pub convergence_achieved: bool,
+    /// Statistics that could not be computed from the samples and were reported as 0,
+    /// and other adjustments made to the inputs
+    #[serde(default)]
+    pub data_quality: Vec<String>,
+    /// Rescaling applied to factor weights that did not sum to 1.0
+    #[serde(default)]
+    pub weight_normalization: Option<WeightNormalization>,
+    /// Kernel density estimate of the compliance score as `(x, density)` pairs,
+    /// present when `compute_density` is set and the scores have spread
+    #[serde(default)]
+    pub density: Option<Vec<(f64, f64)>>,
+}
+
+/// Rescaling applied to factor weights before simulating
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeightNormalization {
+    /// Sum of the weights as supplied
+    pub original_sum: f64,
+    /// Factor every weight was multiplied by
+    pub scale: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -535,6 +823,9 @@ pub struct Statistics {
     pub max: f64,
     pub skewness: f64,
     pub kurtosis: f64,
+    /// Skewness or kurtosis is undefined for these samples and reported as 0
+    #[serde(default)]
+    pub degenerate: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -549,6 +840,9 @@ pub struct FactorSensitivity {
     pub factor_name: String,
     pub correlation_with_compliance: f64,
     pub impact_magnitude: f64,
+    /// Correlation is undefined because a series has no variance; reported as 0
+    #[serde(default)]
+    pub degenerate: bool,
 }
 
 #[cfg(test)]
@@ -614,6 +908,175 @@ mod tests {
         assert!(result.polygon_verification_rate < 0.8);
     }
 
+    /// Every number in the serialized value is finite; serde_json writes NaN and Inf as null
+    fn assert_all_finite(value: &serde_json::Value) {
+        match value {
+            serde_json::Value::Null => panic!("non-finite value serialized as null"),
+            serde_json::Value::Number(number) => assert!(number.as_f64().unwrap().is_finite()),
+            serde_json::Value::Array(items) => items.iter().for_each(assert_all_finite),
+            serde_json::Value::Object(map) => map.values().for_each(assert_all_finite),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_constant_inputs_produce_finite_statistics() {
+        let constant = |value: f64| DistributionType::Empirical { values: vec![value] };
+        let scenario = ComplianceScenario {
+            name: "Constant Scenario".to_string(),
+            compliance_factors: vec![
+                ComplianceFactor {
+                    name: "Documentation Quality".to_string(),
+                    base_value: 0.8,
+                    distribution: constant(0.8),
+                    weight: 0.6,
+                    correlation_factors: vec![("regulatory_stringency".to_string(), -0.2)],
+                },
+                ComplianceFactor {
+                    name: "Process Maturity".to_string(),
+                    base_value: 0.7,
+                    distribution: constant(0.7),
+                    weight: 0.4,
+                    correlation_factors: vec![],
+                },
+            ],
+            market_conditions: MarketConditions {
+                volatility: constant(0.2),
+                growth_rate: constant(0.05),
+                competition_intensity: constant(0.5),
+            },
+            regulatory_environment: RegulatoryEnvironment {
+                stringency: constant(0.6),
+                change_frequency: constant(0.1),
+                enforcement_probability: constant(0.0),
+            },
+            polygon_verification_rate: 0.0,
+        };
+
+        for iterations in [2, 3, 500] {
+            let engine = MonteCarloEngine::new(MonteCarloConfig {
+                iterations,
+                seed: Some(7),
+                ..Default::default()
+            });
+            let result = engine.simulate_compliance_risk(&scenario).unwrap();
+
+            assert!(result.compliance_statistics.degenerate);
+            assert!(result.risk_statistics.degenerate);
+            assert_eq!(result.compliance_statistics.skewness, 0.0);
+            assert_eq!(result.compliance_statistics.kurtosis, 0.0);
+            assert!(result.factor_sensitivities.iter().all(|s| s.degenerate && s.correlation_with_compliance == 0.0));
+            assert_eq!(result.data_quality.len(), 2 + scenario.compliance_factors.len());
+
+            // weight_normalization and density are the only fields that are legitimately null
+            let mut serialized = serde_json::to_value(&result).unwrap();
+            for optional in ["weight_normalization", "density"] {
+                assert!(serialized.as_object_mut().unwrap().remove(optional).unwrap().is_null());
+            }
+            assert_all_finite(&serialized);
+        }
+    }
+
+    fn weighted_scenario(weights: [f64; 2]) -> ComplianceScenario {
+        ComplianceScenario {
+            name: "Weighted Scenario".to_string(),
+            compliance_factors: vec![
+                ComplianceFactor {
+                    name: "Documentation Quality".to_string(),
+                    base_value: 0.8,
+                    distribution: DistributionType::Beta { alpha: 8.0, beta: 2.0 },
+                    weight: weights[0],
+                    correlation_factors: vec![],
+                },
+                ComplianceFactor {
+                    name: "Process Maturity".to_string(),
+                    base_value: 0.7,
+                    distribution: DistributionType::Normal { mean: 0.7, std_dev: 0.1 },
+                    weight: weights[1],
+                    correlation_factors: vec![],
+                },
+            ],
+            market_conditions: MarketConditions {
+                volatility: DistributionType::Beta { alpha: 2.0, beta: 5.0 },
+                growth_rate: DistributionType::Normal { mean: 0.05, std_dev: 0.02 },
+                competition_intensity: DistributionType::Uniform { min: 0.3, max: 0.7 },
+            },
+            regulatory_environment: RegulatoryEnvironment {
+                stringency: DistributionType::Beta { alpha: 5.0, beta: 3.0 },
+                change_frequency: DistributionType::Uniform { min: 0.1, max: 0.3 },
+                enforcement_probability: DistributionType::Beta { alpha: 2.0, beta: 8.0 },
+            },
+            polygon_verification_rate: 0.7,
+        }
+    }
+
+    #[test]
+    fn test_unnormalized_weights_are_rescaled() {
+        let engine = MonteCarloEngine::new(MonteCarloConfig {
+            iterations: 500,
+            seed: Some(11),
+            ..Default::default()
+        });
+
+        let normalized = engine.simulate_compliance_risk(&weighted_scenario([0.625, 0.375])).unwrap();
+        assert!(normalized.weight_normalization.is_none());
+        assert!(normalized.data_quality.is_empty());
+
+        for (weights, original_sum) in [([0.5, 0.3], 0.8), ([0.8125, 0.4875], 1.3)] {
+            let result = engine.simulate_compliance_risk(&weighted_scenario(weights)).unwrap();
+
+            let normalization = result.weight_normalization.as_ref().unwrap();
+            assert!((normalization.original_sum - original_sum).abs() < 1e-12);
+            assert!((normalization.scale - 1.0 / original_sum).abs() < 1e-12);
+            assert_eq!(result.data_quality.len(), 1);
+
+            // Same weights after rescaling, so the same seed gives the same scores
+            let mean = result.compliance_statistics.mean;
+            assert!((mean - normalized.compliance_statistics.mean).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_unnormalized_weights_rejected_without_normalization() {
+        let engine = MonteCarloEngine::new(MonteCarloConfig {
+            iterations: 100,
+            seed: Some(11),
+            normalize_weights: false,
+            ..Default::default()
+        });
+
+        assert!(engine.simulate_compliance_risk(&weighted_scenario([0.5, 0.3])).is_err());
+        assert!(engine.simulate_compliance_risk(&weighted_scenario([0.8125, 0.4875])).is_err());
+        assert!(engine.simulate_compliance_risk(&weighted_scenario([0.625, 0.375])).is_ok());
+
+        // Never valid, whatever the setting
+        let lenient = MonteCarloEngine::new(MonteCarloConfig::default());
+        assert!(lenient.simulate_compliance_risk(&weighted_scenario([1.2, -0.2])).is_err());
+        assert!(lenient.simulate_compliance_risk(&weighted_scenario([0.0, 0.0])).is_err());
+    }
+
+    #[test]
+    fn test_degenerate_statistics_are_flagged() {
+        let engine = MonteCarloEngine::new(MonteCarloConfig::default());
+
+        // Sums of 0.1 are inexact, so the computed spread is rounding noise rather than zero
+        let constant = engine.calculate_statistics(&[0.1; 1000]);
+        assert!(constant.degenerate);
+        assert_eq!((constant.skewness, constant.kurtosis), (0.0, 0.0));
+
+        // Sample skewness needs 3 values and kurtosis 4
+        let pair = engine.calculate_statistics(&[0.2, 0.8]);
+        assert!(pair.degenerate);
+        assert!(pair.skewness.is_finite() && pair.kurtosis.is_finite());
+        assert!(!engine.calculate_statistics(&[0.1, 0.5, 0.7, 0.9, 0.95]).degenerate);
+
+        assert_eq!(engine.calculate_correlation(&[0.1; 100], &[0.3; 100]), None);
+        assert_eq!(engine.calculate_correlation(&[0.1; 100], &(0..100).map(f64::from).collect::<Vec<_>>()), None);
+        assert_eq!(engine.calculate_correlation(&[1.0], &[2.0]), None);
+        assert!((engine.calculate_correlation(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]).unwrap() - 1.0).abs() < 1e-12);
+        assert!((engine.calculate_correlation(&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0]).unwrap() + 1.0).abs() < 1e-12);
+    }
+
     #[test]
     fn test_distribution_sampling() {
         let config = MonteCarloConfig::default();
@@ -635,4 +1098,97 @@ mod tests {
         let sample = engine.sample_distribution(&beta, &mut rng).unwrap();
         assert!(sample >= 0.0 && sample <= 1.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_weighted_empirical_sampling_frequencies() {
+        let engine = MonteCarloEngine::new(MonteCarloConfig { seed: Some(5), ..Default::default() });
+        let mut rng = engine.create_rng();
+
+        let values = vec![0.1, 0.5, 0.9, 0.7];
+        let weights = vec![90.0, 9.0, 1.0, 0.0];
+        let dist = DistributionType::EmpiricalWeighted { values: values.clone(), weights: weights.clone() };
+        let sampler = Sampler::new(&dist).unwrap();
+
+        let draws = 100_000;
+        let mut counts = [0usize; 4];
+        for _ in 0..draws {
+            let sample = sampler.sample(&engine, &mut rng).unwrap();
+            counts[values.iter().position(|v| *v == sample).unwrap()] += 1;
+        }
+
+        let total: f64 = weights.iter().sum();
+        for (count, weight) in counts.iter().zip(&weights) {
+            let expected = weight / total;
+            let observed = *count as f64 / draws as f64;
+            assert!((observed - expected).abs() < 0.005, "expected {}, observed {}", expected, observed);
+        }
+        assert_eq!(counts[3], 0);
+
+        // The unprepared path draws from the same distribution
+        let sample = engine.sample_distribution(&dist, &mut rng).unwrap();
+        assert!(values[..3].contains(&sample));
+    }
+
+    #[test]
+    fn test_weighted_empirical_rejects_invalid_weights() {
+        let engine = MonteCarloEngine::new(MonteCarloConfig::default());
+        let mut rng = engine.create_rng();
+
+        for (values, weights) in [
+            (vec![0.1, 0.2], vec![1.0]),
+            (vec![0.1, 0.2], vec![1.0, -0.5]),
+            (vec![0.1, 0.2], vec![1.0, f64::NAN]),
+            (vec![0.1, 0.2], vec![0.0, 0.0]),
+            (vec![], vec![]),
+        ] {
+            let dist = DistributionType::EmpiricalWeighted { values, weights };
+            assert!(Sampler::new(&dist).is_err());
+            assert!(engine.sample_distribution(&dist, &mut rng).is_err());
+        }
+
+        // Invalid weights fail the simulation up front rather than on the first draw
+        let mut scenario = weighted_scenario([0.625, 0.375]);
+        scenario.compliance_factors[0].distribution =
+            DistributionType::EmpiricalWeighted { values: vec![0.8, 0.9], weights: vec![1.0] };
+        assert!(engine.simulate_compliance_risk(&scenario).is_err());
+    }
+
+    /// Trapezoidal integral of `(x, density)` pairs
+    fn integrate(density: &[(f64, f64)]) -> f64 {
+        density.windows(2).map(|w| (w[1].0 - w[0].0) * (w[0].1 + w[1].1) / 2.0).sum()
+    }
+
+    #[test]
+    fn test_density_integrates_to_one_and_peaks_at_mode() {
+        let engine = MonteCarloEngine::new(MonteCarloConfig { density_points: 512, ..Default::default() });
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(3);
+        let normal = Normal::new(0.6, 0.05).unwrap();
+        let samples: Vec<f64> = (0..5_000).map(|_| normal.sample(&mut rng)).collect();
+
+        let density = engine.calculate_density(&samples).unwrap();
+        assert_eq!(density.len(), 512);
+        assert!((integrate(&density) - 1.0).abs() < 0.01);
+
+        let (peak, _) = density.iter().copied().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        assert!((peak - 0.6).abs() < 0.02, "density peaks at {}", peak);
+
+        assert!(engine.calculate_density(&[0.4; 100]).is_none());
+    }
+
+    #[test]
+    fn test_density_computed_only_when_requested() {
+        let scenario = weighted_scenario([0.625, 0.375]);
+        let config = MonteCarloConfig { iterations: 2_000, seed: Some(13), ..Default::default() };
+
+        let without = MonteCarloEngine::new(config.clone()).simulate_compliance_risk(&scenario).unwrap();
+        assert!(without.density.is_none());
+
+        let engine = MonteCarloEngine::new(MonteCarloConfig { compute_density: true, density_points: 128, ..config });
+        let result = engine.simulate_compliance_risk(&scenario).unwrap();
+        let density = result.density.unwrap();
+        assert_eq!(density.len(), 128);
+        assert!((integrate(&density) - 1.0).abs() < 0.01);
+        assert!(density.first().unwrap().0 < result.compliance_statistics.min);
+        assert!(density.last().unwrap().0 > result.compliance_statistics.max);
+    }
+}
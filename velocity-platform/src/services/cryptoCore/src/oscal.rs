@@ -0,0 +1,256 @@
+/// OSCAL Export Module
+///
+/// Renders compliance proofs as NIST OSCAL Assessment Results for consumers that
+/// ingest OSCAL. The proof itself travels as a base64 back-matter resource, so it
+/// can be extracted and verified exactly as it was issued.
+
+use crate::blockchain_compliance::{AuditEntry, ComplianceProof, ControlResult};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use chrono::{SecondsFormat, TimeZone, Utc};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// OSCAL release the exported documents conform to
+pub const OSCAL_VERSION: &str = "1.1.2";
+
+/// Namespace of the Velocity-specific properties in exported documents
+pub const VELOCITY_OSCAL_NS: &str = "https://velocity.ai/ns/oscal";
+
+impl ComplianceProof {
+    /// OSCAL Assessment Results document for this proof
+    ///
+    /// Aggregate scores become result properties, per-control results become
+    /// findings and the audit chain becomes the assessment log. UUIDs are derived
+    /// from the proof ID, so exporting the same proof twice yields the same document.
+    pub fn to_oscal_assessment_results(&self) -> Value {
+        let data = &self.compliance_data;
+        let proof_resource = self.oscal_uuid("proof");
+        let encoded_proof = serde_json::to_vec(self).expect("compliance proofs are always serializable");
+
+        let mut controls: Vec<(&String, &ControlResult)> = data.controls.iter().collect();
+        controls.sort_by(|a, b| a.0.cmp(b.0));
+        let control_selection = if controls.is_empty() {
+            json!({ "include-all": {} })
+        } else {
+            json!({
+                "include-controls": controls.iter()
+                    .map(|(control_id, _)| json!({ "control-id": control_id }))
+                    .collect::<Vec<_>>(),
+            })
+        };
+
+        let mut result = json!({
+            "uuid": self.oscal_uuid("result"),
+            "title": format!("{} {} assessment of {}", data.framework, data.version, self.entity_id),
+            "description": format!(
+                "{} of {} controls passed, attested by compliance proof {}",
+                data.controls_passed, data.controls_assessed, self.id
+            ),
+            "start": oscal_datetime(data.assessment_date),
+            "props": [
+                oscal_prop("controls-assessed", data.controls_assessed.to_string()),
+                oscal_prop("controls-passed", data.controls_passed.to_string()),
+                oscal_prop("compliance-score", data.compliance_score.to_string()),
+                oscal_prop("evidence-count", data.evidence_count.to_string()),
+                oscal_prop("valid-until", oscal_datetime(data.valid_until)),
+            ],
+            "reviewed-controls": {
+                "control-selections": [control_selection],
+            },
+        });
+        if !controls.is_empty() {
+            result["findings"] = controls.iter()
+                .map(|(control_id, control)| self.oscal_finding(control_id, control))
+                .collect();
+        }
+        if !self.audit_chain.is_empty() {
+            result["assessment-log"] = json!({
+                "entries": self.audit_chain.iter()
+                    .map(|entry| self.oscal_log_entry(entry))
+                    .collect::<Vec<_>>(),
+            });
+        }
+
+        json!({
+            "assessment-results": {
+                "uuid": self.oscal_uuid("assessment-results"),
+                "metadata": {
+                    "title": format!("{} assessment results for {}", self.framework_type, self.entity_id),
+                    "last-modified": oscal_datetime(self.timestamp),
+                    "version": data.version,
+                    "oscal-version": OSCAL_VERSION,
+                    "props": [oscal_prop("proof-id", self.id.clone())],
+                },
+                // No separate assessment plan exists; the proof it was built from stands in
+                "import-ap": { "href": format!("#{}", proof_resource) },
+                "results": [result],
+                "back-matter": {
+                    "resources": [{
+                        "uuid": proof_resource,
+                        "title": "Velocity compliance proof",
+                        "description": "Signed compliance proof backing these results, verifiable with verify_proof",
+                        "props": [
+                            oscal_prop("blockchain-hash", self.blockchain_hash.clone()),
+                            oscal_prop("merkle-root", self.merkle_root.clone()),
+                        ],
+                        "base64": {
+                            "filename": format!("{}.json", self.id),
+                            "media-type": "application/json",
+                            "value": BASE64.encode(encoded_proof),
+                        },
+                    }],
+                },
+            },
+        })
+    }
+
+    fn oscal_finding(&self, control_id: &str, control: &ControlResult) -> Value {
+        let mut finding = json!({
+            "uuid": self.oscal_uuid(&format!("finding/{}", control_id)),
+            "title": format!("Control {}", control_id),
+            "description": if control.passed {
+                format!("Control {} is satisfied", control_id)
+            } else {
+                format!("Control {} is not satisfied", control_id)
+            },
+            "target": {
+                "type": "objective-id",
+                "target-id": control_id,
+                "status": {
+                    "state": if control.passed { "satisfied" } else { "not-satisfied" },
+                },
+            },
+        });
+        if !control.derived_from.is_empty() {
+            finding["props"] = json!([oscal_prop("derived-from", control.derived_from.join(" "))]);
+        }
+        finding
+    }
+
+    fn oscal_log_entry(&self, entry: &AuditEntry) -> Value {
+        json!({
+            "uuid": self.oscal_uuid(&format!("audit/{}", entry.hash)),
+            "title": entry.action,
+            "description": entry.details,
+            "start": oscal_datetime(entry.timestamp),
+            "props": [
+                oscal_prop("actor", entry.actor.clone()),
+                oscal_prop("audit-hash", entry.hash.clone()),
+            ],
+        })
+    }
+
+    /// Name-based UUID for a part of this proof's OSCAL document
+    fn oscal_uuid(&self, part: &str) -> String {
+        let name = format!("{}/{}/{}", VELOCITY_OSCAL_NS, self.id, part);
+        Uuid::new_v5(&Uuid::NAMESPACE_URL, name.as_bytes()).to_string()
+    }
+}
+
+fn oscal_prop(name: &str, value: String) -> Value {
+    json!({ "name": name, "ns": VELOCITY_OSCAL_NS, "value": value })
+}
+
+fn oscal_datetime(unix_seconds: u64) -> String {
+    i64::try_from(unix_seconds)
+        .ok()
+        .and_then(|seconds| Utc.timestamp_opt(seconds, 0).single())
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain_compliance::{BlockchainComplianceEngine, ComplianceData};
+    use std::collections::HashMap;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn assert_fields(value: &Value, fields: &[&str]) {
+        for field in fields {
+            let present = value.get(*field).is_some_and(|v| !v.is_null() && v != "");
+            assert!(present, "missing required OSCAL field {} in {}", field, value);
+        }
+    }
+
+    fn assert_oscal_uuid(value: &Value) {
+        let uuid = Uuid::parse_str(value.as_str().unwrap()).unwrap();
+        assert!(matches!(uuid.get_version_num(), 4 | 5));
+    }
+
+    #[test]
+    fn test_oscal_assessment_results_carry_verifiable_proof() {
+        let mut engine = BlockchainComplianceEngine::new().unwrap();
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let controls: HashMap<String, ControlResult> = [("CC6.1", true), ("CC6.2", true), ("CC7.2", false)]
+            .into_iter()
+            .map(|(control, passed)| (control.to_string(), ControlResult { passed, derived_from: Vec::new() }))
+            .collect();
+        let compliance_data = ComplianceData {
+            framework: "SOC2".to_string(),
+            version: "2017".to_string(),
+            controls_assessed: 64,
+            controls_passed: 62,
+            compliance_score: 96.875,
+            evidence_count: 32,
+            assessment_date: now,
+            valid_until: now + (365 * 24 * 60 * 60),
+            assessor: "External Auditor".to_string(),
+            metadata: HashMap::new(),
+            controls,
+        };
+        let proof = engine
+            .create_compliance_proof("test-entity-oscal".to_string(), "SOC2".to_string(), compliance_data, false)
+            .unwrap();
+
+        let document = proof.to_oscal_assessment_results();
+        assert_eq!(document, proof.to_oscal_assessment_results());
+
+        // Required fields of the OSCAL assessment-results model
+        let results = &document["assessment-results"];
+        assert_fields(results, &["uuid", "metadata", "import-ap", "results"]);
+        assert_oscal_uuid(&results["uuid"]);
+        assert_fields(&results["metadata"], &["title", "last-modified", "version", "oscal-version"]);
+        assert_eq!(results["metadata"]["oscal-version"], OSCAL_VERSION);
+        assert_fields(&results["import-ap"], &["href"]);
+
+        let result = &results["results"][0];
+        assert_fields(result, &["uuid", "title", "description", "start", "reviewed-controls"]);
+        assert_eq!(result["reviewed-controls"]["control-selections"][0]["include-controls"].as_array().unwrap().len(), 3);
+        let props: HashMap<&str, &str> = result["props"].as_array().unwrap().iter()
+            .map(|prop| (prop["name"].as_str().unwrap(), prop["value"].as_str().unwrap()))
+            .collect();
+        assert_eq!(props["controls-assessed"], "64");
+        assert_eq!(props["controls-passed"], "62");
+        assert_eq!(props["compliance-score"], "96.875");
+
+        let findings = result["findings"].as_array().unwrap();
+        assert_eq!(findings.len(), 3);
+        for finding in findings {
+            assert_fields(finding, &["uuid", "title", "description", "target"]);
+            assert_fields(&finding["target"], &["type", "target-id", "status"]);
+            assert_fields(&finding["target"]["status"], &["state"]);
+            assert_oscal_uuid(&finding["uuid"]);
+        }
+        assert_eq!(findings[2]["target"]["target-id"], "CC7.2");
+        assert_eq!(findings[2]["target"]["status"]["state"], "not-satisfied");
+
+        let log = result["assessment-log"]["entries"].as_array().unwrap();
+        assert_eq!(log.len(), proof.audit_chain.len());
+        for entry in log {
+            assert_fields(entry, &["uuid", "start"]);
+        }
+
+        // The embedded proof is the one referenced by import-ap and still verifies
+        let resource = &results["back-matter"]["resources"][0];
+        assert_fields(resource, &["uuid"]);
+        assert_eq!(results["import-ap"]["href"], format!("#{}", resource["uuid"].as_str().unwrap()));
+        let encoded = BASE64.decode(resource["base64"]["value"].as_str().unwrap()).unwrap();
+        let embedded: ComplianceProof = serde_json::from_slice(&encoded).unwrap();
+        assert_eq!(embedded.id, proof.id);
+        let verification = engine.verify_proof(&embedded).unwrap();
+        assert!(verification.is_valid);
+        assert!(verification.verification_details.cryptographic_integrity);
+    }
+}
@@ -6,13 +6,52 @@
 use crate::{CryptoError, Result};
 use crate::hash_engine::{HashAlgorithm, HashEngine};
 use ed25519_dalek::{
-    PublicKey as Ed25519PublicKey, Signature as Ed25519Signature,
-    Verifier, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH
+    Keypair as Ed25519Keypair, PublicKey as Ed25519PublicKey, SecretKey as Ed25519SecretKey,
+    Signature as Ed25519Signature, Signer, Verifier, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH, SIGNATURE_LENGTH
 };
-use rayon::prelude::*;
+use crate::par::*;
+#[cfg(feature = "native-crypto")]
 use ring::signature::{self, UnparsedPublicKey};
+#[cfg(feature = "pq")]
+use pqcrypto_mldsa::mldsa65;
+#[cfg(feature = "pq")]
+use pqcrypto_traits::sign::{DetachedSignature as _, PublicKey as _, SecretKey as _};
+#[cfg(feature = "native-crypto")]
+use secp256k1::{schnorr, Keypair as Secp256k1Keypair, Message, XOnlyPublicKey, SECP256K1};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Length of the messages BIP-340 Schnorr signatures are defined over
+pub const SCHNORR_MESSAGE_LENGTH: usize = 32;
+
+/// BIP-340 tagged hash, `SHA256(SHA256(tag) || SHA256(tag) || message)`
+///
+/// Schnorr signatures cover exactly 32 bytes; callers reduce longer messages
+/// with a tag naming their protocol (such as `TapSighash`) before signing.
+pub fn bip340_tagged_hash(tag: &str, message: &[u8]) -> [u8; SCHNORR_MESSAGE_LENGTH] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// Shortest WebAuthn authenticator data: RP id hash, flags and signature counter
+pub const WEBAUTHN_MIN_AUTHENTICATOR_DATA_LENGTH: usize = 37;
+
+/// Length of an uncompressed SEC1 P-256 public key, `0x04 || x || y`
+pub const P256_UNCOMPRESSED_PUBLIC_KEY_LENGTH: usize = 65;
+
+/// Fresh ML-DSA-65 key pair as raw `(public_key, secret_key)` bytes
+#[cfg(feature = "pq")]
+pub fn ml_dsa65_keypair() -> (Vec<u8>, Vec<u8>) {
+    let (public_key, secret_key) = mldsa65::keypair();
+    (public_key.as_bytes().to_vec(), secret_key.as_bytes().to_vec())
+}
 
 /// Supported signature algorithms
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -21,6 +60,8 @@ pub enum SignatureAlgorithm {
     EcdsaP256,
     RsaPss2048,
     PolygonEcdsa, // Ethereum-compatible ECDSA for Polygon
+    Secp256k1Schnorr, // BIP-340 over 32-byte messages with x-only public keys
+    MlDsa65, // FIPS 204 post-quantum signatures, behind the pq feature
 }
 
 /// Signature verification request
@@ -33,12 +74,48 @@ pub struct SignatureRequest {
     pub polygon_tx_hash: Option<String>,
 }
 
+/// WebAuthn assertion from a passkey or other FIDO2 authenticator (ES256)
+///
+/// Only the signature is checked here. The relying party still has to check
+/// the challenge, origin and RP id hash, and that the signature counter grew.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebAuthnRequest {
+    pub authenticator_data: Vec<u8>,
+    /// Raw `clientDataJSON` bytes exactly as the browser returned them
+    pub client_data_json: Vec<u8>,
+    /// ASN.1 DER ECDSA signature
+    pub signature: Vec<u8>,
+    /// Uncompressed P-256 point of the credential, from its COSE key at registration
+    pub public_key: Vec<u8>,
+}
+
+impl WebAuthnRequest {
+    /// Bytes the authenticator signed, `authenticatorData || SHA256(clientDataJSON)`
+    pub fn signed_message(&self) -> Vec<u8> {
+        [self.authenticator_data.as_slice(), &Sha256::digest(&self.client_data_json)].concat()
+    }
+
+    /// Equivalent ECDSA P-256 request over `signed_message`
+    pub fn signature_request(&self) -> SignatureRequest {
+        SignatureRequest {
+            message: self.signed_message(),
+            signature: self.signature.clone(),
+            public_key: self.public_key.clone(),
+            algorithm: SignatureAlgorithm::EcdsaP256,
+            polygon_tx_hash: None,
+        }
+    }
+}
+
 /// Batch signature verification request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchSignatureRequest {
     pub requests: Vec<SignatureRequest>,
     pub fail_fast: bool, // Stop on first failure
     pub parallel_threshold: usize,
+    /// Parse each distinct public key once for the whole batch
+    #[serde(default)]
+    pub cache_public_keys: bool,
 }
 
 /// Signature verification result
@@ -49,6 +126,82 @@ pub struct SignatureVerificationResult {
     pub polygon_verified: bool,
     pub verification_time_us: u64,
     pub error: Option<String>,
+    /// Never verified because an earlier entry failed a fail-fast batch
+    #[serde(default)]
+    pub skipped: bool,
+}
+
+impl SignatureVerificationResult {
+    fn skipped(request: &SignatureRequest) -> Self {
+        Self {
+            valid: false,
+            algorithm: request.algorithm,
+            polygon_verified: false,
+            verification_time_us: 0,
+            error: Some("Skipped due to fail-fast".to_string()),
+            skipped: true,
+        }
+    }
+}
+
+/// Parsed public keys keyed by their raw bytes
+///
+/// Lives for a single `verify_batch` call, so it never outgrows the batch it
+/// serves. Only keys that parse successfully are cached.
+#[derive(Default)]
+pub struct PublicKeyCache {
+    ed25519: Mutex<HashMap<Vec<u8>, Ed25519PublicKey>>,
+    #[cfg(feature = "native-crypto")]
+    secp256k1: Mutex<HashMap<Vec<u8>, XOnlyPublicKey>>,
+    parse_count: AtomicUsize,
+}
+
+impl PublicKeyCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of keys actually parsed, as opposed to served from the cache
+    pub fn parse_count(&self) -> usize {
+        self.parse_count.load(Ordering::Relaxed)
+    }
+
+    fn ed25519(&self, bytes: &[u8]) -> Result<Ed25519PublicKey> {
+        Self::get_or_parse(&self.ed25519, &self.parse_count, bytes, parse_ed25519_public_key)
+    }
+
+    #[cfg(feature = "native-crypto")]
+    fn secp256k1(&self, bytes: &[u8]) -> Result<XOnlyPublicKey> {
+        Self::get_or_parse(&self.secp256k1, &self.parse_count, bytes, parse_secp256k1_public_key)
+    }
+
+    fn get_or_parse<K: Copy>(
+        keys: &Mutex<HashMap<Vec<u8>, K>>,
+        parse_count: &AtomicUsize,
+        bytes: &[u8],
+        parse: fn(&[u8]) -> Result<K>,
+    ) -> Result<K> {
+        if let Some(key) = keys.lock().unwrap().get(bytes) {
+            return Ok(*key);
+        }
+
+        // Parse outside the lock; racing threads at worst parse the same key twice
+        parse_count.fetch_add(1, Ordering::Relaxed);
+        let key = parse(bytes)?;
+        keys.lock().unwrap().insert(bytes.to_vec(), key);
+        Ok(key)
+    }
+}
+
+fn parse_ed25519_public_key(bytes: &[u8]) -> Result<Ed25519PublicKey> {
+    Ed25519PublicKey::from_bytes(bytes)
+        .map_err(|e| CryptoError::CryptoOperationFailed(format!("Invalid public key: {}", e)))
+}
+
+#[cfg(feature = "native-crypto")]
+fn parse_secp256k1_public_key(bytes: &[u8]) -> Result<XOnlyPublicKey> {
+    XOnlyPublicKey::from_slice(bytes)
+        .map_err(|e| CryptoError::CryptoOperationFailed(format!("Invalid public key: {}", e)))
 }
 
 /// High-performance signature verifier
@@ -67,9 +220,50 @@ impl SignatureVerifier {
 
     /// Verify a single signature
     pub fn verify_signature(&self, request: &SignatureRequest) -> SignatureVerificationResult {
+        self.verify_signature_with(request, None)
+    }
+
+    /// Verify an EIP-712 typed-data signature against the address expected to sign it
+    #[cfg(feature = "native-crypto")]
+    pub fn verify_eip712(&self, request: &crate::eip712::Eip712Request, expected_address: &str) -> Result<bool> {
+        crate::eip712::verify_eip712(request, expected_address)
+    }
+
+    /// Verify the ES256 signature of a WebAuthn assertion against the credential's public key
+    #[cfg(feature = "native-crypto")]
+    pub fn verify_webauthn(&self, request: &WebAuthnRequest) -> Result<bool> {
+        if request.authenticator_data.len() < WEBAUTHN_MIN_AUTHENTICATOR_DATA_LENGTH {
+            return Err(CryptoError::InvalidInput(format!(
+                "Invalid WebAuthn authenticator data length: expected at least {}, got {}",
+                WEBAUTHN_MIN_AUTHENTICATOR_DATA_LENGTH,
+                request.authenticator_data.len()
+            )));
+        }
+
+        if request.public_key.len() != P256_UNCOMPRESSED_PUBLIC_KEY_LENGTH || request.public_key[0] != 0x04 {
+            return Err(CryptoError::InvalidInput(
+                "Invalid WebAuthn public key: expected an uncompressed P-256 point".to_string(),
+            ));
+        }
+
+        self.verify_ecdsa_p256(&request.signature_request())
+    }
+
+    /// Verify a single signature, taking public keys from `keys` when given
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "crypto.verify_signature",
+        level = "debug",
+        skip_all,
+        fields(algorithm = ?request.algorithm, message_len = request.message.len()),
+    ))]
+    fn verify_signature_with(
+        &self,
+        request: &SignatureRequest,
+        keys: Option<&PublicKeyCache>,
+    ) -> SignatureVerificationResult {
         let start = std::time::Instant::now();
 
-        let (valid, error) = match self.verify_internal(request) {
+        let (valid, error) = match self.verify_internal(request, keys) {
             Ok(valid) => (valid, None),
             Err(e) => (false, Some(e.to_string())),
         };
@@ -82,21 +276,38 @@ impl SignatureVerifier {
             polygon_verified,
             verification_time_us: start.elapsed().as_micros() as u64,
             error,
+            skipped: false,
         }
     }
 
     /// Internal verification logic
-    fn verify_internal(&self, request: &SignatureRequest) -> Result<bool> {
+    fn verify_internal(&self, request: &SignatureRequest, keys: Option<&PublicKeyCache>) -> Result<bool> {
         match request.algorithm {
-            SignatureAlgorithm::Ed25519 => self.verify_ed25519(request),
+            SignatureAlgorithm::Ed25519 => self.verify_ed25519(request, keys),
+            #[cfg(feature = "native-crypto")]
             SignatureAlgorithm::EcdsaP256 => self.verify_ecdsa_p256(request),
+            #[cfg(feature = "native-crypto")]
             SignatureAlgorithm::RsaPss2048 => self.verify_rsa_pss(request),
+            #[cfg(feature = "native-crypto")]
             SignatureAlgorithm::PolygonEcdsa => self.verify_polygon_ecdsa(request),
+            #[cfg(feature = "native-crypto")]
+            SignatureAlgorithm::Secp256k1Schnorr => self.verify_secp256k1_schnorr(request, keys),
+            #[cfg(feature = "pq")]
+            SignatureAlgorithm::MlDsa65 => self.verify_ml_dsa65(request),
+            #[cfg(not(feature = "pq"))]
+            SignatureAlgorithm::MlDsa65 => Err(CryptoError::CryptoOperationFailed(
+                "MlDsa65 verification requires the pq feature".to_string(),
+            )),
+            #[cfg(not(feature = "native-crypto"))]
+            algorithm => Err(CryptoError::CryptoOperationFailed(format!(
+                "{:?} verification requires the native-crypto feature",
+                algorithm
+            ))),
         }
     }
 
     /// Verify Ed25519 signature
-    fn verify_ed25519(&self, request: &SignatureRequest) -> Result<bool> {
+    fn verify_ed25519(&self, request: &SignatureRequest, keys: Option<&PublicKeyCache>) -> Result<bool> {
         if request.public_key.len() != PUBLIC_KEY_LENGTH {
             return Err(CryptoError::InvalidInput(format!(
                 "Invalid Ed25519 public key length: expected {}, got {}",
@@ -113,8 +324,10 @@ impl SignatureVerifier {
             )));
         }
 
-        let public_key = Ed25519PublicKey::from_bytes(&request.public_key)
-            .map_err(|e| CryptoError::CryptoOperationFailed(format!("Invalid public key: {}", e)))?;
+        let public_key = match keys {
+            Some(keys) => keys.ed25519(&request.public_key)?,
+            None => parse_ed25519_public_key(&request.public_key)?,
+        };
 
         let signature = Ed25519Signature::from_bytes(&request.signature)
             .map_err(|e| CryptoError::CryptoOperationFailed(format!("Invalid signature: {}", e)))?;
@@ -123,6 +336,7 @@ impl SignatureVerifier {
     }
 
     /// Verify ECDSA P-256 signature
+    #[cfg(feature = "native-crypto")]
     fn verify_ecdsa_p256(&self, request: &SignatureRequest) -> Result<bool> {
         let public_key = UnparsedPublicKey::new(
             &signature::ECDSA_P256_SHA256_ASN1,
@@ -133,6 +347,7 @@ impl SignatureVerifier {
     }
 
     /// Verify RSA-PSS signature
+    #[cfg(feature = "native-crypto")]
     fn verify_rsa_pss(&self, request: &SignatureRequest) -> Result<bool> {
         let public_key = UnparsedPublicKey::new(
             &signature::RSA_PSS_2048_8192_SHA256,
@@ -143,6 +358,7 @@ impl SignatureVerifier {
     }
 
     /// Verify Polygon-compatible ECDSA signature
+    #[cfg(feature = "native-crypto")]
     fn verify_polygon_ecdsa(&self, request: &SignatureRequest) -> Result<bool> {
         // For Polygon/Ethereum signatures, we need to handle the recovery ID
         // and the specific message hashing format
@@ -162,33 +378,158 @@ impl SignatureVerifier {
         Ok(public_key.verify(&message_hash, &request.signature).is_ok())
     }
 
+    /// Verify BIP-340 Schnorr signature
+    #[cfg(feature = "native-crypto")]
+    fn verify_secp256k1_schnorr(&self, request: &SignatureRequest, keys: Option<&PublicKeyCache>) -> Result<bool> {
+        if request.message.len() != SCHNORR_MESSAGE_LENGTH {
+            return Err(CryptoError::InvalidInput(format!(
+                "Invalid Schnorr message length: expected {}, got {}; hash longer messages with bip340_tagged_hash",
+                SCHNORR_MESSAGE_LENGTH,
+                request.message.len()
+            )));
+        }
+
+        let public_key = match keys {
+            Some(keys) => keys.secp256k1(&request.public_key)?,
+            None => parse_secp256k1_public_key(&request.public_key)?,
+        };
+
+        let signature = schnorr::Signature::from_slice(&request.signature)
+            .map_err(|e| CryptoError::CryptoOperationFailed(format!("Invalid signature: {}", e)))?;
+
+        let message = Message::from_digest_slice(&request.message)
+            .map_err(|e| CryptoError::InvalidInput(format!("Invalid message: {}", e)))?;
+
+        Ok(SECP256K1.verify_schnorr(&signature, &message, &public_key).is_ok())
+    }
+
+    /// Verify ML-DSA-65 signature
+    #[cfg(feature = "pq")]
+    fn verify_ml_dsa65(&self, request: &SignatureRequest) -> Result<bool> {
+        let public_key = mldsa65::PublicKey::from_bytes(&request.public_key).map_err(|_| {
+            CryptoError::InvalidInput(format!(
+                "Invalid ML-DSA-65 public key length: expected {}, got {}",
+                mldsa65::public_key_bytes(),
+                request.public_key.len()
+            ))
+        })?;
+
+        let signature = mldsa65::DetachedSignature::from_bytes(&request.signature).map_err(|_| {
+            CryptoError::InvalidInput(format!(
+                "Invalid ML-DSA-65 signature length: expected {}, got {}",
+                mldsa65::signature_bytes(),
+                request.signature.len()
+            ))
+        })?;
+
+        Ok(mldsa65::verify_detached_signature(&signature, &request.message, &public_key).is_ok())
+    }
+
+    /// Sign a message with a raw secret key
+    ///
+    /// Ed25519 takes a 32-byte seed. Schnorr takes a 32-byte secp256k1 secret key
+    /// and, as in verification, a 32-byte message. ML-DSA-65 takes a full secret
+    /// key as returned by `ml_dsa65_keypair`.
+    pub fn sign(&self, message: &[u8], secret_key: &[u8], algorithm: SignatureAlgorithm) -> Result<Vec<u8>> {
+        match algorithm {
+            SignatureAlgorithm::Ed25519 => {
+                if secret_key.len() != SECRET_KEY_LENGTH {
+                    return Err(CryptoError::InvalidInput(format!(
+                        "Invalid Ed25519 secret key length: expected {}, got {}",
+                        SECRET_KEY_LENGTH,
+                        secret_key.len()
+                    )));
+                }
+                let secret = Ed25519SecretKey::from_bytes(secret_key)
+                    .map_err(|e| CryptoError::CryptoOperationFailed(format!("Invalid secret key: {}", e)))?;
+                let public = Ed25519PublicKey::from(&secret);
+                let keypair = Ed25519Keypair { secret, public };
+                Ok(keypair.sign(message).to_bytes().to_vec())
+            }
+            #[cfg(feature = "native-crypto")]
+            SignatureAlgorithm::Secp256k1Schnorr => {
+                let mut aux_rand = [0u8; 32];
+                rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut aux_rand);
+                self.sign_secp256k1_schnorr(message, secret_key, &aux_rand)
+            }
+            #[cfg(feature = "pq")]
+            SignatureAlgorithm::MlDsa65 => {
+                let secret_key = mldsa65::SecretKey::from_bytes(secret_key).map_err(|_| {
+                    CryptoError::InvalidInput(format!(
+                        "Invalid ML-DSA-65 secret key length: expected {}, got {}",
+                        mldsa65::secret_key_bytes(),
+                        secret_key.len()
+                    ))
+                })?;
+                Ok(mldsa65::detached_sign(message, &secret_key).as_bytes().to_vec())
+            }
+            algorithm => Err(CryptoError::CryptoOperationFailed(format!(
+                "Signing is not supported for {:?}",
+                algorithm
+            ))),
+        }
+    }
+
+    /// BIP-340 signing with caller-supplied auxiliary randomness
+    #[cfg(feature = "native-crypto")]
+    fn sign_secp256k1_schnorr(&self, message: &[u8], secret_key: &[u8], aux_rand: &[u8; 32]) -> Result<Vec<u8>> {
+        let message = Message::from_digest_slice(message).map_err(|_| {
+            CryptoError::InvalidInput(format!(
+                "Invalid Schnorr message length: expected {}, got {}",
+                SCHNORR_MESSAGE_LENGTH,
+                message.len()
+            ))
+        })?;
+
+        let keypair = Secp256k1Keypair::from_seckey_slice(SECP256K1, secret_key)
+            .map_err(|e| CryptoError::InvalidInput(format!("Invalid secret key: {}", e)))?;
+
+        Ok(SECP256K1.sign_schnorr_with_aux_rand(&message, &keypair, aux_rand).serialize().to_vec())
+    }
+
     /// Verify signatures in batch
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "crypto.verify_batch",
+        skip_all,
+        fields(
+            batch_size = batch.requests.len(),
+            parallel = batch.requests.len() > batch.parallel_threshold,
+            fail_fast = batch.fail_fast,
+        ),
+    ))]
     pub fn verify_batch(&self, batch: &BatchSignatureRequest) -> Vec<SignatureVerificationResult> {
+        let cache = batch.cache_public_keys.then(PublicKeyCache::new);
+        self.verify_batch_with(batch, cache.as_ref())
+    }
+
+    fn verify_batch_with(
+        &self,
+        batch: &BatchSignatureRequest,
+        keys: Option<&PublicKeyCache>,
+    ) -> Vec<SignatureVerificationResult> {
         if batch.requests.len() > batch.parallel_threshold {
-            self.verify_batch_parallel(batch)
+            self.verify_batch_parallel(batch, keys)
         } else {
-            self.verify_batch_sequential(batch)
+            self.verify_batch_sequential(batch, keys)
         }
     }
 
     /// Sequential batch verification
-    fn verify_batch_sequential(&self, batch: &BatchSignatureRequest) -> Vec<SignatureVerificationResult> {
+    fn verify_batch_sequential(
+        &self,
+        batch: &BatchSignatureRequest,
+        keys: Option<&PublicKeyCache>,
+    ) -> Vec<SignatureVerificationResult> {
         let mut results = Vec::with_capacity(batch.requests.len());
 
         for request in &batch.requests {
-            let result = self.verify_signature(request);
+            let result = self.verify_signature_with(request, keys);
             
             if batch.fail_fast && !result.valid {
                 results.push(result);
-                // Fill remaining with failure results
-                for _ in results.len()..batch.requests.len() {
-                    results.push(SignatureVerificationResult {
-                        valid: false,
-                        algorithm: SignatureAlgorithm::Ed25519,
-                        polygon_verified: false,
-                        verification_time_us: 0,
-                        error: Some("Skipped due to fail-fast".to_string()),
-                    });
+                // Mark the remaining requests as skipped
+                for request in &batch.requests[results.len()..] {
+                    results.push(SignatureVerificationResult::skipped(request));
                 }
                 break;
             }
@@ -200,29 +541,37 @@ impl SignatureVerifier {
     }
 
     /// Parallel batch verification
-    fn verify_batch_parallel(&self, batch: &BatchSignatureRequest) -> Vec<SignatureVerificationResult> {
+    fn verify_batch_parallel(
+        &self,
+        batch: &BatchSignatureRequest,
+        keys: Option<&PublicKeyCache>,
+    ) -> Vec<SignatureVerificationResult> {
         if batch.fail_fast {
             // For fail-fast mode, we need to check results sequentially
             // but compute them in parallel chunks
-            self.verify_batch_parallel_fail_fast(batch)
+            self.verify_batch_parallel_fail_fast(batch, keys)
         } else {
             // Full parallel processing
             batch.requests
                 .par_iter()
-                .map(|request| self.verify_signature(request))
+                .map(|request| self.verify_signature_with(request, keys))
                 .collect()
         }
     }
 
     /// Parallel batch verification with fail-fast
-    fn verify_batch_parallel_fail_fast(&self, batch: &BatchSignatureRequest) -> Vec<SignatureVerificationResult> {
+    fn verify_batch_parallel_fail_fast(
+        &self,
+        batch: &BatchSignatureRequest,
+        keys: Option<&PublicKeyCache>,
+    ) -> Vec<SignatureVerificationResult> {
         let chunk_size = 100; // Process in chunks for fail-fast
         let mut results = Vec::with_capacity(batch.requests.len());
         
         for chunk in batch.requests.chunks(chunk_size) {
             let chunk_results: Vec<_> = chunk
                 .par_iter()
-                .map(|request| self.verify_signature(request))
+                .map(|request| self.verify_signature_with(request, keys))
                 .collect();
 
             let mut failed = false;
@@ -236,15 +585,9 @@ impl SignatureVerifier {
             }
 
             if failed {
-                // Fill remaining with failure results
-                for _ in results.len()..batch.requests.len() {
-                    results.push(SignatureVerificationResult {
-                        valid: false,
-                        algorithm: SignatureAlgorithm::Ed25519,
-                        polygon_verified: false,
-                        verification_time_us: 0,
-                        error: Some("Skipped due to fail-fast".to_string()),
-                    });
+                // Mark the remaining requests as skipped
+                for request in &batch.requests[results.len()..] {
+                    results.push(SignatureVerificationResult::skipped(request));
                 }
                 break;
             }
@@ -356,6 +699,7 @@ impl SignatureVerifier {
             requests,
             fail_fast: false,
             parallel_threshold: 10,
+            cache_public_keys: false,
         };
 
         let results = self.verify_batch(&batch_request);
@@ -441,6 +785,7 @@ mod tests {
             requests,
             fail_fast: false,
             parallel_threshold: 5,
+            cache_public_keys: false,
         };
 
         let results = verifier.verify_batch(&batch);
@@ -478,4 +823,307 @@ mod tests {
         assert!(result.threshold_met);
         assert_eq!(result.valid_signatures, 5);
     }
-}
\ No newline at end of file
+    #[test]
+    fn test_public_key_cache_parses_each_signer_once() {
+        let verifier = SignatureVerifier::new(false);
+        let mut csprng = OsRng{};
+        let signers: Vec<Keypair> = (0..10).map(|_| Keypair::generate(&mut csprng)).collect();
+
+        let mut requests: Vec<SignatureRequest> = (0..1000)
+            .map(|i| {
+                let keypair = &signers[i % signers.len()];
+                let message = format!("Message {}", i).into_bytes();
+                SignatureRequest {
+                    signature: keypair.sign(&message).to_bytes().to_vec(),
+                    message,
+                    public_key: keypair.public.to_bytes().to_vec(),
+                    algorithm: SignatureAlgorithm::Ed25519,
+                    polygon_tx_hash: None,
+                }
+            })
+            .collect();
+        requests[500].message = b"tampered".to_vec();
+
+        let summarize = |results: &[SignatureVerificationResult]| -> Vec<(bool, Option<String>)> {
+            results.iter().map(|r| (r.valid, r.error.clone())).collect()
+        };
+        let mut batch = BatchSignatureRequest {
+            requests,
+            fail_fast: false,
+            parallel_threshold: 1000,
+            cache_public_keys: false,
+        };
+        let uncached = summarize(&verifier.verify_batch(&batch));
+        assert_eq!(uncached.iter().filter(|(valid, _)| *valid).count(), 999);
+
+        // Without a cache every request parses its key; with one each signer is parsed once
+        let cache = PublicKeyCache::new();
+        let cached = summarize(&verifier.verify_batch_with(&batch, Some(&cache)));
+        assert_eq!(cached, uncached);
+        assert_eq!(cache.parse_count(), signers.len());
+
+        batch.parallel_threshold = 10;
+        let cache = PublicKeyCache::new();
+        assert_eq!(summarize(&verifier.verify_batch_with(&batch, Some(&cache))), uncached);
+        assert!(cache.parse_count() < batch.requests.len());
+
+        batch.cache_public_keys = true;
+        assert_eq!(summarize(&verifier.verify_batch(&batch)), uncached);
+    }
+
+    #[test]
+    fn test_fail_fast_marks_skipped_entries() {
+        let verifier = SignatureVerifier::new(false);
+        let mut csprng = OsRng{};
+        let keypair = Keypair::generate(&mut csprng);
+
+        let mut requests: Vec<SignatureRequest> = (0..300)
+            .map(|i| {
+                let message = format!("Message {}", i).into_bytes();
+                SignatureRequest {
+                    signature: keypair.sign(&message).to_bytes().to_vec(),
+                    message,
+                    public_key: keypair.public.to_bytes().to_vec(),
+                    algorithm: SignatureAlgorithm::Ed25519,
+                    polygon_tx_hash: None,
+                }
+            })
+            .collect();
+        requests[1].message = b"tampered".to_vec();
+        for request in &mut requests[2..] {
+            request.algorithm = SignatureAlgorithm::EcdsaP256;
+        }
+
+        // Sequential and chunked parallel fail-fast paths
+        for parallel_threshold in [1000, 10] {
+            let batch = BatchSignatureRequest {
+                requests: requests.clone(),
+                fail_fast: true,
+                parallel_threshold,
+                cache_public_keys: false,
+            };
+            let results = verifier.verify_batch(&batch);
+            assert_eq!(results.len(), requests.len());
+
+            assert!(results[0].valid && !results[0].skipped);
+            // The genuine failure is reported as such, not as skipped
+            assert!(!results[1].valid && !results[1].skipped);
+            assert_eq!(results[1].algorithm, SignatureAlgorithm::Ed25519);
+
+            let skipped = &results[2..];
+            assert!(skipped.iter().all(|r| r.skipped && !r.valid));
+            assert!(skipped.iter().all(|r| r.algorithm == SignatureAlgorithm::EcdsaP256));
+        }
+    }
+
+    #[cfg(feature = "native-crypto")]
+    fn schnorr_request(public_key: &str, message: &str, signature: &str) -> SignatureRequest {
+        SignatureRequest {
+            message: hex::decode(message).unwrap(),
+            signature: hex::decode(signature).unwrap(),
+            public_key: hex::decode(public_key).unwrap(),
+            algorithm: SignatureAlgorithm::Secp256k1Schnorr,
+            polygon_tx_hash: None,
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "native-crypto")]
+    fn test_schnorr_bip340_vectors() {
+        let verifier = SignatureVerifier::new(false);
+
+        // (secret key, public key, aux rand, message, signature) from the BIP-340 test vectors
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+            ),
+            (
+                "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+            ),
+        ];
+
+        for (secret_key, public_key, aux_rand, message, signature) in vectors {
+            let request = schnorr_request(public_key, message, signature);
+            let result = verifier.verify_signature(&request);
+            assert!(result.valid, "vector failed: {:?}", result.error);
+            assert_eq!(result.algorithm, SignatureAlgorithm::Secp256k1Schnorr);
+
+            let aux_rand: [u8; 32] = hex::decode(aux_rand).unwrap().try_into().unwrap();
+            let signed = verifier
+                .sign_secp256k1_schnorr(&request.message, &hex::decode(secret_key).unwrap(), &aux_rand)
+                .unwrap();
+            assert_eq!(signed, request.signature);
+        }
+
+        // Negated s value
+        let request = schnorr_request(
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A14602975563CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2",
+        );
+        assert!(!verifier.verify_signature(&request).valid);
+
+        // Public key not on the curve
+        let request = schnorr_request(
+            "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+        );
+        let result = verifier.verify_signature(&request);
+        assert!(!result.valid);
+        assert!(result.error.is_some());
+
+        // Messages must already be reduced to 32 bytes
+        let mut request = schnorr_request(
+            "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+        );
+        request.message.push(0);
+        assert!(verifier.verify_signature(&request).error.is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "pq")]
+    fn test_ml_dsa65_sign_and_verify() {
+        let verifier = SignatureVerifier::new(false);
+        let (public_key, secret_key) = ml_dsa65_keypair();
+
+        let requests: Vec<SignatureRequest> = (0..10)
+            .map(|i| {
+                let message = format!("Message {}", i).into_bytes();
+                SignatureRequest {
+                    signature: verifier.sign(&message, &secret_key, SignatureAlgorithm::MlDsa65).unwrap(),
+                    message,
+                    public_key: public_key.clone(),
+                    algorithm: SignatureAlgorithm::MlDsa65,
+                    polygon_tx_hash: None,
+                }
+            })
+            .collect();
+        assert!(verifier.verify_signature(&requests[0]).valid);
+
+        let mut tampered = requests[0].clone();
+        tampered.message = b"tampered".to_vec();
+        let result = verifier.verify_signature(&tampered);
+        assert!(!result.valid);
+        assert!(result.error.is_none());
+
+        // Another signer's key does not verify
+        let mut wrong_key = requests[0].clone();
+        wrong_key.public_key = ml_dsa65_keypair().0;
+        assert!(!verifier.verify_signature(&wrong_key).valid);
+
+        // An Ed25519-sized key is rejected as malformed
+        let mut truncated = requests[0].clone();
+        truncated.public_key.truncate(PUBLIC_KEY_LENGTH);
+        assert!(verifier.verify_signature(&truncated).error.is_some());
+
+        let batch = BatchSignatureRequest {
+            requests,
+            fail_fast: false,
+            parallel_threshold: 5,
+            cache_public_keys: true,
+        };
+        let results = verifier.verify_batch(&batch);
+        assert_eq!(results.len(), 10);
+        assert!(results.iter().all(|r| r.valid && r.algorithm == SignatureAlgorithm::MlDsa65));
+    }
+
+    /// Assertion recorded from an ES256 credential registered for `velocity.example`
+    #[cfg(feature = "native-crypto")]
+    fn recorded_webauthn_assertion() -> WebAuthnRequest {
+        WebAuthnRequest {
+            authenticator_data: hex::decode(
+                "854e4280a40e180cb45f8acb3b3ce4385b05d1c6eabbad70c38bfe8f4eeccff2050000002a",
+            )
+            .unwrap(),
+            client_data_json: br#"{"type":"webauthn.get","challenge":"rgGA34veZpf45eOtnG-wl3nzkz1C2JDkh2VFx1PwrOE","origin":"https://velocity.example","crossOrigin":false}"#.to_vec(),
+            signature: hex::decode(
+                "3045022043500181426bf4391aafefce6ddc0fe67749652e291497035afb225810af4e02022100dd9d8ae1e92961fa54d0d402c925b7a2aba4c55ce228b4284d8a57473988a30c",
+            )
+            .unwrap(),
+            public_key: hex::decode(
+                "04f5c2ffecf8b1920bc4747c80fff03de4a3a48569ad869043dcd6ed363033132868b3d4410066701a3aff1c58867765b08b8ca493741f8f8dc3aeb0ff5e5adb42",
+            )
+            .unwrap(),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "native-crypto")]
+    fn test_webauthn_assertion_vector() {
+        let verifier = SignatureVerifier::new(false);
+        let assertion = recorded_webauthn_assertion();
+        assert!(verifier.verify_webauthn(&assertion).unwrap());
+        assert!(verifier.verify_signature(&assertion.signature_request()).valid);
+
+        // The signature covers the client data, so a changed challenge fails
+        let mut tampered = assertion.clone();
+        let client_data = String::from_utf8(tampered.client_data_json).unwrap();
+        tampered.client_data_json = client_data.replace("rgGA", "rgGB").into_bytes();
+        assert!(!verifier.verify_webauthn(&tampered).unwrap());
+
+        // So does a bumped signature counter in the authenticator data
+        let mut tampered = assertion.clone();
+        tampered.authenticator_data[36] += 1;
+        assert!(!verifier.verify_webauthn(&tampered).unwrap());
+
+        let mut truncated = assertion.clone();
+        truncated.authenticator_data.truncate(WEBAUTHN_MIN_AUTHENTICATOR_DATA_LENGTH - 1);
+        assert!(verifier.verify_webauthn(&truncated).is_err());
+
+        let mut compressed = assertion;
+        compressed.public_key[0] = 0x03;
+        compressed.public_key.truncate(33);
+        assert!(verifier.verify_webauthn(&compressed).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "native-crypto")]
+    fn test_schnorr_sign_and_batch_verify() {
+        let verifier = SignatureVerifier::new(false);
+        let secret_key = bip340_tagged_hash("velocity/test-key", b"schnorr signer");
+        let public_key = Secp256k1Keypair::from_seckey_slice(SECP256K1, &secret_key)
+            .unwrap()
+            .x_only_public_key()
+            .0
+            .serialize()
+            .to_vec();
+
+        let requests = (0..10)
+            .map(|i| {
+                let message = bip340_tagged_hash("velocity/attestation", format!("Message {}", i).as_bytes()).to_vec();
+                let signature = verifier
+                    .sign(&message, &secret_key, SignatureAlgorithm::Secp256k1Schnorr)
+                    .unwrap();
+                SignatureRequest {
+                    message,
+                    signature,
+                    public_key: public_key.clone(),
+                    algorithm: SignatureAlgorithm::Secp256k1Schnorr,
+                    polygon_tx_hash: None,
+                }
+            })
+            .collect();
+
+        let batch = BatchSignatureRequest {
+            requests,
+            fail_fast: false,
+            parallel_threshold: 5,
+            cache_public_keys: false,
+        };
+
+        let results = verifier.verify_batch(&batch);
+        assert_eq!(results.len(), 10);
+        assert!(results.iter().all(|r| r.valid));
+    }
+}
@@ -0,0 +1,327 @@
+/// Sparse Merkle Tree for membership and non-membership proofs
+///
+/// Keys are 256-bit values addressing a fixed-depth tree where absent leaves take
+/// a well-known default hash. Used for revocation registries and set membership.
+
+use crate::{ct_eq, CryptoError, Result};
+use crate::hash_engine::{HashAlgorithm, HashEngine};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Depth of the tree in bits (one level per key bit)
+pub const SMT_DEPTH: usize = 256;
+
+/// 256-bit key addressing a leaf
+pub type SmtKey = [u8; 32];
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+pub struct SparseMerkleTree {
+    /// Stored leaf values
+    leaves: HashMap<SmtKey, Vec<u8>>,
+    /// Non-default nodes keyed by (height, key prefix); height 0 = leaves
+    nodes: HashMap<(u16, SmtKey), Vec<u8>>,
+    /// Hash of an empty subtree at each height
+    defaults: Vec<Vec<u8>>,
+    engine: HashEngine,
+    algorithm: HashAlgorithm,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SparseMerkleProof {
+    /// Key the proof refers to
+    pub key: SmtKey,
+    /// Sibling hashes from leaf level to root (None = empty subtree)
+    pub siblings: Vec<Option<Vec<u8>>>,
+    /// Hash algorithm used by the tree
+    pub algorithm: HashAlgorithm,
+}
+
+impl SparseMerkleTree {
+    /// Create an empty sparse Merkle tree
+    pub fn new(algorithm: HashAlgorithm) -> Result<Self> {
+        let engine = HashEngine::new(algorithm);
+        let defaults = Self::default_hashes(&engine)?;
+
+        Ok(Self {
+            leaves: HashMap::new(),
+            nodes: HashMap::new(),
+            defaults,
+            engine,
+            algorithm,
+        })
+    }
+
+    /// Derive a tree key from arbitrary identifier bytes (e.g. a credential id)
+    pub fn derive_key(&self, id: &[u8]) -> Result<SmtKey> {
+        let digest = self.engine.hash(id)?;
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&digest[..32]);
+        Ok(key)
+    }
+
+    /// Insert or update the value stored at `key`
+    pub fn insert(&mut self, key: SmtKey, value: Vec<u8>) -> Result<()> {
+        let leaf = leaf_hash(&self.engine, &key, &value)?;
+        self.leaves.insert(key, value);
+        self.update_path(&key, leaf)
+    }
+
+    /// Remove `key` from the tree, returning its previous value
+    pub fn remove(&mut self, key: &SmtKey) -> Result<Option<Vec<u8>>> {
+        let previous = self.leaves.remove(key);
+        if previous.is_some() {
+            let empty_leaf = self.defaults[0].clone();
+            self.update_path(key, empty_leaf)?;
+        }
+        Ok(previous)
+    }
+
+    /// Get the value stored at `key`
+    pub fn get(&self, key: &SmtKey) -> Option<&Vec<u8>> {
+        self.leaves.get(key)
+    }
+
+    /// Check whether `key` is present
+    pub fn contains(&self, key: &SmtKey) -> bool {
+        self.leaves.contains_key(key)
+    }
+
+    /// Number of stored leaves
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Whether the tree has no leaves
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Get the root hash of the tree
+    pub fn root(&self) -> Vec<u8> {
+        self.node_or_default(SMT_DEPTH, &[0u8; 32])
+    }
+
+    /// Prove that `key` is present in the tree
+    pub fn prove_membership(&self, key: &SmtKey) -> Result<SparseMerkleProof> {
+        if !self.contains(key) {
+            return Err(CryptoError::InvalidInput("Key is not present in the tree".to_string()));
+        }
+        Ok(self.build_proof(key))
+    }
+
+    /// Prove that `key` is absent from the tree
+    pub fn prove_non_membership(&self, key: &SmtKey) -> Result<SparseMerkleProof> {
+        if self.contains(key) {
+            return Err(CryptoError::InvalidInput("Key is present in the tree".to_string()));
+        }
+        Ok(self.build_proof(key))
+    }
+
+    fn build_proof(&self, key: &SmtKey) -> SparseMerkleProof {
+        let siblings = (0..SMT_DEPTH)
+            .map(|height| {
+                let sibling_id = sibling_prefix(key, SMT_DEPTH - height);
+                self.nodes.get(&(height as u16, sibling_id)).cloned()
+            })
+            .collect();
+
+        SparseMerkleProof {
+            key: *key,
+            siblings,
+            algorithm: self.algorithm,
+        }
+    }
+
+    fn update_path(&mut self, key: &SmtKey, leaf: Vec<u8>) -> Result<()> {
+        let mut current = leaf;
+
+        for height in 0..SMT_DEPTH {
+            let prefix_bits = SMT_DEPTH - height;
+            self.set_node(height, mask_prefix(key, prefix_bits), current.clone());
+
+            let sibling = self.node_or_default(height, &sibling_prefix(key, prefix_bits));
+            current = if get_bit(key, prefix_bits - 1) {
+                node_hash(&self.engine, &sibling, &current)?
+            } else {
+                node_hash(&self.engine, &current, &sibling)?
+            };
+        }
+
+        self.set_node(SMT_DEPTH, [0u8; 32], current);
+        Ok(())
+    }
+
+    fn set_node(&mut self, height: usize, prefix: SmtKey, hash: Vec<u8>) {
+        if hash == self.defaults[height] {
+            self.nodes.remove(&(height as u16, prefix));
+        } else {
+            self.nodes.insert((height as u16, prefix), hash);
+        }
+    }
+
+    fn node_or_default(&self, height: usize, prefix: &SmtKey) -> Vec<u8> {
+        self.nodes
+            .get(&(height as u16, *prefix))
+            .cloned()
+            .unwrap_or_else(|| self.defaults[height].clone())
+    }
+
+    fn default_hashes(engine: &HashEngine) -> Result<Vec<Vec<u8>>> {
+        let mut defaults = Vec::with_capacity(SMT_DEPTH + 1);
+        defaults.push(engine.hash(&[LEAF_PREFIX])?);
+        for height in 0..SMT_DEPTH {
+            let child = &defaults[height];
+            let parent = node_hash(engine, child, child)?;
+            defaults.push(parent);
+        }
+        Ok(defaults)
+    }
+}
+
+/// Verify that `key` maps to `value` under `root`
+pub fn verify_membership_proof(root: &[u8], key: &SmtKey, value: &[u8], proof: &SparseMerkleProof) -> bool {
+    if &proof.key != key {
+        return false;
+    }
+
+    let engine = HashEngine::new(proof.algorithm);
+    match leaf_hash(&engine, key, value) {
+        Ok(leaf) => matches!(compute_root(&engine, proof, leaf), Ok(computed) if ct_eq(&computed, root)),
+        Err(_) => false,
+    }
+}
+
+/// Verify that `key` is absent from the tree with the given `root`
+pub fn verify_non_membership_proof(root: &[u8], key: &SmtKey, proof: &SparseMerkleProof) -> bool {
+    if &proof.key != key {
+        return false;
+    }
+
+    let engine = HashEngine::new(proof.algorithm);
+    match engine.hash(&[LEAF_PREFIX]) {
+        Ok(empty_leaf) => matches!(compute_root(&engine, proof, empty_leaf), Ok(computed) if ct_eq(&computed, root)),
+        Err(_) => false,
+    }
+}
+
+fn compute_root(engine: &HashEngine, proof: &SparseMerkleProof, leaf: Vec<u8>) -> Result<Vec<u8>> {
+    if proof.siblings.len() != SMT_DEPTH {
+        return Err(CryptoError::InvalidInput(format!(
+            "Sparse Merkle proof must have {} siblings",
+            SMT_DEPTH
+        )));
+    }
+
+    let defaults = SparseMerkleTree::default_hashes(engine)?;
+    let mut current = leaf;
+
+    for (height, sibling) in proof.siblings.iter().enumerate() {
+        let sibling = sibling.as_ref().unwrap_or(&defaults[height]);
+        current = if get_bit(&proof.key, SMT_DEPTH - height - 1) {
+            node_hash(engine, sibling, &current)?
+        } else {
+            node_hash(engine, &current, sibling)?
+        };
+    }
+
+    Ok(current)
+}
+
+fn leaf_hash(engine: &HashEngine, key: &SmtKey, value: &[u8]) -> Result<Vec<u8>> {
+    engine.hash(&[&[LEAF_PREFIX][..], key, value].concat())
+}
+
+fn node_hash(engine: &HashEngine, left: &[u8], right: &[u8]) -> Result<Vec<u8>> {
+    engine.hash(&[&[NODE_PREFIX][..], left, right].concat())
+}
+
+/// Bit at `index` counting from the most significant bit of the key
+fn get_bit(key: &SmtKey, index: usize) -> bool {
+    (key[index / 8] >> (7 - index % 8)) & 1 == 1
+}
+
+/// Keep the first `bits` bits of the key and zero the rest
+fn mask_prefix(key: &SmtKey, bits: usize) -> SmtKey {
+    let mut masked = [0u8; 32];
+    let full_bytes = bits / 8;
+    masked[..full_bytes].copy_from_slice(&key[..full_bytes]);
+    if bits % 8 != 0 {
+        masked[full_bytes] = key[full_bytes] & (0xFFu8 << (8 - bits % 8));
+    }
+    masked
+}
+
+/// Prefix of the sibling of the node identified by the first `bits` bits of the key
+fn sibling_prefix(key: &SmtKey, bits: usize) -> SmtKey {
+    let mut sibling = mask_prefix(key, bits);
+    let index = bits - 1;
+    sibling[index / 8] ^= 1 << (7 - index % 8);
+    sibling
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> SmtKey {
+        let mut key = [0u8; 32];
+        key[0] = byte;
+        key[31] = byte.wrapping_mul(7);
+        key
+    }
+
+    #[test]
+    fn test_membership_proofs_after_inserts() {
+        let mut tree = SparseMerkleTree::new(HashAlgorithm::Blake3).unwrap();
+        for i in 0..16u8 {
+            tree.insert(key(i), format!("revoked_{}", i).into_bytes()).unwrap();
+        }
+
+        let root = tree.root();
+        for i in 0..16u8 {
+            let proof = tree.prove_membership(&key(i)).unwrap();
+            let value = format!("revoked_{}", i).into_bytes();
+            assert!(verify_membership_proof(&root, &key(i), &value, &proof));
+            assert!(!verify_membership_proof(&root, &key(i), b"tampered", &proof));
+            assert!(!verify_non_membership_proof(&root, &key(i), &proof));
+        }
+    }
+
+    #[test]
+    fn test_non_membership_for_absent_keys() {
+        let mut tree = SparseMerkleTree::new(HashAlgorithm::Sha256).unwrap();
+        let empty_root = tree.root();
+        let absent = tree.derive_key(b"credential_never_revoked").unwrap();
+
+        let proof = tree.prove_non_membership(&absent).unwrap();
+        assert!(verify_non_membership_proof(&empty_root, &absent, &proof));
+
+        tree.insert(key(1), b"revoked".to_vec()).unwrap();
+        tree.insert(key(2), b"revoked".to_vec()).unwrap();
+        let root = tree.root();
+
+        let proof = tree.prove_non_membership(&absent).unwrap();
+        assert!(verify_non_membership_proof(&root, &absent, &proof));
+        assert!(tree.prove_non_membership(&key(1)).is_err());
+    }
+
+    #[test]
+    fn test_non_membership_after_removal() {
+        let mut tree = SparseMerkleTree::new(HashAlgorithm::Blake3).unwrap();
+        tree.insert(key(1), b"revoked".to_vec()).unwrap();
+        let root_before = tree.root();
+
+        tree.insert(key(2), b"revoked".to_vec()).unwrap();
+        let membership = tree.prove_membership(&key(2)).unwrap();
+        assert_eq!(tree.remove(&key(2)).unwrap(), Some(b"revoked".to_vec()));
+
+        let root = tree.root();
+        assert_eq!(root, root_before);
+        assert!(!verify_membership_proof(&root, &key(2), b"revoked", &membership));
+
+        let proof = tree.prove_non_membership(&key(2)).unwrap();
+        assert!(verify_non_membership_proof(&root, &key(2), &proof));
+    }
+}
@@ -5,10 +5,9 @@
 
 use crate::{CryptoError, Result};
 use crate::hash_engine::{HashAlgorithm, HashEngine};
-use rayon::prelude::*;
+use crate::par::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::Arc;
 
 /// Trust activity types with their base weights
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -34,15 +33,61 @@ pub struct TrustActivity {
     pub metadata: HashMap<String, String>,
 }
 
+/// Time-decay curve applied to an activity's weight as it ages
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DecayFunction {
+    /// Weight halves every `half_life_days`
+    Exponential { half_life_days: f64 },
+    /// Weight falls linearly to zero at `zero_after_days`
+    Linear { zero_after_days: f64 },
+    /// Piecewise-constant multipliers as (age_days, multiplier), sorted by age;
+    /// activities younger than the first step keep full weight
+    Step { steps: Vec<(f64, f64)> },
+    /// No decay
+    None,
+}
+
+impl DecayFunction {
+    /// Weight multiplier for an activity of the given age
+    pub fn factor(&self, age_days: f64) -> f64 {
+        match self {
+            DecayFunction::Exponential { half_life_days } => {
+                if *half_life_days <= 0.0 {
+                    return 0.0;
+                }
+                0.5f64.powf(age_days / half_life_days)
+            }
+            DecayFunction::Linear { zero_after_days } => {
+                if *zero_after_days <= 0.0 {
+                    return 0.0;
+                }
+                (1.0 - age_days / zero_after_days).max(0.0)
+            }
+            DecayFunction::Step { steps } => steps.iter()
+                .take_while(|(after_days, _)| age_days >= *after_days)
+                .last()
+                .map(|(_, multiplier)| *multiplier)
+                .unwrap_or(1.0),
+            DecayFunction::None => 1.0,
+        }
+    }
+}
+
 /// Trust score calculation configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TrustCalculatorConfig {
     pub activity_weights: HashMap<TrustActivityType, f64>,
+    /// Daily geometric decay used for activity types without a `decay_functions` entry
     pub time_decay_factor: f64,
+    /// Per-type decay curves
+    pub decay_functions: HashMap<TrustActivityType, DecayFunction>,
     pub reputation_multiplier: f64,
     pub confidence_threshold: f64,
     pub polygon_verification_boost: f64,
     pub parallel_threshold: usize,
+    /// Activities older than this are excluded entirely (None = keep forever)
+    pub max_activity_age_secs: Option<u64>,
 }
 
 impl Default for TrustCalculatorConfig {
@@ -59,10 +104,12 @@ impl Default for TrustCalculatorConfig {
         Self {
             activity_weights: weights,
             time_decay_factor: 0.95,
+            decay_functions: HashMap::new(),
             reputation_multiplier: 1.2,
             confidence_threshold: 0.7,
             polygon_verification_boost: 1.5,
             parallel_threshold: 100,
+            max_activity_age_secs: None,
         }
     }
 }
@@ -71,6 +118,16 @@ impl Default for TrustCalculatorConfig {
 pub struct TrustCalculator {
     config: TrustCalculatorConfig,
     hash_engine: HashEngine,
+    /// Activities recorded through `record_activities`
+    state: TrustAggregate,
+}
+
+/// Serializable snapshot of a calculator's accumulated state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalculatorState {
+    /// Hash of the configuration the state was accumulated under
+    pub config_hash: String,
+    pub aggregate: TrustAggregate,
 }
 
 impl TrustCalculator {
@@ -78,153 +135,277 @@ impl TrustCalculator {
         Self {
             config,
             hash_engine: HashEngine::new(HashAlgorithm::Blake3),
+            state: TrustAggregate::default(),
+        }
+    }
+
+    /// Fold activities into the calculator's accumulated state and return the updated score
+    pub fn record_activities(&mut self, activities: &[TrustActivity]) -> Result<TrustScore> {
+        self.record_activities_at(activities, current_unix_time())
+    }
+
+    /// `record_activities` evaluated at `current_time` (unix seconds)
+    pub fn record_activities_at(&mut self, activities: &[TrustActivity], current_time: u64) -> Result<TrustScore> {
+        self.check_incremental()?;
+        let (score, state) = self.extend_aggregate(self.state.clone(), activities, current_time)?;
+        self.state = state;
+        Ok(score)
+    }
+
+    /// Capture the accumulated state so incremental updates can resume after a restart
+    pub fn snapshot(&self) -> Result<CalculatorState> {
+        Ok(CalculatorState {
+            config_hash: self.config_hash()?,
+            aggregate: self.state.clone(),
+        })
+    }
+
+    /// Resume from a snapshot taken with an identical configuration
+    pub fn restore(&mut self, state: CalculatorState) -> Result<()> {
+        if state.config_hash != self.config_hash()? {
+            return Err(CryptoError::VerificationFailed(
+                "Calculator state was produced with a different configuration".to_string(),
+            ));
         }
+
+        self.state = state.aggregate;
+        Ok(())
+    }
+
+    /// Deterministic hash over every configuration value that affects scoring
+    fn config_hash(&self) -> Result<String> {
+        let mut weights: Vec<(String, f64)> = self.config.activity_weights.iter()
+            .map(|(activity_type, weight)| (format!("{:?}", activity_type), *weight))
+            .collect();
+        weights.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut decay_functions: Vec<String> = self.config.decay_functions.iter()
+            .map(|(activity_type, decay)| format!("{:?}={:?};", activity_type, decay))
+            .collect();
+        decay_functions.sort();
+
+        let mut config_data = String::new();
+        for (activity_type, weight) in weights {
+            config_data.push_str(&format!("{}={};", activity_type, weight.to_bits()));
+        }
+        config_data.push_str(&decay_functions.concat());
+        config_data.push_str(&format!(
+            "decay={};reputation={};confidence={};polygon={};max_age={:?}",
+            self.config.time_decay_factor.to_bits(),
+            self.config.reputation_multiplier.to_bits(),
+            self.config.confidence_threshold.to_bits(),
+            self.config.polygon_verification_boost.to_bits(),
+            self.config.max_activity_age_secs,
+        ));
+
+        let hash = self.hash_engine.hash(config_data.as_bytes())?;
+        Ok(hex::encode(hash))
     }
 
     /// Calculate trust score from activities
     pub fn calculate_trust_score(&self, activities: &[TrustActivity]) -> Result<TrustScore> {
+        self.calculate_trust_score_at(activities, current_unix_time())
+    }
+
+    /// Calculate trust score with decay and expiry evaluated at `current_time`
+    /// (unix seconds) instead of the system clock
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "crypto.calculate_trust_score",
+        skip_all,
+        fields(activity_count = activities.len()),
+    ))]
+    pub fn calculate_trust_score_at(&self, activities: &[TrustActivity], current_time: u64) -> Result<TrustScore> {
         if activities.is_empty() {
             return Ok(TrustScore::default());
         }
 
-        // Use parallel processing for large datasets
-        let scores = if activities.len() > self.config.parallel_threshold {
-            self.calculate_parallel(activities)?
-        } else {
-            self.calculate_sequential(activities)?
-        };
+        let (score, _) = self.extend_aggregate(TrustAggregate::default(), activities, current_time)?;
+        Ok(score)
+    }
 
-        Ok(scores)
+    /// Whether decay can be folded into running totals: every activity type
+    /// decays exponentially (or not at all)
+    pub fn supports_incremental_updates(&self) -> bool {
+        self.config.decay_functions.values().all(|decay| {
+            matches!(decay, DecayFunction::Exponential { .. } | DecayFunction::None)
+        })
     }
 
-    /// Sequential calculation for smaller datasets
-    fn calculate_sequential(&self, activities: &[TrustActivity]) -> Result<TrustScore> {
-        let mut weighted_sum = 0.0;
-        let mut total_weight = 0.0;
-        let mut polygon_verified_count = 0;
-        let mut activity_breakdown = HashMap::new();
-        
-        let current_time = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+    fn check_incremental(&self) -> Result<()> {
+        if !self.supports_incremental_updates() {
+            return Err(CryptoError::InvalidInput(
+                "Incremental trust score updates need exponential decay; recalculate from activities".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn extend_aggregate(
+        &self,
+        mut aggregate: TrustAggregate,
+        activities: &[TrustActivity],
+        current_time: u64,
+    ) -> Result<(TrustScore, TrustAggregate)> {
+        // Use parallel processing for large datasets
+        let entries: Vec<WeightedActivity> = if activities.len() > self.config.parallel_threshold {
+            activities.par_iter().map(|activity| self.weighted_activity(activity)).collect()
+        } else {
+            activities.iter().map(|activity| self.weighted_activity(activity)).collect()
+        };
 
         for activity in activities {
-            let base_weight = self.config.activity_weights
-                .get(&activity.activity_type)
-                .copied()
-                .unwrap_or(0.1);
-
-            // Apply time decay
-            let time_diff = (current_time - activity.timestamp) as f64;
-            let time_decay = self.config.time_decay_factor.powf(time_diff / 86400.0); // Daily decay
-
-            // Apply confidence and reputation multipliers
-            let confidence_factor = if activity.confidence >= self.config.confidence_threshold {
-                activity.confidence
-            } else {
-                activity.confidence * 0.5 // Penalty for low confidence
-            };
+            aggregate.activity_digest = self.chain_activity_digest(&aggregate.activity_digest, activity)?;
+        }
 
-            let reputation_factor = 1.0 + (activity.verifier_reputation - 0.5) * self.config.reputation_multiplier;
+        // Carry the existing totals forward to `current_time`
+        if current_time != aggregate.as_of {
+            let elapsed_days = (current_time as f64 - aggregate.as_of as f64) / 86400.0;
+            for (activity_type, totals) in aggregate.totals.iter_mut() {
+                let decay = self.time_decay(*activity_type, elapsed_days);
+                totals.weighted_value *= decay;
+                totals.weight *= decay;
+            }
+        }
+        aggregate.as_of = current_time;
+        let mut expired_activities = self.subtract_expired(&mut aggregate, current_time);
 
-            // Apply Polygon verification boost
-            let polygon_factor = if activity.polygon_tx_hash.is_some() {
-                polygon_verified_count += 1;
-                self.config.polygon_verification_boost
-            } else {
-                1.0
+        for mut entry in entries {
+            let Some((contribution, weight)) = self.decayed_contribution(&entry, current_time) else {
+                expired_activities += 1; // Outside the activity window
+                continue;
             };
+            let totals = aggregate.totals_mut(entry.activity_type);
+            totals.count += 1;
+            totals.polygon_verified += entry.polygon_verified as usize;
+            totals.confidence_sum += entry.confidence;
+            totals.reputation_sum += entry.verifier_reputation;
+            totals.weighted_value += contribution;
+            totals.weight += weight;
+
+            if self.config.max_activity_age_secs.is_some() {
+                // Future-dated activities were weighted as issued now
+                entry.timestamp = entry.timestamp.min(current_time);
+                aggregate.expiring.push(entry);
+            }
+        }
 
-            let final_weight = base_weight * time_decay * confidence_factor * reputation_factor * polygon_factor;
-            let contribution = activity.value * final_weight;
-
-            weighted_sum += contribution;
-            total_weight += final_weight;
+        let score = self.finish(&aggregate, expired_activities, current_time)?;
+        Ok((score, aggregate))
+    }
 
-            // Track activity breakdown
-            *activity_breakdown.entry(activity.activity_type).or_insert(0.0) += contribution;
-        }
+    /// Time-independent weight components of an activity
+    fn weighted_activity(&self, activity: &TrustActivity) -> WeightedActivity {
+        let base_weight = self.config.activity_weights
+            .get(&activity.activity_type)
+            .copied()
+            .unwrap_or(0.1);
 
-        let raw_score = if total_weight > 0.0 {
-            weighted_sum / total_weight
+        // Apply confidence and reputation multipliers
+        let confidence_factor = if activity.confidence >= self.config.confidence_threshold {
+            activity.confidence
         } else {
-            0.0
+            activity.confidence * 0.5 // Penalty for low confidence
         };
 
-        // Normalize score to 0-100 range
-        let normalized_score = (raw_score * 100.0).min(100.0).max(0.0);
+        let reputation_factor = 1.0 + (activity.verifier_reputation - 0.5) * self.config.reputation_multiplier;
 
-        // Calculate trust hash for immutability
-        let trust_hash = self.calculate_trust_hash(activities, normalized_score)?;
+        // Apply Polygon verification boost
+        let polygon_factor = if activity.polygon_tx_hash.is_some() {
+            self.config.polygon_verification_boost
+        } else {
+            1.0
+        };
 
-        Ok(TrustScore {
-            score: normalized_score,
-            confidence: self.calculate_confidence(activities),
-            total_activities: activities.len(),
-            polygon_verified_activities: polygon_verified_count,
-            activity_breakdown,
-            calculation_timestamp: current_time,
-            trust_hash,
-            verification_method: "velocity_trust_protocol_v1".to_string(),
-        })
+        WeightedActivity {
+            activity_type: activity.activity_type,
+            timestamp: activity.timestamp,
+            value: activity.value,
+            static_weight: base_weight * confidence_factor * reputation_factor * polygon_factor,
+            confidence: activity.confidence,
+            verifier_reputation: activity.verifier_reputation,
+            polygon_verified: activity.polygon_tx_hash.is_some(),
+        }
     }
 
-    /// Parallel calculation for large datasets
-    fn calculate_parallel(&self, activities: &[TrustActivity]) -> Result<TrustScore> {
-        let current_time = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let config = Arc::new(self.config.clone());
+    /// Apply time decay to an activity, returning (contribution, weight), or
+    /// None if the activity is older than the configured window
+    fn decayed_contribution(&self, entry: &WeightedActivity, current_time: u64) -> Option<(f64, f64)> {
+        if self.is_expired(entry, current_time) {
+            return None;
+        }
 
-        // Process activities in parallel
-        let results: Vec<(f64, f64, bool, TrustActivityType, f64)> = activities
-            .par_iter()
-            .map(|activity| {
-                let base_weight = config.activity_weights
-                    .get(&activity.activity_type)
-                    .copied()
-                    .unwrap_or(0.1);
+        let age_days = current_time.saturating_sub(entry.timestamp) as f64 / 86400.0;
+        let final_weight = entry.static_weight * self.time_decay(entry.activity_type, age_days);
+        Some((entry.value * final_weight, final_weight))
+    }
 
-                let time_diff = (current_time - activity.timestamp) as f64;
-                let time_decay = config.time_decay_factor.powf(time_diff / 86400.0);
+    /// Weight multiplier for an activity of `activity_type` after `age_days`
+    fn time_decay(&self, activity_type: TrustActivityType, age_days: f64) -> f64 {
+        match self.config.decay_functions.get(&activity_type) {
+            Some(decay) => decay.factor(age_days),
+            None => self.config.time_decay_factor.powf(age_days), // Daily decay
+        }
+    }
 
-                let confidence_factor = if activity.confidence >= config.confidence_threshold {
-                    activity.confidence
-                } else {
-                    activity.confidence * 0.5
-                };
+    fn is_expired(&self, entry: &WeightedActivity, current_time: u64) -> bool {
+        self.config.max_activity_age_secs
+            .map(|max_age| current_time.saturating_sub(entry.timestamp) > max_age)
+            .unwrap_or(false)
+    }
 
-                let reputation_factor = 1.0 + (activity.verifier_reputation - 0.5) * config.reputation_multiplier;
+    /// Drop activities older than `max_activity_age_secs` from an aggregate's stored
+    /// state and subtract them from its totals. Returns the number of activities removed.
+    pub fn purge_expired(&self, aggregate: &mut TrustAggregate) -> usize {
+        self.purge_expired_at(aggregate, current_unix_time())
+    }
 
-                let polygon_factor = if activity.polygon_tx_hash.is_some() {
-                    config.polygon_verification_boost
-                } else {
-                    1.0
-                };
+    /// `purge_expired` evaluated at `current_time` (unix seconds)
+    pub fn purge_expired_at(&self, aggregate: &mut TrustAggregate, current_time: u64) -> usize {
+        self.subtract_expired(aggregate, current_time)
+    }
 
-                let final_weight = base_weight * time_decay * confidence_factor * reputation_factor * polygon_factor;
-                let contribution = activity.value * final_weight;
+    /// Remove expired activities from the totals, which are decayed to `aggregate.as_of`
+    fn subtract_expired(&self, aggregate: &mut TrustAggregate, current_time: u64) -> usize {
+        let (expired, retained): (Vec<_>, Vec<_>) = std::mem::take(&mut aggregate.expiring)
+            .into_iter()
+            .partition(|entry| self.is_expired(entry, current_time));
+        aggregate.expiring = retained;
+
+        for entry in &expired {
+            let age_days = aggregate.as_of.saturating_sub(entry.timestamp) as f64 / 86400.0;
+            let weight = entry.static_weight * self.time_decay(entry.activity_type, age_days);
+
+            let totals = aggregate.totals_mut(entry.activity_type);
+            totals.count = totals.count.saturating_sub(1);
+            totals.polygon_verified = totals.polygon_verified.saturating_sub(entry.polygon_verified as usize);
+            totals.confidence_sum -= entry.confidence;
+            totals.reputation_sum -= entry.verifier_reputation;
+            totals.weighted_value -= entry.value * weight;
+            totals.weight -= weight;
+        }
 
-                (contribution, final_weight, activity.polygon_tx_hash.is_some(), activity.activity_type, contribution)
-            })
-            .collect();
+        // A type with nothing left in the window carries no weight, only rounding residue
+        aggregate.totals.retain(|(_, totals)| totals.count > 0);
+        expired.len()
+    }
 
-        // Aggregate results
+    /// Produce a trust score from aggregate totals
+    fn finish(&self, aggregate: &TrustAggregate, expired_activities: usize, current_time: u64) -> Result<TrustScore> {
         let mut weighted_sum = 0.0;
         let mut total_weight = 0.0;
-        let mut polygon_verified_count = 0;
+        let mut overall = ActivityTotals::default();
         let mut activity_breakdown = HashMap::new();
 
-        for (contribution, weight, is_polygon_verified, activity_type, activity_contribution) in results {
-            weighted_sum += contribution;
-            total_weight += weight;
-            if is_polygon_verified {
-                polygon_verified_count += 1;
-            }
-            *activity_breakdown.entry(activity_type).or_insert(0.0) += activity_contribution;
+        for (activity_type, totals) in &aggregate.totals {
+            weighted_sum += totals.weighted_value;
+            total_weight += totals.weight;
+            overall.count += totals.count;
+            overall.polygon_verified += totals.polygon_verified;
+            overall.confidence_sum += totals.confidence_sum;
+            overall.reputation_sum += totals.reputation_sum;
+
+            // Track activity breakdown
+            activity_breakdown.insert(*activity_type, totals.weighted_value);
         }
 
         let raw_score = if total_weight > 0.0 {
@@ -233,14 +414,18 @@ impl TrustCalculator {
             0.0
         };
 
+        // Normalize score to 0-100 range
         let normalized_score = (raw_score * 100.0).min(100.0).max(0.0);
-        let trust_hash = self.calculate_trust_hash(activities, normalized_score)?;
+
+        // Calculate trust hash for immutability
+        let trust_hash = self.calculate_trust_hash(&aggregate.activity_digest, normalized_score)?;
 
         Ok(TrustScore {
             score: normalized_score,
-            confidence: self.calculate_confidence(activities),
-            total_activities: activities.len(),
-            polygon_verified_activities: polygon_verified_count,
+            confidence: self.calculate_confidence(&overall),
+            total_activities: overall.count,
+            expired_activities,
+            polygon_verified_activities: overall.polygon_verified,
             activity_breakdown,
             calculation_timestamp: current_time,
             trust_hash,
@@ -249,80 +434,141 @@ impl TrustCalculator {
     }
 
     /// Calculate overall confidence in the trust score
-    fn calculate_confidence(&self, activities: &[TrustActivity]) -> f64 {
-        if activities.is_empty() {
+    fn calculate_confidence(&self, totals: &ActivityTotals) -> f64 {
+        if totals.count == 0 {
             return 0.0;
         }
 
-        let avg_confidence = activities.iter()
-            .map(|a| a.confidence)
-            .sum::<f64>() / activities.len() as f64;
-
-        let avg_reputation = activities.iter()
-            .map(|a| a.verifier_reputation)
-            .sum::<f64>() / activities.len() as f64;
-
-        let polygon_ratio = activities.iter()
-            .filter(|a| a.polygon_tx_hash.is_some())
-            .count() as f64 / activities.len() as f64;
+        let count = totals.count as f64;
+        let avg_confidence = totals.confidence_sum / count;
+        let avg_reputation = totals.reputation_sum / count;
+        let polygon_ratio = totals.polygon_verified as f64 / count;
 
         // Weighted confidence calculation
         (avg_confidence * 0.4 + avg_reputation * 0.3 + polygon_ratio * 0.3).min(1.0)
     }
 
+    /// Extend the running activity hash chain with one activity
+    fn chain_activity_digest(&self, previous: &str, activity: &TrustActivity) -> Result<String> {
+        let activity_data = format!(
+            "{}{:?}:{}:{}:{}:{}",
+            previous,
+            activity.activity_type,
+            activity.timestamp,
+            activity.value,
+            activity.confidence,
+            activity.polygon_tx_hash.as_deref().unwrap_or("none")
+        );
+
+        let hash = self.hash_engine.hash(activity_data.as_bytes())?;
+        Ok(hex::encode(hash))
+    }
+
     /// Calculate cryptographic hash of trust calculation
-    fn calculate_trust_hash(&self, activities: &[TrustActivity], score: f64) -> Result<String> {
+    fn calculate_trust_hash(&self, activity_digest: &str, score: f64) -> Result<String> {
         let mut hash_data = Vec::new();
         
         // Include score
         hash_data.extend_from_slice(&score.to_le_bytes());
         
-        // Include activity hashes
-        for activity in activities {
-            let activity_data = format!(
-                "{:?}:{}:{}:{}:{}",
-                activity.activity_type,
-                activity.timestamp,
-                activity.value,
-                activity.confidence,
-                activity.polygon_tx_hash.as_deref().unwrap_or("none")
-            );
-            hash_data.extend_from_slice(activity_data.as_bytes());
-        }
+        // Include the hash chain over all activities
+        hash_data.extend_from_slice(activity_digest.as_bytes());
 
         let hash = self.hash_engine.hash(&hash_data)?;
         Ok(hex::encode(hash))
     }
 
-    /// Update trust score incrementally with new activities
+    /// Update trust score incrementally with new activities.
+    ///
+    /// Folds `new_activities` into `aggregate`, so the result matches a full
+    /// recalculation over all activities seen so far at O(new activities) cost.
+    /// Needs a configuration that `supports_incremental_updates`. Activities
+    /// dated after `current_time` count as issued at `current_time`.
     pub fn update_trust_score(
         &self,
-        current_score: &TrustScore,
+        aggregate: &mut TrustAggregate,
         new_activities: &[TrustActivity],
     ) -> Result<TrustScore> {
-        // This is a simplified incremental update
-        // In production, you'd maintain more state for true incremental updates
-        let weight_factor = 0.9; // Weight of existing score
-        let new_score = self.calculate_trust_score(new_activities)?;
-        
-        let updated_score = current_score.score * weight_factor + new_score.score * (1.0 - weight_factor);
-        let updated_confidence = current_score.confidence * weight_factor + new_score.confidence * (1.0 - weight_factor);
+        self.update_trust_score_at(aggregate, new_activities, current_unix_time())
+    }
 
-        let mut updated_breakdown = current_score.activity_breakdown.clone();
-        for (activity_type, contribution) in new_score.activity_breakdown {
-            *updated_breakdown.entry(activity_type).or_insert(0.0) += contribution * (1.0 - weight_factor);
-        }
+    /// `update_trust_score` evaluated at `current_time` (unix seconds)
+    pub fn update_trust_score_at(
+        &self,
+        aggregate: &mut TrustAggregate,
+        new_activities: &[TrustActivity],
+        current_time: u64,
+    ) -> Result<TrustScore> {
+        self.check_incremental()?;
+        let (score, updated) = self.extend_aggregate(aggregate.clone(), new_activities, current_time)?;
+        *aggregate = updated;
+        Ok(score)
+    }
+}
 
-        Ok(TrustScore {
-            score: updated_score,
-            confidence: updated_confidence,
-            total_activities: current_score.total_activities + new_activities.len(),
-            polygon_verified_activities: current_score.polygon_verified_activities + new_score.polygon_verified_activities,
-            activity_breakdown: updated_breakdown,
-            calculation_timestamp: new_score.calculation_timestamp,
-            trust_hash: self.calculate_trust_hash(new_activities, updated_score)?,
-            verification_method: "velocity_trust_protocol_v1".to_string(),
-        })
+fn current_unix_time() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Time-independent weight components of an activity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeightedActivity {
+    pub activity_type: TrustActivityType,
+    pub timestamp: u64,
+    pub value: f64,
+    /// Base weight × confidence × reputation × Polygon factors, before time decay
+    pub static_weight: f64,
+    pub confidence: f64,
+    pub verifier_reputation: f64,
+    pub polygon_verified: bool,
+}
+
+/// Running totals over the activities of one type
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActivityTotals {
+    pub count: usize,
+    pub polygon_verified: usize,
+    pub confidence_sum: f64,
+    pub reputation_sum: f64,
+    /// Sum of value × decayed weight, as of the aggregate's `as_of`
+    pub weighted_value: f64,
+    /// Sum of decayed weights, as of the aggregate's `as_of`
+    pub weight: f64,
+}
+
+/// Aggregate state sufficient to update a trust score exactly without the original activities.
+///
+/// Holds one set of totals per activity type however many activities it has seen,
+/// plus the activities still inside the age window when one is configured.
+/// Exponential decay factors over time, so decayed totals are carried forward
+/// by one multiplication per type instead of re-decaying every activity.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrustAggregate {
+    /// Totals per activity type, in the order types were first seen
+    pub totals: Vec<(TrustActivityType, ActivityTotals)>,
+    /// Time (unix seconds) the decayed totals were evaluated at
+    pub as_of: u64,
+    /// Running hash chain over all ingested activities
+    pub activity_digest: String,
+    /// Activities still inside the age window, kept only when `max_activity_age_secs`
+    /// is set so they can be subtracted from the totals once they expire
+    #[serde(default)]
+    pub expiring: Vec<WeightedActivity>,
+}
+
+impl TrustAggregate {
+    fn totals_mut(&mut self, activity_type: TrustActivityType) -> &mut ActivityTotals {
+        let index = match self.totals.iter().position(|(seen, _)| *seen == activity_type) {
+            Some(index) => index,
+            None => {
+                self.totals.push((activity_type, ActivityTotals::default()));
+                self.totals.len() - 1
+            }
+        };
+        &mut self.totals[index].1
     }
 }
 
@@ -332,6 +578,9 @@ pub struct TrustScore {
     pub score: f64,
     pub confidence: f64,
     pub total_activities: usize,
+    /// Activities excluded for being older than `max_activity_age_secs`
+    #[serde(default)]
+    pub expired_activities: usize,
     pub polygon_verified_activities: usize,
     pub activity_breakdown: HashMap<TrustActivityType, f64>,
     pub calculation_timestamp: u64,
@@ -345,6 +594,7 @@ impl Default for TrustScore {
             score: 0.0,
             confidence: 0.0,
             total_activities: 0,
+            expired_activities: 0,
             polygon_verified_activities: 0,
             activity_breakdown: HashMap::new(),
             calculation_timestamp: 0,
@@ -434,14 +684,201 @@ mod tests {
             create_test_activity(TrustActivityType::ComplianceVerification, 0.8, true),
         ];
         
-        let initial_score = calculator.calculate_trust_score(&initial_activities).unwrap();
+        let mut aggregate = TrustAggregate::default();
+        let initial_score = calculator.update_trust_score(&mut aggregate, &initial_activities).unwrap();
         
         let new_activities = vec![
             create_test_activity(TrustActivityType::AuditCompletion, 0.95, true),
         ];
         
-        let updated_score = calculator.update_trust_score(&initial_score, &new_activities).unwrap();
+        let updated_score = calculator.update_trust_score(&mut aggregate, &new_activities).unwrap();
         assert!(updated_score.score > initial_score.score);
         assert_eq!(updated_score.total_activities, 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_incremental_update_matches_full_recompute() {
+        let calculator = TrustCalculator::new(TrustCalculatorConfig::default());
+        let now = current_unix_time();
+
+        // Oldest first, so each increment only holds activities issued by then
+        let activities: Vec<TrustActivity> = (0..40)
+            .rev()
+            .map(|i| {
+                let mut activity = create_test_activity(
+                    if i % 2 == 0 { TrustActivityType::AuditCompletion } else { TrustActivityType::ExpertValidation },
+                    0.5 + (i as f64 / 100.0),
+                    i % 4 == 0,
+                );
+                activity.timestamp = now - (i as u64) * 3600 * 7;
+                activity.confidence = if i % 5 == 0 { 0.6 } else { 0.9 };
+                activity
+            })
+            .collect();
+
+        let mut full_aggregate = TrustAggregate::default();
+        let full = calculator.update_trust_score_at(&mut full_aggregate, &activities, now).unwrap();
+        assert_eq!(full.trust_hash, calculator.calculate_trust_score_at(&activities, now).unwrap().trust_hash);
+
+        // Apply in several increments, as activities arrive over time
+        let mut aggregate = TrustAggregate::default();
+        let mut incremental = calculator.update_trust_score_at(&mut aggregate, &activities[..10], now - 86400).unwrap();
+        for chunk in activities[10..].chunks(7) {
+            incremental = calculator.update_trust_score_at(&mut aggregate, chunk, now).unwrap();
+        }
+
+        assert!((full.score - incremental.score).abs() < 1e-9);
+        assert!((full.confidence - incremental.confidence).abs() < 1e-12);
+        assert_eq!(full.total_activities, incremental.total_activities);
+        assert_eq!(full.polygon_verified_activities, incremental.polygon_verified_activities);
+        for (activity_type, contribution) in &full.activity_breakdown {
+            assert!((contribution - incremental.activity_breakdown[activity_type]).abs() < 1e-9);
+        }
+        assert_eq!(full_aggregate.activity_digest, aggregate.activity_digest);
+
+        // State stays one entry per activity type, and out of the score itself
+        assert_eq!(aggregate.totals.len(), 2);
+        assert!(serde_json::to_value(&incremental).unwrap().get("aggregate").is_none());
+    }
+
+    #[test]
+    fn test_expired_activities_are_excluded_and_purged() {
+        let config = TrustCalculatorConfig {
+            max_activity_age_secs: Some(30 * 86400),
+            ..TrustCalculatorConfig::default()
+        };
+        let calculator = TrustCalculator::new(config);
+        let now = current_unix_time();
+
+        let recent = create_test_activity(TrustActivityType::AuditCompletion, 0.9, false);
+        let mut ancient = create_test_activity(TrustActivityType::AuditCompletion, 0.1, true);
+        ancient.timestamp = now - 365 * 86400;
+
+        let windowed = calculator.calculate_trust_score_at(&[recent.clone(), ancient], now).unwrap();
+        let recent_only = calculator.calculate_trust_score_at(&[recent], now).unwrap();
+
+        assert!((windowed.score - recent_only.score).abs() < 1e-9);
+        assert_eq!(windowed.total_activities, 1);
+        assert_eq!(windowed.expired_activities, 1);
+        assert_eq!(windowed.polygon_verified_activities, 0);
+
+        // An activity that ages out of the window is purged from stored state
+        let mut aging = create_test_activity(TrustActivityType::AuditCompletion, 0.2, true);
+        aging.timestamp = now - 20 * 86400;
+        let recent = create_test_activity(TrustActivityType::AuditCompletion, 0.9, false);
+        let mut aggregate = TrustAggregate::default();
+        calculator.update_trust_score_at(&mut aggregate, &[recent.clone(), aging.clone()], now).unwrap();
+        assert_eq!(aggregate.expiring.len(), 2);
+
+        let later = now + 15 * 86400;
+        assert_eq!(calculator.purge_expired_at(&mut aggregate, later), 1);
+        assert_eq!(aggregate.expiring.len(), 1);
+        assert_eq!(aggregate.totals[0].1.count, 1);
+        assert_eq!(aggregate.totals[0].1.polygon_verified, 0);
+
+        let updated = calculator.update_trust_score_at(&mut aggregate, &[], later).unwrap();
+        let recalculated = calculator.calculate_trust_score_at(&[recent, aging], later).unwrap();
+        assert!((updated.score - recalculated.score).abs() < 1e-9);
+        assert!((updated.confidence - recalculated.confidence).abs() < 1e-9);
+        assert_eq!(updated.total_activities, 1);
+        assert_eq!(updated.polygon_verified_activities, 0);
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let config = TrustCalculatorConfig::default();
+        let now = current_unix_time();
+        let activities: Vec<TrustActivity> = (0..6)
+            .map(|i| {
+                let mut activity = create_test_activity(TrustActivityType::ComplianceVerification, 0.7 + i as f64 / 20.0, i % 2 == 0);
+                activity.timestamp = now - i * 86400;
+                activity
+            })
+            .collect();
+
+        let mut original = TrustCalculator::new(config.clone());
+        original.record_activities_at(&activities[..5], now).unwrap();
+        let serialized = serde_json::to_string(&original.snapshot().unwrap()).unwrap();
+
+        let mut restored = TrustCalculator::new(config.clone());
+        restored.restore(serde_json::from_str(&serialized).unwrap()).unwrap();
+        let resumed = restored.record_activities_at(&activities[5..], now).unwrap();
+
+        let from_scratch = TrustCalculator::new(config).calculate_trust_score_at(&activities, now).unwrap();
+        assert_eq!(resumed.score, from_scratch.score);
+        assert_eq!(resumed.trust_hash, from_scratch.trust_hash);
+        assert_eq!(resumed.total_activities, 6);
+    }
+
+    #[test]
+    fn test_restore_rejects_mismatched_config() {
+        let mut original = TrustCalculator::new(TrustCalculatorConfig::default());
+        original.record_activities(&[create_test_activity(TrustActivityType::AuditCompletion, 0.9, false)]).unwrap();
+        let snapshot = original.snapshot().unwrap();
+
+        let mut different = TrustCalculator::new(TrustCalculatorConfig {
+            time_decay_factor: 0.9,
+            ..TrustCalculatorConfig::default()
+        });
+        assert!(different.restore(snapshot).is_err());
+    }
+
+    #[test]
+    fn test_decay_function_curves() {
+        let exponential = DecayFunction::Exponential { half_life_days: 10.0 };
+        let linear = DecayFunction::Linear { zero_after_days: 20.0 };
+        let step = DecayFunction::Step { steps: vec![(30.0, 0.5), (90.0, 0.1)] };
+
+        assert!((exponential.factor(0.0) - 1.0).abs() < 1e-12);
+        assert!((exponential.factor(10.0) - 0.5).abs() < 1e-12);
+        assert!((exponential.factor(20.0) - 0.25).abs() < 1e-12);
+
+        assert!((linear.factor(10.0) - 0.5).abs() < 1e-12);
+        assert_eq!(linear.factor(20.0), 0.0);
+        assert_eq!(linear.factor(40.0), 0.0);
+
+        assert_eq!(step.factor(29.0), 1.0);
+        assert_eq!(step.factor(30.0), 0.5);
+        assert_eq!(step.factor(365.0), 0.1);
+
+        assert_eq!(DecayFunction::None.factor(10_000.0), 1.0);
+
+        // Exponential decays slower than linear early on but never reaches zero
+        assert!(exponential.factor(25.0) > linear.factor(25.0));
+    }
+
+    #[test]
+    fn test_per_type_decay_functions_honored() {
+        let mut config = TrustCalculatorConfig::default();
+        config.decay_functions.insert(TrustActivityType::RegulatoryApproval, DecayFunction::None);
+        config.decay_functions.insert(
+            TrustActivityType::ContinuousMonitoring,
+            DecayFunction::Linear { zero_after_days: 1.0 },
+        );
+
+        let now = current_unix_time();
+        let mut activities = vec![
+            create_test_activity(TrustActivityType::RegulatoryApproval, 0.9, false),
+            create_test_activity(TrustActivityType::ContinuousMonitoring, 0.2, false),
+        ];
+        for activity in &mut activities {
+            activity.timestamp = now - 10 * 86400;
+        }
+
+        // Exercise both the sequential and parallel paths
+        for parallel_threshold in [100, 0] {
+            let calculator = TrustCalculator::new(TrustCalculatorConfig {
+                parallel_threshold,
+                ..config.clone()
+            });
+            let score = calculator.calculate_trust_score_at(&activities, now).unwrap();
+
+            assert_eq!(score.activity_breakdown[&TrustActivityType::ContinuousMonitoring], 0.0);
+            assert!((score.score - 90.0).abs() < 1e-9);
+
+            let undecayed = calculator.weighted_activity(&activities[0]);
+            let contribution = score.activity_breakdown[&TrustActivityType::RegulatoryApproval];
+            assert!((contribution - undecayed.static_weight * 0.9).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+/// Protobuf Wire Format Module
+///
+/// prost types generated from `proto/velocity/compliance/v1/compliance.proto`,
+/// with conversions to and from the serde types for non-Rust verifier nodes.
+/// Verification rebuilds the blockchain hash from decoded fields, so a proof
+/// verifies the same after a protobuf round trip.
+
+use crate::blockchain_compliance::{
+    AttestationLevel, AuditEntry, ComplianceData, ComplianceProof, ControlResult, CrossIndustryAttestation,
+    NetworkConsensus, PartnerType, TrustedPartnerVerification,
+};
+use crate::{CryptoError, Result};
+
+/// Generated protobuf types (`velocity.compliance.v1`)
+pub mod proto {
+    include!(concat!(env!("OUT_DIR"), "/velocity.compliance.v1.rs"));
+}
+
+impl ComplianceProof {
+    pub fn to_proto(&self) -> proto::ComplianceProof {
+        proto::ComplianceProof {
+            id: self.id.clone(),
+            entity_id: self.entity_id.clone(),
+            framework_type: self.framework_type.clone(),
+            compliance_data: Some(self.compliance_data.to_proto()),
+            blockchain_hash: self.blockchain_hash.clone(),
+            merkle_root: self.merkle_root.clone(),
+            timestamp: self.timestamp,
+            verification_signature: self.verification_signature.clone(),
+            audit_chain: self.audit_chain.iter().map(AuditEntry::to_proto).collect(),
+            cross_industry_attestation: self.cross_industry_attestation.as_ref().map(CrossIndustryAttestation::to_proto),
+            network_consensus: self.network_consensus.as_ref().map(NetworkConsensus::to_proto),
+            disclosure_salt: self.disclosure_salt.clone(),
+            evidence_commitment: self.evidence_commitment.clone(),
+            hash_version: self.hash_version,
+        }
+    }
+
+    pub fn from_proto(message: proto::ComplianceProof) -> Result<Self> {
+        let compliance_data = message
+            .compliance_data
+            .ok_or_else(|| CryptoError::SerializationError("Compliance proof is missing its compliance data".to_string()))?;
+
+        Ok(ComplianceProof {
+            id: message.id,
+            entity_id: message.entity_id,
+            framework_type: message.framework_type,
+            compliance_data: ComplianceData::from_proto(compliance_data),
+            blockchain_hash: message.blockchain_hash,
+            merkle_root: message.merkle_root,
+            timestamp: message.timestamp,
+            verification_signature: message.verification_signature,
+            audit_chain: message.audit_chain.into_iter().map(AuditEntry::from_proto).collect(),
+            cross_industry_attestation: message
+                .cross_industry_attestation
+                .map(CrossIndustryAttestation::from_proto)
+                .transpose()?,
+            network_consensus: message.network_consensus.map(NetworkConsensus::from_proto),
+            disclosure_salt: message.disclosure_salt,
+            evidence_commitment: message.evidence_commitment,
+            hash_version: message.hash_version,
+        })
+    }
+}
+
+impl ComplianceData {
+    pub fn to_proto(&self) -> proto::ComplianceData {
+        proto::ComplianceData {
+            framework: self.framework.clone(),
+            version: self.version.clone(),
+            controls_assessed: self.controls_assessed,
+            controls_passed: self.controls_passed,
+            compliance_score: self.compliance_score,
+            evidence_count: self.evidence_count,
+            assessment_date: self.assessment_date,
+            valid_until: self.valid_until,
+            assessor: self.assessor.clone(),
+            metadata: self.metadata.clone(),
+            controls: self
+                .controls
+                .iter()
+                .map(|(control_id, result)| (control_id.clone(), result.to_proto()))
+                .collect(),
+        }
+    }
+
+    pub fn from_proto(message: proto::ComplianceData) -> Self {
+        ComplianceData {
+            framework: message.framework,
+            version: message.version,
+            controls_assessed: message.controls_assessed,
+            controls_passed: message.controls_passed,
+            compliance_score: message.compliance_score,
+            evidence_count: message.evidence_count,
+            assessment_date: message.assessment_date,
+            valid_until: message.valid_until,
+            assessor: message.assessor,
+            metadata: message.metadata,
+            controls: message
+                .controls
+                .into_iter()
+                .map(|(control_id, result)| (control_id, ControlResult::from_proto(result)))
+                .collect(),
+        }
+    }
+}
+
+impl ControlResult {
+    pub fn to_proto(&self) -> proto::ControlResult {
+        proto::ControlResult {
+            passed: self.passed,
+            derived_from: self.derived_from.clone(),
+        }
+    }
+
+    pub fn from_proto(message: proto::ControlResult) -> Self {
+        ControlResult {
+            passed: message.passed,
+            derived_from: message.derived_from,
+        }
+    }
+}
+
+impl AuditEntry {
+    pub fn to_proto(&self) -> proto::AuditEntry {
+        proto::AuditEntry {
+            timestamp: self.timestamp,
+            action: self.action.clone(),
+            actor: self.actor.clone(),
+            details: self.details.clone(),
+            hash: self.hash.clone(),
+            previous_hash: self.previous_hash.clone(),
+            signature: self.signature.clone(),
+        }
+    }
+
+    pub fn from_proto(message: proto::AuditEntry) -> Self {
+        AuditEntry {
+            timestamp: message.timestamp,
+            action: message.action,
+            actor: message.actor,
+            details: message.details,
+            hash: message.hash,
+            previous_hash: message.previous_hash,
+            signature: message.signature,
+        }
+    }
+}
+
+impl CrossIndustryAttestation {
+    pub fn to_proto(&self) -> proto::CrossIndustryAttestation {
+        proto::CrossIndustryAttestation {
+            industry_type: self.industry_type.clone(),
+            regulatory_body: self.regulatory_body.clone(),
+            attestation_level: attestation_level_to_proto(&self.attestation_level) as i32,
+            cross_validated_frameworks: self.cross_validated_frameworks.clone(),
+            trusted_partner_verifications: self
+                .trusted_partner_verifications
+                .iter()
+                .map(TrustedPartnerVerification::to_proto)
+                .collect(),
+            network_hash: self.network_hash.clone(),
+        }
+    }
+
+    pub fn from_proto(message: proto::CrossIndustryAttestation) -> Result<Self> {
+        Ok(CrossIndustryAttestation {
+            industry_type: message.industry_type,
+            regulatory_body: message.regulatory_body,
+            attestation_level: attestation_level_from_proto(message.attestation_level)?,
+            cross_validated_frameworks: message.cross_validated_frameworks,
+            trusted_partner_verifications: message
+                .trusted_partner_verifications
+                .into_iter()
+                .map(TrustedPartnerVerification::from_proto)
+                .collect::<Result<_>>()?,
+            network_hash: message.network_hash,
+        })
+    }
+}
+
+impl TrustedPartnerVerification {
+    pub fn to_proto(&self) -> proto::TrustedPartnerVerification {
+        proto::TrustedPartnerVerification {
+            partner_id: self.partner_id.clone(),
+            partner_name: self.partner_name.clone(),
+            partner_type: partner_type_to_proto(&self.partner_type) as i32,
+            verification_hash: self.verification_hash.clone(),
+            verification_timestamp: self.verification_timestamp,
+            digital_signature: self.digital_signature.clone(),
+            public_key: self.public_key.clone(),
+        }
+    }
+
+    pub fn from_proto(message: proto::TrustedPartnerVerification) -> Result<Self> {
+        Ok(TrustedPartnerVerification {
+            partner_id: message.partner_id,
+            partner_name: message.partner_name,
+            partner_type: partner_type_from_proto(message.partner_type)?,
+            verification_hash: message.verification_hash,
+            verification_timestamp: message.verification_timestamp,
+            digital_signature: message.digital_signature,
+            public_key: message.public_key,
+        })
+    }
+}
+
+impl NetworkConsensus {
+    pub fn to_proto(&self) -> proto::NetworkConsensus {
+        proto::NetworkConsensus {
+            participant_count: self.participant_count,
+            consensus_threshold: self.consensus_threshold,
+            consensus_reached: self.consensus_reached,
+            consensus_hash: self.consensus_hash.clone(),
+            participant_signatures: self.participant_signatures.clone(),
+            consensus_timestamp: self.consensus_timestamp,
+            consensus_proof: self.consensus_proof.clone(),
+        }
+    }
+
+    pub fn from_proto(message: proto::NetworkConsensus) -> Self {
+        NetworkConsensus {
+            participant_count: message.participant_count,
+            consensus_threshold: message.consensus_threshold,
+            consensus_reached: message.consensus_reached,
+            consensus_hash: message.consensus_hash,
+            participant_signatures: message.participant_signatures,
+            consensus_timestamp: message.consensus_timestamp,
+            consensus_proof: message.consensus_proof,
+        }
+    }
+}
+
+fn attestation_level_to_proto(level: &AttestationLevel) -> proto::AttestationLevel {
+    match level {
+        AttestationLevel::Bronze => proto::AttestationLevel::Bronze,
+        AttestationLevel::Silver => proto::AttestationLevel::Silver,
+        AttestationLevel::Gold => proto::AttestationLevel::Gold,
+        AttestationLevel::Platinum => proto::AttestationLevel::Platinum,
+    }
+}
+
+fn attestation_level_from_proto(value: i32) -> Result<AttestationLevel> {
+    match proto::AttestationLevel::try_from(value) {
+        Ok(proto::AttestationLevel::Bronze) => Ok(AttestationLevel::Bronze),
+        Ok(proto::AttestationLevel::Silver) => Ok(AttestationLevel::Silver),
+        Ok(proto::AttestationLevel::Gold) => Ok(AttestationLevel::Gold),
+        Ok(proto::AttestationLevel::Platinum) => Ok(AttestationLevel::Platinum),
+        Ok(proto::AttestationLevel::Unspecified) | Err(_) => {
+            Err(CryptoError::SerializationError(format!("Invalid attestation level {}", value)))
+        }
+    }
+}
+
+fn partner_type_to_proto(partner_type: &PartnerType) -> proto::PartnerType {
+    match partner_type {
+        PartnerType::AuditFirm => proto::PartnerType::AuditFirm,
+        PartnerType::RegulatoryBody => proto::PartnerType::RegulatoryBody,
+        PartnerType::IndustryAssociation => proto::PartnerType::IndustryAssociation,
+        PartnerType::CertifiedAssessor => proto::PartnerType::CertifiedAssessor,
+    }
+}
+
+fn partner_type_from_proto(value: i32) -> Result<PartnerType> {
+    match proto::PartnerType::try_from(value) {
+        Ok(proto::PartnerType::AuditFirm) => Ok(PartnerType::AuditFirm),
+        Ok(proto::PartnerType::RegulatoryBody) => Ok(PartnerType::RegulatoryBody),
+        Ok(proto::PartnerType::IndustryAssociation) => Ok(PartnerType::IndustryAssociation),
+        Ok(proto::PartnerType::CertifiedAssessor) => Ok(PartnerType::CertifiedAssessor),
+        Ok(proto::PartnerType::Unspecified) | Err(_) => {
+            Err(CryptoError::SerializationError(format!("Invalid partner type {}", value)))
+        }
+    }
+}
@@ -0,0 +1,248 @@
+/// Zero-Knowledge Proofs over Compliance Data
+///
+/// Pedersen commitments combined with Bulletproofs range proofs let an auditor
+/// check threshold statements about committed values (such as the evidence count
+/// of a compliance proof) without learning the values themselves.
+
+use crate::{CryptoError, Result};
+use crate::blockchain_compliance::{ComplianceData, ComplianceProof};
+use bulletproofs::{BulletproofGens, PedersenGens, RangeProof};
+use curve25519_dalek_ng::ristretto::CompressedRistretto;
+use curve25519_dalek_ng::scalar::Scalar;
+use merlin::Transcript;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+
+/// Bit width of the range proven for `evidence_count - minimum`
+pub const EVIDENCE_COUNT_BITS: usize = 32;
+
+const EVIDENCE_SUFFICIENCY_DOMAIN: &[u8] = b"velocity-zk-evidence-sufficiency-v1";
+
+/// Public commitment to the evidence count of a compliance proof
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvidenceCountCommitment {
+    /// Compliance proof the commitment belongs to
+    pub proof_id: String,
+    /// Compressed Ristretto point, hex encoded
+    pub commitment: String,
+}
+
+impl EvidenceCountCommitment {
+    /// Commitment signed into `proof`, or `None` if the proof predates them
+    pub fn of_proof(proof: &ComplianceProof) -> Option<Self> {
+        if proof.evidence_commitment.is_empty() {
+            return None;
+        }
+        Some(EvidenceCountCommitment {
+            proof_id: proof.id.clone(),
+            commitment: proof.evidence_commitment.clone(),
+        })
+    }
+}
+
+/// Secret opening of an evidence count commitment, kept by the prover
+#[derive(Debug, Clone)]
+pub struct EvidenceCountOpening {
+    pub evidence_count: u32,
+    blinding: Scalar,
+}
+
+/// Proof that a committed evidence count is at least `minimum`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvidenceSufficiencyProof {
+    /// Compliance proof the statement is bound to
+    pub proof_id: String,
+    /// Required minimum evidence count
+    pub minimum: u32,
+    /// Serialized Bulletproofs range proof, hex encoded
+    pub range_proof: String,
+}
+
+/// Commit to `compliance_data.evidence_count` for the compliance proof `proof_id`
+pub fn commit_evidence_count(
+    proof_id: &str,
+    compliance_data: &ComplianceData,
+) -> (EvidenceCountCommitment, EvidenceCountOpening) {
+    let (commitment, opening) = commit_count(compliance_data.evidence_count);
+    let commitment = EvidenceCountCommitment {
+        proof_id: proof_id.to_string(),
+        commitment,
+    };
+    (commitment, opening)
+}
+
+/// Hex-encoded commitment to `evidence_count` under a fresh blinding factor
+pub(crate) fn commit_count(evidence_count: u32) -> (String, EvidenceCountOpening) {
+    let blinding = Scalar::random(&mut OsRng);
+    let point = PedersenGens::default()
+        .commit(Scalar::from(evidence_count as u64), blinding)
+        .compress();
+    (hex::encode(point.as_bytes()), EvidenceCountOpening { evidence_count, blinding })
+}
+
+/// Prove that the committed evidence count is at least `minimum`
+///
+/// The range proof shows `evidence_count - minimum` lies in `[0, 2^32)` under the
+/// commitment shifted by `minimum`. The transcript binds the proof id, commitment
+/// and minimum, so the proof cannot be replayed against another entity's commitment.
+pub fn prove_evidence_sufficient(
+    commitment: &EvidenceCountCommitment,
+    opening: &EvidenceCountOpening,
+    minimum: u32,
+) -> Result<EvidenceSufficiencyProof> {
+    if opening.evidence_count < minimum {
+        return Err(CryptoError::InvalidInput(format!(
+            "Evidence count {} is below the required minimum {}",
+            opening.evidence_count, minimum
+        )));
+    }
+
+    let pc_gens = PedersenGens::default();
+    let expected = pc_gens
+        .commit(Scalar::from(opening.evidence_count as u64), opening.blinding)
+        .compress();
+    if decode_commitment(commitment)? != expected {
+        return Err(CryptoError::InvalidInput(
+            "Opening does not match the evidence count commitment".to_string(),
+        ));
+    }
+
+    let bp_gens = BulletproofGens::new(EVIDENCE_COUNT_BITS, 1);
+    let mut transcript = evidence_transcript(commitment, minimum);
+    let (range_proof, _) = RangeProof::prove_single(
+        &bp_gens,
+        &pc_gens,
+        &mut transcript,
+        (opening.evidence_count - minimum) as u64,
+        &opening.blinding,
+        EVIDENCE_COUNT_BITS,
+    )
+    .map_err(|e| CryptoError::CryptoOperationFailed(format!("Range proof generation failed: {}", e)))?;
+
+    Ok(EvidenceSufficiencyProof {
+        proof_id: commitment.proof_id.clone(),
+        minimum,
+        range_proof: hex::encode(range_proof.to_bytes()),
+    })
+}
+
+/// Verify that `commitment` hides an evidence count of at least `minimum`
+pub fn verify_evidence_sufficient(
+    commitment: &EvidenceCountCommitment,
+    proof: &EvidenceSufficiencyProof,
+    minimum: u32,
+) -> bool {
+    if proof.proof_id != commitment.proof_id || proof.minimum != minimum {
+        return false;
+    }
+
+    let point = match decode_commitment(commitment).ok().and_then(|c| c.decompress()) {
+        Some(point) => point,
+        None => return false,
+    };
+    let range_proof = match hex::decode(&proof.range_proof)
+        .ok()
+        .and_then(|bytes| RangeProof::from_bytes(&bytes).ok())
+    {
+        Some(range_proof) => range_proof,
+        None => return false,
+    };
+
+    let pc_gens = PedersenGens::default();
+    let shifted = (point - Scalar::from(minimum as u64) * pc_gens.B).compress();
+    let bp_gens = BulletproofGens::new(EVIDENCE_COUNT_BITS, 1);
+    let mut transcript = evidence_transcript(commitment, minimum);
+
+    range_proof
+        .verify_single(&bp_gens, &pc_gens, &mut transcript, &shifted, EVIDENCE_COUNT_BITS)
+        .is_ok()
+}
+
+fn evidence_transcript(commitment: &EvidenceCountCommitment, minimum: u32) -> Transcript {
+    let mut transcript = Transcript::new(EVIDENCE_SUFFICIENCY_DOMAIN);
+    transcript.append_message(b"proof_id", commitment.proof_id.as_bytes());
+    transcript.append_message(b"commitment", commitment.commitment.as_bytes());
+    transcript.append_u64(b"minimum", minimum as u64);
+    transcript
+}
+
+fn decode_commitment(commitment: &EvidenceCountCommitment) -> Result<CompressedRistretto> {
+    let bytes = hex::decode(&commitment.commitment)
+        .map_err(|e| CryptoError::InvalidInput(format!("Invalid commitment encoding: {}", e)))?;
+    if bytes.len() != 32 {
+        return Err(CryptoError::InvalidInput("Commitment must be 32 bytes".to_string()));
+    }
+    Ok(CompressedRistretto::from_slice(&bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn compliance_data(evidence_count: u32) -> ComplianceData {
+        ComplianceData {
+            framework: "SOC2".to_string(),
+            version: "2017".to_string(),
+            controls_assessed: 64,
+            controls_passed: 61,
+            compliance_score: 95.3,
+            evidence_count,
+            assessment_date: 1_700_000_000,
+            valid_until: 1_731_536_000,
+            assessor: "External Auditor".to_string(),
+            metadata: HashMap::new(),
+            controls: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_evidence_sufficiency_round_trip() {
+        let (commitment, opening) = commit_evidence_count("proof-entity-a", &compliance_data(342));
+
+        let proof = prove_evidence_sufficient(&commitment, &opening, 100).unwrap();
+        assert!(verify_evidence_sufficient(&commitment, &proof, 100));
+
+        let exact = prove_evidence_sufficient(&commitment, &opening, 342).unwrap();
+        assert!(verify_evidence_sufficient(&commitment, &exact, 342));
+
+        // A proof for one threshold does not establish a higher one
+        assert!(!verify_evidence_sufficient(&commitment, &proof, 343));
+    }
+
+    #[test]
+    fn test_insufficient_evidence_cannot_be_proven() {
+        let (commitment, opening) = commit_evidence_count("proof-entity-a", &compliance_data(12));
+        assert!(prove_evidence_sufficient(&commitment, &opening, 13).is_err());
+
+        // Forging the claimed minimum on a valid proof is rejected
+        let mut proof = prove_evidence_sufficient(&commitment, &opening, 12).unwrap();
+        proof.minimum = 50;
+        assert!(!verify_evidence_sufficient(&commitment, &proof, 50));
+    }
+
+    #[test]
+    fn test_proof_bound_to_proof_id_commitment() {
+        let (commitment_a, opening_a) = commit_evidence_count("proof-entity-a", &compliance_data(500));
+        let (commitment_b, _) = commit_evidence_count("proof-entity-b", &compliance_data(500));
+
+        let proof = prove_evidence_sufficient(&commitment_a, &opening_a, 200).unwrap();
+        assert!(verify_evidence_sufficient(&commitment_a, &proof, 200));
+
+        // Replaying against another entity's commitment fails, even with the id rewritten
+        assert!(!verify_evidence_sufficient(&commitment_b, &proof, 200));
+        let mut replayed = proof.clone();
+        replayed.proof_id = commitment_b.proof_id.clone();
+        assert!(!verify_evidence_sufficient(&commitment_b, &replayed, 200));
+
+        // Re-labelling entity A's commitment with another proof id also fails
+        let relabelled = EvidenceCountCommitment {
+            proof_id: "proof-entity-b".to_string(),
+            commitment: commitment_a.commitment.clone(),
+        };
+        assert!(!verify_evidence_sufficient(&relabelled, &replayed, 200));
+
+        // An opening cannot be used to prove against a different commitment
+        assert!(prove_evidence_sufficient(&commitment_b, &opening_a, 200).is_err());
+    }
+}
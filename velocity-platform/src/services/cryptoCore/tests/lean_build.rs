@@ -0,0 +1,28 @@
+/// Build check for the lean WASM configuration
+///
+/// `--no-default-features` drops rayon, num_cpus and ring. This compiles the
+/// library in that configuration so a stray unconditional use of any of them
+/// fails the test suite rather than the browser bundle.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn no_default_features_build_compiles() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // A separate target dir avoids waiting on the lock held by this test run
+    let target_dir = manifest_dir.join("target").join("lean-build-check");
+
+    let output = Command::new(env!("CARGO"))
+        .current_dir(manifest_dir)
+        .args(["check", "--lib", "--no-default-features"])
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .output()
+        .expect("failed to run cargo");
+
+    assert!(
+        output.status.success(),
+        "--no-default-features build failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
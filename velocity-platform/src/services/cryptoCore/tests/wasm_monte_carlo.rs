@@ -0,0 +1,74 @@
+#![cfg(target_arch = "wasm32")]
+
+/// Browser tests for the Monte Carlo WASM bindings
+///
+/// Run with `wasm-pack test --node` (or `--headless --chrome`).
+
+use velocity_crypto_core::ffi::wasm::wasm_simulate_compliance_risk;
+use wasm_bindgen_test::*;
+
+const SCENARIO: &str = r#"{
+    "name": "Browser Dashboard Scenario",
+    "compliance_factors": [
+        {
+            "name": "Documentation Quality",
+            "base_value": 0.8,
+            "distribution": { "Beta": { "alpha": 8.0, "beta": 2.0 } },
+            "weight": 0.5,
+            "correlation_factors": [["regulatory_stringency", -0.2]]
+        },
+        {
+            "name": "Process Maturity",
+            "base_value": 0.7,
+            "distribution": { "Normal": { "mean": 0.7, "std_dev": 0.1 } },
+            "weight": 0.5,
+            "correlation_factors": []
+        }
+    ],
+    "market_conditions": {
+        "volatility": { "Beta": { "alpha": 2.0, "beta": 5.0 } },
+        "growth_rate": { "Normal": { "mean": 0.05, "std_dev": 0.02 } },
+        "competition_intensity": { "Uniform": { "min": 0.3, "max": 0.7 } }
+    },
+    "regulatory_environment": {
+        "stringency": { "Beta": { "alpha": 5.0, "beta": 3.0 } },
+        "change_frequency": { "Uniform": { "min": 0.1, "max": 0.3 } },
+        "enforcement_probability": { "Beta": { "alpha": 2.0, "beta": 8.0 } }
+    },
+    "polygon_verification_rate": 0.7
+}"#;
+
+#[wasm_bindgen_test]
+fn small_scenario_returns_sensible_statistics() {
+    // Above the default parallel_threshold, so this also covers the sequential fallback
+    let config = r#"{"iterations": 2000, "seed": 42}"#;
+    let json = wasm_simulate_compliance_risk(SCENARIO, config).unwrap();
+    let result: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(result["scenario_name"], "Browser Dashboard Scenario");
+    assert_eq!(result["iterations"], 2000);
+
+    let stats = &result["compliance_statistics"];
+    let mean = stats["mean"].as_f64().unwrap();
+    assert!(mean > 0.5 && mean < 1.0);
+    assert!(stats["std_dev"].as_f64().unwrap() > 0.0);
+    assert!(stats["min"].as_f64().unwrap() <= stats["median"].as_f64().unwrap());
+    assert!(stats["median"].as_f64().unwrap() <= stats["max"].as_f64().unwrap());
+
+    let intervals = result["confidence_intervals"].as_array().unwrap();
+    assert_eq!(intervals.len(), 2);
+    for interval in intervals {
+        assert!(interval["lower_bound"].as_f64().unwrap() <= interval["upper_bound"].as_f64().unwrap());
+    }
+    let polygon_rate = result["polygon_verification_rate"].as_f64().unwrap();
+    assert!(polygon_rate > 0.6 && polygon_rate < 0.8);
+
+    // Seeded runs are reproducible
+    assert_eq!(wasm_simulate_compliance_risk(SCENARIO, config).unwrap(), json);
+}
+
+#[wasm_bindgen_test]
+fn malformed_input_is_rejected() {
+    assert!(wasm_simulate_compliance_risk("{not json", "{}").is_err());
+    assert!(wasm_simulate_compliance_risk(SCENARIO, r#"{"iterations": "many"}"#).is_err());
+}
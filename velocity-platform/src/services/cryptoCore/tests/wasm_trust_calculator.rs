@@ -0,0 +1,100 @@
+#![cfg(target_arch = "wasm32")]
+
+/// Browser tests for the TrustCalculator WASM bindings
+///
+/// Run with `wasm-pack test --node` (or `--headless --chrome`).
+
+use velocity_crypto_core::ffi::wasm::WasmTrustCalculator;
+use wasm_bindgen_test::*;
+
+const NOW: u64 = 1_700_000_000;
+const DAY: u64 = 86_400;
+
+/// 90-day window with a 30-day half-life for expert validations
+const CONFIG: &str = r#"{
+    "max_activity_age_secs": 7776000,
+    "decay_functions": { "ExpertValidation": { "Exponential": { "half_life_days": 30.0 } } }
+}"#;
+
+/// Exponential decay only, which running totals can carry forward exactly
+const INCREMENTAL_CONFIG: &str = r#"{
+    "decay_functions": { "ExpertValidation": { "Exponential": { "half_life_days": 30.0 } } }
+}"#;
+
+fn activity(activity_type: &str, age_days: u64, value: f64, confidence: f64, reputation: f64, polygon_tx_hash: Option<&str>) -> serde_json::Value {
+    serde_json::json!({
+        "activity_type": activity_type,
+        "timestamp": NOW - age_days * DAY,
+        "value": value,
+        "confidence": confidence,
+        "verifier_reputation": reputation,
+        "polygon_tx_hash": polygon_tx_hash,
+        "metadata": {}
+    })
+}
+
+fn score_of(json: &str) -> serde_json::Value {
+    serde_json::from_str(json).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn fixed_now_gives_known_score() {
+    let calculator = WasmTrustCalculator::new(CONFIG).unwrap();
+    let activities = serde_json::json!([
+        activity("RegulatoryApproval", 1, 0.9, 0.95, 0.8, Some("0xabc")),
+        activity("ExpertValidation", 30, 0.8, 0.9, 0.7, None),
+        // Outside the 90-day window
+        activity("ComplianceVerification", 200, 0.5, 0.9, 0.9, None),
+    ])
+    .to_string();
+
+    let json = calculator.calculate_trust_score(&activities, NOW).unwrap();
+    let score = score_of(&json);
+    assert!((score["score"].as_f64().unwrap() - 88.31909990511048).abs() < 1e-9);
+    assert!((score["confidence"].as_f64().unwrap() - 0.745).abs() < 1e-12);
+    assert_eq!(score["total_activities"], 2);
+    assert_eq!(score["expired_activities"], 1);
+    assert_eq!(score["polygon_verified_activities"], 1);
+    assert_eq!(score["calculation_timestamp"], NOW);
+
+    // Same inputs and `now` give an identical score, hash included
+    assert_eq!(calculator.calculate_trust_score(&activities, NOW).unwrap(), json);
+
+    // An activity window cannot be kept in running totals
+    let mut windowed = WasmTrustCalculator::new(CONFIG).unwrap();
+    assert!(windowed.record_activities(&activities, NOW).is_err());
+}
+
+#[wasm_bindgen_test]
+fn recorded_activities_match_full_recalculation() {
+    let earlier = serde_json::json!([
+        activity("RegulatoryApproval", 1, 0.9, 0.95, 0.8, Some("0xabc")),
+        activity("ExpertValidation", 30, 0.8, 0.9, 0.7, None),
+    ]);
+    let audit = activity("AuditCompletion", 0, 0.95, 1.0, 0.9, None);
+    let mut all = earlier.as_array().unwrap().clone();
+    all.push(audit.clone());
+
+    let mut calculator = WasmTrustCalculator::new(INCREMENTAL_CONFIG).unwrap();
+    calculator.record_activities(&earlier.to_string(), NOW - DAY).unwrap();
+
+    // Resuming from a snapshot continues the same running state
+    let mut resumed = WasmTrustCalculator::new(INCREMENTAL_CONFIG).unwrap();
+    resumed.restore(&calculator.snapshot().unwrap()).unwrap();
+    let updated = score_of(&resumed.record_activities(&serde_json::json!([audit]).to_string(), NOW).unwrap());
+
+    let full = score_of(&calculator.calculate_trust_score(&serde_json::Value::Array(all).to_string(), NOW).unwrap());
+    assert!((updated["score"].as_f64().unwrap() - full["score"].as_f64().unwrap()).abs() < 1e-9);
+    assert_eq!(updated["total_activities"], 3);
+    assert!(updated.get("aggregate").is_none());
+}
+
+#[wasm_bindgen_test]
+fn malformed_input_is_rejected() {
+    assert!(WasmTrustCalculator::new(r#"{"time_decay_factor": "fast"}"#).is_err());
+
+    let mut calculator = WasmTrustCalculator::new("{}").unwrap();
+    assert!(calculator.calculate_trust_score("[{not json", NOW).is_err());
+    assert!(calculator.record_activities("[{not json", NOW).is_err());
+    assert!(calculator.restore("{}").is_err());
+}